@@ -0,0 +1,274 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// Maximum number of digits that can be represented by [`Decimal`]
+pub const DECIMAL_MAX_PRECISION: u32 = 38;
+
+/// Minimum number of fractional digits in the result of decimal division
+pub const DECIMAL_DIVISION_MIN_SCALE: u32 = 6;
+
+/// Exact decimal number represented as unscaled integer and scale,
+/// for example `12.345` is represented as value `12345` with scale `3`
+#[derive(Clone, Copy)]
+pub struct Decimal {
+    pub value: i128,
+    pub scale: u32,
+}
+
+impl Decimal {
+    pub fn new(value: i128, scale: u32) -> Self {
+        Decimal { value, scale }
+    }
+
+    pub fn from_int(value: i64) -> Self {
+        Decimal {
+            value: value as i128,
+            scale: 0,
+        }
+    }
+
+    /// Parse decimal from string like `-12.345`, or None if it's not valid or out of range
+    pub fn parse(input: &str) -> Option<Decimal> {
+        let input = input.trim();
+        let (is_negative, digits) = match input.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, input.strip_prefix('+').unwrap_or(input)),
+        };
+
+        let (integer_part, fraction_part) = digits.split_once('.').unwrap_or((digits, ""));
+        if integer_part.is_empty() && fraction_part.is_empty() {
+            return None;
+        }
+
+        let mut value: i128 = 0;
+        for c in integer_part.chars().chain(fraction_part.chars()) {
+            let digit = c.to_digit(10)? as i128;
+            value = value.checked_mul(10)?.checked_add(digit)?;
+        }
+
+        let decimal = Decimal {
+            value: if is_negative { -value } else { value },
+            scale: fraction_part.len() as u32,
+        };
+
+        if decimal.scale > DECIMAL_MAX_PRECISION {
+            return decimal.rescale(DECIMAL_MAX_PRECISION);
+        }
+
+        Some(decimal)
+    }
+
+    /// Convert float to the shortest decimal that represents it, or None if it's not finite
+    pub fn from_f64(value: f64) -> Option<Decimal> {
+        if !value.is_finite() {
+            return None;
+        }
+        Decimal::parse(&value.to_string())
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse::<f64>().unwrap_or_default()
+    }
+
+    /// Round to integer and return it, or None if it's out of the integer range
+    pub fn to_i64(&self) -> Option<i64> {
+        let rounded = self.rescale(0)?;
+        i64::try_from(rounded.value).ok()
+    }
+
+    /// Change the scale of the number, the value is rounded half away from zero if the scale is reduced
+    /// Return None if the result is out of range
+    pub fn rescale(&self, scale: u32) -> Option<Decimal> {
+        if scale >= self.scale {
+            let factor = power_of_ten(scale - self.scale)?;
+            return Some(Decimal {
+                value: self.value.checked_mul(factor)?,
+                scale,
+            });
+        }
+
+        let factor = power_of_ten(self.scale - scale)?;
+        Some(Decimal {
+            value: divide_and_round(self.value, factor),
+            scale,
+        })
+    }
+
+    /// Remove the trailing fractional zeros, so equal numbers like `1.0` and `1.00` have the same representation
+    pub fn normalize(&self) -> Decimal {
+        let mut decimal = *self;
+        while decimal.scale > 0 && decimal.value % 10 == 0 {
+            decimal.value /= 10;
+            decimal.scale -= 1;
+        }
+        decimal
+    }
+
+    /// Return true if the number can be represented with precision digits and scale fractional digits
+    pub fn fits_precision(&self, precision: u32, scale: u32) -> bool {
+        match (self.rescale(scale), power_of_ten(precision)) {
+            (Some(rescaled), Some(limit)) => rescaled.value.abs() < limit,
+            (Some(_), None) => true,
+            _ => false,
+        }
+    }
+
+    pub fn add(&self, other: &Decimal) -> Result<Decimal, String> {
+        let scale = add_result_scale(self.scale, other.scale);
+        let (lhs, rhs) = align_scales(self, other, scale)?;
+        let value = lhs.checked_add(rhs).ok_or_else(out_of_range_error)?;
+        Ok(Decimal { value, scale })
+    }
+
+    pub fn sub(&self, other: &Decimal) -> Result<Decimal, String> {
+        let scale = add_result_scale(self.scale, other.scale);
+        let (lhs, rhs) = align_scales(self, other, scale)?;
+        let value = lhs.checked_sub(rhs).ok_or_else(out_of_range_error)?;
+        Ok(Decimal { value, scale })
+    }
+
+    pub fn mul(&self, other: &Decimal) -> Result<Decimal, String> {
+        let value = self
+            .value
+            .checked_mul(other.value)
+            .ok_or_else(out_of_range_error)?;
+        let product = Decimal {
+            value,
+            scale: self.scale + other.scale,
+        };
+
+        product
+            .rescale(mul_result_scale(self.scale, other.scale))
+            .ok_or_else(out_of_range_error)
+    }
+
+    pub fn div(&self, other: &Decimal) -> Result<Decimal, String> {
+        if other.value == 0 {
+            return Err("Can't perform `/` operator with 0 value".to_string());
+        }
+
+        // Scale the dividend so the quotient has the result scale
+        let scale = div_result_scale(self.scale, other.scale);
+        let factor =
+            power_of_ten(scale + other.scale - self.scale).ok_or_else(out_of_range_error)?;
+        let dividend = self
+            .value
+            .checked_mul(factor)
+            .ok_or_else(out_of_range_error)?;
+
+        if dividend == i128::MIN && other.value == -1 {
+            return Err(out_of_range_error());
+        }
+
+        Ok(Decimal {
+            value: divide_and_round(dividend, other.value),
+            scale,
+        })
+    }
+
+    pub fn rem(&self, other: &Decimal) -> Result<Decimal, String> {
+        if other.value == 0 {
+            return Err("Can't perform `%` operator with 0 value".to_string());
+        }
+
+        let scale = add_result_scale(self.scale, other.scale);
+        let (lhs, rhs) = align_scales(self, other, scale)?;
+        Ok(Decimal {
+            value: lhs % rhs,
+            scale,
+        })
+    }
+
+    pub fn neg(&self) -> Result<Decimal, String> {
+        let value = self.value.checked_neg().ok_or_else(out_of_range_error)?;
+        Ok(Decimal {
+            value,
+            scale: self.scale,
+        })
+    }
+}
+
+/// Scale of the result of `+`, `-` and `%` operators
+pub fn add_result_scale(lhs_scale: u32, rhs_scale: u32) -> u32 {
+    lhs_scale.max(rhs_scale)
+}
+
+/// Scale of the result of `*` operator
+pub fn mul_result_scale(lhs_scale: u32, rhs_scale: u32) -> u32 {
+    (lhs_scale + rhs_scale).min(DECIMAL_MAX_PRECISION)
+}
+
+/// Scale of the result of `/` operator
+pub fn div_result_scale(lhs_scale: u32, rhs_scale: u32) -> u32 {
+    lhs_scale.max(rhs_scale).max(DECIMAL_DIVISION_MIN_SCALE)
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        match align_scales(self, other, scale) {
+            Ok((lhs, rhs)) => lhs.cmp(&rhs),
+            Err(_) => self.to_f64().total_cmp(&other.to_f64()),
+        }
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let digits = self.value.unsigned_abs().to_string();
+        let sign = if self.value < 0 { "-" } else { "" };
+        let scale = self.scale as usize;
+        if scale == 0 {
+            return write!(f, "{}{}", sign, digits);
+        }
+
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (integer_part, fraction_part) = digits.split_at(digits.len() - scale);
+        write!(f, "{}{}.{}", sign, integer_part, fraction_part)
+    }
+}
+
+fn power_of_ten(exponent: u32) -> Option<i128> {
+    10_i128.checked_pow(exponent)
+}
+
+fn align_scales(lhs: &Decimal, rhs: &Decimal, scale: u32) -> Result<(i128, i128), String> {
+    match (lhs.rescale(scale), rhs.rescale(scale)) {
+        (Some(lhs), Some(rhs)) => Ok((lhs.value, rhs.value)),
+        _ => Err(out_of_range_error()),
+    }
+}
+
+/// Integer division rounded half away from zero
+fn divide_and_round(dividend: i128, divisor: i128) -> i128 {
+    let quotient = dividend / divisor;
+    let remainder = (dividend % divisor).unsigned_abs();
+    if remainder >= divisor.unsigned_abs() - remainder {
+        if (dividend < 0) == (divisor < 0) {
+            quotient + 1
+        } else {
+            quotient - 1
+        }
+    } else {
+        quotient
+    }
+}
+
+fn out_of_range_error() -> String {
+    "Decimal value out of range".to_string()
+}
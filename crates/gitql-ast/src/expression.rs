@@ -11,11 +11,14 @@ use super::types::DataType;
 
 use crate::interval::Interval;
 use crate::operator::ArithmeticOperator;
+use crate::operator::ArrowOperator;
 use crate::operator::BinaryBitwiseOperator;
 use crate::operator::BinaryLogicalOperator;
 use crate::operator::ComparisonOperator;
 use crate::operator::GroupComparisonOperator;
 use crate::operator::PrefixUnaryOperator;
+use crate::types::composite::CompositeType;
+use crate::types::datetimetz::DateTimeTzType;
 use crate::types::float::FloatType;
 use crate::types::interval::IntervalType;
 
@@ -25,13 +28,16 @@ pub enum ExprKind {
     String,
     Symbol,
     Array,
+    Row,
     GlobalVariable,
+    Placeholder,
     Number,
     Boolean,
     Interval,
     PrefixUnary,
     Index,
     Slice,
+    Arrow,
     Arithmetic,
     Comparison,
     GroupComparison,
@@ -50,8 +56,11 @@ pub enum ExprKind {
     IsNull,
     Null,
     Cast,
+    TryCast,
+    AtTimeZone,
     Grouping,
     MemberAccess,
+    Lambda,
 }
 
 dyn_clone::clone_trait_object!(Expr);
@@ -158,6 +167,27 @@ impl Expr for ArrayExpr {
     }
 }
 
+/// Row constructor `ROW(value, ...)` with anonymous record type
+#[derive(Clone)]
+pub struct RowExpr {
+    pub values: Vec<Box<dyn Expr>>,
+    pub row_type: CompositeType,
+}
+
+impl Expr for RowExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::Row
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        Box::new(self.row_type.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Clone)]
 pub struct GlobalVariableExpr {
     pub name: String,
@@ -178,6 +208,27 @@ impl Expr for GlobalVariableExpr {
     }
 }
 
+/// Query parameter placeholder, positional `$1` or named `:name`, its value is bound before execution
+#[derive(Clone)]
+pub struct PlaceholderExpr {
+    pub name: String,
+    pub result_type: Box<dyn DataType>,
+}
+
+impl Expr for PlaceholderExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::Placeholder
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        self.result_type.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub enum Number {
     Int(i64),
@@ -293,6 +344,29 @@ impl Expr for IndexExpr {
     }
 }
 
+/// Access member of value using `->` or `->>` operators, for example `json -> 'key'`
+#[derive(Clone)]
+pub struct ArrowExpr {
+    pub left: Box<dyn Expr>,
+    pub right: Box<dyn Expr>,
+    pub operator: ArrowOperator,
+    pub result_type: Box<dyn DataType>,
+}
+
+impl Expr for ArrowExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::Arrow
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        self.result_type.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Clone)]
 pub struct SliceExpr {
     pub collection: Box<dyn Expr>,
@@ -645,7 +719,7 @@ impl Expr for InExpr {
     }
 
     fn expr_type(&self) -> Box<dyn DataType> {
-        self.values_type.clone()
+        Box::new(BoolType)
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -710,6 +784,48 @@ impl Expr for CastExpr {
     }
 }
 
+/// Cast the value like [`CastExpr`] but return NULL instead of error if the value can't be converted
+#[derive(Clone)]
+pub struct TryCastExpr {
+    pub value: Box<dyn Expr>,
+    pub result_type: Box<dyn DataType>,
+}
+
+impl Expr for TryCastExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::TryCast
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        self.result_type.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Convert date time to the same instant in time zone, for example `datetime AT TIME ZONE 'Europe/Berlin'`
+#[derive(Clone)]
+pub struct AtTimeZoneExpr {
+    pub value: Box<dyn Expr>,
+    pub time_zone: Box<dyn Expr>,
+}
+
+impl Expr for AtTimeZoneExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::AtTimeZone
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        Box::new(DateTimeTzType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Clone)]
 pub struct GroupExpr {
     pub expr: Box<dyn Expr>,
@@ -749,3 +865,24 @@ impl Expr for MemberAccessExpr {
         self
     }
 }
+
+#[derive(Clone)]
+pub struct LambdaExpr {
+    pub parameters: Vec<String>,
+    pub body: Box<dyn Expr>,
+    pub lambda_type: Box<dyn DataType>,
+}
+
+impl Expr for LambdaExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::Lambda
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        self.lambda_type.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -78,3 +78,25 @@ pub fn is_valid_datetime_format(datetime_str: &str) -> bool {
     // Check the validity of date and time components
     is_valid_date_format(parts[0]) && is_valid_time_format(parts[1])
 }
+
+/// Check if String literal is matching SQL DateTime format with optional UTC offset suffix
+/// like `Z`, `+02`, `+0200` or `+02:00`
+pub fn is_valid_datetime_tz_format(datetime_str: &str) -> bool {
+    if is_valid_datetime_format(datetime_str) {
+        return true;
+    }
+
+    if let Some(datetime_str) = datetime_str.strip_suffix('Z') {
+        return is_valid_datetime_format(datetime_str);
+    }
+
+    match datetime_str.rfind(['+', '-']) {
+        Some(index) if index > 10 => {
+            let offset = datetime_str[index + 1..].replace(':', "");
+            (offset.len() == 2 || offset.len() == 4)
+                && offset.chars().all(|c| c.is_ascii_digit())
+                && is_valid_datetime_format(datetime_str[..index].trim_end())
+        }
+        _ => false,
+    }
+}
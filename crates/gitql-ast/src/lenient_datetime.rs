@@ -0,0 +1,358 @@
+use crate::Interval;
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+const MONTH_NAMES: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Date time written in one of the lenient formats accepted by git,
+/// for example `2 weeks ago`, `yesterday`, `last monday` or `2024-03-01 12:00 +0200`
+///
+/// Relative variants are resolved against the current time when the value is evaluated
+#[derive(Clone, PartialEq)]
+pub enum LenientDateTime {
+    /// Calendar date and time with optional UTC offset in seconds, UTC is used if it's missing
+    Absolute {
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        offset: Option<i32>,
+    },
+    /// The current time
+    Now,
+    /// Start of the day relative to today, `today` is 0 and `yesterday` is -1
+    StartOfDay(i64),
+    /// Start of the most recent weekday before today, Monday is 0 and Sunday is 6
+    LastWeekday(u32),
+    /// Amount of time before now like `2 weeks ago` or `last month`
+    Ago(Interval),
+    /// Seconds since the Unix epoch like the git raw format `1700000000 +0200` or `@1700000000`
+    Timestamp(i64),
+}
+
+impl LenientDateTime {
+    /// Parse lenient date time from string, or None if it's not in one of the supported formats
+    pub fn parse(input: &str) -> Option<LenientDateTime> {
+        let input = input.trim().to_lowercase();
+        if input.is_empty() {
+            return None;
+        }
+        parse_relative(&input)
+            .or_else(|| parse_timestamp(&input))
+            .or_else(|| parse_absolute(&input))
+    }
+}
+
+/// Parse the seconds since the Unix epoch with optional UTC offset that doesn't change the instant,
+/// without `@` prefix the seconds must have at least 9 digits like git to not be confused with dates
+fn parse_timestamp(input: &str) -> Option<LenientDateTime> {
+    let mut tokens = input.split_whitespace();
+    let seconds = tokens.next()?;
+    let (seconds, min_digits) = match seconds.strip_prefix('@') {
+        Some(seconds) => (seconds, 1),
+        None => (seconds, 9),
+    };
+
+    if seconds.len() < min_digits || !seconds.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    if let Some(offset) = tokens.next() {
+        parse_offset(offset)?;
+    }
+
+    if tokens.next().is_some() {
+        return None;
+    }
+
+    Some(LenientDateTime::Timestamp(seconds.parse::<i64>().ok()?))
+}
+
+fn parse_relative(input: &str) -> Option<LenientDateTime> {
+    // Git also accepts dots as separators like `2.weeks.ago`
+    let input = if input.ends_with("ago") {
+        input.replace('.', " ")
+    } else {
+        input.to_string()
+    };
+
+    let words: Vec<&str> = input.split_whitespace().collect();
+    match words.as_slice() {
+        ["now"] => Some(LenientDateTime::Now),
+        ["today"] => Some(LenientDateTime::StartOfDay(0)),
+        ["yesterday"] => Some(LenientDateTime::StartOfDay(-1)),
+        ["last", name] => {
+            if let Some(weekday) = weekday_index(name) {
+                return Some(LenientDateTime::LastWeekday(weekday));
+            }
+            let mut interval = Interval::default();
+            add_unit_to_interval(&mut interval, 1, name)?;
+            Some(LenientDateTime::Ago(interval))
+        }
+        [amounts @ .., "ago"] if !amounts.is_empty() && amounts.len() % 2 == 0 => {
+            let mut interval = Interval::default();
+            for pair in amounts.chunks(2) {
+                let amount = match pair[0] {
+                    "a" | "an" | "one" => 1,
+                    number => parse_digits(number)? as i64,
+                };
+                add_unit_to_interval(&mut interval, amount, pair[1])?;
+            }
+            Some(LenientDateTime::Ago(interval))
+        }
+        _ => None,
+    }
+}
+
+fn add_unit_to_interval(interval: &mut Interval, amount: i64, unit: &str) -> Option<()> {
+    let unit = unit.strip_suffix('s').unwrap_or(unit);
+    match unit {
+        "second" | "sec" => interval.seconds += amount as f64,
+        "minute" | "min" => interval.minutes += amount,
+        "hour" => interval.hours += amount,
+        "day" => interval.days += amount,
+        "week" => interval.days += amount * 7,
+        "month" => interval.months += amount,
+        "year" => interval.years += amount,
+        _ => return None,
+    }
+    Some(())
+}
+
+/// Parse calendar dates like `2024-03-01 12:00 +0200`, `2024-03-01T12:00:00Z`,
+/// RFC 2822 `Fri, 1 Mar 2024 12:00:00 +0200 (CET)` or the default git format `Fri Mar 1 12:00:00 2024 +0200`
+fn parse_absolute(input: &str) -> Option<LenientDateTime> {
+    // RFC 2822 comment after the date like the zone name in `+0100 (CET)` is ignored
+    let input = match input.split_once('(') {
+        Some((date, comment)) if comment.ends_with(')') && !comment.contains('(') => date,
+        _ => input,
+    };
+
+    let mut year: Option<i32> = None;
+    let mut month: Option<u32> = None;
+    let mut day: Option<u32> = None;
+    let mut time: Option<(u32, u32, u32)> = None;
+    let mut offset: Option<i32> = None;
+
+    let mut tokens: Vec<&str> = vec![];
+    for token in input.split(|c: char| c.is_whitespace() || c == ',') {
+        if token.is_empty() {
+            continue;
+        }
+
+        // ISO 8601 date and time separated by `T`
+        match token.split_once('t') {
+            Some((date, time)) if date.contains('-') && time.contains(':') => {
+                tokens.push(date);
+                tokens.push(time);
+            }
+            _ => tokens.push(token),
+        }
+    }
+
+    for token in tokens {
+        if token.contains(':') && !token.starts_with(['+', '-']) {
+            let (time_part, offset_part) = split_time_and_offset(token);
+            set_once(&mut time, parse_time(time_part)?)?;
+            if let Some(offset_part) = offset_part {
+                set_once(&mut offset, parse_offset(offset_part)?)?;
+            }
+            continue;
+        }
+
+        if token.starts_with(['+', '-']) || zone_name_offset(token).is_some() {
+            set_once(&mut offset, parse_offset(token)?)?;
+            continue;
+        }
+
+        if token.contains(['-', '/']) {
+            let parts: Vec<&str> = token.split(['-', '/']).collect();
+            if parts.len() != 3 || parts[0].len() != 4 {
+                return None;
+            }
+            set_once(&mut year, parse_digits(parts[0])? as i32)?;
+            set_once(&mut month, parse_digits(parts[1])?)?;
+            set_once(&mut day, parse_digits(parts[2])?)?;
+            continue;
+        }
+
+        if let Some(number) = parse_digits(token) {
+            match token.len() {
+                4 => set_once(&mut year, number as i32)?,
+                1 | 2 => set_once(&mut day, number)?,
+                _ => return None,
+            }
+            continue;
+        }
+
+        if let Some(index) = month_index(token) {
+            set_once(&mut month, index + 1)?;
+            continue;
+        }
+
+        if weekday_index(token).is_some() {
+            continue;
+        }
+
+        return None;
+    }
+
+    let (year, month, day) = (year?, month?, day?);
+    if year < 1 || !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    let (hour, minute, second) = time.unwrap_or((0, 0, 0));
+    Some(LenientDateTime::Absolute {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        offset,
+    })
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T) -> Option<()> {
+    if slot.is_some() {
+        return None;
+    }
+    *slot = Some(value);
+    Some(())
+}
+
+fn split_time_and_offset(token: &str) -> (&str, Option<&str>) {
+    if let Some(time) = token.strip_suffix('z') {
+        return (time, Some("z"));
+    }
+
+    match token.find(['+', '-']) {
+        Some(index) => (&token[..index], Some(&token[index..])),
+        None => (token, None),
+    }
+}
+
+/// Parse time in format HH:MM, HH:MM:SS or HH:MM:SS.SSS, fractions of second are ignored
+fn parse_time(input: &str) -> Option<(u32, u32, u32)> {
+    let parts: Vec<&str> = input.split(':').collect();
+    if parts.len() < 2 || parts.len() > 3 {
+        return None;
+    }
+
+    let hour = parse_digits(parts[0])?;
+    let minute = parse_digits(parts[1])?;
+    let second = match parts.get(2) {
+        Some(seconds) => {
+            let (whole, fraction) = seconds.split_once('.').unwrap_or((seconds, "0"));
+            parse_digits(fraction)?;
+            parse_digits(whole)?
+        }
+        None => 0,
+    };
+
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    Some((hour, minute, second))
+}
+
+/// Parse UTC offset in format ±HH, ±HHMM, ±HH:MM or zone name like UTC or EST to seconds
+fn parse_offset(input: &str) -> Option<i32> {
+    if let Some(offset) = zone_name_offset(input) {
+        return Some(offset);
+    }
+
+    let (sign, digits) = match input.split_at(1) {
+        ("+", digits) => (1, digits),
+        ("-", digits) => (-1, digits),
+        _ => return None,
+    };
+
+    let digits = digits.replace(':', "");
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    let (hours, minutes) = match digits.len() {
+        2 => (parse_digits(&digits)?, 0),
+        4 => (parse_digits(&digits[..2])?, parse_digits(&digits[2..])?),
+        _ => return None,
+    };
+
+    if hours > 14 || minutes > 59 {
+        return None;
+    }
+
+    Some(sign * (hours * 3600 + minutes * 60) as i32)
+}
+
+/// Offset in seconds of UTC and the North American zone names allowed by RFC 2822
+fn zone_name_offset(name: &str) -> Option<i32> {
+    let hours = match name {
+        "z" | "ut" | "utc" | "gmt" => 0,
+        "edt" => -4,
+        "est" | "cdt" => -5,
+        "cst" | "mdt" => -6,
+        "mst" | "pdt" => -7,
+        "pst" => -8,
+        _ => return None,
+    };
+    Some(hours * 3600)
+}
+
+fn parse_digits(input: &str) -> Option<u32> {
+    if input.is_empty() || !input.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    input.parse::<u32>().ok()
+}
+
+/// Return the index of month from the full or the three letters name
+fn month_index(name: &str) -> Option<u32> {
+    MONTH_NAMES
+        .iter()
+        .position(|month| *month == name || (name.len() == 3 && month.starts_with(name)))
+        .map(|index| index as u32)
+}
+
+/// Return the index of weekday from the full or the three letters name
+fn weekday_index(name: &str) -> Option<u32> {
+    WEEKDAY_NAMES
+        .iter()
+        .position(|weekday| *weekday == name || (name.len() == 3 && weekday.starts_with(name)))
+        .map(|index| index as u32)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
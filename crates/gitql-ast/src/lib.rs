@@ -4,5 +4,15 @@ pub mod operator;
 pub mod statement;
 pub mod types;
 
+mod decimal;
+pub use decimal::Decimal;
+pub use decimal::DECIMAL_MAX_PRECISION;
+
 mod interval;
 pub use interval::Interval;
+
+mod lenient_datetime;
+pub use lenient_datetime::LenientDateTime;
+
+mod semver;
+pub use semver::SemVer;
@@ -47,3 +47,9 @@ pub enum BinaryBitwiseOperator {
     RightShift,
     LeftShift,
 }
+
+#[derive(Clone, PartialEq)]
+pub enum ArrowOperator {
+    Arrow,
+    LongArrow,
+}
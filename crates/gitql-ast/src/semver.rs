@@ -0,0 +1,176 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// Semantic version `MAJOR.MINOR.PATCH[-PRERELEASE][+BUILD]` with optional `v` prefix
+#[derive(Clone)]
+pub struct SemVer {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre_release: Vec<String>,
+    pub build: Vec<String>,
+}
+
+impl SemVer {
+    /// Parse semantic version from string like `v1.2.3-rc.1+build.5`, or None if it's not valid
+    pub fn parse(input: &str) -> Option<SemVer> {
+        let input = input.trim();
+        let input = input
+            .strip_prefix('v')
+            .or_else(|| input.strip_prefix('V'))
+            .unwrap_or(input);
+
+        let (input, build) = match input.split_once('+') {
+            Some((version, build)) => (version, parse_identifiers(build, false)?),
+            None => (input, vec![]),
+        };
+
+        let (input, pre_release) = match input.split_once('-') {
+            Some((version, pre_release)) => (version, parse_identifiers(pre_release, true)?),
+            None => (input, vec![]),
+        };
+
+        let mut numbers = input.split('.');
+        let major = parse_numeric_identifier(numbers.next()?)?;
+        let minor = parse_numeric_identifier(numbers.next()?)?;
+        let patch = parse_numeric_identifier(numbers.next()?)?;
+        if numbers.next().is_some() {
+            return None;
+        }
+
+        Some(SemVer {
+            major,
+            minor,
+            patch,
+            pre_release,
+            build,
+        })
+    }
+
+    /// Compare by the semantic versioning precedence then by the build metadata,
+    /// so versions that differ only in build metadata are not equal
+    pub fn cmp_strict(&self, other: &SemVer) -> Ordering {
+        self.cmp(other).then_with(|| self.build.cmp(&other.build))
+    }
+
+    pub fn is_prerelease(&self) -> bool {
+        !self.pre_release.is_empty()
+    }
+
+    /// Return the kind of the change from this version to the other version
+    /// `major`, `minor`, `patch`, `prerelease` or `none` if they have the same precedence
+    pub fn bump_kind(&self, other: &SemVer) -> &'static str {
+        if self.major != other.major {
+            "major"
+        } else if self.minor != other.minor {
+            "minor"
+        } else if self.patch != other.patch {
+            "patch"
+        } else if self.pre_release != other.pre_release {
+            "prerelease"
+        } else {
+            "none"
+        }
+    }
+}
+
+impl Ord for SemVer {
+    /// Compare by the semantic versioning precedence, build metadata is ignored
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then(self.minor.cmp(&other.minor))
+            .then(self.patch.cmp(&other.patch))
+            .then_with(|| compare_pre_release(&self.pre_release, &other.pre_release))
+    }
+}
+
+impl PartialEq for SemVer {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SemVer {}
+
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for SemVer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if !self.pre_release.is_empty() {
+            write!(f, "-{}", self.pre_release.join("."))?;
+        }
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build.join("."))?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_numeric_identifier(input: &str) -> Option<u64> {
+    if input.is_empty() || (input.len() > 1 && input.starts_with('0')) {
+        return None;
+    }
+
+    if !input.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    input.parse::<u64>().ok()
+}
+
+fn parse_identifiers(input: &str, is_pre_release: bool) -> Option<Vec<String>> {
+    let mut identifiers = vec![];
+    for identifier in input.split('.') {
+        if identifier.is_empty()
+            || !identifier
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            return None;
+        }
+
+        // Numeric pre release identifiers must not include leading zeros
+        if is_pre_release
+            && identifier.chars().all(|c| c.is_ascii_digit())
+            && parse_numeric_identifier(identifier).is_none()
+        {
+            return None;
+        }
+
+        identifiers.push(identifier.to_string());
+    }
+    Some(identifiers)
+}
+
+/// Version with pre release has lower precedence than the normal version,
+/// numeric identifiers are compared numerically and have lower precedence than alphanumeric
+fn compare_pre_release(lhs: &[String], rhs: &[String]) -> Ordering {
+    match (lhs.is_empty(), rhs.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        _ => {}
+    }
+
+    for (left, right) in lhs.iter().zip(rhs.iter()) {
+        let ordering = match (left.parse::<u64>(), right.parse::<u64>()) {
+            (Ok(left), Ok(right)) => left.cmp(&right),
+            (Ok(_), Err(_)) => Ordering::Less,
+            (Err(_), Ok(_)) => Ordering::Greater,
+            (Err(_), Err(_)) => left.cmp(right),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    lhs.len().cmp(&rhs.len())
+}
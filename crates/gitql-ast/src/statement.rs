@@ -10,6 +10,7 @@ pub enum StatementKind {
     Select,
     Where,
     Having,
+    Qualify,
     Limit,
     Offset,
     OrderBy,
@@ -27,14 +28,17 @@ pub trait Statement: DynClone {
     fn as_any(&self) -> &dyn Any;
 }
 
+#[derive(Clone)]
 pub enum Query {
     Do(DoStatement),
     Select(GQLQuery),
     GlobalVariableDeclaration(GlobalVariableStatement),
     Describe(DescribeStatement),
     ShowTables,
+    Explain(ExplainStatement),
 }
 
+#[derive(Clone)]
 pub struct GQLQuery {
     pub statements: HashMap<&'static str, Box<dyn Statement>>,
     pub alias_table: HashMap<String, String>,
@@ -77,6 +81,7 @@ pub enum JoinKind {
     Inner,
     Left,
     Right,
+    Full,
     Default,
 }
 
@@ -93,12 +98,26 @@ pub struct Join {
     pub operand: JoinOperand,
     pub kind: JoinKind,
     pub predicate: Option<Box<dyn Expr>>,
+    /// Columns from `USING (...)` or `NATURAL JOIN`, matched by equality and merged into one column
+    pub using_columns: Vec<String>,
+}
+
+/// Expand the elements of Array or collection value into rows using `UNNEST`,
+/// each row of the tables is repeated for each one of its elements
+#[derive(Clone)]
+pub struct UnnestSelection {
+    pub expression: Box<dyn Expr>,
+    pub element_column: String,
+    pub ordinality_column: Option<String>,
+    /// Keep the rows that have no elements with `NULL` element, used with `LEFT JOIN UNNEST`
+    pub is_outer: bool,
 }
 
 #[derive(Clone)]
 pub struct SelectStatement {
     pub table_selections: Vec<TableSelection>,
     pub joins: Vec<Join>,
+    pub unnests: Vec<UnnestSelection>,
     pub selected_expr_titles: Vec<String>,
     pub selected_expr: Vec<Box<dyn Expr>>,
     pub distinct: Distinct,
@@ -144,6 +163,21 @@ impl Statement for HavingStatement {
     }
 }
 
+#[derive(Clone)]
+pub struct QualifyStatement {
+    pub condition: Box<dyn Expr>,
+}
+
+impl Statement for QualifyStatement {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn kind(&self) -> StatementKind {
+        StatementKind::Qualify
+    }
+}
+
 #[derive(Clone)]
 pub struct LimitStatement {
     pub count: usize,
@@ -206,7 +240,10 @@ impl Statement for OrderByStatement {
 #[derive(Clone)]
 pub struct GroupByStatement {
     pub values: Vec<Box<dyn Expr>>,
-    pub has_with_roll_up: bool,
+    /// Each grouping set is a list of indices of the grouped values,
+    /// simple `GROUP BY` has one set with all values,
+    /// `WITH ROLLUP`, `CUBE` and `GROUPING SETS` can have many sets
+    pub grouping_sets: Vec<Vec<usize>>,
 }
 
 impl Statement for GroupByStatement {
@@ -229,11 +266,48 @@ pub struct WindowOrderingClause {
     pub order_by: OrderByStatement,
 }
 
+#[derive(Clone, PartialEq)]
+pub enum WindowFrameKind {
+    Rows,
+    Range,
+}
+
+#[derive(Clone)]
+pub enum WindowFrameBound {
+    UnboundedPreceding,
+    Preceding(Box<dyn Expr>),
+    CurrentRow,
+    Following(Box<dyn Expr>),
+    UnboundedFollowing,
+}
+
+impl WindowFrameBound {
+    /// Position of the bound kind from the first to the last row of the partition,
+    /// used to make sure the frame start is not after the frame end
+    pub fn order(&self) -> u8 {
+        match self {
+            WindowFrameBound::UnboundedPreceding => 0,
+            WindowFrameBound::Preceding(_) => 1,
+            WindowFrameBound::CurrentRow => 2,
+            WindowFrameBound::Following(_) => 3,
+            WindowFrameBound::UnboundedFollowing => 4,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct WindowFrameClause {
+    pub kind: WindowFrameKind,
+    pub start: WindowFrameBound,
+    pub end: WindowFrameBound,
+}
+
 #[derive(Clone)]
 pub struct WindowDefinition {
     pub name: Option<String>,
     pub partitioning_clause: Option<WindowPartitioningClause>,
     pub ordering_clause: Option<WindowOrderingClause>,
+    pub frame_clause: Option<Box<WindowFrameClause>>,
 }
 
 #[derive(Clone)]
@@ -271,10 +345,22 @@ impl Statement for WindowFunctionsStatement {
     }
 }
 
+#[derive(Clone)]
+pub struct AggregateFunction {
+    pub function_name: String,
+    pub arguments: Vec<Box<dyn Expr>>,
+    pub is_distinct: bool,
+    pub ordering: Option<OrderByStatement>,
+    pub separator: Option<String>,
+    pub filter: Option<Box<dyn Expr>>,
+}
+
 #[derive(Clone)]
 pub enum AggregateValue {
     Expression(Box<dyn Expr>),
-    Function(String, Vec<Box<dyn Expr>>),
+    Function(AggregateFunction),
+    /// `GROUPING(...)` arguments, each one is a grouped column
+    Grouping(Vec<Box<dyn Expr>>),
 }
 
 #[derive(Clone)]
@@ -326,7 +412,14 @@ impl Statement for IntoStatement {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct DescribeStatement {
     pub table_name: String,
 }
+
+#[derive(Clone)]
+pub struct ExplainStatement {
+    pub query: GQLQuery,
+    /// Execute the query and report the time and rows count of each stage
+    pub analyze: bool,
+}
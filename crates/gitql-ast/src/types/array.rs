@@ -63,4 +63,12 @@ impl DataType for ArrayType {
     fn logical_or_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
         Box::new(BoolType)
     }
+
+    fn can_perform_unnest_op(&self) -> bool {
+        true
+    }
+
+    fn unnest_op_result_type(&self) -> Box<dyn DataType> {
+        self.base.clone()
+    }
 }
@@ -8,15 +8,23 @@ use crate::expression::Expr;
 use super::any::AnyType;
 use super::array::ArrayType;
 use super::boolean::BoolType;
+use super::bytes::BytesType;
 use super::composite::CompositeType;
 use super::date::DateType;
 use super::datetime::DateTimeType;
+use super::datetimetz::DateTimeTzType;
+use super::decimal::DecimalType;
+use super::enumeration::EnumType;
 use super::float::FloatType;
 use super::integer::IntType;
 use super::interval::IntervalType;
+use super::json::JsonType;
+use super::lambda::LambdaType;
+use super::multirange::MultiRangeType;
 use super::null::NullType;
 use super::optional::OptionType;
 use super::range::RangeType;
+use super::semver::SemVerType;
 use super::text::TextType;
 use super::time::TimeType;
 use super::undefined::UndefType;
@@ -269,6 +277,36 @@ pub trait DataType: DynClone {
         Box::new(NullType)
     }
 
+    /// Return a list of types that it's possible to perform `->' operator with
+    /// between current DataType and any one of them
+    fn can_perform_arrow_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![]
+    }
+
+    /// Return the expected type after perform `->' operator on current type
+    ///
+    /// Note that you don't need to check again that the argument type is possible to perform operator with
+    #[allow(unused_variables)]
+    #[allow(clippy::borrowed_box)]
+    fn arrow_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        Box::new(NullType)
+    }
+
+    /// Return a list of types that it's possible to perform `->>' operator with
+    /// between current DataType and any one of them
+    fn can_perform_long_arrow_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![]
+    }
+
+    /// Return the expected type after perform `->>' operator on current type
+    ///
+    /// Note that you don't need to check again that the argument type is possible to perform operator with
+    #[allow(unused_variables)]
+    #[allow(clippy::borrowed_box)]
+    fn long_arrow_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        Box::new(NullType)
+    }
+
     /// Return true if this type support Slice operator with
     fn can_perform_slice_op(&self) -> bool {
         false
@@ -438,6 +476,18 @@ pub trait DataType: DynClone {
         Box::new(NullType)
     }
 
+    /// Return true if it's possible to expand current type into rows using `UNNEST`
+    fn can_perform_unnest_op(&self) -> bool {
+        false
+    }
+
+    /// Return the expected type of each element after expanding current type using `UNNEST`
+    ///
+    /// Note that you don't need to check again that it's possible to perform operator on current type
+    fn unnest_op_result_type(&self) -> Box<dyn DataType> {
+        Box::new(NullType)
+    }
+
     /// Return a list of types that it's possible to perform unary `@>' operator with
     /// between current DataType and any one of them
     ///
@@ -537,11 +587,36 @@ impl dyn DataType {
         self.as_any().downcast_ref::<DateTimeType>().is_some()
     }
 
+    /// Return true if this type is [`DateTimeTzType`]
+    pub fn is_date_time_tz(&self) -> bool {
+        self.as_any().downcast_ref::<DateTimeTzType>().is_some()
+    }
+
     /// Return true if this type is [`IntervalType`]
     pub fn is_interval(&self) -> bool {
         self.as_any().downcast_ref::<IntervalType>().is_some()
     }
 
+    /// Return true if this type is [`JsonType`]
+    pub fn is_json(&self) -> bool {
+        self.as_any().downcast_ref::<JsonType>().is_some()
+    }
+
+    /// Return true if this type is [`DecimalType`]
+    pub fn is_decimal(&self) -> bool {
+        self.as_any().downcast_ref::<DecimalType>().is_some()
+    }
+
+    /// Return true if this type is [`SemVerType`]
+    pub fn is_semver(&self) -> bool {
+        self.as_any().downcast_ref::<SemVerType>().is_some()
+    }
+
+    /// Return true if this type is [`BytesType`]
+    pub fn is_bytes(&self) -> bool {
+        self.as_any().downcast_ref::<BytesType>().is_some()
+    }
+
     /// Return true if this type is [`ArrayType`]
     pub fn is_array(&self) -> bool {
         self.as_any().downcast_ref::<ArrayType>().is_some()
@@ -552,6 +627,11 @@ impl dyn DataType {
         self.as_any().downcast_ref::<RangeType>().is_some()
     }
 
+    /// Return true if this type is [`MultiRangeType`]
+    pub fn is_multirange(&self) -> bool {
+        self.as_any().downcast_ref::<MultiRangeType>().is_some()
+    }
+
     /// Return true if this type is [`VariantType`]
     pub fn is_variant(&self) -> bool {
         self.as_any().downcast_ref::<VariantType>().is_some()
@@ -594,6 +674,16 @@ impl dyn DataType {
         self.as_any().downcast_ref::<CompositeType>().is_some()
     }
 
+    /// Return true if this type is [`EnumType`]
+    pub fn is_enum(&self) -> bool {
+        self.as_any().downcast_ref::<EnumType>().is_some()
+    }
+
+    /// Return true if this type is [`LambdaType`]
+    pub fn is_lambda(&self) -> bool {
+        self.as_any().downcast_ref::<LambdaType>().is_some()
+    }
+
     /// Return true if this type is [`UndefType`]
     pub fn is_undefined(&self) -> bool {
         self.as_any().downcast_ref::<UndefType>().is_some()
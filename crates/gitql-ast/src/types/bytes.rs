@@ -0,0 +1,49 @@
+use std::any::Any;
+
+use super::base::DataType;
+use super::text::TextType;
+
+#[derive(Clone)]
+pub struct BytesType;
+
+impl DataType for BytesType {
+    fn literal(&self) -> String {
+        "Bytes".to_string()
+    }
+
+    fn equals(&self, other: &Box<dyn DataType>) -> bool {
+        other.is_any() || other.is_bytes() || other.is_variant_with(|t| t.is_bytes())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn can_perform_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(BytesType)]
+    }
+
+    fn can_perform_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(BytesType)]
+    }
+
+    fn can_perform_gt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(BytesType)]
+    }
+
+    fn can_perform_gte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(BytesType)]
+    }
+
+    fn can_perform_lt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(BytesType)]
+    }
+
+    fn can_perform_lte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(BytesType)]
+    }
+
+    fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(TextType)]
+    }
+}
@@ -1,23 +1,27 @@
 use std::any::Any;
-use std::collections::HashMap;
+
+use indexmap::IndexMap;
+
+use crate::expression::Expr;
+use crate::expression::RowExpr;
 
 use super::base::DataType;
 
 #[derive(Clone)]
 pub struct CompositeType {
     pub name: String,
-    pub members: HashMap<String, Box<dyn DataType>>,
+    pub members: IndexMap<String, Box<dyn DataType>>,
 }
 
 impl CompositeType {
-    pub fn new(name: String, members: HashMap<String, Box<dyn DataType>>) -> Self {
+    pub fn new(name: String, members: IndexMap<String, Box<dyn DataType>>) -> Self {
         CompositeType { name, members }
     }
 
     pub fn empty(name: String) -> Self {
         CompositeType {
             name,
-            members: HashMap::default(),
+            members: IndexMap::default(),
         }
     }
 
@@ -56,4 +60,22 @@ impl DataType for CompositeType {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    /// Row constructor can be casted to composite type with the same number of members
+    /// if every value can be used or implicit casted to the member type in order
+    fn has_implicit_cast_from(&self, expr: &Box<dyn Expr>) -> bool {
+        if let Some(row_expr) = expr.as_any().downcast_ref::<RowExpr>() {
+            if row_expr.values.len() != self.members.len() {
+                return false;
+            }
+
+            return row_expr.values.iter().zip(self.members.values()).all(
+                |(value, member_type)| {
+                    member_type.equals(&value.expr_type())
+                        || member_type.has_implicit_cast_from(value)
+                },
+            );
+        }
+        false
+    }
 }
@@ -5,6 +5,8 @@ use crate::expression::StringExpr;
 use crate::format_checker::is_valid_datetime_format;
 use crate::types::array::ArrayType;
 use crate::types::date::DateType;
+use crate::types::datetimetz::DateTimeTzType;
+use crate::LenientDateTime;
 
 use super::base::DataType;
 
@@ -25,7 +27,7 @@ impl DataType for DateTimeType {
     }
 
     fn can_perform_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(DateTimeType)]
+        vec![Box::new(DateTimeType), Box::new(DateTimeTzType)]
     }
 
     fn can_perform_group_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
@@ -33,7 +35,7 @@ impl DataType for DateTimeType {
     }
 
     fn can_perform_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(DateTimeType)]
+        vec![Box::new(DateTimeType), Box::new(DateTimeTzType)]
     }
 
     fn can_perform_group_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
@@ -41,7 +43,7 @@ impl DataType for DateTimeType {
     }
 
     fn can_perform_gt_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(DateTimeType)]
+        vec![Box::new(DateTimeType), Box::new(DateTimeTzType)]
     }
 
     fn can_perform_group_gt_op_with(&self) -> Vec<Box<dyn DataType>> {
@@ -49,7 +51,7 @@ impl DataType for DateTimeType {
     }
 
     fn can_perform_gte_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(DateTimeType)]
+        vec![Box::new(DateTimeType), Box::new(DateTimeTzType)]
     }
 
     fn can_perform_group_gte_op_with(&self) -> Vec<Box<dyn DataType>> {
@@ -57,7 +59,7 @@ impl DataType for DateTimeType {
     }
 
     fn can_perform_lt_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(DateTimeType)]
+        vec![Box::new(DateTimeType), Box::new(DateTimeTzType)]
     }
 
     fn can_perform_group_lt_op_with(&self) -> Vec<Box<dyn DataType>> {
@@ -65,7 +67,7 @@ impl DataType for DateTimeType {
     }
 
     fn can_perform_lte_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(DateTimeType)]
+        vec![Box::new(DateTimeType), Box::new(DateTimeTzType)]
     }
 
     fn can_perform_group_lte_op_with(&self) -> Vec<Box<dyn DataType>> {
@@ -74,12 +76,13 @@ impl DataType for DateTimeType {
 
     fn has_implicit_cast_from(&self, expr: &Box<dyn Expr>) -> bool {
         if let Some(string_expr) = expr.as_any().downcast_ref::<StringExpr>() {
-            return is_valid_datetime_format(&string_expr.value);
+            return is_valid_datetime_format(&string_expr.value)
+                || LenientDateTime::parse(&string_expr.value).is_some();
         }
         false
     }
 
     fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(DateType)]
+        vec![Box::new(DateType), Box::new(DateTimeTzType)]
     }
 }
@@ -0,0 +1,66 @@
+use std::any::Any;
+
+use crate::expression::Expr;
+use crate::expression::StringExpr;
+use crate::format_checker::is_valid_datetime_tz_format;
+use crate::types::date::DateType;
+use crate::types::datetime::DateTimeType;
+use crate::types::text::TextType;
+
+use super::base::DataType;
+
+#[derive(Clone)]
+pub struct DateTimeTzType;
+
+impl DataType for DateTimeTzType {
+    fn literal(&self) -> String {
+        "DateTimeTz".to_string()
+    }
+
+    fn equals(&self, other: &Box<dyn DataType>) -> bool {
+        other.is_any() || other.is_date_time_tz() || other.is_variant_with(|t| t.is_date_time_tz())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn can_perform_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DateTimeTzType), Box::new(DateTimeType)]
+    }
+
+    fn can_perform_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DateTimeTzType), Box::new(DateTimeType)]
+    }
+
+    fn can_perform_gt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DateTimeTzType), Box::new(DateTimeType)]
+    }
+
+    fn can_perform_gte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DateTimeTzType), Box::new(DateTimeType)]
+    }
+
+    fn can_perform_lt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DateTimeTzType), Box::new(DateTimeType)]
+    }
+
+    fn can_perform_lte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DateTimeTzType), Box::new(DateTimeType)]
+    }
+
+    fn has_implicit_cast_from(&self, expr: &Box<dyn Expr>) -> bool {
+        if let Some(string_expr) = expr.as_any().downcast_ref::<StringExpr>() {
+            return is_valid_datetime_tz_format(&string_expr.value);
+        }
+        false
+    }
+
+    fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
+        vec![
+            Box::new(DateTimeType),
+            Box::new(DateType),
+            Box::new(TextType),
+        ]
+    }
+}
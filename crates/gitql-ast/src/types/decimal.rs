@@ -0,0 +1,143 @@
+use std::any::Any;
+
+use crate::decimal::add_result_scale;
+use crate::decimal::div_result_scale;
+use crate::decimal::mul_result_scale;
+use crate::decimal::DECIMAL_MAX_PRECISION;
+use crate::types::float::FloatType;
+use crate::types::integer::IntType;
+use crate::types::text::TextType;
+
+use super::base::DataType;
+
+/// Exact numeric type with precision as the total number of digits
+/// and scale as the number of digits after the decimal point
+#[derive(Clone)]
+pub struct DecimalType {
+    pub precision: u32,
+    pub scale: u32,
+}
+
+impl DecimalType {
+    pub fn new(precision: u32, scale: u32) -> Self {
+        DecimalType { precision, scale }
+    }
+}
+
+impl Default for DecimalType {
+    fn default() -> Self {
+        DecimalType {
+            precision: DECIMAL_MAX_PRECISION,
+            scale: 0,
+        }
+    }
+}
+
+/// Return the scale of the decimal type or zero for other numeric types
+#[allow(clippy::borrowed_box)]
+fn scale_of(data_type: &Box<dyn DataType>) -> u32 {
+    match data_type.as_any().downcast_ref::<DecimalType>() {
+        Some(decimal_type) => decimal_type.scale,
+        None => 0,
+    }
+}
+
+impl DataType for DecimalType {
+    fn literal(&self) -> String {
+        format!("Decimal({}, {})", self.precision, self.scale)
+    }
+
+    fn equals(&self, other: &Box<dyn DataType>) -> bool {
+        other.is_any() || other.is_decimal() || other.is_variant_with(|t| t.is_decimal())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn can_perform_add_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DecimalType::default()), Box::new(IntType)]
+    }
+
+    fn add_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        let scale = add_result_scale(self.scale, scale_of(other));
+        Box::new(DecimalType::new(DECIMAL_MAX_PRECISION, scale))
+    }
+
+    fn can_perform_sub_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DecimalType::default()), Box::new(IntType)]
+    }
+
+    fn sub_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        let scale = add_result_scale(self.scale, scale_of(other));
+        Box::new(DecimalType::new(DECIMAL_MAX_PRECISION, scale))
+    }
+
+    fn can_perform_mul_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DecimalType::default()), Box::new(IntType)]
+    }
+
+    fn mul_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        let scale = mul_result_scale(self.scale, scale_of(other));
+        Box::new(DecimalType::new(DECIMAL_MAX_PRECISION, scale))
+    }
+
+    fn can_perform_div_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DecimalType::default()), Box::new(IntType)]
+    }
+
+    fn div_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        let scale = div_result_scale(self.scale, scale_of(other));
+        Box::new(DecimalType::new(DECIMAL_MAX_PRECISION, scale))
+    }
+
+    fn can_perform_rem_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DecimalType::default()), Box::new(IntType)]
+    }
+
+    fn rem_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        let scale = add_result_scale(self.scale, scale_of(other));
+        Box::new(DecimalType::new(DECIMAL_MAX_PRECISION, scale))
+    }
+
+    fn can_perform_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DecimalType::default()), Box::new(IntType)]
+    }
+
+    fn can_perform_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DecimalType::default()), Box::new(IntType)]
+    }
+
+    fn can_perform_gt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DecimalType::default()), Box::new(IntType)]
+    }
+
+    fn can_perform_gte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DecimalType::default()), Box::new(IntType)]
+    }
+
+    fn can_perform_lt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DecimalType::default()), Box::new(IntType)]
+    }
+
+    fn can_perform_lte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(DecimalType::default()), Box::new(IntType)]
+    }
+
+    fn can_perform_neg_op(&self) -> bool {
+        true
+    }
+
+    fn neg_op_result_type(&self) -> Box<dyn DataType> {
+        Box::new(self.clone())
+    }
+
+    fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
+        vec![
+            Box::new(DecimalType::default()),
+            Box::new(IntType),
+            Box::new(FloatType),
+            Box::new(TextType),
+        ]
+    }
+}
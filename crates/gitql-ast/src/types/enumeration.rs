@@ -0,0 +1,87 @@
+use std::any::Any;
+
+use crate::expression::Expr;
+use crate::expression::StringExpr;
+
+use super::base::DataType;
+use super::text::TextType;
+
+/// User defined enum type with ordered set of labels, created by `CREATE TYPE name AS ENUM (...)`
+#[derive(Clone)]
+pub struct EnumType {
+    pub name: String,
+    pub labels: Vec<String>,
+}
+
+impl EnumType {
+    pub fn new(name: String, labels: Vec<String>) -> Self {
+        EnumType { name, labels }
+    }
+
+    /// Return the position of the label in the enum declaration or None if it's not a member
+    pub fn label_position(&self, label: &str) -> Option<usize> {
+        self.labels.iter().position(|member| member == label)
+    }
+}
+
+impl DataType for EnumType {
+    fn literal(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn equals(&self, other: &Box<dyn DataType>) -> bool {
+        if other.is_any() {
+            return true;
+        }
+
+        let enum_type: Box<dyn DataType> = Box::new(self.clone());
+        if other.is_variant_contains(&enum_type) {
+            return true;
+        }
+
+        if let Some(other_enum) = other.as_any().downcast_ref::<EnumType>() {
+            return self.name.eq(&other_enum.name) && self.labels.eq(&other_enum.labels);
+        }
+
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn can_perform_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(self.clone())]
+    }
+
+    fn can_perform_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(self.clone())]
+    }
+
+    fn can_perform_gt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(self.clone())]
+    }
+
+    fn can_perform_gte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(self.clone())]
+    }
+
+    fn can_perform_lt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(self.clone())]
+    }
+
+    fn can_perform_lte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(self.clone())]
+    }
+
+    fn has_implicit_cast_from(&self, expr: &Box<dyn Expr>) -> bool {
+        if let Some(string_expr) = expr.as_any().downcast_ref::<StringExpr>() {
+            return self.label_position(&string_expr.value).is_some();
+        }
+        false
+    }
+
+    fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(TextType)]
+    }
+}
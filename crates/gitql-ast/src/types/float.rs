@@ -1,6 +1,6 @@
 use std::any::Any;
 
-use crate::types::{array::ArrayType, integer::IntType};
+use crate::types::{array::ArrayType, decimal::DecimalType, integer::IntType};
 
 use super::base::DataType;
 
@@ -109,6 +109,6 @@ impl DataType for FloatType {
     }
 
     fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DecimalType::default())]
     }
 }
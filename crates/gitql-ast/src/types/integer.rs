@@ -2,6 +2,7 @@ use std::any::Any;
 
 use crate::types::array::ArrayType;
 use crate::types::boolean::BoolType;
+use crate::types::decimal::DecimalType;
 use crate::types::float::FloatType;
 
 use super::base::DataType;
@@ -23,42 +24,57 @@ impl DataType for IntType {
     }
 
     fn can_perform_add_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DecimalType::default())]
     }
 
-    fn add_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+    fn add_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        if other.is_decimal() {
+            return DecimalType::default().add_op_result_type(other);
+        }
         Box::new(IntType)
     }
 
     fn can_perform_sub_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DecimalType::default())]
     }
 
-    fn sub_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+    fn sub_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        if other.is_decimal() {
+            return DecimalType::default().sub_op_result_type(other);
+        }
         Box::new(IntType)
     }
 
     fn can_perform_mul_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DecimalType::default())]
     }
 
-    fn mul_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+    fn mul_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        if other.is_decimal() {
+            return DecimalType::default().mul_op_result_type(other);
+        }
         Box::new(IntType)
     }
 
     fn can_perform_div_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DecimalType::default())]
     }
 
-    fn div_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+    fn div_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        if other.is_decimal() {
+            return DecimalType::default().div_op_result_type(other);
+        }
         Box::new(IntType)
     }
 
     fn can_perform_rem_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DecimalType::default())]
     }
 
-    fn rem_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+    fn rem_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        if other.is_decimal() {
+            return DecimalType::default().rem_op_result_type(other);
+        }
         Box::new(IntType)
     }
 
@@ -111,7 +127,7 @@ impl DataType for IntType {
     }
 
     fn can_perform_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DecimalType::default())]
     }
 
     fn can_perform_group_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
@@ -119,7 +135,7 @@ impl DataType for IntType {
     }
 
     fn can_perform_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DecimalType::default())]
     }
 
     fn can_perform_group_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
@@ -127,7 +143,7 @@ impl DataType for IntType {
     }
 
     fn can_perform_gt_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DecimalType::default())]
     }
 
     fn can_perform_group_gt_op_with(&self) -> Vec<Box<dyn DataType>> {
@@ -135,7 +151,7 @@ impl DataType for IntType {
     }
 
     fn can_perform_gte_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DecimalType::default())]
     }
 
     fn can_perform_group_gte_op_with(&self) -> Vec<Box<dyn DataType>> {
@@ -143,7 +159,7 @@ impl DataType for IntType {
     }
 
     fn can_perform_lt_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DecimalType::default())]
     }
 
     fn can_perform_group_lt_op_with(&self) -> Vec<Box<dyn DataType>> {
@@ -151,7 +167,7 @@ impl DataType for IntType {
     }
 
     fn can_perform_lte_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DecimalType::default())]
     }
 
     fn can_perform_group_lte_op_with(&self) -> Vec<Box<dyn DataType>> {
@@ -167,6 +183,10 @@ impl DataType for IntType {
     }
 
     fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(FloatType), Box::new(BoolType)]
+        vec![
+            Box::new(FloatType),
+            Box::new(BoolType),
+            Box::new(DecimalType::default()),
+        ]
     }
 }
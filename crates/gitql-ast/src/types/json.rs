@@ -0,0 +1,50 @@
+use std::any::Any;
+
+use super::base::DataType;
+use super::integer::IntType;
+use super::text::TextType;
+
+#[derive(Clone)]
+pub struct JsonType;
+
+impl DataType for JsonType {
+    fn literal(&self) -> String {
+        "Json".to_string()
+    }
+
+    fn equals(&self, other: &Box<dyn DataType>) -> bool {
+        other.is_any() || other.is_json() || other.is_variant_with(|t| t.is_json())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn can_perform_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(JsonType)]
+    }
+
+    fn can_perform_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(JsonType)]
+    }
+
+    fn can_perform_arrow_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(TextType), Box::new(IntType)]
+    }
+
+    fn arrow_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        Box::new(JsonType)
+    }
+
+    fn can_perform_long_arrow_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(TextType), Box::new(IntType)]
+    }
+
+    fn long_arrow_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        Box::new(TextType)
+    }
+
+    fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(TextType)]
+    }
+}
@@ -0,0 +1,53 @@
+use std::any::Any;
+
+use super::base::DataType;
+
+/// Type of lambda expression that passed as argument to higher order functions
+/// (T1, T2, ...) -> R
+#[derive(Clone)]
+pub struct LambdaType {
+    pub parameters: Vec<Box<dyn DataType>>,
+    pub return_type: Box<dyn DataType>,
+}
+
+impl LambdaType {
+    pub fn new(parameters: Vec<Box<dyn DataType>>, return_type: Box<dyn DataType>) -> Self {
+        LambdaType {
+            parameters,
+            return_type,
+        }
+    }
+}
+
+impl DataType for LambdaType {
+    fn literal(&self) -> String {
+        let parameters: Vec<String> = self.parameters.iter().map(|p| p.literal()).collect();
+        format!(
+            "Lambda(({}) -> {})",
+            parameters.join(", "),
+            self.return_type.literal()
+        )
+    }
+
+    fn equals(&self, other: &Box<dyn DataType>) -> bool {
+        let lambda_type: Box<dyn DataType> = Box::new(self.clone());
+        if other.is_any() || other.is_variant_contains(&lambda_type) {
+            return true;
+        }
+
+        if let Some(other_lambda) = other.as_any().downcast_ref::<LambdaType>() {
+            return self.parameters.len() == other_lambda.parameters.len()
+                && self
+                    .parameters
+                    .iter()
+                    .zip(other_lambda.parameters.iter())
+                    .all(|(first, second)| first.equals(second))
+                && self.return_type.equals(&other_lambda.return_type);
+        }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
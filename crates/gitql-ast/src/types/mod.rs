@@ -1,16 +1,24 @@
 pub mod any;
 pub mod array;
 pub mod boolean;
+pub mod bytes;
 pub mod composite;
 pub mod date;
 pub mod datetime;
+pub mod datetimetz;
+pub mod decimal;
 pub mod dynamic;
+pub mod enumeration;
 pub mod float;
 pub mod integer;
 pub mod interval;
+pub mod json;
+pub mod lambda;
+pub mod multirange;
 pub mod null;
 pub mod optional;
 pub mod range;
+pub mod semver;
 pub mod text;
 pub mod time;
 pub mod undefined;
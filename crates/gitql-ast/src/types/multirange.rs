@@ -0,0 +1,81 @@
+use std::any::Any;
+
+use super::base::DataType;
+use super::boolean::BoolType;
+use super::range::RangeType;
+
+#[derive(Clone)]
+pub struct MultiRangeType {
+    pub base: Box<dyn DataType>,
+}
+
+impl MultiRangeType {
+    pub fn new(base: Box<dyn DataType>) -> Self {
+        MultiRangeType { base }
+    }
+}
+
+impl DataType for MultiRangeType {
+    fn literal(&self) -> String {
+        format!("MultiRange({})", self.base.literal())
+    }
+
+    fn equals(&self, other: &Box<dyn DataType>) -> bool {
+        let multirange_type: Box<dyn DataType> = Box::new(self.clone());
+        if other.is_any() || other.is_variant_contains(&multirange_type) {
+            return true;
+        }
+
+        if let Some(other_multirange) = other.as_any().downcast_ref::<MultiRangeType>() {
+            return self.base.equals(&other_multirange.base);
+        }
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn can_perform_add_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(self.clone())]
+    }
+
+    fn add_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        Box::new(self.clone())
+    }
+
+    fn can_perform_sub_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(self.clone())]
+    }
+
+    fn sub_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        Box::new(self.clone())
+    }
+
+    fn can_perform_mul_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(self.clone())]
+    }
+
+    fn mul_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        Box::new(self.clone())
+    }
+
+    fn can_perform_contains_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![
+            Box::new(self.clone()),
+            Box::new(RangeType::new(self.base.clone())),
+            self.base.clone(),
+        ]
+    }
+
+    fn can_perform_logical_and_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![
+            Box::new(self.clone()),
+            Box::new(RangeType::new(self.base.clone())),
+        ]
+    }
+
+    fn logical_and_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        Box::new(BoolType)
+    }
+}
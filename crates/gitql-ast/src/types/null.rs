@@ -1,6 +1,7 @@
 use std::any::Any;
 
 use super::base::DataType;
+use super::boolean::BoolType;
 
 #[derive(Clone)]
 pub struct NullType;
@@ -17,4 +18,28 @@ impl DataType for NullType {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn can_perform_not_op(&self) -> bool {
+        true
+    }
+
+    fn not_op_result_type(&self) -> Box<dyn DataType> {
+        Box::new(NullType)
+    }
+
+    fn can_perform_neg_op(&self) -> bool {
+        true
+    }
+
+    fn neg_op_result_type(&self) -> Box<dyn DataType> {
+        Box::new(NullType)
+    }
+
+    fn can_perform_bang_op(&self) -> bool {
+        true
+    }
+
+    fn bang_op_result_type(&self) -> Box<dyn DataType> {
+        Box::new(BoolType)
+    }
 }
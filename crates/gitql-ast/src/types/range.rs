@@ -2,6 +2,7 @@ use std::any::Any;
 
 use super::base::DataType;
 use super::boolean::BoolType;
+use super::multirange::MultiRangeType;
 
 #[derive(Clone)]
 pub struct RangeType {
@@ -35,10 +36,45 @@ impl DataType for RangeType {
         self
     }
 
+    fn can_perform_add_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(self.clone())]
+    }
+
+    fn add_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        Box::new(self.clone())
+    }
+
+    fn can_perform_sub_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(self.clone())]
+    }
+
+    fn sub_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        Box::new(self.clone())
+    }
+
+    fn can_perform_mul_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(self.clone())]
+    }
+
+    fn mul_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        Box::new(self.clone())
+    }
+
     fn can_perform_contains_op_with(&self) -> Vec<Box<dyn DataType>> {
         vec![Box::new(self.clone()), self.base.clone()]
     }
 
+    fn can_perform_logical_and_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![
+            Box::new(self.clone()),
+            Box::new(MultiRangeType::new(self.base.clone())),
+        ]
+    }
+
+    fn logical_and_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        Box::new(BoolType)
+    }
+
     fn can_perform_logical_or_op_with(&self) -> Vec<Box<dyn DataType>> {
         vec![Box::new(self.clone())]
     }
@@ -0,0 +1,60 @@
+use std::any::Any;
+
+use crate::expression::Expr;
+use crate::expression::StringExpr;
+use crate::SemVer;
+
+use super::base::DataType;
+use super::text::TextType;
+
+#[derive(Clone)]
+pub struct SemVerType;
+
+impl DataType for SemVerType {
+    fn literal(&self) -> String {
+        "SemVer".to_string()
+    }
+
+    fn equals(&self, other: &Box<dyn DataType>) -> bool {
+        other.is_any() || other.is_semver() || other.is_variant_with(|t| t.is_semver())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn can_perform_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(SemVerType)]
+    }
+
+    fn can_perform_bang_eq_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(SemVerType)]
+    }
+
+    fn can_perform_gt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(SemVerType)]
+    }
+
+    fn can_perform_gte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(SemVerType)]
+    }
+
+    fn can_perform_lt_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(SemVerType)]
+    }
+
+    fn can_perform_lte_op_with(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(SemVerType)]
+    }
+
+    fn has_implicit_cast_from(&self, expr: &Box<dyn Expr>) -> bool {
+        if let Some(string_expr) = expr.as_any().downcast_ref::<StringExpr>() {
+            return SemVer::parse(&string_expr.value).is_some();
+        }
+        false
+    }
+
+    fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
+        vec![Box::new(TextType)]
+    }
+}
@@ -1,6 +1,13 @@
 use std::any::Any;
 
 use crate::types::array::ArrayType;
+use crate::types::bytes::BytesType;
+use crate::types::datetimetz::DateTimeTzType;
+use crate::types::decimal::DecimalType;
+use crate::types::float::FloatType;
+use crate::types::integer::IntType;
+use crate::types::json::JsonType;
+use crate::types::semver::SemVerType;
 
 use super::base::DataType;
 
@@ -79,4 +86,16 @@ impl DataType for TextType {
     fn can_perform_regexp_op_with(&self) -> Vec<Box<dyn DataType>> {
         vec![Box::new(TextType)]
     }
+
+    fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
+        vec![
+            Box::new(IntType),
+            Box::new(FloatType),
+            Box::new(JsonType),
+            Box::new(SemVerType),
+            Box::new(BytesType),
+            Box::new(DateTimeTzType),
+            Box::new(DecimalType::default()),
+        ]
+    }
 }
@@ -19,8 +19,22 @@ pub struct Arguments {
     pub page_size: usize,
     pub enable_line_editor: bool,
     pub output_format: OutputFormat,
+    pub jobs: usize,
+    pub init_script: Option<String>,
+    pub parameters: Vec<Parameter>,
 }
 
+/// Query parameter passed as `name=value` or `name:type=value`
+#[derive(Debug, PartialEq)]
+pub struct Parameter {
+    pub name: String,
+    pub type_name: Option<String>,
+    pub value: String,
+}
+
+/// Types that can be set explicitly for the query parameter value
+const PARAMETER_TYPES: [&str; 4] = ["int", "float", "bool", "text"];
+
 /// Create a new instance of Arguments with the default settings
 impl Arguments {
     fn new() -> Arguments {
@@ -31,6 +45,9 @@ impl Arguments {
             page_size: 10,
             enable_line_editor: false,
             output_format: OutputFormat::Render,
+            jobs: std::thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+            init_script: None,
+            parameters: vec![],
         }
     }
 }
@@ -162,6 +179,76 @@ pub fn parse_arguments(args: &[String]) -> Command {
 
                 arg_index += 1;
             }
+            "--jobs" | "-j" => {
+                arg_index += 1;
+                if arg_index >= args_len {
+                    let message =
+                        format!("Argument {} must be followed by the number of jobs", arg);
+                    return Command::Error(message);
+                }
+
+                let jobs_result = args[arg_index].parse::<usize>();
+                if jobs_result.is_err() || jobs_result == Ok(0) {
+                    return Command::Error("Invalid number of jobs".to_string());
+                }
+
+                arguments.jobs = jobs_result.ok().unwrap();
+                arg_index += 1;
+            }
+            "--init" | "-i" => {
+                arg_index += 1;
+                if arg_index >= args_len {
+                    let message = format!("Argument {} must be followed by the file", arg);
+                    return Command::Error(message);
+                }
+
+                arguments.init_script = Some(args[arg_index].to_string());
+                arg_index += 1;
+            }
+            "--param" => {
+                arg_index += 1;
+                if arg_index >= args_len {
+                    let message = format!("Argument {} must be followed by name=value", arg);
+                    return Command::Error(message);
+                }
+
+                let Some((name, value)) = args[arg_index].split_once('=') else {
+                    return Command::Error(
+                        "Invalid parameter, expect name=value or name:type=value".to_string(),
+                    );
+                };
+
+                let name = name.trim_start_matches(['$', ':']);
+                let (name, type_name) = match name.split_once(':') {
+                    Some((name, type_name)) => (name, Some(type_name.to_lowercase())),
+                    None => (name, None),
+                };
+
+                let name = name.to_lowercase();
+                if name.is_empty() {
+                    return Command::Error(
+                        "Invalid parameter, expect name=value or name:type=value".to_string(),
+                    );
+                }
+
+                if let Some(type_name) = &type_name {
+                    if !PARAMETER_TYPES.contains(&type_name.as_str()) {
+                        let message = format!(
+                            "Unknown parameter type `{}`, expect one of {}",
+                            type_name,
+                            PARAMETER_TYPES.join(", ")
+                        );
+                        return Command::Error(message);
+                    }
+                }
+
+                arguments.parameters.push(Parameter {
+                    name,
+                    type_name,
+                    value: value.to_string(),
+                });
+                arg_index += 1;
+            }
             _ => return Command::Error(format!("Unknown command {}", arg)),
         }
     }
@@ -202,9 +289,13 @@ pub fn print_help_list() {
     println!("-r,  --repos <REPOS>        Path for local repositories to run query on");
     println!("-s,  --script <file>        Script file contains one or more query");
     println!("-q,  --query <GitQL Query>  GitQL query to run on selected repositories");
+    println!("-i,  --init <file>          Script file to run before the session, for example definitions");
+    println!("     --param <name=value>   Bind value to query parameter `$1` or `:name`");
+    println!("                            the type can be set explicitly with name:type=value");
     println!("-p,  --pagination           Enable print result with pagination");
     println!("-ps, --pagesize             Set pagination page size [default: 10]");
     println!("-o,  --output               Set output format [render, json, csv]");
+    println!("-j,  --jobs                 Set number of threads [default: CPU cores]");
     println!("-a,  --analysis             Print Query analysis");
     println!("-e,  --editor               Enable GitQL Rich Line Editor");
     println!("-h,  --help                 Print GitQL help");
@@ -305,4 +396,104 @@ mod tests {
         let command = parse_arguments(&arguments);
         assert!(matches!(command, Command::Error { .. }));
     }
+
+    #[test]
+    fn test_arguments_with_valid_jobs() {
+        let arguments = vec!["gitql".to_string(), "--jobs".to_string(), "4".to_string()];
+        let command = parse_arguments(&arguments);
+        assert!(matches!(
+            command,
+            Command::ReplMode(Arguments { jobs: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn test_arguments_with_invalid_jobs() {
+        let arguments = vec!["gitql".to_string(), "--jobs".to_string(), "0".to_string()];
+        let command = parse_arguments(&arguments);
+        assert!(matches!(command, Command::Error { .. }));
+    }
+
+    #[test]
+    fn test_arguments_with_valid_init_script() {
+        let arguments = vec![
+            "gitql".to_string(),
+            "--init".to_string(),
+            "init.gql".to_string(),
+        ];
+        let command = parse_arguments(&arguments);
+        assert!(matches!(
+            command,
+            Command::ReplMode(Arguments {
+                init_script: Some(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_arguments_with_missing_init_script() {
+        let arguments = vec!["gitql".to_string(), "--init".to_string()];
+        let command = parse_arguments(&arguments);
+        assert!(matches!(command, Command::Error { .. }));
+    }
+
+    #[test]
+    fn test_arguments_with_valid_parameters() {
+        let arguments = vec![
+            "gitql".to_string(),
+            "--param".to_string(),
+            "1=10".to_string(),
+            "--param".to_string(),
+            ":Author=amr".to_string(),
+            "--param".to_string(),
+            "id:TEXT=123".to_string(),
+        ];
+        let command = parse_arguments(&arguments);
+        let Command::ReplMode(arguments) = command else {
+            panic!("Expect repl mode command");
+        };
+        assert_eq!(
+            arguments.parameters,
+            vec![
+                Parameter {
+                    name: "1".to_string(),
+                    type_name: None,
+                    value: "10".to_string()
+                },
+                Parameter {
+                    name: "author".to_string(),
+                    type_name: None,
+                    value: "amr".to_string()
+                },
+                Parameter {
+                    name: "id".to_string(),
+                    type_name: Some("text".to_string()),
+                    value: "123".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_arguments_with_unknown_parameter_type() {
+        let arguments = vec![
+            "gitql".to_string(),
+            "--param".to_string(),
+            "id:uuid=123".to_string(),
+        ];
+        let command = parse_arguments(&arguments);
+        assert!(matches!(command, Command::Error { .. }));
+    }
+
+    #[test]
+    fn test_arguments_with_invalid_parameter() {
+        let arguments = vec![
+            "gitql".to_string(),
+            "--param".to_string(),
+            "name".to_string(),
+        ];
+        let command = parse_arguments(&arguments);
+        assert!(matches!(command, Command::Error { .. }));
+    }
 }
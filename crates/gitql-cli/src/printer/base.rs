@@ -1,5 +1,22 @@
 use gitql_core::object::GitQLObject;
+use gitql_core::object::Row;
 
 pub trait OutputPrinter {
-    fn print(&self, object: &mut GitQLObject);
+    /// Print all rows of the object at once
+    fn print(&mut self, object: &mut GitQLObject) {
+        self.start(&object.titles);
+        if let Some(group) = object.groups.first() {
+            self.print_rows(&group.rows);
+        }
+        self.finish();
+    }
+
+    /// Start printing a new result with the given titles
+    fn start(&mut self, titles: &[String]);
+
+    /// Print rows of the current result, can be called many times while the rows are received
+    fn print_rows(&mut self, rows: &[Row]);
+
+    /// Flush the remaining rows and finish printing the current result
+    fn finish(&mut self);
 }
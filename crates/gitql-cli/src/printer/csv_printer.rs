@@ -1,27 +1,48 @@
 use csv::Writer;
-use gitql_core::object::GitQLObject;
+use gitql_core::object::Row;
 
 use super::base::OutputPrinter;
 
-pub struct CSVPrinter;
+#[derive(Default)]
+pub struct CSVPrinter {
+    row_len: usize,
+}
 
 impl OutputPrinter for CSVPrinter {
-    fn print(&self, object: &mut GitQLObject) {
-        let mut writer = Writer::from_writer(vec![]);
-        let _ = writer.write_record(object.titles.clone());
-        let row_len = object.titles.len();
-        if let Some(group) = object.groups.first() {
-            for row in &group.rows {
-                let mut values_row: Vec<String> = Vec::with_capacity(row_len);
-                for value in &row.values {
-                    values_row.push(value.literal());
-                }
-                let _ = writer.write_record(values_row);
+    fn start(&mut self, titles: &[String]) {
+        self.row_len = titles.len();
+        print!("Ok(\"");
+        print_csv_records(&[titles.to_vec()]);
+    }
+
+    fn print_rows(&mut self, rows: &[Row]) {
+        let mut records: Vec<Vec<String>> = Vec::with_capacity(rows.len());
+        for row in rows {
+            let mut values_row: Vec<String> = Vec::with_capacity(self.row_len);
+            for value in &row.values {
+                values_row.push(value.literal());
             }
+            records.push(values_row);
         }
+        print_csv_records(&records);
+    }
+
+    fn finish(&mut self) {
+        println!("\")");
+    }
+}
+
+/// Print the records escaped as part of debug string
+fn print_csv_records(records: &[Vec<String>]) {
+    let mut writer = Writer::from_writer(vec![]);
+    for record in records {
+        let _ = writer.write_record(record);
+    }
 
-        if let Ok(writer_content) = writer.into_inner() {
-            println!("{:?}", String::from_utf8(writer_content));
+    if let Ok(writer_content) = writer.into_inner() {
+        if let Ok(content) = String::from_utf8(writer_content) {
+            let escaped_content = format!("{:?}", content);
+            print!("{}", &escaped_content[1..escaped_content.len() - 1]);
         }
     }
 }
@@ -1,29 +1,41 @@
-use gitql_core::object::GitQLObject;
+use gitql_core::object::Row;
 
 use super::base::OutputPrinter;
 
-pub struct JSONPrinter;
+#[derive(Default)]
+pub struct JSONPrinter {
+    titles: Vec<String>,
+    printed_rows_count: usize,
+}
 
 impl OutputPrinter for JSONPrinter {
-    fn print(&self, object: &mut GitQLObject) {
-        let mut elements: Vec<serde_json::Value> = vec![];
+    fn start(&mut self, titles: &[String]) {
+        self.titles = titles.to_vec();
+        self.printed_rows_count = 0;
+        print!("[");
+    }
+
+    fn print_rows(&mut self, rows: &[Row]) {
+        for row in rows {
+            let mut object = serde_json::Map::new();
+            for (i, value) in row.values.iter().enumerate() {
+                object.insert(
+                    self.titles[i].to_string(),
+                    serde_json::Value::String(value.literal()),
+                );
+            }
 
-        if let Some(group) = object.groups.first() {
-            let titles = &object.titles;
-            for row in &group.rows {
-                let mut object = serde_json::Map::new();
-                for (i, value) in row.values.iter().enumerate() {
-                    object.insert(
-                        titles[i].to_string(),
-                        serde_json::Value::String(value.literal()),
-                    );
+            if let Ok(json_str) = serde_json::to_string(&serde_json::Value::Object(object)) {
+                if self.printed_rows_count > 0 {
+                    print!(",");
                 }
-                elements.push(serde_json::Value::Object(object));
+                print!("{}", json_str);
+                self.printed_rows_count += 1;
             }
         }
+    }
 
-        if let Ok(json_str) = serde_json::to_string(&serde_json::Value::Array(elements)) {
-            println!("{}", json_str);
-        }
+    fn finish(&mut self) {
+        println!("]");
     }
 }
@@ -1,3 +1,6 @@
+use comfy_table::ColumnConstraint;
+use comfy_table::TableComponent;
+use comfy_table::Width;
 use gitql_core::object::Row;
 
 use super::base::OutputPrinter;
@@ -8,9 +11,16 @@ enum PaginationInput {
     Quit,
 }
 
+/// Number of rows printed as one part of the table when rows are received incrementally without pagination
+const TABLE_CHUNK_SIZE: usize = 1000;
+
 pub struct TablePrinter {
     pub pagination: bool,
     pub page_size: usize,
+    titles: Vec<String>,
+    rows: Vec<Row>,
+    /// Columns widths of the table that is printed in parts, set after printing the first part
+    columns_widths: Option<Vec<u16>>,
 }
 
 impl TablePrinter {
@@ -18,44 +28,136 @@ impl TablePrinter {
         TablePrinter {
             pagination,
             page_size,
+            titles: vec![],
+            rows: vec![],
+            columns_widths: None,
+        }
+    }
+
+    fn table_headers(&self) -> Vec<comfy_table::Cell> {
+        let header_color = comfy_table::Color::Green;
+        let mut table_headers = vec![];
+        for key in &self.titles {
+            table_headers.push(comfy_table::Cell::new(key).fg(header_color));
+        }
+        table_headers
+    }
+
+    fn print_table_part(&mut self, rows: &[Row], is_last_part: bool) {
+        for line in self.render_table_part(rows, is_last_part) {
+            println!("{line}");
+        }
+    }
+
+    /// Render the buffered rows as a part of one table, the first part has the header and the next parts
+    /// continue it with the same columns widths, only the last part has the bottom border
+    fn render_table_part(&mut self, rows: &[Row], is_last_part: bool) -> Vec<String> {
+        let mut table = create_table();
+        add_rows_to_table(&mut table, self.titles.len(), rows);
+
+        match &self.columns_widths {
+            None => {
+                table.set_header(self.table_headers());
+            }
+            Some(columns_widths) => {
+                // Constraints are applied to the columns of the rows that already added
+                let constraints = columns_widths
+                    .iter()
+                    .map(|width| ColumnConstraint::Absolute(Width::Fixed(*width)));
+                table.set_constraints(constraints);
+
+                // Draw the top border as a separator between the previous part rows and this part rows
+                let separators = [
+                    (
+                        TableComponent::TopLeftCorner,
+                        TableComponent::LeftBorderIntersections,
+                    ),
+                    (TableComponent::TopBorder, TableComponent::HorizontalLines),
+                    (
+                        TableComponent::TopBorderIntersections,
+                        TableComponent::MiddleIntersections,
+                    ),
+                    (
+                        TableComponent::TopRightCorner,
+                        TableComponent::RightBorderIntersections,
+                    ),
+                ];
+                for (border, separator) in separators {
+                    if let Some(character) = table.style(separator) {
+                        table.set_style(border, character);
+                    }
+                }
+            }
+        }
+
+        if !is_last_part {
+            table.remove_style(TableComponent::BottomLeftCorner);
+            table.remove_style(TableComponent::BottomBorder);
+            table.remove_style(TableComponent::BottomBorderIntersections);
+            table.remove_style(TableComponent::BottomRightCorner);
+        }
+
+        let lines: Vec<String> = table.lines().collect();
+        if self.columns_widths.is_none() {
+            self.columns_widths = Some(top_border_columns_widths(&lines[0]));
         }
+        lines
     }
 }
 
 impl OutputPrinter for TablePrinter {
-    fn print(&self, object: &mut gitql_core::object::GitQLObject) {
-        if object.is_empty() || object.groups[0].is_empty() {
+    fn start(&mut self, titles: &[String]) {
+        self.titles = titles.to_vec();
+        self.rows.clear();
+    }
+
+    fn print_rows(&mut self, rows: &[Row]) {
+        self.rows.extend_from_slice(rows);
+
+        // Without pagination there is no need to wait for all rows before printing, the last row
+        // is kept to be printed with the bottom border of the table when all rows are received
+        if !self.pagination && self.rows.len() > TABLE_CHUNK_SIZE {
+            let last_row = self.rows.pop().unwrap();
+            let rows = std::mem::replace(&mut self.rows, vec![last_row]);
+            self.print_table_part(&rows, false);
+        }
+    }
+
+    fn finish(&mut self) {
+        let rows = std::mem::take(&mut self.rows);
+
+        // Print the last part of the table that is printed in parts
+        if self.columns_widths.is_some() {
+            self.print_table_part(&rows, true);
+            self.columns_widths = None;
             return;
         }
 
-        let titles = &object.titles;
-        let group = object.groups.first().unwrap();
-        let group_len = group.len();
+        let rows_len = rows.len();
+        if rows_len == 0 {
+            return;
+        }
 
         // Setup table headers
-        let header_color = comfy_table::Color::Green;
-        let mut table_headers = vec![];
-        for key in titles {
-            table_headers.push(comfy_table::Cell::new(key).fg(header_color));
-        }
+        let table_headers = self.table_headers();
 
         // Print all data without pagination
-        if !self.pagination || self.page_size >= group_len {
-            print_group_as_table(titles, table_headers, &group.rows);
+        if !self.pagination || self.page_size >= rows_len {
+            print_group_as_table(&self.titles, table_headers, &rows);
             return;
         }
 
         // Setup the pagination mode
-        let number_of_pages = (group_len as f64 / self.page_size as f64).ceil() as usize;
+        let number_of_pages = (rows_len as f64 / self.page_size as f64).ceil() as usize;
         let mut current_page = 1;
 
         loop {
             let start_index = (current_page - 1) * self.page_size;
-            let end_index = (start_index + self.page_size).min(group_len);
+            let end_index = (start_index + self.page_size).min(rows_len);
 
-            let current_page_groups = &group.rows[start_index..end_index];
+            let current_page_groups = &rows[start_index..end_index];
             println!("Page {}/{}", current_page, number_of_pages);
-            print_group_as_table(titles, table_headers.clone(), current_page_groups);
+            print_group_as_table(&self.titles, table_headers.clone(), current_page_groups);
 
             let pagination_input = handle_pagination_input(current_page, number_of_pages);
             match pagination_input {
@@ -68,18 +170,25 @@ impl OutputPrinter for TablePrinter {
 }
 
 fn print_group_as_table(titles: &[String], table_headers: Vec<comfy_table::Cell>, rows: &[Row]) {
+    let mut table = create_table();
+    table.set_header(table_headers);
+    add_rows_to_table(&mut table, titles.len(), rows);
+
+    // Print table
+    println!("{table}");
+}
+
+fn create_table() -> comfy_table::Table {
     let mut table = comfy_table::Table::new();
 
     // Setup table style
     table.load_preset(comfy_table::presets::UTF8_FULL);
     table.apply_modifier(comfy_table::modifiers::UTF8_ROUND_CORNERS);
     table.set_content_arrangement(comfy_table::ContentArrangement::Dynamic);
+    table
+}
 
-    table.set_header(table_headers);
-
-    let titles_len = titles.len();
-
-    // Add rows to the table
+fn add_rows_to_table(table: &mut comfy_table::Table, titles_len: usize, rows: &[Row]) {
     for row in rows {
         let mut table_row: Vec<comfy_table::Cell> = vec![];
         for index in 0..titles_len {
@@ -89,9 +198,19 @@ fn print_group_as_table(titles: &[String], table_headers: Vec<comfy_table::Cell>
         }
         table.add_row(table_row);
     }
+}
 
-    // Print table
-    println!("{table}");
+/// Columns widths including the padding from the rendered top border like `╭────┬──╮`
+fn top_border_columns_widths(top_border: &str) -> Vec<u16> {
+    let characters: Vec<char> = top_border.chars().collect();
+    if characters.len() < 2 {
+        return vec![];
+    }
+
+    characters[1..characters.len() - 1]
+        .split(|character| *character == '┬')
+        .map(|column| column.len() as u16)
+        .collect()
 }
 
 fn handle_pagination_input(current_page: usize, number_of_pages: usize) -> PaginationInput {
@@ -138,3 +257,47 @@ fn handle_pagination_input(current_page: usize, number_of_pages: usize) -> Pagin
         println!("Invalid input");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gitql_core::values::integer::IntValue;
+    use gitql_core::values::text::TextValue;
+
+    use super::*;
+
+    fn create_rows(range: std::ops::Range<i64>) -> Vec<Row> {
+        range
+            .map(|index| Row {
+                values: vec![
+                    Box::new(IntValue::new(index)),
+                    Box::new(TextValue::new(format!("title {index}"))),
+                ],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_table_parts_are_rendered_as_one_table() {
+        let titles = vec!["id".to_string(), "title".to_string()];
+        let mut printer = TablePrinter::new(false, 10);
+        printer.start(&titles);
+
+        let mut lines = printer.render_table_part(&create_rows(1..4), false);
+        lines.extend(printer.render_table_part(&create_rows(4..7), false));
+        lines.extend(printer.render_table_part(&create_rows(7..9), true));
+
+        let mut table = create_table();
+        table.set_header(printer.table_headers());
+        add_rows_to_table(&mut table, titles.len(), &create_rows(1..9));
+        let expected: Vec<String> = table.lines().collect();
+
+        assert_eq!(lines, expected);
+    }
+
+    #[test]
+    fn test_top_border_columns_widths() {
+        assert_eq!(top_border_columns_widths("╭────┬──╮"), vec![4, 2]);
+        assert_eq!(top_border_columns_widths("╭───╮"), vec![3]);
+        assert!(top_border_columns_widths("").is_empty());
+    }
+}
@@ -1,15 +1,62 @@
 use std::collections::HashMap;
-
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use chrono_tz::Tz;
+use gitql_ast::statement::GQLQuery;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::text::TextType;
 use gitql_ast::types::DataType;
 
 use crate::schema::Schema;
 use crate::signature::AggregationFunction;
+use crate::signature::FallibleAggregationFunction;
+use crate::signature::FallibleFunction;
+use crate::signature::FallibleWindowFunction;
+use crate::signature::HigherOrderFunction;
 use crate::signature::Signature;
 use crate::signature::StandardFunction;
+use crate::signature::UserFunction;
 use crate::signature::WindowFunction;
 use crate::types_table::TypesTable;
+use crate::values::datetimetz::parse_time_zone;
+use crate::values::integer::IntValue;
+use crate::values::text::TextValue;
 use crate::values::Value;
 
+/// Name of the system variable that hold the session time zone
+pub const TIME_ZONE_VARIABLE: &str = "@@time_zone";
+
+/// Name of the system variable that hold the minimum rows count on both sides of equi join to use sort-merge join
+pub const SORT_MERGE_JOIN_MIN_ROWS_VARIABLE: &str = "@@sort_merge_join_min_rows";
+
+/// Default minimum rows count on both sides of equi join to use sort-merge join,
+/// on very large inputs sorting rows indices is cheaper than keeping a table of every key literal
+pub const DEFAULT_SORT_MERGE_JOIN_MIN_ROWS: i64 = 100_000;
+
+/// Maximum depth of nested user functions calls and views evaluation
+pub const MAX_CALL_DEPTH: usize = 64;
+
+/// Names of the user definitions, allocated once for the whole program session
+static DEFINITIONS_NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+/// Return static reference to the name of user function, view, column or type,
+/// the same name is allocated only once so replacing the definitions doesn't allocate it again
+pub fn intern_name(name: &str) -> &'static str {
+    let names = DEFINITIONS_NAMES.get_or_init(|| Mutex::new(HashSet::new()));
+    let mut names = names.lock().unwrap();
+    match names.get(name) {
+        Some(interned) => interned,
+        None => {
+            let interned: &'static str = Box::leak(name.to_string().into_boxed_str());
+            names.insert(interned);
+            interned
+        }
+    }
+}
+
 /// Environment that track schema, functions, scopes and types
 /// to be used in different places in the query engine
 pub struct Environment {
@@ -22,46 +69,94 @@ pub struct Environment {
     /// Standard function references
     pub std_functions: HashMap<&'static str, StandardFunction>,
 
+    /// Fallible function references, their signatures are registered with standard functions
+    pub fallible_functions: HashMap<&'static str, FallibleFunction>,
+
+    /// Higher order function references, their signatures are registered with standard functions
+    pub higher_order_functions: HashMap<&'static str, HigherOrderFunction>,
+
+    /// User functions references, their signatures are registered with standard functions
+    pub user_functions: HashMap<String, Rc<UserFunction>>,
+
+    /// Views queries, their columns are registered in the schema as tables
+    pub views: HashMap<String, GQLQuery>,
+
     /// Aggregation function signatures
     pub aggregation_signatures: HashMap<&'static str, Signature>,
 
     /// Aggregation function references
     pub aggregation_functions: HashMap<&'static str, AggregationFunction>,
 
+    /// Fallible aggregation function references, their signatures are registered with aggregation functions
+    pub fallible_aggregation_functions: HashMap<&'static str, FallibleAggregationFunction>,
+
     /// Window function signatures
     pub window_signatures: HashMap<&'static str, Signature>,
 
     /// Window function references
     pub window_functions: HashMap<&'static str, WindowFunction>,
 
+    /// Fallible window function references, their signatures are registered with window functions
+    pub fallible_window_functions: HashMap<&'static str, FallibleWindowFunction>,
+
     /// All Global Variables values that can life for this program session
     pub globals: HashMap<String, Box<dyn Value>>,
 
     /// All Global Variables Types that can life for this program session
     pub globals_types: HashMap<String, Box<dyn DataType>>,
 
+    /// Query parameters values bound to the placeholders by name or position
+    pub parameters: HashMap<String, Box<dyn Value>>,
+
     /// Local variables types in the current scope, later will be multi layer scopes
     pub scopes: HashMap<String, Box<dyn DataType>>,
 
     /// A Table of DataTypes mapped to their original names or aliases
     pub types_table: TypesTable,
+
+    /// Current depth of nested user functions calls and views evaluation
+    pub call_depth: usize,
 }
 
 impl Environment {
     /// Create new [`Environment`] instance with Data Schema
     pub fn new(schema: Schema) -> Self {
+        let mut globals: HashMap<String, Box<dyn Value>> = HashMap::default();
+        let mut globals_types: HashMap<String, Box<dyn DataType>> = HashMap::default();
+        globals.insert(
+            TIME_ZONE_VARIABLE.to_string(),
+            Box::new(TextValue::new(Tz::UTC.name().to_string())),
+        );
+        globals_types.insert(TIME_ZONE_VARIABLE.to_string(), Box::new(TextType));
+        globals.insert(
+            SORT_MERGE_JOIN_MIN_ROWS_VARIABLE.to_string(),
+            Box::new(IntValue::new(DEFAULT_SORT_MERGE_JOIN_MIN_ROWS)),
+        );
+        globals_types.insert(
+            SORT_MERGE_JOIN_MIN_ROWS_VARIABLE.to_string(),
+            Box::new(IntType),
+        );
+
         Self {
             schema,
             std_signatures: HashMap::default(),
             std_functions: HashMap::default(),
+            fallible_functions: HashMap::default(),
+            higher_order_functions: HashMap::default(),
+            user_functions: HashMap::default(),
+            views: HashMap::default(),
             aggregation_signatures: HashMap::default(),
             aggregation_functions: HashMap::default(),
+            fallible_aggregation_functions: HashMap::default(),
             window_signatures: HashMap::default(),
             window_functions: HashMap::default(),
-            globals: HashMap::default(),
-            globals_types: HashMap::default(),
+            fallible_window_functions: HashMap::default(),
+            globals,
+            globals_types,
+            parameters: HashMap::default(),
             scopes: HashMap::default(),
             types_table: TypesTable::new(),
+            call_depth: 0,
         }
     }
 
@@ -75,6 +170,19 @@ impl Environment {
         self.std_functions.extend(functions.to_owned());
     }
 
+    /// Register fallible functions references
+    pub fn with_fallible_functions(&mut self, functions: &HashMap<&'static str, FallibleFunction>) {
+        self.fallible_functions.extend(functions.to_owned());
+    }
+
+    /// Register higher order functions references
+    pub fn with_higher_order_functions(
+        &mut self,
+        functions: &HashMap<&'static str, HigherOrderFunction>,
+    ) {
+        self.higher_order_functions.extend(functions.to_owned());
+    }
+
     /// Register aggregation functions signatures and references
     pub fn with_aggregation_functions(
         &mut self,
@@ -85,6 +193,15 @@ impl Environment {
         self.aggregation_functions.extend(aggregation.to_owned());
     }
 
+    /// Register fallible aggregation functions references
+    pub fn with_fallible_aggregation_functions(
+        &mut self,
+        aggregation: &HashMap<&'static str, FallibleAggregationFunction>,
+    ) {
+        self.fallible_aggregation_functions
+            .extend(aggregation.to_owned());
+    }
+
     /// Register Window functions signatures and references
     pub fn with_window_functions(
         &mut self,
@@ -95,14 +212,40 @@ impl Environment {
         self.window_functions.extend(window.to_owned());
     }
 
+    /// Register fallible Window functions references
+    pub fn with_fallible_window_functions(
+        &mut self,
+        window: &HashMap<&'static str, FallibleWindowFunction>,
+    ) {
+        self.fallible_window_functions.extend(window.to_owned());
+    }
+
     /// Register new Modified Types table
     pub fn with_types_table(&mut self, types_table: TypesTable) {
         self.types_table = types_table
     }
 
+    /// Return the session time zone set by `SET @@time_zone`, UTC by default
+    pub fn session_time_zone(&self) -> Tz {
+        self.globals
+            .get(TIME_ZONE_VARIABLE)
+            .and_then(|value| value.as_text())
+            .and_then(|name| parse_time_zone(&name))
+            .unwrap_or(Tz::UTC)
+    }
+
+    /// Return the minimum rows count set by `SET @@sort_merge_join_min_rows`, 100000 by default
+    pub fn sort_merge_join_min_rows(&self) -> usize {
+        self.globals
+            .get(SORT_MERGE_JOIN_MIN_ROWS_VARIABLE)
+            .and_then(|value| value.as_int())
+            .unwrap_or(DEFAULT_SORT_MERGE_JOIN_MIN_ROWS)
+            .max(0) as usize
+    }
+
     /// Return true if this name is a valid standard function
     pub fn is_std_function(&self, str: &str) -> bool {
-        self.std_functions.contains_key(str)
+        self.std_functions.contains_key(str) || self.fallible_functions.contains_key(str)
     }
 
     /// Return Standard function signature by name
@@ -115,6 +258,36 @@ impl Environment {
         self.std_functions.get(str)
     }
 
+    /// Return Fallible function reference by name
+    pub fn fallible_function(&self, str: &str) -> Option<&FallibleFunction> {
+        self.fallible_functions.get(str)
+    }
+
+    /// Return true if this name is a valid higher order function
+    pub fn is_higher_order_function(&self, str: &str) -> bool {
+        self.higher_order_functions.contains_key(str)
+    }
+
+    /// Return Higher order function reference by name
+    pub fn higher_order_function(&self, str: &str) -> Option<&HigherOrderFunction> {
+        self.higher_order_functions.get(str)
+    }
+
+    /// Return true if this name is a valid user function
+    pub fn is_user_function(&self, str: &str) -> bool {
+        self.user_functions.contains_key(str)
+    }
+
+    /// Return User function reference by name
+    pub fn user_function(&self, str: &str) -> Option<&Rc<UserFunction>> {
+        self.user_functions.get(str)
+    }
+
+    /// Return true if this name is a valid view
+    pub fn is_view(&self, str: &str) -> bool {
+        self.views.contains_key(str)
+    }
+
     /// Return true if this name is a valid aggregation function
     pub fn is_aggregation_function(&self, str: &str) -> bool {
         self.aggregation_signatures.contains_key(str)
@@ -130,9 +303,14 @@ impl Environment {
         self.aggregation_functions.get(str)
     }
 
+    /// Return Fallible aggregation function reference by name
+    pub fn fallible_aggregation_function(&self, str: &str) -> Option<&FallibleAggregationFunction> {
+        self.fallible_aggregation_functions.get(str)
+    }
+
     /// Return true if this name is a valid Window function
     pub fn is_window_function(&self, str: &str) -> bool {
-        self.window_functions.contains_key(str)
+        self.window_functions.contains_key(str) || self.fallible_window_functions.contains_key(str)
     }
 
     /// Return Window function signature by name
@@ -145,6 +323,11 @@ impl Environment {
         self.window_functions.get(str)
     }
 
+    /// Return Fallible Window function reference by name
+    pub fn fallible_window_function(&self, str: &str) -> Option<&FallibleWindowFunction> {
+        self.fallible_window_functions.get(str)
+    }
+
     /// Define in the current scope
     pub fn define(&mut self, str: String, data_type: Box<dyn DataType>) {
         self.scopes.insert(str, data_type);
@@ -155,6 +338,11 @@ impl Environment {
         self.globals_types.insert(str, data_type);
     }
 
+    /// Bind value to query parameter by name or position
+    pub fn bind_parameter(&mut self, name: String, value: Box<dyn Value>) {
+        self.parameters.insert(name, value);
+    }
+
     /// Returns true if local or global scopes has contains field
     pub fn contains(&self, str: &String) -> bool {
         self.scopes.contains_key(str) || self.globals_types.contains_key(str)
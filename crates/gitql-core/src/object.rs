@@ -10,6 +10,9 @@ pub struct Row {
 #[derive(Clone, Default)]
 pub struct Group {
     pub rows: Vec<Row>,
+    /// Indices of the columns that are not part of the grouping set of this group,
+    /// for example the columns that are rolled up in the subtotal groups of `CUBE`
+    pub rolled_up_columns: Vec<usize>,
 }
 
 impl Group {
@@ -40,7 +43,10 @@ impl GitQLObject {
         }
 
         self.groups.clear();
-        self.groups.push(Group { rows })
+        self.groups.push(Group {
+            rows,
+            rolled_up_columns: vec![],
+        })
     }
 
     /// Returns true of there is no groups
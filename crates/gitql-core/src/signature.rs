@@ -1,10 +1,23 @@
 use super::values::Value;
 
+use gitql_ast::expression::Expr;
 use gitql_ast::types::DataType;
 
 /// Standard function accept array of values and return single [`Value`]
 pub type StandardFunction = fn(&[Box<dyn Value>]) -> Box<dyn Value>;
 
+/// Fallible function accept array of values and return single [`Value`],
+/// or an error message if the arguments values are invalid such as unknown format or unit names
+pub type FallibleFunction = fn(&[Box<dyn Value>]) -> Result<Box<dyn Value>, String>;
+
+/// Lambda call evaluate the lambda body with the passed parameters values and return single [`Value`]
+pub type LambdaCall<'a> = dyn FnMut(&[Box<dyn Value>]) -> Result<Box<dyn Value>, String> + 'a;
+
+/// Higher order function accept array of values for the arguments before the lambda
+/// and a lambda call to evaluate the last argument then return single [`Value`]
+pub type HigherOrderFunction =
+    fn(&[Box<dyn Value>], &mut LambdaCall) -> Result<Box<dyn Value>, String>;
+
 /// Aggregation function accept a selected row values for each row in group and return single [`Value`]
 ///
 /// [`Vec<Vec<Value>>`] represent the selected values from each row in group
@@ -18,6 +31,10 @@ pub type StandardFunction = fn(&[Box<dyn Value>]) -> Box<dyn Value>;
 ///
 pub type AggregationFunction = fn(&[Vec<Box<dyn Value>>]) -> Box<dyn Value>;
 
+/// Fallible aggregation function accept the same group values as [`AggregationFunction`],
+/// and return an error message if the arguments values are invalid
+pub type FallibleAggregationFunction = fn(&[Vec<Box<dyn Value>>]) -> Result<Box<dyn Value>, String>;
+
 /// Window function  a selected row values for each row in a specific frame and return single [`Value`]
 ///
 /// [`Vec<Vec<Value>>`] represent the selected values from each row in frame of rows
@@ -29,8 +46,16 @@ pub type AggregationFunction = fn(&[Vec<Box<dyn Value>>]) -> Box<dyn Value>;
 /// This implementation allow Window` function to accept more than one parameter,
 /// and also accept any Expression not only field name
 ///
+/// Missing optional parameters are passed as Null values, and if the window has `ORDER BY`
+/// the ordering values of each row are appended after the parameters, for example
+/// `rank() OVER (ORDER BY name)` is called with [[name], [name], [name]]
+///
 pub type WindowFunction = fn(&[Vec<Box<dyn Value>>]) -> Vec<Box<dyn Value>>;
 
+/// Fallible window function accept the same frame values as [`WindowFunction`],
+/// and return an error message if the arguments values are invalid
+pub type FallibleWindowFunction = fn(&[Vec<Box<dyn Value>>]) -> Result<Vec<Box<dyn Value>>, String>;
+
 /// Signature struct is a representation of function type
 ///
 /// Function type in GitQL used to track parameters and return type for now
@@ -81,3 +106,13 @@ impl Signature {
         self
     }
 }
+
+/// User function defined by `CREATE FUNCTION` statement, evaluated by binding the arguments
+/// values to the parameters names inside the body expression
+#[derive(Clone)]
+pub struct UserFunction {
+    pub parameters: Vec<String>,
+    pub body: Box<dyn Expr>,
+    /// Names of the user functions called directly by the body
+    pub dependencies: Vec<String>,
+}
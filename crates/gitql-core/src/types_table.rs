@@ -1,10 +1,15 @@
 use std::collections::HashMap;
 
 use gitql_ast::types::boolean::BoolType;
+use gitql_ast::types::bytes::BytesType;
 use gitql_ast::types::date::DateType;
 use gitql_ast::types::datetime::DateTimeType;
+use gitql_ast::types::datetimetz::DateTimeTzType;
+use gitql_ast::types::decimal::DecimalType;
 use gitql_ast::types::float::FloatType;
 use gitql_ast::types::integer::IntType;
+use gitql_ast::types::json::JsonType;
+use gitql_ast::types::semver::SemVerType;
 use gitql_ast::types::text::TextType;
 use gitql_ast::types::time::TimeType;
 use gitql_ast::types::DataType;
@@ -78,9 +83,18 @@ fn register_primitives_types(types_map: &mut HashMap<&'static str, Box<dyn DataT
     types_map.insert("date", Box::new(DateType));
     types_map.insert("time", Box::new(TimeType));
     types_map.insert("datetime", Box::new(DateTimeType));
+    types_map.insert("datetimetz", Box::new(DateTimeTzType));
+    types_map.insert("json", Box::new(JsonType));
+    types_map.insert("semver", Box::new(SemVerType));
+    types_map.insert("decimal", Box::new(DecimalType::default()));
+    types_map.insert("bytes", Box::new(BytesType));
 
     // SQL Type Aliases
     types_map.insert("int", Box::new(IntType));
     types_map.insert("float", Box::new(FloatType));
     types_map.insert("bool", Box::new(BoolType));
+    types_map.insert("numeric", Box::new(DecimalType::default()));
+    types_map.insert("timestamptz", Box::new(DateTimeTzType));
+    types_map.insert("blob", Box::new(BytesType));
+    types_map.insert("bytea", Box::new(BytesType));
 }
@@ -160,4 +160,8 @@ impl Value for ArrayValue {
 
         Ok(Box::new(BoolValue::new_false()))
     }
+
+    fn unnest_op(&self) -> Result<Vec<Box<dyn Value>>, String> {
+        Ok(self.values.clone())
+    }
 }
@@ -2,21 +2,30 @@ use std::any::Any;
 use std::cmp::Ordering;
 use std::fmt;
 
+use chrono_tz::Tz;
 use dyn_clone::DynClone;
 use gitql_ast::operator::GroupComparisonOperator;
 use gitql_ast::types::DataType;
+use gitql_ast::Decimal;
 use gitql_ast::Interval;
+use gitql_ast::SemVer;
 
 use super::array::ArrayValue;
 use super::boolean::BoolValue;
+use super::bytes::BytesValue;
 use super::composite::CompositeValue;
 use super::date::DateValue;
 use super::datetime::DateTimeValue;
+use super::datetimetz::DateTimeTzValue;
+use super::decimal::DecimalValue;
 use super::float::FloatValue;
 use super::integer::IntValue;
 use super::interval::IntervalValue;
+use super::json::JsonValue;
+use super::multirange::MultiRangeValue;
 use super::null::NullValue;
 use super::range::RangeValue;
+use super::semver::SemVerValue;
 use super::text::TextValue;
 use super::time::TimeValue;
 
@@ -147,6 +156,20 @@ pub trait Value: DynClone {
         Err("Unsupported operator for this type".to_string())
     }
 
+    /// Perform `->` operator and return new [`Value`] represent the result or Exception message as [`String`]
+    #[allow(unused_variables)]
+    #[allow(clippy::borrowed_box)]
+    fn arrow_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        Err("Unsupported operator for this type".to_string())
+    }
+
+    /// Perform `->>` operator and return new [`Value`] represent the result or Exception message as [`String`]
+    #[allow(unused_variables)]
+    #[allow(clippy::borrowed_box)]
+    fn long_arrow_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        Err("Unsupported operator for this type".to_string())
+    }
+
     /// Perform unary `[S:E]` operator and return new [`Value`] represent the result or Exception message as [`String`]
     #[allow(unused_variables)]
     #[allow(clippy::borrowed_box)]
@@ -299,6 +322,11 @@ pub trait Value: DynClone {
         Err("Unsupported operator for this type".to_string())
     }
 
+    /// Perform `UNNEST` operator and return list of [`Value`] represent the elements or Exception message as [`String`]
+    fn unnest_op(&self) -> Result<Vec<Box<dyn Value>>, String> {
+        Err("Unsupported operator for this type".to_string())
+    }
+
     /// Perform `@>` operator and return new [`Value`] represent the result or Exception message as [`String`]
     #[allow(unused_variables)]
     #[allow(clippy::borrowed_box)]
@@ -439,6 +467,20 @@ impl dyn Value {
         None
     }
 
+    /// Return true if this value is [`DateTimeTzValue`]
+    pub fn is_date_time_tz(&self) -> bool {
+        self.as_any().downcast_ref::<DateTimeTzValue>().is_some()
+    }
+
+    /// Return the timestamp and time zone of [`DateTimeTzValue`]
+    /// or None if this type it's called from wrong [`Value`]
+    pub fn as_date_time_tz(&self) -> Option<(i64, Tz)> {
+        if let Some(date_time_tz_value) = self.as_any().downcast_ref::<DateTimeTzValue>() {
+            return Some((date_time_tz_value.value, date_time_tz_value.time_zone));
+        }
+        None
+    }
+
     /// Return true if this value is [`IntervalValue`]
     pub fn is_interval(&self) -> bool {
         self.as_any().downcast_ref::<IntervalValue>().is_some()
@@ -453,6 +495,62 @@ impl dyn Value {
         None
     }
 
+    /// Return true if this value is [`JsonValue`]
+    pub fn is_json(&self) -> bool {
+        self.as_any().downcast_ref::<JsonValue>().is_some()
+    }
+
+    /// Return [`serde_json::Value`] represent the value of [`JsonValue`]
+    /// or None if this type it's called from wrong [`Value`]
+    pub fn as_json(&self) -> Option<serde_json::Value> {
+        if let Some(json_value) = self.as_any().downcast_ref::<JsonValue>() {
+            return Some(json_value.value.clone());
+        }
+        None
+    }
+
+    /// Return true if this value is [`DecimalValue`]
+    pub fn is_decimal(&self) -> bool {
+        self.as_any().downcast_ref::<DecimalValue>().is_some()
+    }
+
+    /// Return [`Decimal`] represent the value of [`DecimalValue`]
+    /// or None if this type it's called from wrong [`Value`]
+    pub fn as_decimal(&self) -> Option<Decimal> {
+        if let Some(decimal_value) = self.as_any().downcast_ref::<DecimalValue>() {
+            return Some(decimal_value.value);
+        }
+        None
+    }
+
+    /// Return true if this value is [`SemVerValue`]
+    pub fn is_semver(&self) -> bool {
+        self.as_any().downcast_ref::<SemVerValue>().is_some()
+    }
+
+    /// Return [`SemVer`] represent the value of [`SemVerValue`]
+    /// or None if this type it's called from wrong [`Value`]
+    pub fn as_semver(&self) -> Option<SemVer> {
+        if let Some(semver_value) = self.as_any().downcast_ref::<SemVerValue>() {
+            return Some(semver_value.version.clone());
+        }
+        None
+    }
+
+    /// Return true if this value is [`BytesValue`]
+    pub fn is_bytes(&self) -> bool {
+        self.as_any().downcast_ref::<BytesValue>().is_some()
+    }
+
+    /// Return bytes represent the value of [`BytesValue`]
+    /// or None if this type it's called from wrong [`Value`]
+    pub fn as_bytes(&self) -> Option<Vec<u8>> {
+        if let Some(bytes_value) = self.as_any().downcast_ref::<BytesValue>() {
+            return Some(bytes_value.value.clone());
+        }
+        None
+    }
+
     /// Return true if this value is [`ArrayValue`]
     pub fn is_array(&self) -> bool {
         self.as_any().downcast_ref::<ArrayValue>().is_some()
@@ -489,6 +587,11 @@ impl dyn Value {
         None
     }
 
+    /// Return true if this value is [`MultiRangeValue`]
+    pub fn is_multirange(&self) -> bool {
+        self.as_any().downcast_ref::<MultiRangeValue>().is_some()
+    }
+
     /// Return true if this value is [`NullValue`]
     pub fn is_null(&self) -> bool {
         self.as_any().downcast_ref::<NullValue>().is_some()
@@ -297,3 +297,19 @@ impl Value for BoolValue {
         Err("Unexpected value to perform `CAST` with".to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::values::null::NullValue;
+
+    #[test]
+    fn test_bool_with_null() {
+        let value = BoolValue::new_true();
+        let null: Box<dyn Value> = Box::new(NullValue);
+        assert!(!value.equals(&null));
+        assert_eq!(value.compare(&null), None);
+        assert!(value.eq_op(&null).is_err());
+        assert!(value.logical_and_op(&null).is_err());
+    }
+}
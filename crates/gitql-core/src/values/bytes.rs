@@ -0,0 +1,113 @@
+use std::any::Any;
+use std::cmp::Ordering;
+use std::fmt::Write;
+
+use gitql_ast::types::bytes::BytesType;
+use gitql_ast::types::DataType;
+
+use super::base::Value;
+use super::boolean::BoolValue;
+use super::text::TextValue;
+
+#[derive(Clone)]
+pub struct BytesValue {
+    pub value: Vec<u8>,
+}
+
+impl BytesValue {
+    pub fn new(value: Vec<u8>) -> Self {
+        BytesValue { value }
+    }
+
+    /// Return the bytes as lowercase hex digits without prefix
+    pub fn to_hex(&self) -> String {
+        let mut hex = String::with_capacity(self.value.len() * 2);
+        for byte in self.value.iter() {
+            let _ = write!(hex, "{:02x}", byte);
+        }
+        hex
+    }
+}
+
+impl Value for BytesValue {
+    fn literal(&self) -> String {
+        format!("\\x{}", self.to_hex())
+    }
+
+    fn equals(&self, other: &Box<dyn Value>) -> bool {
+        if let Some(other_bytes) = other.as_any().downcast_ref::<BytesValue>() {
+            return self.value == other_bytes.value;
+        }
+        false
+    }
+
+    fn compare(&self, other: &Box<dyn Value>) -> Option<Ordering> {
+        if let Some(other_bytes) = other.as_any().downcast_ref::<BytesValue>() {
+            return Some(self.value.cmp(&other_bytes.value));
+        }
+        None
+    }
+
+    fn data_type(&self) -> Box<dyn DataType> {
+        Box::new(BytesType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_bytes) = other.as_any().downcast_ref::<BytesValue>() {
+            return Ok(Box::new(BoolValue::new(self.value == other_bytes.value)));
+        }
+        Err("Unexpected type to perform `=` with".to_string())
+    }
+
+    fn bang_eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_bytes) = other.as_any().downcast_ref::<BytesValue>() {
+            return Ok(Box::new(BoolValue::new(self.value != other_bytes.value)));
+        }
+        Err("Unexpected type to perform `!=` with".to_string())
+    }
+
+    fn gt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_bytes) = other.as_any().downcast_ref::<BytesValue>() {
+            return Ok(Box::new(BoolValue::new(self.value > other_bytes.value)));
+        }
+        Err("Unexpected type to perform `>` with".to_string())
+    }
+
+    fn gte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_bytes) = other.as_any().downcast_ref::<BytesValue>() {
+            return Ok(Box::new(BoolValue::new(self.value >= other_bytes.value)));
+        }
+        Err("Unexpected type to perform `>=` with".to_string())
+    }
+
+    fn lt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_bytes) = other.as_any().downcast_ref::<BytesValue>() {
+            return Ok(Box::new(BoolValue::new(self.value < other_bytes.value)));
+        }
+        Err("Unexpected type to perform `<` with".to_string())
+    }
+
+    fn lte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_bytes) = other.as_any().downcast_ref::<BytesValue>() {
+            return Ok(Box::new(BoolValue::new(self.value <= other_bytes.value)));
+        }
+        Err("Unexpected type to perform `<=` with".to_string())
+    }
+
+    fn cast_op(&self, target_type: &Box<dyn DataType>) -> Result<Box<dyn Value>, String> {
+        if target_type.is_text() {
+            return match String::from_utf8(self.value.clone()) {
+                Ok(text) => Ok(Box::new(TextValue::new(text))),
+                Err(_) => Err(
+                    "Invalid UTF-8 sequence in bytes, use `encode` to convert it to hex or base64 text"
+                        .to_string(),
+                ),
+            };
+        }
+        Err("Unexpected value to perform `CAST` with".to_string())
+    }
+}
@@ -1,6 +1,5 @@
 use std::any::Any;
 use std::cmp::Ordering;
-use std::collections::HashMap;
 
 use gitql_ast::types::composite::CompositeType;
 use gitql_ast::types::DataType;
@@ -62,7 +61,7 @@ impl Value for CompositeValue {
 
     fn data_type(&self) -> Box<dyn DataType> {
         let name = self.name.to_string();
-        let mut members: HashMap<String, Box<dyn DataType>> = HashMap::new();
+        let mut members: IndexMap<String, Box<dyn DataType>> = IndexMap::new();
         for member in self.members.iter() {
             members.insert(member.0.to_string(), member.1.data_type().clone());
         }
@@ -72,4 +71,36 @@ impl Value for CompositeValue {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    /// Cast row to composite type with the same number of members by position
+    fn cast_op(&self, target_type: &Box<dyn DataType>) -> Result<Box<dyn Value>, String> {
+        if let Some(composite_type) = target_type.as_any().downcast_ref::<CompositeType>() {
+            if composite_type.members.len() != self.members.len() {
+                return Err(format!(
+                    "Can't cast row with {} values to type `{}` with {} members",
+                    self.members.len(),
+                    composite_type.name,
+                    composite_type.members.len()
+                ));
+            }
+
+            let mut members: IndexMap<String, Box<dyn Value>> = IndexMap::new();
+            for ((name, member_type), value) in
+                composite_type.members.iter().zip(self.members.values())
+            {
+                let value = if value.is_null() || member_type.equals(&value.data_type()) {
+                    value.clone()
+                } else {
+                    value.cast_op(member_type)?
+                };
+                members.insert(name.to_string(), value);
+            }
+
+            return Ok(Box::new(CompositeValue::new(
+                composite_type.name.to_string(),
+                members,
+            )));
+        }
+        Err("Unexpected value to perform `CAST` with".to_string())
+    }
 }
@@ -5,6 +5,13 @@ use crate::values::null::NullValue;
 use crate::values::time::TimeValue;
 use crate::values::Value;
 
+use chrono::Datelike;
+use chrono::Months;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::TimeDelta;
+use gitql_ast::LenientDateTime;
+
 pub fn string_literal_to_time(literal: &str) -> Box<dyn Value> {
     Box::new(TimeValue {
         value: literal.to_string(),
@@ -24,19 +31,55 @@ pub fn string_literal_to_date(literal: &str) -> Box<dyn Value> {
 }
 
 pub fn string_literal_to_date_time(literal: &str) -> Box<dyn Value> {
-    let date_time_format = if literal.contains('.') {
-        "%Y-%m-%d %H:%M:%S%.3f"
-    } else {
-        "%Y-%m-%d %H:%M:%S"
-    };
+    let timestamp = string_literal_to_timestamp(literal).unwrap_or(0);
+    Box::new(DateTimeValue { value: timestamp })
+}
 
-    let date_time = chrono::NaiveDateTime::parse_from_str(literal, date_time_format);
-    if date_time.is_err() {
-        return Box::new(DateTimeValue { value: 0 });
+/// Parse date time in one of the lenient formats accepted by git like `2024-03-01 12:00 +0200`
+/// or `2 weeks ago` to UTC timestamp, relative dates are resolved against the current time
+pub fn string_literal_to_timestamp(literal: &str) -> Option<i64> {
+    let date_time = LenientDateTime::parse(literal)?;
+    let now = chrono::Utc::now().naive_utc();
+    match date_time {
+        LenientDateTime::Absolute {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            offset,
+        } => {
+            let date = NaiveDate::from_ymd_opt(year, month, day)?;
+            let date_time = date.and_hms_opt(hour, minute, second)?;
+            Some(date_time.and_utc().timestamp() - offset.unwrap_or(0) as i64)
+        }
+        LenientDateTime::Now => Some(now.and_utc().timestamp()),
+        LenientDateTime::StartOfDay(days) => {
+            let date = now.date().checked_add_signed(TimeDelta::days(days))?;
+            Some(date.and_time(NaiveTime::MIN).and_utc().timestamp())
+        }
+        LenientDateTime::LastWeekday(weekday) => {
+            let today = now.date().weekday().num_days_from_monday();
+            let days_back = match (today + 7 - weekday) % 7 {
+                0 => 7,
+                days => days,
+            };
+            let date = now.date() - TimeDelta::days(days_back as i64);
+            Some(date.and_time(NaiveTime::MIN).and_utc().timestamp())
+        }
+        LenientDateTime::Timestamp(timestamp) => Some(timestamp),
+        LenientDateTime::Ago(interval) => {
+            let months = u32::try_from(interval.years * 12 + interval.months).ok()?;
+            let date_time = now.checked_sub_months(Months::new(months))?;
+            let seconds = interval.days * 86400
+                + interval.hours * 3600
+                + interval.minutes * 60
+                + interval.seconds as i64;
+            let date_time = date_time.checked_sub_signed(TimeDelta::try_seconds(seconds)?)?;
+            Some(date_time.and_utc().timestamp())
+        }
     }
-
-    let timestamp = date_time.ok().unwrap().and_utc().timestamp();
-    Box::new(DateTimeValue { value: timestamp })
 }
 
 pub fn string_literal_to_boolean(literal: &str) -> Box<dyn Value> {
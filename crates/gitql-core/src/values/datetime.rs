@@ -4,8 +4,10 @@ use std::cmp::Ordering;
 use super::base::Value;
 use super::boolean::BoolValue;
 use super::date::DateValue;
+use super::datetimetz::DateTimeTzValue;
 
 use chrono::DateTime;
+use chrono_tz::Tz;
 use gitql_ast::operator::GroupComparisonOperator;
 use gitql_ast::types::datetime::DateTimeType;
 use gitql_ast::types::DataType;
@@ -23,6 +25,15 @@ impl DateTimeValue {
     }
 }
 
+/// Return the timestamp of date time with or without time zone
+#[allow(clippy::borrowed_box)]
+fn instant_of(value: &Box<dyn Value>) -> Option<i64> {
+    if let Some((timestamp, _)) = value.as_date_time_tz() {
+        return Some(timestamp);
+    }
+    value.as_date_time()
+}
+
 impl Value for DateTimeValue {
     fn literal(&self) -> String {
         let datetime = DateTime::from_timestamp(self.value, 0).unwrap();
@@ -52,8 +63,8 @@ impl Value for DateTimeValue {
     }
 
     fn eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
-        if let Some(other_text) = other.as_any().downcast_ref::<DateTimeValue>() {
-            let are_equals = self.value == other_text.value;
+        if let Some(other_value) = instant_of(other) {
+            let are_equals = self.value == other_value;
             return Ok(Box::new(BoolValue { value: are_equals }));
         }
         Err("Unexpected type to perform `=` with".to_string())
@@ -87,8 +98,8 @@ impl Value for DateTimeValue {
     }
 
     fn bang_eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
-        if let Some(other_text) = other.as_any().downcast_ref::<DateTimeValue>() {
-            let are_equals = self.value != other_text.value;
+        if let Some(other_value) = instant_of(other) {
+            let are_equals = self.value != other_value;
             return Ok(Box::new(BoolValue { value: are_equals }));
         }
         Err("Unexpected type to perform `!=` with".to_string())
@@ -122,8 +133,8 @@ impl Value for DateTimeValue {
     }
 
     fn gt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
-        if let Some(other_text) = other.as_any().downcast_ref::<DateTimeValue>() {
-            let are_equals = self.value > other_text.value;
+        if let Some(other_value) = instant_of(other) {
+            let are_equals = self.value > other_value;
             return Ok(Box::new(BoolValue { value: are_equals }));
         }
         Err("Unexpected type to perform `>` with".to_string())
@@ -157,8 +168,8 @@ impl Value for DateTimeValue {
     }
 
     fn gte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
-        if let Some(other_text) = other.as_any().downcast_ref::<DateTimeValue>() {
-            let are_equals = self.value >= other_text.value;
+        if let Some(other_value) = instant_of(other) {
+            let are_equals = self.value >= other_value;
             return Ok(Box::new(BoolValue { value: are_equals }));
         }
         Err("Unexpected type to perform `>=` with".to_string())
@@ -192,8 +203,8 @@ impl Value for DateTimeValue {
     }
 
     fn lt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
-        if let Some(other_text) = other.as_any().downcast_ref::<DateTimeValue>() {
-            let are_equals = self.value < other_text.value;
+        if let Some(other_value) = instant_of(other) {
+            let are_equals = self.value < other_value;
             return Ok(Box::new(BoolValue { value: are_equals }));
         }
         Err("Unexpected type to perform `<` with".to_string())
@@ -227,8 +238,8 @@ impl Value for DateTimeValue {
     }
 
     fn lte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
-        if let Some(other_text) = other.as_any().downcast_ref::<DateTimeValue>() {
-            let are_equals = self.value <= other_text.value;
+        if let Some(other_value) = instant_of(other) {
+            let are_equals = self.value <= other_value;
             return Ok(Box::new(BoolValue { value: are_equals }));
         }
         Err("Unexpected type to perform `<=` with".to_string())
@@ -267,6 +278,10 @@ impl Value for DateTimeValue {
                 timestamp: self.value,
             }));
         }
+        if target_type.is_date_time_tz() {
+            return Ok(Box::new(DateTimeTzValue::new(self.value, Tz::UTC)));
+        }
+
         Err("Unexpected type to perform `Cast` with".to_string())
     }
 }
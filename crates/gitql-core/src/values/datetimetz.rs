@@ -0,0 +1,188 @@
+use std::any::Any;
+use std::cmp::Ordering;
+
+use super::base::Value;
+use super::boolean::BoolValue;
+use super::date::DateValue;
+use super::datetime::DateTimeValue;
+use super::text::TextValue;
+
+use chrono::DateTime;
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use chrono_tz::TZ_VARIANTS;
+use gitql_ast::types::datetimetz::DateTimeTzType;
+use gitql_ast::types::DataType;
+
+const VALUE_DATE_TIME_TZ_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f%:z";
+
+/// Date time instant as seconds since Unix epoch, displayed in the time zone
+#[derive(Clone)]
+pub struct DateTimeTzValue {
+    pub value: i64,
+    pub time_zone: Tz,
+}
+
+impl DateTimeTzValue {
+    pub fn new(timestamp: i64, time_zone: Tz) -> Self {
+        DateTimeTzValue {
+            value: timestamp,
+            time_zone,
+        }
+    }
+
+    /// Parse date time literal with optional UTC offset like `2024-01-01 10:00:00+02:00`,
+    /// literal without offset is interpreted as local time in the default time zone
+    pub fn parse(literal: &str, default_time_zone: Tz) -> Option<Self> {
+        let literal = literal.trim();
+        for format in ["%Y-%m-%d %H:%M:%S%.f%#z", "%Y-%m-%d %H:%M:%S%.f %#z"] {
+            if let Ok(date_time) = DateTime::parse_from_str(literal, format) {
+                return Some(DateTimeTzValue::new(
+                    date_time.timestamp(),
+                    default_time_zone,
+                ));
+            }
+        }
+
+        if let Some(literal) = literal.strip_suffix('Z') {
+            let date_time = NaiveDateTime::parse_from_str(literal, "%Y-%m-%d %H:%M:%S%.f").ok()?;
+            return Some(DateTimeTzValue::new(
+                date_time.and_utc().timestamp(),
+                default_time_zone,
+            ));
+        }
+
+        let date_time = NaiveDateTime::parse_from_str(literal, "%Y-%m-%d %H:%M:%S%.f").ok()?;
+        let local_date_time = default_time_zone
+            .from_local_datetime(&date_time)
+            .earliest()?;
+        Some(DateTimeTzValue::new(
+            local_date_time.timestamp(),
+            default_time_zone,
+        ))
+    }
+
+    /// Return the date time in the value time zone
+    pub fn local_date_time(&self) -> DateTime<Tz> {
+        let date_time = DateTime::from_timestamp(self.value, 0).unwrap();
+        date_time.with_timezone(&self.time_zone)
+    }
+}
+
+/// Parse IANA time zone name like `Europe/Berlin` case insensitively, or None if it's unknown
+pub fn parse_time_zone(name: &str) -> Option<Tz> {
+    let name = name.trim();
+    if let Ok(time_zone) = name.parse::<Tz>() {
+        return Some(time_zone);
+    }
+
+    TZ_VARIANTS
+        .iter()
+        .find(|time_zone| time_zone.name().eq_ignore_ascii_case(name))
+        .copied()
+}
+
+/// Return the timestamp of date time with or without time zone
+#[allow(clippy::borrowed_box)]
+fn instant_of(value: &Box<dyn Value>) -> Option<i64> {
+    if let Some((timestamp, _)) = value.as_date_time_tz() {
+        return Some(timestamp);
+    }
+    value.as_date_time()
+}
+
+impl Value for DateTimeTzValue {
+    fn literal(&self) -> String {
+        format!(
+            "{}",
+            self.local_date_time().format(VALUE_DATE_TIME_TZ_FORMAT)
+        )
+    }
+
+    fn equals(&self, other: &Box<dyn Value>) -> bool {
+        if let Some(other_date_time) = other.as_any().downcast_ref::<DateTimeTzValue>() {
+            return self.value == other_date_time.value;
+        }
+        false
+    }
+
+    fn compare(&self, other: &Box<dyn Value>) -> Option<Ordering> {
+        if let Some(other_date_time) = other.as_any().downcast_ref::<DateTimeTzValue>() {
+            return self.value.partial_cmp(&other_date_time.value);
+        }
+        None
+    }
+
+    fn data_type(&self) -> Box<dyn DataType> {
+        Box::new(DateTimeTzType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_value) = instant_of(other) {
+            return Ok(Box::new(BoolValue::new(self.value == other_value)));
+        }
+        Err("Unexpected type to perform `=` with".to_string())
+    }
+
+    fn bang_eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_value) = instant_of(other) {
+            return Ok(Box::new(BoolValue::new(self.value != other_value)));
+        }
+        Err("Unexpected type to perform `!=` with".to_string())
+    }
+
+    fn gt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_value) = instant_of(other) {
+            return Ok(Box::new(BoolValue::new(self.value > other_value)));
+        }
+        Err("Unexpected type to perform `>` with".to_string())
+    }
+
+    fn gte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_value) = instant_of(other) {
+            return Ok(Box::new(BoolValue::new(self.value >= other_value)));
+        }
+        Err("Unexpected type to perform `>=` with".to_string())
+    }
+
+    fn lt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_value) = instant_of(other) {
+            return Ok(Box::new(BoolValue::new(self.value < other_value)));
+        }
+        Err("Unexpected type to perform `<` with".to_string())
+    }
+
+    fn lte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_value) = instant_of(other) {
+            return Ok(Box::new(BoolValue::new(self.value <= other_value)));
+        }
+        Err("Unexpected type to perform `<=` with".to_string())
+    }
+
+    fn cast_op(&self, target_type: &Box<dyn DataType>) -> Result<Box<dyn Value>, String> {
+        if target_type.is_date_time() {
+            return Ok(Box::new(DateTimeValue::new(self.value)));
+        }
+
+        if target_type.is_date() {
+            let local_date = self.local_date_time().date_naive();
+            let timestamp = local_date
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc()
+                .timestamp();
+            return Ok(Box::new(DateValue { timestamp }));
+        }
+
+        if target_type.is_text() {
+            return Ok(Box::new(TextValue::new(self.literal())));
+        }
+
+        Err("Unexpected type to perform `Cast` with".to_string())
+    }
+}
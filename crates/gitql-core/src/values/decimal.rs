@@ -0,0 +1,201 @@
+use std::any::Any;
+use std::cmp::Ordering;
+
+use gitql_ast::types::decimal::DecimalType;
+use gitql_ast::types::DataType;
+use gitql_ast::Decimal;
+use gitql_ast::DECIMAL_MAX_PRECISION;
+
+use super::base::Value;
+use super::boolean::BoolValue;
+use super::float::FloatValue;
+use super::integer::IntValue;
+use super::text::TextValue;
+
+#[derive(Clone)]
+pub struct DecimalValue {
+    pub value: Decimal,
+    pub precision: u32,
+}
+
+impl DecimalValue {
+    pub fn new(value: Decimal) -> Self {
+        DecimalValue {
+            value,
+            precision: DECIMAL_MAX_PRECISION,
+        }
+    }
+
+    /// Create [`DecimalValue`] with precision and scale, the value is rounded to the scale
+    /// and return Error if it has more digits than the precision allow
+    pub fn with_precision(value: Decimal, precision: u32, scale: u32) -> Result<Self, String> {
+        if !value.fits_precision(precision, scale) {
+            return Err(format!(
+                "Decimal value `{}` overflows type `Decimal({}, {})`",
+                value, precision, scale
+            ));
+        }
+
+        Ok(DecimalValue {
+            value: value.rescale(scale).unwrap(),
+            precision,
+        })
+    }
+}
+
+/// Return decimal or integer value as [`Decimal`] to be used as operand with decimal
+#[allow(clippy::borrowed_box)]
+fn decimal_operand(value: &Box<dyn Value>) -> Option<Decimal> {
+    if let Some(decimal) = value.as_decimal() {
+        return Some(decimal);
+    }
+    value.as_int().map(Decimal::from_int)
+}
+
+impl Value for DecimalValue {
+    fn literal(&self) -> String {
+        self.value.to_string()
+    }
+
+    fn equals(&self, other: &Box<dyn Value>) -> bool {
+        if let Some(other_decimal) = other.as_decimal() {
+            return self.value == other_decimal;
+        }
+        false
+    }
+
+    fn compare(&self, other: &Box<dyn Value>) -> Option<Ordering> {
+        if let Some(other_decimal) = other.as_decimal() {
+            return self.value.partial_cmp(&other_decimal);
+        }
+        None
+    }
+
+    fn data_type(&self) -> Box<dyn DataType> {
+        Box::new(DecimalType::new(self.precision, self.value.scale))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn add_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_decimal) = decimal_operand(other) {
+            let value = self.value.add(&other_decimal)?;
+            return Ok(Box::new(DecimalValue::new(value)));
+        }
+        Err("Unexpected type to perform `+` with".to_string())
+    }
+
+    fn sub_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_decimal) = decimal_operand(other) {
+            let value = self.value.sub(&other_decimal)?;
+            return Ok(Box::new(DecimalValue::new(value)));
+        }
+        Err("Unexpected type to perform `-` with".to_string())
+    }
+
+    fn mul_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_decimal) = decimal_operand(other) {
+            let value = self.value.mul(&other_decimal)?;
+            return Ok(Box::new(DecimalValue::new(value)));
+        }
+        Err("Unexpected type to perform `*` with".to_string())
+    }
+
+    fn div_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_decimal) = decimal_operand(other) {
+            let value = self.value.div(&other_decimal)?;
+            return Ok(Box::new(DecimalValue::new(value)));
+        }
+        Err("Unexpected type to perform `/` with".to_string())
+    }
+
+    fn rem_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_decimal) = decimal_operand(other) {
+            let value = self.value.rem(&other_decimal)?;
+            return Ok(Box::new(DecimalValue::new(value)));
+        }
+        Err("Unexpected type to perform `%` with".to_string())
+    }
+
+    fn eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_decimal) = decimal_operand(other) {
+            return Ok(Box::new(BoolValue::new(self.value == other_decimal)));
+        }
+        Err("Unexpected type to perform `=` with".to_string())
+    }
+
+    fn bang_eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_decimal) = decimal_operand(other) {
+            return Ok(Box::new(BoolValue::new(self.value != other_decimal)));
+        }
+        Err("Unexpected type to perform `!=` with".to_string())
+    }
+
+    fn gt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_decimal) = decimal_operand(other) {
+            return Ok(Box::new(BoolValue::new(self.value > other_decimal)));
+        }
+        Err("Unexpected type to perform `>` with".to_string())
+    }
+
+    fn gte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_decimal) = decimal_operand(other) {
+            return Ok(Box::new(BoolValue::new(self.value >= other_decimal)));
+        }
+        Err("Unexpected type to perform `>=` with".to_string())
+    }
+
+    fn lt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_decimal) = decimal_operand(other) {
+            return Ok(Box::new(BoolValue::new(self.value < other_decimal)));
+        }
+        Err("Unexpected type to perform `<` with".to_string())
+    }
+
+    fn lte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_decimal) = decimal_operand(other) {
+            return Ok(Box::new(BoolValue::new(self.value <= other_decimal)));
+        }
+        Err("Unexpected type to perform `<=` with".to_string())
+    }
+
+    fn neg_op(&self) -> Result<Box<dyn Value>, String> {
+        Ok(Box::new(DecimalValue {
+            value: self.value.neg()?,
+            precision: self.precision,
+        }))
+    }
+
+    fn cast_op(&self, target_type: &Box<dyn DataType>) -> Result<Box<dyn Value>, String> {
+        if let Some(decimal_type) = target_type.as_any().downcast_ref::<DecimalType>() {
+            let value = DecimalValue::with_precision(
+                self.value,
+                decimal_type.precision,
+                decimal_type.scale,
+            )?;
+            return Ok(Box::new(value));
+        }
+
+        if target_type.is_int() {
+            return match self.value.to_i64() {
+                Some(value) => Ok(Box::new(IntValue::new(value))),
+                None => Err(format!(
+                    "Decimal value `{}` overflows type `Int`",
+                    self.value
+                )),
+            };
+        }
+
+        if target_type.is_float() {
+            return Ok(Box::new(FloatValue::new(self.value.to_f64())));
+        }
+
+        if target_type.is_text() {
+            return Ok(Box::new(TextValue::new(self.literal())));
+        }
+
+        Err("Unexpected value to perform `CAST` with".to_string())
+    }
+}
@@ -0,0 +1,109 @@
+use std::any::Any;
+use std::cmp::Ordering;
+
+use gitql_ast::types::enumeration::EnumType;
+use gitql_ast::types::DataType;
+
+use super::base::Value;
+use super::boolean::BoolValue;
+use super::text::TextValue;
+
+/// Label of user defined enum type, ordered by the position of the label in the type declaration
+#[derive(Clone)]
+pub struct EnumValue {
+    pub position: usize,
+    pub enum_type: EnumType,
+}
+
+impl EnumValue {
+    /// Create enum value from label, or None if it's not a member of the enum type
+    pub fn new(label: &str, enum_type: EnumType) -> Option<Self> {
+        let position = enum_type.label_position(label)?;
+        Some(EnumValue {
+            position,
+            enum_type,
+        })
+    }
+
+    /// Return the position of the other value if it has the same enum type
+    #[allow(clippy::borrowed_box)]
+    fn other_position(&self, other: &Box<dyn Value>) -> Option<usize> {
+        let other_enum = other.as_any().downcast_ref::<EnumValue>()?;
+        if self.enum_type.name != other_enum.enum_type.name {
+            return None;
+        }
+        Some(other_enum.position)
+    }
+}
+
+impl Value for EnumValue {
+    fn literal(&self) -> String {
+        self.enum_type.labels[self.position].to_string()
+    }
+
+    fn equals(&self, other: &Box<dyn Value>) -> bool {
+        self.other_position(other) == Some(self.position)
+    }
+
+    fn compare(&self, other: &Box<dyn Value>) -> Option<Ordering> {
+        let other_position = self.other_position(other)?;
+        Some(self.position.cmp(&other_position))
+    }
+
+    fn data_type(&self) -> Box<dyn DataType> {
+        Box::new(self.enum_type.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_position) = self.other_position(other) {
+            return Ok(Box::new(BoolValue::new(self.position == other_position)));
+        }
+        Err("Unexpected type to perform `=` with".to_string())
+    }
+
+    fn bang_eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_position) = self.other_position(other) {
+            return Ok(Box::new(BoolValue::new(self.position != other_position)));
+        }
+        Err("Unexpected type to perform `!=` with".to_string())
+    }
+
+    fn gt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_position) = self.other_position(other) {
+            return Ok(Box::new(BoolValue::new(self.position > other_position)));
+        }
+        Err("Unexpected type to perform `>` with".to_string())
+    }
+
+    fn gte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_position) = self.other_position(other) {
+            return Ok(Box::new(BoolValue::new(self.position >= other_position)));
+        }
+        Err("Unexpected type to perform `>=` with".to_string())
+    }
+
+    fn lt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_position) = self.other_position(other) {
+            return Ok(Box::new(BoolValue::new(self.position < other_position)));
+        }
+        Err("Unexpected type to perform `<` with".to_string())
+    }
+
+    fn lte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_position) = self.other_position(other) {
+            return Ok(Box::new(BoolValue::new(self.position <= other_position)));
+        }
+        Err("Unexpected type to perform `<=` with".to_string())
+    }
+
+    fn cast_op(&self, target_type: &Box<dyn DataType>) -> Result<Box<dyn Value>, String> {
+        if target_type.is_text() {
+            return Ok(Box::new(TextValue::new(self.literal())));
+        }
+        Err("Unexpected value to perform `CAST` with".to_string())
+    }
+}
@@ -2,11 +2,14 @@ use std::any::Any;
 use std::cmp::Ordering;
 
 use gitql_ast::operator::GroupComparisonOperator;
+use gitql_ast::types::decimal::DecimalType;
 use gitql_ast::types::float::FloatType;
 use gitql_ast::types::DataType;
+use gitql_ast::Decimal;
 
 use super::base::Value;
 use super::boolean::BoolValue;
+use super::decimal::DecimalValue;
 use super::integer::IntValue;
 
 #[derive(Clone)]
@@ -300,6 +303,34 @@ impl Value for FloatValue {
             return Ok(Box::new(IntValue { value }));
         }
 
+        // Cast Decimal
+        if let Some(decimal_type) = target_type.as_any().downcast_ref::<DecimalType>() {
+            return match Decimal::from_f64(self.value) {
+                Some(decimal) => Ok(Box::new(DecimalValue::with_precision(
+                    decimal,
+                    decimal_type.precision,
+                    decimal_type.scale,
+                )?)),
+                None => Err(format!("Can't cast `{}` to type decimal", self.value)),
+            };
+        }
+
         Err("Unexpected value to perform `CAST` with".to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::values::null::NullValue;
+
+    #[test]
+    fn test_float_with_null() {
+        let value = FloatValue::new(1.5);
+        let null: Box<dyn Value> = Box::new(NullValue);
+        assert!(!value.equals(&null));
+        assert_eq!(value.compare(&null), None);
+        assert!(value.eq_op(&null).is_err());
+        assert!(value.mul_op(&null).is_err());
+    }
+}
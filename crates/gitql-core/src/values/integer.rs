@@ -2,11 +2,14 @@ use std::any::Any;
 use std::cmp::Ordering;
 
 use gitql_ast::operator::GroupComparisonOperator;
+use gitql_ast::types::decimal::DecimalType;
 use gitql_ast::types::integer::IntType;
 use gitql_ast::types::DataType;
+use gitql_ast::Decimal;
 
 use super::base::Value;
 use super::boolean::BoolValue;
+use super::decimal::DecimalValue;
 use super::float::FloatValue;
 
 #[derive(Clone)]
@@ -53,25 +56,49 @@ impl Value for IntValue {
 
     fn add_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
         if let Some(other_int) = other.as_any().downcast_ref::<IntValue>() {
-            let value = self.value + other_int.value;
-            return Ok(Box::new(IntValue::new(value)));
+            return match self.value.checked_add(other_int.value) {
+                Some(value) => Ok(Box::new(IntValue::new(value))),
+                None => Err(overflow_error("+")),
+            };
         }
+
+        if let Some(other_decimal) = other.as_decimal() {
+            let value = Decimal::from_int(self.value).add(&other_decimal)?;
+            return Ok(Box::new(DecimalValue::new(value)));
+        }
+
         Err("Unexpected type to perform `+` with".to_string())
     }
 
     fn sub_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
         if let Some(other_int) = other.as_any().downcast_ref::<IntValue>() {
-            let value = self.value - other_int.value;
-            return Ok(Box::new(IntValue::new(value)));
+            return match self.value.checked_sub(other_int.value) {
+                Some(value) => Ok(Box::new(IntValue::new(value))),
+                None => Err(overflow_error("-")),
+            };
+        }
+
+        if let Some(other_decimal) = other.as_decimal() {
+            let value = Decimal::from_int(self.value).sub(&other_decimal)?;
+            return Ok(Box::new(DecimalValue::new(value)));
         }
+
         Err("Unexpected type to perform `-` with".to_string())
     }
 
     fn mul_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
         if let Some(other_int) = other.as_any().downcast_ref::<IntValue>() {
-            let value = self.value * other_int.value;
-            return Ok(Box::new(IntValue::new(value)));
+            return match self.value.checked_mul(other_int.value) {
+                Some(value) => Ok(Box::new(IntValue::new(value))),
+                None => Err(overflow_error("*")),
+            };
+        }
+
+        if let Some(other_decimal) = other.as_decimal() {
+            let value = Decimal::from_int(self.value).mul(&other_decimal)?;
+            return Ok(Box::new(DecimalValue::new(value)));
         }
+
         Err("Unexpected type to perform `*` with".to_string())
     }
 
@@ -80,17 +107,36 @@ impl Value for IntValue {
             if other_int.value == 0 {
                 return Err("Can't perform `/` operator with 0 value".to_string());
             }
-            let value = self.value / other_int.value;
-            return Ok(Box::new(IntValue::new(value)));
+            return match self.value.checked_div(other_int.value) {
+                Some(value) => Ok(Box::new(IntValue::new(value))),
+                None => Err(overflow_error("/")),
+            };
         }
+
+        if let Some(other_decimal) = other.as_decimal() {
+            let value = Decimal::from_int(self.value).div(&other_decimal)?;
+            return Ok(Box::new(DecimalValue::new(value)));
+        }
+
         Err("Unexpected type to perform `/` with".to_string())
     }
 
     fn rem_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
         if let Some(other_int) = other.as_any().downcast_ref::<IntValue>() {
-            let value = self.value % other_int.value;
-            return Ok(Box::new(IntValue::new(value)));
+            if other_int.value == 0 {
+                return Err("Can't perform `%` operator with 0 value".to_string());
+            }
+            return match self.value.checked_rem(other_int.value) {
+                Some(value) => Ok(Box::new(IntValue::new(value))),
+                None => Err(overflow_error("%")),
+            };
+        }
+
+        if let Some(other_decimal) = other.as_decimal() {
+            let value = Decimal::from_int(self.value).rem(&other_decimal)?;
+            return Ok(Box::new(DecimalValue::new(value)));
         }
+
         Err("Unexpected type to perform `%` with".to_string())
     }
 
@@ -99,8 +145,13 @@ impl Value for IntValue {
             if other_int.value < 0 {
                 return Err("Caret right side hand can't be negative value".to_string());
             }
-            let value = self.value.pow(other_int.value as u32);
-            return Ok(Box::new(IntValue::new(value)));
+            let value = u32::try_from(other_int.value)
+                .ok()
+                .and_then(|exponent| self.value.checked_pow(exponent));
+            return match value {
+                Some(value) => Ok(Box::new(IntValue::new(value))),
+                None => Err(overflow_error("^")),
+            };
         }
         Err("Unexpected type to perform `^` with".to_string())
     }
@@ -131,16 +182,26 @@ impl Value for IntValue {
 
     fn shl_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
         if let Some(other_int) = other.as_any().downcast_ref::<IntValue>() {
-            let value = self.value << other_int.value;
-            return Ok(Box::new(IntValue::new(value)));
+            let value = u32::try_from(other_int.value)
+                .ok()
+                .and_then(|shift| self.value.checked_shl(shift));
+            return match value {
+                Some(value) => Ok(Box::new(IntValue::new(value))),
+                None => Err(overflow_error("<<")),
+            };
         }
         Err("Unexpected type to perform `<<` with".to_string())
     }
 
     fn shr_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
         if let Some(other_int) = other.as_any().downcast_ref::<IntValue>() {
-            let value = self.value >> other_int.value;
-            return Ok(Box::new(IntValue::new(value)));
+            let value = u32::try_from(other_int.value)
+                .ok()
+                .and_then(|shift| self.value.checked_shr(shift));
+            return match value {
+                Some(value) => Ok(Box::new(IntValue::new(value))),
+                None => Err(overflow_error(">>")),
+            };
         }
         Err("Unexpected type to perform `>>` with".to_string())
     }
@@ -150,6 +211,12 @@ impl Value for IntValue {
             let value = self.value == other_bool.value;
             return Ok(Box::new(BoolValue::new(value)));
         }
+
+        if let Some(other_decimal) = other.as_decimal() {
+            let value = Decimal::from_int(self.value) == other_decimal;
+            return Ok(Box::new(BoolValue::new(value)));
+        }
+
         Err("Unexpected type to perform `=` with".to_string())
     }
 
@@ -185,6 +252,12 @@ impl Value for IntValue {
             let value = self.value != other_bool.value;
             return Ok(Box::new(BoolValue::new(value)));
         }
+
+        if let Some(other_decimal) = other.as_decimal() {
+            let value = Decimal::from_int(self.value) != other_decimal;
+            return Ok(Box::new(BoolValue::new(value)));
+        }
+
         Err("Unexpected type to perform `!=` with".to_string())
     }
 
@@ -220,6 +293,12 @@ impl Value for IntValue {
             let value = self.value > other_bool.value;
             return Ok(Box::new(BoolValue::new(value)));
         }
+
+        if let Some(other_decimal) = other.as_decimal() {
+            let value = Decimal::from_int(self.value) > other_decimal;
+            return Ok(Box::new(BoolValue::new(value)));
+        }
+
         Err("Unexpected type to perform `>` with".to_string())
     }
 
@@ -255,6 +334,12 @@ impl Value for IntValue {
             let value = self.value >= other_bool.value;
             return Ok(Box::new(BoolValue::new(value)));
         }
+
+        if let Some(other_decimal) = other.as_decimal() {
+            let value = Decimal::from_int(self.value) >= other_decimal;
+            return Ok(Box::new(BoolValue::new(value)));
+        }
+
         Err("Unexpected type to perform `>` with".to_string())
     }
 
@@ -290,6 +375,12 @@ impl Value for IntValue {
             let value = self.value < other_bool.value;
             return Ok(Box::new(BoolValue::new(value)));
         }
+
+        if let Some(other_decimal) = other.as_decimal() {
+            let value = Decimal::from_int(self.value) < other_decimal;
+            return Ok(Box::new(BoolValue::new(value)));
+        }
+
         Err("Unexpected type to perform `<` with".to_string())
     }
 
@@ -325,6 +416,12 @@ impl Value for IntValue {
             let value = self.value <= other_bool.value;
             return Ok(Box::new(BoolValue::new(value)));
         }
+
+        if let Some(other_decimal) = other.as_decimal() {
+            let value = Decimal::from_int(self.value) <= other_decimal;
+            return Ok(Box::new(BoolValue::new(value)));
+        }
+
         Err("Unexpected type to perform `<=` with".to_string())
     }
 
@@ -356,7 +453,10 @@ impl Value for IntValue {
     }
 
     fn neg_op(&self) -> Result<Box<dyn Value>, String> {
-        Ok(Box::new(IntValue { value: -self.value }))
+        match self.value.checked_neg() {
+            Some(value) => Ok(Box::new(IntValue { value })),
+            None => Err(overflow_error("-")),
+        }
     }
 
     fn cast_op(&self, target_type: &Box<dyn DataType>) -> Result<Box<dyn Value>, String> {
@@ -372,6 +472,36 @@ impl Value for IntValue {
             return Ok(Box::new(FloatValue { value }));
         }
 
+        // Cast to Decimal
+        if let Some(decimal_type) = target_type.as_any().downcast_ref::<DecimalType>() {
+            let value = DecimalValue::with_precision(
+                Decimal::from_int(self.value),
+                decimal_type.precision,
+                decimal_type.scale,
+            )?;
+            return Ok(Box::new(value));
+        }
+
         Err("Unexpected value to perform `CAST` with".to_string())
     }
 }
+
+fn overflow_error(operator: &str) -> String {
+    format!("Integer overflow while performing `{}` operator", operator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::values::null::NullValue;
+
+    #[test]
+    fn test_int_with_null() {
+        let value = IntValue::new(1);
+        let null: Box<dyn Value> = Box::new(NullValue);
+        assert!(!value.equals(&null));
+        assert_eq!(value.compare(&null), None);
+        assert!(value.eq_op(&null).is_err());
+        assert!(value.add_op(&null).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use std::any::Any;
+use std::cmp::Ordering;
+
+use gitql_ast::types::json::JsonType;
+use gitql_ast::types::DataType;
+
+use super::base::Value;
+use super::boolean::BoolValue;
+use super::composite::CompositeValue;
+use super::null::NullValue;
+use super::text::TextValue;
+
+#[derive(Clone)]
+pub struct JsonValue {
+    pub value: serde_json::Value,
+}
+
+impl JsonValue {
+    pub fn new(value: serde_json::Value) -> Self {
+        JsonValue { value }
+    }
+
+    /// Return the member of the object by key or the element of array by index, or None if not exists
+    #[allow(clippy::borrowed_box)]
+    pub fn member(&self, key: &Box<dyn Value>) -> Option<&serde_json::Value> {
+        if let Some(name) = key.as_text() {
+            return self.value.get(name.as_str());
+        }
+
+        if let Some(index) = key.as_int() {
+            let array = self.value.as_array()?;
+            let index = if index < 0 {
+                array.len() as i64 + index
+            } else {
+                index
+            };
+            return usize::try_from(index).ok().and_then(|i| array.get(i));
+        }
+
+        None
+    }
+}
+
+impl Value for JsonValue {
+    fn literal(&self) -> String {
+        self.value.to_string()
+    }
+
+    fn equals(&self, other: &Box<dyn Value>) -> bool {
+        if let Some(other_json) = other.as_any().downcast_ref::<JsonValue>() {
+            return self.value == other_json.value;
+        }
+        false
+    }
+
+    fn compare(&self, other: &Box<dyn Value>) -> Option<Ordering> {
+        if let Some(other_json) = other.as_any().downcast_ref::<JsonValue>() {
+            return self.literal().partial_cmp(&other_json.literal());
+        }
+        None
+    }
+
+    fn data_type(&self) -> Box<dyn DataType> {
+        Box::new(JsonType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_json) = other.as_any().downcast_ref::<JsonValue>() {
+            let is_equals = self.value == other_json.value;
+            return Ok(Box::new(BoolValue::new(is_equals)));
+        }
+        Err("Unexpected type to perform `=` with".to_string())
+    }
+
+    fn bang_eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_json) = other.as_any().downcast_ref::<JsonValue>() {
+            let is_not_equals = self.value != other_json.value;
+            return Ok(Box::new(BoolValue::new(is_not_equals)));
+        }
+        Err("Unexpected type to perform `!=` with".to_string())
+    }
+
+    fn arrow_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if !other.is_text() && !other.is_int() {
+            return Err("Unexpected type to perform `->` with".to_string());
+        }
+
+        match self.member(other) {
+            Some(member) => Ok(Box::new(JsonValue::new(member.clone()))),
+            None => Ok(Box::new(NullValue)),
+        }
+    }
+
+    fn long_arrow_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if !other.is_text() && !other.is_int() {
+            return Err("Unexpected type to perform `->>` with".to_string());
+        }
+
+        match self.member(other) {
+            Some(serde_json::Value::Null) | None => Ok(Box::new(NullValue)),
+            Some(member) => Ok(Box::new(TextValue::new(json_to_text(member)))),
+        }
+    }
+
+    fn cast_op(&self, target_type: &Box<dyn DataType>) -> Result<Box<dyn Value>, String> {
+        if target_type.is_text() {
+            return Ok(Box::new(TextValue::new(json_to_text(&self.value))));
+        }
+
+        Err("Unexpected value to perform `CAST` with".to_string())
+    }
+}
+
+/// Convert Json value to text, strings are returned without quotes
+pub fn json_to_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(string) => string.to_string(),
+        _ => value.to_string(),
+    }
+}
+
+/// Convert any value to the equivalent Json value, types without Json equivalent are converted to string
+#[allow(clippy::borrowed_box)]
+pub fn value_to_json(value: &Box<dyn Value>) -> serde_json::Value {
+    if value.is_null() {
+        return serde_json::Value::Null;
+    }
+
+    if let Some(json) = value.as_any().downcast_ref::<JsonValue>() {
+        return json.value.clone();
+    }
+
+    if let Some(boolean) = value.as_bool() {
+        return serde_json::Value::Bool(boolean);
+    }
+
+    if let Some(integer) = value.as_int() {
+        return serde_json::Value::from(integer);
+    }
+
+    if let Some(float) = value.as_float() {
+        return serde_json::Value::from(float);
+    }
+
+    if let Some(text) = value.as_text() {
+        return serde_json::Value::String(text);
+    }
+
+    if let Some(elements) = value.as_array() {
+        return serde_json::Value::Array(elements.iter().map(value_to_json).collect());
+    }
+
+    if let Some(composite) = value.as_any().downcast_ref::<CompositeValue>() {
+        let mut object = serde_json::Map::with_capacity(composite.members.len());
+        for (name, member) in composite.members.iter() {
+            object.insert(name.to_string(), value_to_json(member));
+        }
+        return serde_json::Value::Object(object);
+    }
+
+    serde_json::Value::String(value.literal())
+}
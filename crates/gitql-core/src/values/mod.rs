@@ -1,14 +1,21 @@
 pub mod array;
 pub mod boolean;
+pub mod bytes;
 pub mod composite;
 pub mod converters;
 pub mod date;
 pub mod datetime;
+pub mod datetimetz;
+pub mod decimal;
+pub mod enumeration;
 pub mod float;
 pub mod integer;
 pub mod interval;
+pub mod json;
+pub mod multirange;
 pub mod null;
 pub mod range;
+pub mod semver;
 pub mod text;
 pub mod time;
 
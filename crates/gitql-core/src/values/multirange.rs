@@ -0,0 +1,180 @@
+use std::any::Any;
+use std::cmp::Ordering;
+
+use gitql_ast::types::multirange::MultiRangeType;
+use gitql_ast::types::DataType;
+
+use super::base::Value;
+use super::boolean::BoolValue;
+use super::range::RangeValue;
+
+/// Ordered set of non empty, non overlapping and non adjacent ranges
+#[derive(Clone)]
+pub struct MultiRangeValue {
+    pub ranges: Vec<RangeValue>,
+    pub base_type: Box<dyn DataType>,
+}
+
+impl MultiRangeValue {
+    /// Create a new MultiRange after dropping empty ranges and merging the overlapping or adjacent ones
+    pub fn new(ranges: Vec<RangeValue>, base_type: Box<dyn DataType>) -> Self {
+        let mut ranges: Vec<RangeValue> = ranges.into_iter().filter(|r| !r.is_empty()).collect();
+        ranges.sort_by(|a, b| a.start.compare(&b.start).unwrap_or(Ordering::Equal));
+
+        let mut merged: Vec<RangeValue> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            if let Some(last) = merged.last_mut() {
+                if let Some(union) = last.union(&range) {
+                    *last = union;
+                    continue;
+                }
+            }
+            merged.push(range);
+        }
+
+        MultiRangeValue {
+            ranges: merged,
+            base_type,
+        }
+    }
+
+    /// Return true if the value is inside one of the ranges
+    #[allow(clippy::borrowed_box)]
+    pub fn contains_value(&self, value: &Box<dyn Value>) -> bool {
+        self.ranges.iter().any(|range| range.contains_value(value))
+    }
+
+    /// Return true if the range is fully covered by one of the ranges
+    pub fn contains_range(&self, other: &RangeValue) -> bool {
+        other.is_empty() || self.ranges.iter().any(|range| range.contains_range(other))
+    }
+
+    /// Return true if any of the ranges overlaps with the other range
+    pub fn overlaps_range(&self, other: &RangeValue) -> bool {
+        self.ranges.iter().any(|range| range.overlaps(other))
+    }
+
+    /// Return the values that are in this MultiRange or the other one
+    pub fn union(&self, other: &MultiRangeValue) -> MultiRangeValue {
+        let mut ranges = self.ranges.clone();
+        ranges.extend(other.ranges.iter().cloned());
+        MultiRangeValue::new(ranges, self.base_type.clone())
+    }
+
+    /// Return the values that are in both MultiRanges
+    pub fn intersection(&self, other: &MultiRangeValue) -> MultiRangeValue {
+        let mut ranges = vec![];
+        for range in self.ranges.iter() {
+            for other_range in other.ranges.iter() {
+                ranges.push(range.intersection(other_range));
+            }
+        }
+        MultiRangeValue::new(ranges, self.base_type.clone())
+    }
+
+    /// Return the values that are in this MultiRange but not in the other one
+    pub fn difference(&self, other: &MultiRangeValue) -> MultiRangeValue {
+        let mut ranges = self.ranges.clone();
+        for other_range in other.ranges.iter() {
+            ranges = ranges
+                .iter()
+                .flat_map(|range| range.subtract(other_range))
+                .collect();
+        }
+        MultiRangeValue::new(ranges, self.base_type.clone())
+    }
+}
+
+impl Value for MultiRangeValue {
+    fn literal(&self) -> String {
+        let ranges: Vec<String> = self.ranges.iter().map(|range| range.literal()).collect();
+        format!("{{{}}}", ranges.join(", "))
+    }
+
+    fn equals(&self, other: &Box<dyn Value>) -> bool {
+        if let Some(other_multirange) = other.as_any().downcast_ref::<MultiRangeValue>() {
+            return self.base_type.equals(&other_multirange.base_type)
+                && self.ranges.len() == other_multirange.ranges.len()
+                && self.ranges.iter().zip(other_multirange.ranges.iter()).all(
+                    |(range, other_range)| {
+                        range.start.equals(&other_range.start) && range.end.equals(&other_range.end)
+                    },
+                );
+        }
+        false
+    }
+
+    fn compare(&self, _other: &Box<dyn Value>) -> Option<Ordering> {
+        None
+    }
+
+    fn data_type(&self) -> Box<dyn DataType> {
+        Box::new(MultiRangeType {
+            base: self.base_type.clone(),
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn add_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_multirange) = other.as_any().downcast_ref::<MultiRangeValue>() {
+            return Ok(Box::new(self.union(other_multirange)));
+        }
+        Err("Unexpected type to perform `MultiRange union +` with".to_string())
+    }
+
+    fn sub_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_multirange) = other.as_any().downcast_ref::<MultiRangeValue>() {
+            return Ok(Box::new(self.difference(other_multirange)));
+        }
+        Err("Unexpected type to perform `MultiRange difference -` with".to_string())
+    }
+
+    fn mul_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_multirange) = other.as_any().downcast_ref::<MultiRangeValue>() {
+            return Ok(Box::new(self.intersection(other_multirange)));
+        }
+        Err("Unexpected type to perform `MultiRange intersection *` with".to_string())
+    }
+
+    fn logical_and_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_range) = other.as_any().downcast_ref::<RangeValue>() {
+            let is_overlap = self.overlaps_range(other_range);
+            return Ok(Box::new(BoolValue { value: is_overlap }));
+        }
+
+        if let Some(other_multirange) = other.as_any().downcast_ref::<MultiRangeValue>() {
+            let is_overlap = other_multirange
+                .ranges
+                .iter()
+                .any(|other_range| self.overlaps_range(other_range));
+            return Ok(Box::new(BoolValue { value: is_overlap }));
+        }
+
+        Err("Unexpected type to perform `MultiRange Overlap &&` with".to_string())
+    }
+
+    fn contains_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_range) = other.as_any().downcast_ref::<RangeValue>() {
+            let is_in_range = self.contains_range(other_range);
+            return Ok(Box::new(BoolValue { value: is_in_range }));
+        }
+
+        if let Some(other_multirange) = other.as_any().downcast_ref::<MultiRangeValue>() {
+            let is_in_range = other_multirange
+                .ranges
+                .iter()
+                .all(|other_range| self.contains_range(other_range));
+            return Ok(Box::new(BoolValue { value: is_in_range }));
+        }
+
+        if self.base_type.equals(&other.data_type()) {
+            let is_in_range = self.contains_value(other);
+            return Ok(Box::new(BoolValue { value: is_in_range }));
+        }
+
+        Err("Unexpected type to perform `MultiRange contains @>` with".to_string())
+    }
+}
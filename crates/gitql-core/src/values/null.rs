@@ -30,3 +30,33 @@ impl Value for NullValue {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::values::integer::IntValue;
+
+    #[test]
+    fn test_null_equals_only_null() {
+        let null: Box<dyn Value> = Box::new(NullValue);
+        let int: Box<dyn Value> = Box::new(IntValue::new(1));
+        assert!(NullValue.equals(&null));
+        assert!(!NullValue.equals(&int));
+    }
+
+    #[test]
+    fn test_null_has_no_order() {
+        let null: Box<dyn Value> = Box::new(NullValue);
+        let int: Box<dyn Value> = Box::new(IntValue::new(1));
+        assert_eq!(NullValue.compare(&null), None);
+        assert_eq!(NullValue.compare(&int), None);
+    }
+
+    #[test]
+    fn test_null_operators_are_not_defined() {
+        let int: Box<dyn Value> = Box::new(IntValue::new(1));
+        assert!(NullValue.eq_op(&int).is_err());
+        assert!(NullValue.add_op(&int).is_err());
+        assert!(NullValue.not_op().is_err());
+    }
+}
@@ -6,7 +6,10 @@ use gitql_ast::types::DataType;
 
 use super::base::Value;
 use super::boolean::BoolValue;
+use super::multirange::MultiRangeValue;
 
+/// Range of values from inclusive start to exclusive end `[start, end)`,
+/// the range is empty if the start is not lower than the end
 #[derive(Clone)]
 pub struct RangeValue {
     pub start: Box<dyn Value>,
@@ -22,18 +25,148 @@ impl RangeValue {
             base_type,
         }
     }
+
+    /// Return true if the range has no values
+    pub fn is_empty(&self) -> bool {
+        compare_bounds(&self.start, &self.end).is_ge()
+    }
+
+    /// Return true if the value is inside the range
+    #[allow(clippy::borrowed_box)]
+    pub fn contains_value(&self, value: &Box<dyn Value>) -> bool {
+        compare_bounds(value, &self.start).is_ge() && compare_bounds(value, &self.end).is_lt()
+    }
+
+    /// Return true if every value of the other range is inside this range
+    pub fn contains_range(&self, other: &RangeValue) -> bool {
+        other.is_empty()
+            || (!self.is_empty()
+                && compare_bounds(&other.start, &self.start).is_ge()
+                && compare_bounds(&other.end, &self.end).is_le())
+    }
+
+    /// Return true if both ranges have at least one value in common
+    pub fn overlaps(&self, other: &RangeValue) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && compare_bounds(&self.start, &other.end).is_lt()
+            && compare_bounds(&other.start, &self.end).is_lt()
+    }
+
+    /// Return true if one of the ranges ends where the other one starts
+    pub fn is_adjacent(&self, other: &RangeValue) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && (self.end.equals(&other.start) || other.end.equals(&self.start))
+    }
+
+    /// Return the values that are in both ranges, the result may be empty
+    pub fn intersection(&self, other: &RangeValue) -> RangeValue {
+        if !self.overlaps(other) {
+            return self.empty_range();
+        }
+
+        RangeValue::new(
+            max_bound(&self.start, &other.start).clone(),
+            min_bound(&self.end, &other.end).clone(),
+            self.base_type.clone(),
+        )
+    }
+
+    /// Return the values that are in one of the ranges,
+    /// or None if the ranges neither overlap nor adjacent so the result is not contiguous
+    pub fn union(&self, other: &RangeValue) -> Option<RangeValue> {
+        if other.is_empty() {
+            return Some(self.clone());
+        }
+
+        if self.is_empty() {
+            return Some(other.clone());
+        }
+
+        if !self.overlaps(other) && !self.is_adjacent(other) {
+            return None;
+        }
+
+        Some(self.merge(other))
+    }
+
+    /// Return the smallest range that contains both ranges
+    pub fn merge(&self, other: &RangeValue) -> RangeValue {
+        if other.is_empty() {
+            return self.clone();
+        }
+
+        if self.is_empty() {
+            return other.clone();
+        }
+
+        RangeValue::new(
+            min_bound(&self.start, &other.start).clone(),
+            max_bound(&self.end, &other.end).clone(),
+            self.base_type.clone(),
+        )
+    }
+
+    /// Return the parts of this range that are not in the other range,
+    /// the result has zero, one or two non empty ranges
+    pub fn subtract(&self, other: &RangeValue) -> Vec<RangeValue> {
+        if self.is_empty() {
+            return vec![];
+        }
+
+        if !self.overlaps(other) {
+            return vec![self.clone()];
+        }
+
+        let mut parts = Vec::with_capacity(2);
+        if compare_bounds(&self.start, &other.start).is_lt() {
+            parts.push(RangeValue::new(
+                self.start.clone(),
+                other.start.clone(),
+                self.base_type.clone(),
+            ));
+        }
+
+        if compare_bounds(&other.end, &self.end).is_lt() {
+            parts.push(RangeValue::new(
+                other.end.clone(),
+                self.end.clone(),
+                self.base_type.clone(),
+            ));
+        }
+
+        parts
+    }
+
+    fn empty_range(&self) -> RangeValue {
+        RangeValue::new(
+            self.start.clone(),
+            self.start.clone(),
+            self.base_type.clone(),
+        )
+    }
 }
 
 impl Value for RangeValue {
     fn literal(&self) -> String {
+        if self.is_empty() {
+            return "empty".to_string();
+        }
         format!("{}..{}", self.start.literal(), self.end.literal())
     }
 
     fn equals(&self, other: &Box<dyn Value>) -> bool {
         if let Some(other_range) = other.as_any().downcast_ref::<RangeValue>() {
-            return self.base_type.equals(&other_range.base_type)
-                && self.start.equals(&other_range.start)
-                && self.end.equals(&other_range.end);
+            if !self.base_type.equals(&other_range.base_type) {
+                return false;
+            }
+
+            if self.is_empty() || other_range.is_empty() {
+                return self.is_empty() && other_range.is_empty();
+            }
+
+            return self.start.equals(&other_range.start) && self.end.equals(&other_range.end);
         }
         false
     }
@@ -52,43 +185,91 @@ impl Value for RangeValue {
         self
     }
 
-    fn logical_or_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+    fn add_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
         if let Some(other_range) = other.as_any().downcast_ref::<RangeValue>() {
-            if !self.equals(other) {
-                return Err("Overlap operator expect both Ranges to have same type".to_string());
-            }
-
-            let max_start = if self.start.compare(&other_range.start).unwrap().is_ge() {
-                &self.start
-            } else {
-                &other_range.start
+            return match self.union(other_range) {
+                Some(range) => Ok(Box::new(range)),
+                None => Err("Result of range union would not be contiguous".to_string()),
             };
+        }
+        Err("Unexpected type to perform `Range union +` with".to_string())
+    }
 
-            let max_end = if self.end.compare(&other_range.end).unwrap().is_lt() {
-                &self.end
-            } else {
-                &other_range.end
+    fn sub_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_range) = other.as_any().downcast_ref::<RangeValue>() {
+            let mut parts = self.subtract(other_range);
+            return match parts.len() {
+                0 => Ok(Box::new(self.empty_range())),
+                1 => Ok(Box::new(parts.remove(0))),
+                _ => Err("Result of range difference would not be contiguous".to_string()),
             };
+        }
+        Err("Unexpected type to perform `Range difference -` with".to_string())
+    }
+
+    fn mul_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_range) = other.as_any().downcast_ref::<RangeValue>() {
+            return Ok(Box::new(self.intersection(other_range)));
+        }
+        Err("Unexpected type to perform `Range intersection *` with".to_string())
+    }
 
-            let is_overlap = max_end.compare(max_start).unwrap().is_ge();
+    fn logical_and_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_range) = other.as_any().downcast_ref::<RangeValue>() {
+            if !self.base_type.equals(&other_range.base_type) {
+                return Err("Overlap operator expect both Ranges to have same type".to_string());
+            }
+
+            let is_overlap = self.overlaps(other_range);
+            return Ok(Box::new(BoolValue { value: is_overlap }));
+        }
+
+        if let Some(other_multirange) = other.as_any().downcast_ref::<MultiRangeValue>() {
+            let is_overlap = other_multirange.overlaps_range(self);
             return Ok(Box::new(BoolValue { value: is_overlap }));
         }
+
         Err("Unexpected type to perform `Range Overlap &&` with".to_string())
     }
 
+    fn logical_or_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        self.logical_and_op(other)
+    }
+
     fn contains_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
         if let Some(other_range) = other.as_any().downcast_ref::<RangeValue>() {
-            let is_in_range = other_range.start.compare(&self.start).unwrap().is_ge()
-                && other_range.end.compare(&self.end).unwrap().is_le();
+            let is_in_range = self.contains_range(other_range);
             return Ok(Box::new(BoolValue { value: is_in_range }));
         }
 
         if self.base_type.equals(&other.data_type()) {
-            let is_in_range = other.compare(&self.start).unwrap().is_ge()
-                && other.compare(&self.end).unwrap().is_le();
+            let is_in_range = self.contains_value(other);
             return Ok(Box::new(BoolValue { value: is_in_range }));
         }
 
         Err("Unexpected type to perform `Range contains @>` with".to_string())
     }
 }
+
+#[allow(clippy::borrowed_box)]
+fn compare_bounds(first: &Box<dyn Value>, second: &Box<dyn Value>) -> Ordering {
+    first.compare(second).unwrap_or(Ordering::Equal)
+}
+
+#[allow(clippy::borrowed_box)]
+fn min_bound<'a>(first: &'a Box<dyn Value>, second: &'a Box<dyn Value>) -> &'a Box<dyn Value> {
+    if compare_bounds(first, second).is_le() {
+        first
+    } else {
+        second
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+fn max_bound<'a>(first: &'a Box<dyn Value>, second: &'a Box<dyn Value>) -> &'a Box<dyn Value> {
+    if compare_bounds(first, second).is_ge() {
+        first
+    } else {
+        second
+    }
+}
@@ -0,0 +1,140 @@
+use std::any::Any;
+use std::cmp::Ordering;
+
+use gitql_ast::types::semver::SemVerType;
+use gitql_ast::types::DataType;
+use gitql_ast::SemVer;
+
+use super::base::Value;
+use super::boolean::BoolValue;
+use super::text::TextValue;
+
+#[derive(Clone)]
+pub struct SemVerValue {
+    pub version: SemVer,
+}
+
+impl SemVerValue {
+    pub fn new(version: SemVer) -> Self {
+        SemVerValue { version }
+    }
+}
+
+impl Value for SemVerValue {
+    fn literal(&self) -> String {
+        self.version.to_string()
+    }
+
+    fn equals(&self, other: &Box<dyn Value>) -> bool {
+        if let Some(other_semver) = other.as_any().downcast_ref::<SemVerValue>() {
+            return self.version == other_semver.version;
+        }
+        false
+    }
+
+    fn compare(&self, other: &Box<dyn Value>) -> Option<Ordering> {
+        if let Some(other_semver) = other.as_any().downcast_ref::<SemVerValue>() {
+            return self.version.partial_cmp(&other_semver.version);
+        }
+        None
+    }
+
+    fn data_type(&self) -> Box<dyn DataType> {
+        Box::new(SemVerType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_semver) = other.as_any().downcast_ref::<SemVerValue>() {
+            return Ok(Box::new(BoolValue::new(
+                self.version == other_semver.version,
+            )));
+        }
+        Err("Unexpected type to perform `=` with".to_string())
+    }
+
+    fn bang_eq_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_semver) = other.as_any().downcast_ref::<SemVerValue>() {
+            return Ok(Box::new(BoolValue::new(
+                self.version != other_semver.version,
+            )));
+        }
+        Err("Unexpected type to perform `!=` with".to_string())
+    }
+
+    fn gt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_semver) = other.as_any().downcast_ref::<SemVerValue>() {
+            return Ok(Box::new(BoolValue::new(
+                self.version > other_semver.version,
+            )));
+        }
+        Err("Unexpected type to perform `>` with".to_string())
+    }
+
+    fn gte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_semver) = other.as_any().downcast_ref::<SemVerValue>() {
+            return Ok(Box::new(BoolValue::new(
+                self.version >= other_semver.version,
+            )));
+        }
+        Err("Unexpected type to perform `>=` with".to_string())
+    }
+
+    fn lt_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_semver) = other.as_any().downcast_ref::<SemVerValue>() {
+            return Ok(Box::new(BoolValue::new(
+                self.version < other_semver.version,
+            )));
+        }
+        Err("Unexpected type to perform `<` with".to_string())
+    }
+
+    fn lte_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_semver) = other.as_any().downcast_ref::<SemVerValue>() {
+            return Ok(Box::new(BoolValue::new(
+                self.version <= other_semver.version,
+            )));
+        }
+        Err("Unexpected type to perform `<=` with".to_string())
+    }
+
+    fn cast_op(&self, target_type: &Box<dyn DataType>) -> Result<Box<dyn Value>, String> {
+        if target_type.is_text() {
+            return Ok(Box::new(TextValue::new(self.literal())));
+        }
+        Err("Unexpected value to perform `CAST` with".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn semver(version: &str) -> Box<dyn Value> {
+        Box::new(SemVerValue::new(SemVer::parse(version).unwrap()))
+    }
+
+    #[test]
+    fn test_semver_ignores_build_metadata() {
+        let value = semver("1.2.3+build.1");
+        let other = semver("1.2.3+build.2");
+        assert!(value.equals(&other));
+        assert_eq!(value.compare(&other), Some(Ordering::Equal));
+        assert_eq!(value.eq_op(&other).unwrap().as_bool(), Some(true));
+        assert_eq!(value.gt_op(&other).unwrap().as_bool(), Some(false));
+    }
+
+    #[test]
+    fn test_semver_strict_compare() {
+        let version = SemVer::parse("1.2.3+build.1").unwrap();
+        let other = SemVer::parse("1.2.3+build.2").unwrap();
+        assert_eq!(version.cmp_strict(&other), Ordering::Less);
+        assert_eq!(version.cmp_strict(&version), Ordering::Equal);
+
+        let pre_release = SemVer::parse("1.2.3-rc.1+build.9").unwrap();
+        assert_eq!(pre_release.cmp_strict(&version), Ordering::Less);
+    }
+}
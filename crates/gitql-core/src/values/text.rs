@@ -1,19 +1,32 @@
 use std::any::Any;
 use std::cmp::Ordering;
 
+use chrono_tz::Tz;
 use gitql_ast::operator::GroupComparisonOperator;
 use regex::Regex;
 use regex::RegexBuilder;
 
+use gitql_ast::types::decimal::DecimalType;
+use gitql_ast::types::enumeration::EnumType;
 use gitql_ast::types::text::TextType;
 use gitql_ast::types::DataType;
+use gitql_ast::Decimal;
+use gitql_ast::SemVer;
 
 use super::base::Value;
 use super::boolean::BoolValue;
+use super::bytes::BytesValue;
 use super::converters::string_literal_to_boolean;
 use super::converters::string_literal_to_date;
 use super::converters::string_literal_to_date_time;
 use super::converters::string_literal_to_time;
+use super::datetimetz::DateTimeTzValue;
+use super::decimal::DecimalValue;
+use super::enumeration::EnumValue;
+use super::float::FloatValue;
+use super::integer::IntValue;
+use super::json::JsonValue;
+use super::semver::SemVerValue;
 
 #[derive(Clone)]
 pub struct TextValue {
@@ -264,7 +277,9 @@ impl Value for TextValue {
     }
 
     fn like_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
-        let pattern_text = other.as_text().unwrap();
+        let Some(pattern_text) = other.as_text() else {
+            return Err("Unexpected type to perform `LIKE` with".to_string());
+        };
         let pattern = &format!(
             "^{}$",
             pattern_text
@@ -288,7 +303,9 @@ impl Value for TextValue {
     }
 
     fn glob_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
-        let pattern_text = other.as_text().unwrap();
+        let Some(pattern_text) = other.as_text() else {
+            return Err("Unexpected type to perform `GLOB` with".to_string());
+        };
         let pattern = &format!(
             "^{}$",
             pattern_text
@@ -307,7 +324,9 @@ impl Value for TextValue {
     }
 
     fn regexp_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
-        let pattern_text = other.as_text().unwrap();
+        let Some(pattern_text) = other.as_text() else {
+            return Err("Unexpected type to perform `REGEXP` with".to_string());
+        };
         let pattern = &format!(
             "^{}$",
             pattern_text
@@ -347,6 +366,99 @@ impl Value for TextValue {
             return Ok(string_literal_to_date_time(&self.value));
         }
 
+        if target_type.is_date_time_tz() {
+            return match DateTimeTzValue::parse(&self.value, Tz::UTC) {
+                Some(date_time) => Ok(Box::new(date_time)),
+                None => Err(format!(
+                    "Invalid input syntax for type datetimetz: `{}`",
+                    self.value
+                )),
+            };
+        }
+
+        if target_type.is_int() {
+            return match self.value.trim().parse::<i64>() {
+                Ok(value) => Ok(Box::new(IntValue::new(value))),
+                Err(_) => Err(format!(
+                    "Invalid input syntax for type int: `{}`",
+                    self.value
+                )),
+            };
+        }
+
+        if target_type.is_float() {
+            return match self.value.trim().parse::<f64>() {
+                Ok(value) => Ok(Box::new(FloatValue::new(value))),
+                Err(_) => Err(format!(
+                    "Invalid input syntax for type float: `{}`",
+                    self.value
+                )),
+            };
+        }
+
+        if target_type.is_json() {
+            return match serde_json::from_str(&self.value) {
+                Ok(json) => Ok(Box::new(JsonValue::new(json))),
+                Err(error) => Err(format!("Invalid input syntax for type json: {}", error)),
+            };
+        }
+
+        if let Some(decimal_type) = target_type.as_any().downcast_ref::<DecimalType>() {
+            return match Decimal::parse(&self.value) {
+                Some(decimal) => Ok(Box::new(DecimalValue::with_precision(
+                    decimal,
+                    decimal_type.precision,
+                    decimal_type.scale,
+                )?)),
+                None => Err(format!(
+                    "Invalid input syntax for type decimal: `{}`",
+                    self.value
+                )),
+            };
+        }
+
+        if target_type.is_bytes() {
+            return Ok(Box::new(BytesValue::new(self.value.as_bytes().to_vec())));
+        }
+
+        if let Some(enum_type) = target_type.as_any().downcast_ref::<EnumType>() {
+            return match EnumValue::new(&self.value, enum_type.clone()) {
+                Some(value) => Ok(Box::new(value)),
+                None => Err(format!(
+                    "Invalid input value for enum `{}`: `{}`",
+                    enum_type.name, self.value
+                )),
+            };
+        }
+
+        if target_type.is_semver() {
+            return match SemVer::parse(&self.value) {
+                Some(version) => Ok(Box::new(SemVerValue::new(version))),
+                None => Err(format!(
+                    "Invalid input syntax for type semver: `{}`",
+                    self.value
+                )),
+            };
+        }
+
         Err("Unexpected value to perform `CAST` with".to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::values::null::NullValue;
+
+    #[test]
+    fn test_text_with_null() {
+        let value = TextValue::new("gql".to_string());
+        let null: Box<dyn Value> = Box::new(NullValue);
+        assert!(!value.equals(&null));
+        assert_eq!(value.compare(&null), None);
+        assert!(value.eq_op(&null).is_err());
+        assert!(value.like_op(&null).is_err());
+        assert!(value.glob_op(&null).is_err());
+        assert!(value.regexp_op(&null).is_err());
+    }
+}
@@ -1,8 +1,24 @@
 use gitql_core::object::Row;
 
+/// Iterator over batches of rows, each batch is loaded only when it requested by the engine
+pub type RowsBatchIterator<'a> = Box<dyn Iterator<Item = Result<Vec<Row>, String>> + 'a>;
+
 /// DataProvider is a component that used to provide and map the data to the GitQL Engine
 ///
 /// User should implement [`DataProvider`] trait for each data format for example files, logs, api
 pub trait DataProvider {
     fn provide(&self, table: &str, selected_columns: &[String]) -> Result<Vec<Row>, String>;
+
+    /// Provide the rows as batches on demand, so queries that don't need all rows at once
+    /// can be executed without loading the whole table into memory
+    ///
+    /// By default all rows are provided as one batch using [`DataProvider::provide`]
+    fn provide_batches<'a>(
+        &'a self,
+        table: &str,
+        selected_columns: &[String],
+    ) -> Result<RowsBatchIterator<'a>, String> {
+        let rows = self.provide(table, selected_columns)?;
+        Ok(Box::new(std::iter::once(Ok(rows))))
+    }
 }
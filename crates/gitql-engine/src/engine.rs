@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::time::Instant;
 use std::vec;
 
 use gitql_ast::statement::DescribeStatement;
@@ -19,17 +20,27 @@ use crate::data_provider::DataProvider;
 use crate::engine_distinct::apply_distinct_operator;
 use crate::engine_evaluator::evaluate_expression;
 use crate::engine_executor::execute_global_variable_statement;
+use crate::engine_executor::execute_select_statement;
 use crate::engine_executor::execute_statement;
+use crate::engine_explain::describe_distinct;
+use crate::engine_explain::describe_statement;
+use crate::engine_explain::evaluate_explain_query;
+use crate::engine_explain::QueryProfile;
+use crate::engine_explain::StageStats;
+use crate::engine_stream::create_rows_stream;
+use crate::engine_stream::is_streamable_query;
+use crate::engine_stream::RowsStream;
 
 /// Static Logical Plan, later must be replaced by optimized and Logical Planner
-const FIXED_LOGICAL_PLAN_LEN: usize = 9;
-const FIXED_LOGICAL_PLAN: [&str; FIXED_LOGICAL_PLAN_LEN] = [
+const FIXED_LOGICAL_PLAN_LEN: usize = 10;
+pub(crate) const FIXED_LOGICAL_PLAN: [&str; FIXED_LOGICAL_PLAN_LEN] = [
     "select",
     "where",
     "group",
     "aggregation",
     "having",
     "window_functions",
+    "qualify",
     "order",
     "offset",
     "limit",
@@ -42,6 +53,11 @@ pub enum EvaluationResult {
     SetGlobalVariable,
 }
 
+pub enum StreamEvaluationResult<'a> {
+    SelectedRowsStream(Box<RowsStream<'a>>),
+    Evaluated(EvaluationResult),
+}
+
 #[allow(clippy::borrowed_box)]
 pub fn evaluate(
     env: &mut Environment,
@@ -50,20 +66,52 @@ pub fn evaluate(
 ) -> Result<Vec<EvaluationResult>, String> {
     let mut evaluations_results: Vec<EvaluationResult> = vec![];
     for query in queries {
-        let evaluation_result = match query {
-            Query::Do(do_statement) => evaluate_do_query(env, &do_statement),
-            Query::Select(gql_query) => evaluate_select_query(env, data_provider, gql_query),
-            Query::GlobalVariableDeclaration(global) => {
-                evaluate_global_declaration_query(env, &global)
-            }
-            Query::Describe(describe_statement) => evaluate_describe_query(env, describe_statement),
-            Query::ShowTables => evaluate_show_tables_query(env),
-        }?;
+        let evaluation_result = evaluate_query(env, data_provider, query)?;
         evaluations_results.push(evaluation_result);
     }
     Ok(evaluations_results)
 }
 
+/// Evaluate a single query, if it's a select query without blocking statements
+/// the result is a stream that load and execute the rows on demand, otherwise it evaluated directly
+#[allow(clippy::borrowed_box)]
+pub fn evaluate_as_stream<'a>(
+    env: &'a mut Environment,
+    data_provider: &'a Box<dyn DataProvider>,
+    query: Query,
+) -> Result<StreamEvaluationResult<'a>, String> {
+    if let Query::Select(gql_query) = query {
+        if is_streamable_query(env, &gql_query) {
+            let stream = create_rows_stream(env, data_provider, gql_query)?;
+            return Ok(StreamEvaluationResult::SelectedRowsStream(Box::new(stream)));
+        }
+
+        let evaluation_result = evaluate_select_query(env, data_provider, gql_query, None)?;
+        return Ok(StreamEvaluationResult::Evaluated(evaluation_result));
+    }
+
+    let evaluation_result = evaluate_query(env, data_provider, query)?;
+    Ok(StreamEvaluationResult::Evaluated(evaluation_result))
+}
+
+#[allow(clippy::borrowed_box)]
+fn evaluate_query(
+    env: &mut Environment,
+    data_provider: &Box<dyn DataProvider>,
+    query: Query,
+) -> Result<EvaluationResult, String> {
+    match query {
+        Query::Do(do_statement) => evaluate_do_query(env, &do_statement),
+        Query::Select(gql_query) => evaluate_select_query(env, data_provider, gql_query, None),
+        Query::GlobalVariableDeclaration(global) => evaluate_global_declaration_query(env, &global),
+        Query::Describe(describe_statement) => evaluate_describe_query(env, describe_statement),
+        Query::ShowTables => evaluate_show_tables_query(env),
+        Query::Explain(explain_statement) => {
+            evaluate_explain_query(env, data_provider, explain_statement)
+        }
+    }
+}
+
 fn evaluate_do_query(
     env: &mut Environment,
     do_statement: &DoStatement,
@@ -76,11 +124,13 @@ fn evaluate_do_query(
     )?))
 }
 
+/// Evaluate the select query, if the profile is passed the time and rows count of each stage are collected
 #[allow(clippy::borrowed_box)]
-fn evaluate_select_query(
+pub(crate) fn evaluate_select_query(
     env: &mut Environment,
     data_provider: &Box<dyn DataProvider>,
     query: GQLQuery,
+    mut profile: Option<&mut QueryProfile>,
 ) -> Result<EvaluationResult, String> {
     let mut gitql_object = GitQLObject::default();
     let mut alias_table: HashMap<String, String> = query.alias_table;
@@ -102,14 +152,14 @@ fn evaluate_select_query(
                         .downcast_ref::<SelectStatement>()
                         .unwrap();
 
-                    execute_statement(
+                    execute_select_statement(
                         env,
-                        statement,
+                        select_statement,
+                        &alias_table,
                         data_provider,
                         &mut gitql_object,
-                        &mut alias_table,
                         &hidden_selections_map,
-                        has_group_by_statement,
+                        profile.as_deref_mut(),
                     )?;
 
                     // If the main group is empty, no need to perform other statements
@@ -120,6 +170,9 @@ fn evaluate_select_query(
                     distinct = Some(select_statement.distinct.to_owned());
                 }
                 _ => {
+                    let stage_start = Instant::now();
+                    let input_rows = rows_count(&gitql_object);
+
                     execute_statement(
                         env,
                         statement,
@@ -129,6 +182,18 @@ fn evaluate_select_query(
                         &hidden_selections_map,
                         has_group_by_statement,
                     )?;
+
+                    if let Some(profile) = profile.as_deref_mut() {
+                        // Group stage output is the groups, each of them is aggregated into one row
+                        let output_rows = if logical_node_name == "group" {
+                            gitql_object.groups.len()
+                        } else {
+                            rows_count(&gitql_object)
+                        };
+                        let details = describe_statement(logical_node_name, statement);
+                        let stats = StageStats::new(stage_start, input_rows, output_rows);
+                        profile.push(logical_node_name, details, stats);
+                    }
                 }
             }
         }
@@ -136,7 +201,17 @@ fn evaluate_select_query(
 
     // Apply the distinct operation after executing statements
     if let Some(distinct) = distinct {
+        let stage_start = Instant::now();
+        let input_rows = rows_count(&gitql_object);
+
         apply_distinct_operator(&distinct, &mut gitql_object, &hidden_selections);
+
+        if let Some(profile) = profile.as_deref_mut() {
+            if let Some(details) = describe_distinct(&distinct) {
+                let stats = StageStats::new(stage_start, input_rows, rows_count(&gitql_object));
+                profile.push("distinct", details, stats);
+            }
+        }
     }
 
     // Remove Hidden Selection from the rows after executing the query plan
@@ -171,6 +246,9 @@ fn evaluate_select_query(
 
     // Into statement must be executed last after flatted and remove hidden selections
     if let Some(into_statement) = statements_map.get_mut("into") {
+        let stage_start = Instant::now();
+        let input_rows = rows_count(&gitql_object);
+
         execute_statement(
             env,
             into_statement,
@@ -181,6 +259,12 @@ fn evaluate_select_query(
             has_group_by_statement,
         )?;
 
+        if let Some(profile) = profile {
+            let details = describe_statement("into", into_statement);
+            let stats = StageStats::new(stage_start, input_rows, input_rows);
+            profile.push("into", details, stats);
+        }
+
         return Ok(EvaluationResult::SelectedInfo);
     }
 
@@ -224,7 +308,10 @@ fn evaluate_describe_query(
         })
     }
 
-    gitql_object.groups.push(Group { rows });
+    gitql_object.groups.push(Group {
+        rows,
+        rolled_up_columns: vec![],
+    });
     Ok(EvaluationResult::SelectedGroups(gitql_object))
 }
 
@@ -242,11 +329,18 @@ fn evaluate_show_tables_query(env: &mut Environment) -> Result<EvaluationResult,
 
     let mut gitql_object = GitQLObject::default();
     gitql_object.titles.push("Tables".to_owned());
-    gitql_object.groups.push(Group { rows });
+    gitql_object.groups.push(Group {
+        rows,
+        rolled_up_columns: vec![],
+    });
 
     Ok(EvaluationResult::SelectedGroups(gitql_object))
 }
 
+fn rows_count(gitql_object: &GitQLObject) -> usize {
+    gitql_object.groups.iter().map(|group| group.len()).sum()
+}
+
 fn remove_hidden_selected_from_groups(
     titles: &mut Vec<String>,
     groups: &mut [Group],
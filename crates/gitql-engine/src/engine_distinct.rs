@@ -37,7 +37,7 @@ fn apply_distinct_all_operation(object: &mut GitQLObject, hidden_selections: &[S
     let hidden_selection_count = hidden_selections.len();
 
     let objects = &object.groups[0].rows;
-    let mut new_objects = Group { rows: vec![] };
+    let mut new_objects = Group::default();
     let mut values_set: HashSet<u64> = HashSet::new();
 
     for object in objects {
@@ -72,7 +72,7 @@ fn apply_distinct_all_operation(object: &mut GitQLObject, hidden_selections: &[S
 /// Apply Distinct on one or more valid fields from the object
 fn apply_distinct_on_operation(object: &mut GitQLObject, distinct_fields: &[String]) {
     let objects = &object.groups[0].rows;
-    let mut new_objects: Group = Group { rows: vec![] };
+    let mut new_objects: Group = Group::default();
     let mut values_set: HashSet<u64> = HashSet::new();
     let titles = &object.titles;
 
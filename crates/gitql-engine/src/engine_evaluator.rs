@@ -1,6 +1,8 @@
 use gitql_ast::expression::ArithmeticExpr;
 use gitql_ast::expression::ArrayExpr;
+use gitql_ast::expression::ArrowExpr;
 use gitql_ast::expression::AssignmentExpr;
+use gitql_ast::expression::AtTimeZoneExpr;
 use gitql_ast::expression::BenchmarkCallExpr;
 use gitql_ast::expression::BetweenExpr;
 use gitql_ast::expression::BetweenKind;
@@ -22,25 +24,38 @@ use gitql_ast::expression::InExpr;
 use gitql_ast::expression::IndexExpr;
 use gitql_ast::expression::IntervalExpr;
 use gitql_ast::expression::IsNullExpr;
+use gitql_ast::expression::LambdaExpr;
 use gitql_ast::expression::LikeExpr;
 use gitql_ast::expression::LogicalExpr;
 use gitql_ast::expression::MemberAccessExpr;
 use gitql_ast::expression::Number;
 use gitql_ast::expression::NumberExpr;
+use gitql_ast::expression::PlaceholderExpr;
 use gitql_ast::expression::RegexExpr;
+use gitql_ast::expression::RowExpr;
 use gitql_ast::expression::SliceExpr;
 use gitql_ast::expression::StringExpr;
 use gitql_ast::expression::SymbolExpr;
+use gitql_ast::expression::TryCastExpr;
 use gitql_ast::expression::UnaryExpr;
 use gitql_ast::operator::ArithmeticOperator;
+use gitql_ast::operator::ArrowOperator;
 use gitql_ast::operator::BinaryBitwiseOperator;
 use gitql_ast::operator::BinaryLogicalOperator;
 use gitql_ast::operator::ComparisonOperator;
 use gitql_ast::operator::PrefixUnaryOperator;
+use gitql_ast::types::dynamic::DynamicType;
+use gitql_ast::types::optional::OptionType;
+use gitql_ast::types::varargs::VarargsType;
+use gitql_ast::types::DataType;
 use gitql_core::environment::Environment;
+use gitql_core::environment::MAX_CALL_DEPTH;
+use gitql_core::signature::HigherOrderFunction;
 use gitql_core::values::array::ArrayValue;
 use gitql_core::values::boolean::BoolValue;
 use gitql_core::values::composite::CompositeValue;
+use gitql_core::values::datetimetz::parse_time_zone;
+use gitql_core::values::datetimetz::DateTimeTzValue;
 use gitql_core::values::float::FloatValue;
 use gitql_core::values::integer::IntValue;
 use gitql_core::values::interval::IntervalValue;
@@ -78,6 +93,10 @@ pub fn evaluate_expression(
             let expr = expression.as_any().downcast_ref::<ArrayExpr>().unwrap();
             evaluate_array(env, expr, titles, object)
         }
+        Row => {
+            let expr = expression.as_any().downcast_ref::<RowExpr>().unwrap();
+            evaluate_row(env, expr, titles, object)
+        }
         GlobalVariable => {
             let expr = expression
                 .as_any()
@@ -85,6 +104,13 @@ pub fn evaluate_expression(
                 .unwrap();
             evaluate_global_variable(env, expr)
         }
+        Placeholder => {
+            let expr = expression
+                .as_any()
+                .downcast_ref::<PlaceholderExpr>()
+                .unwrap();
+            evaluate_placeholder(env, expr)
+        }
         Number => {
             let expr = expression.as_any().downcast_ref::<NumberExpr>().unwrap();
             evaluate_number(expr)
@@ -109,6 +135,10 @@ pub fn evaluate_expression(
             let expr = expression.as_any().downcast_ref::<SliceExpr>().unwrap();
             evaluate_collection_slice(env, expr, titles, object)
         }
+        Arrow => {
+            let expr = expression.as_any().downcast_ref::<ArrowExpr>().unwrap();
+            evaluate_arrow(env, expr, titles, object)
+        }
         Arithmetic => {
             let expr = expression
                 .as_any()
@@ -192,6 +222,17 @@ pub fn evaluate_expression(
             let expr = expression.as_any().downcast_ref::<CastExpr>().unwrap();
             evaluate_cast(env, expr, titles, object)
         }
+        TryCast => {
+            let expr = expression.as_any().downcast_ref::<TryCastExpr>().unwrap();
+            evaluate_try_cast(env, expr, titles, object)
+        }
+        AtTimeZone => {
+            let expr = expression
+                .as_any()
+                .downcast_ref::<AtTimeZoneExpr>()
+                .unwrap();
+            evaluate_at_time_zone(env, expr, titles, object)
+        }
         Grouping => {
             let expr = expression.as_any().downcast_ref::<GroupExpr>().unwrap();
             evaluate_grouping(env, expr, titles, object)
@@ -203,6 +244,9 @@ pub fn evaluate_expression(
                 .unwrap();
             evaluate_member_access(env, expr, titles, object)
         }
+        Lambda => Err(
+            "Lambda expression can only be used as argument of higher order function".to_string(),
+        ),
         Null => Ok(Box::new(NullValue)),
     }
 }
@@ -248,6 +292,20 @@ fn evaluate_array(
     Ok(Box::new(ArrayValue::new(values, expr.element_type.clone())))
 }
 
+fn evaluate_row(
+    env: &mut Environment,
+    expr: &RowExpr,
+    titles: &[String],
+    object: &Vec<Box<dyn Value>>,
+) -> Result<Box<dyn Value>, String> {
+    let mut row = CompositeValue::empty(expr.row_type.name.to_string());
+    for (name, value) in expr.row_type.members.keys().zip(expr.values.iter()) {
+        let value = evaluate_expression(env, value, titles, object)?;
+        row = row.add_member(name.to_string(), value);
+    }
+    Ok(Box::new(row))
+}
+
 fn evaluate_global_variable(
     env: &mut Environment,
     expr: &GlobalVariableExpr,
@@ -263,6 +321,16 @@ fn evaluate_global_variable(
     ))
 }
 
+fn evaluate_placeholder(
+    env: &mut Environment,
+    expr: &PlaceholderExpr,
+) -> Result<Box<dyn Value>, String> {
+    match env.parameters.get(&expr.name) {
+        Some(value) => Ok(value.clone()),
+        None => Err(format!("Missing value for parameter `{}`", expr.name)),
+    }
+}
+
 fn evaluate_number(expr: &NumberExpr) -> Result<Box<dyn Value>, String> {
     Ok(match expr.value {
         Number::Int(integer) => Box::new(IntValue::new(integer)),
@@ -289,6 +357,24 @@ fn evaluate_collection_index(
     array.index_op(&index)
 }
 
+fn evaluate_arrow(
+    env: &mut Environment,
+    expr: &ArrowExpr,
+    titles: &[String],
+    object: &Vec<Box<dyn Value>>,
+) -> Result<Box<dyn Value>, String> {
+    let lhs = evaluate_expression(env, &expr.left, titles, object)?;
+    if lhs.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
+    let rhs = evaluate_expression(env, &expr.right, titles, object)?;
+    match expr.operator {
+        ArrowOperator::Arrow => lhs.arrow_op(&rhs),
+        ArrowOperator::LongArrow => lhs.long_arrow_op(&rhs),
+    }
+}
+
 fn evaluate_collection_slice(
     env: &mut Environment,
     expr: &SliceExpr,
@@ -319,6 +405,10 @@ fn evaluate_prefix_unary(
     object: &Vec<Box<dyn Value>>,
 ) -> Result<Box<dyn Value>, String> {
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
+    if rhs.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
     match expr.operator {
         PrefixUnaryOperator::Negative => rhs.neg_op(),
         PrefixUnaryOperator::Bang => rhs.bang_op(),
@@ -334,6 +424,10 @@ fn evaluate_arithmetic(
 ) -> Result<Box<dyn Value>, String> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
+    if lhs.is_null() || rhs.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
     match expr.operator {
         ArithmeticOperator::Plus => lhs.add_op(&rhs),
         ArithmeticOperator::Minus => lhs.sub_op(&rhs),
@@ -352,6 +446,15 @@ fn evaluate_comparison(
 ) -> Result<Box<dyn Value>, String> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
+    if lhs.is_null() || rhs.is_null() {
+        // Unlike other comparisons `<=>` treats NULL as a known value
+        if expr.operator == ComparisonOperator::NullSafeEqual {
+            let is_equal = lhs.is_null() && rhs.is_null();
+            return Ok(Box::new(BoolValue::new(is_equal)));
+        }
+        return Ok(Box::new(NullValue));
+    }
+
     match expr.operator {
         ComparisonOperator::Greater => lhs.gt_op(&rhs),
         ComparisonOperator::GreaterEqual => lhs.gte_op(&rhs),
@@ -359,7 +462,7 @@ fn evaluate_comparison(
         ComparisonOperator::LessEqual => lhs.lte_op(&rhs),
         ComparisonOperator::Equal => lhs.eq_op(&rhs),
         ComparisonOperator::NotEqual => lhs.bang_eq_op(&rhs),
-        ComparisonOperator::NullSafeEqual => lhs.null_safe_eq_op(&rhs),
+        ComparisonOperator::NullSafeEqual => lhs.eq_op(&rhs),
     }
 }
 
@@ -371,6 +474,12 @@ fn evaluate_group_comparison(
 ) -> Result<Box<dyn Value>, String> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
+    if (lhs.is_null() || rhs.is_null())
+        && expr.comparison_operator != ComparisonOperator::NullSafeEqual
+    {
+        return Ok(Box::new(NullValue));
+    }
+
     match expr.comparison_operator {
         ComparisonOperator::Greater => lhs.group_gt_op(&rhs, &expr.group_operator),
         ComparisonOperator::GreaterEqual => lhs.group_gte_op(&rhs, &expr.group_operator),
@@ -390,6 +499,9 @@ fn evaluate_contains(
 ) -> Result<Box<dyn Value>, String> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
+    if lhs.is_null() || rhs.is_null() {
+        return Ok(Box::new(NullValue));
+    }
     lhs.contains_op(&rhs)
 }
 
@@ -401,6 +513,9 @@ fn evaluate_contained_by(
 ) -> Result<Box<dyn Value>, String> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
+    if lhs.is_null() || rhs.is_null() {
+        return Ok(Box::new(NullValue));
+    }
     rhs.contains_op(&lhs)
 }
 
@@ -412,6 +527,9 @@ fn evaluate_like(
 ) -> Result<Box<dyn Value>, String> {
     let input = evaluate_expression(env, &expr.input, titles, object)?;
     let pattern = evaluate_expression(env, &expr.pattern, titles, object)?;
+    if input.is_null() || pattern.is_null() {
+        return Ok(Box::new(NullValue));
+    }
     input.like_op(&pattern)
 }
 
@@ -423,6 +541,9 @@ fn evaluate_regex(
 ) -> Result<Box<dyn Value>, String> {
     let input = evaluate_expression(env, &expr.input, titles, object)?;
     let pattern = evaluate_expression(env, &expr.pattern, titles, object)?;
+    if input.is_null() || pattern.is_null() {
+        return Ok(Box::new(NullValue));
+    }
     input.regexp_op(&pattern)
 }
 
@@ -434,6 +555,9 @@ fn evaluate_glob(
 ) -> Result<Box<dyn Value>, String> {
     let input = evaluate_expression(env, &expr.input, titles, object)?;
     let pattern = evaluate_expression(env, &expr.pattern, titles, object)?;
+    if input.is_null() || pattern.is_null() {
+        return Ok(Box::new(NullValue));
+    }
     input.glob_op(&pattern)
 }
 
@@ -444,7 +568,19 @@ fn evaluate_logical(
     object: &Vec<Box<dyn Value>>,
 ) -> Result<Box<dyn Value>, String> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
+
+    // Skip the right hand side if the left boolean value already decides the result
+    match (&expr.operator, lhs.as_bool()) {
+        (BinaryLogicalOperator::And, Some(false)) => return Ok(Box::new(BoolValue::new(false))),
+        (BinaryLogicalOperator::Or, Some(true)) => return Ok(Box::new(BoolValue::new(true))),
+        _ => {}
+    }
+
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
+    if lhs.is_null() || rhs.is_null() {
+        return Ok(evaluate_logical_with_null(&expr.operator, &lhs, &rhs));
+    }
+
     match expr.operator {
         BinaryLogicalOperator::And => lhs.logical_and_op(&rhs),
         BinaryLogicalOperator::Or => lhs.logical_or_op(&rhs),
@@ -452,6 +588,22 @@ fn evaluate_logical(
     }
 }
 
+/// Three-valued logic where NULL is an unknown value, so `NULL AND FALSE` is false,
+/// `NULL OR TRUE` is true and the result is NULL otherwise
+#[allow(clippy::borrowed_box)]
+fn evaluate_logical_with_null(
+    operator: &BinaryLogicalOperator,
+    lhs: &Box<dyn Value>,
+    rhs: &Box<dyn Value>,
+) -> Box<dyn Value> {
+    let known_value = if lhs.is_null() { rhs } else { lhs };
+    match (operator, known_value.as_bool()) {
+        (BinaryLogicalOperator::And, Some(false)) => Box::new(BoolValue::new(false)),
+        (BinaryLogicalOperator::Or, Some(true)) => Box::new(BoolValue::new(true)),
+        _ => Box::new(NullValue),
+    }
+}
+
 fn evaluate_bitwise(
     env: &mut Environment,
     expr: &BitwiseExpr,
@@ -460,6 +612,10 @@ fn evaluate_bitwise(
 ) -> Result<Box<dyn Value>, String> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
+    if lhs.is_null() || rhs.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
     match expr.operator {
         BinaryBitwiseOperator::Or => lhs.or_op(&rhs),
         BinaryBitwiseOperator::And => lhs.and_op(&rhs),
@@ -476,14 +632,190 @@ fn evaluate_call(
     object: &Vec<Box<dyn Value>>,
 ) -> Result<Box<dyn Value>, String> {
     let function_name = expr.function_name.as_str();
+    if let Some(function) = env.higher_order_function(function_name).copied() {
+        return evaluate_higher_order_call(env, function, expr, titles, object);
+    }
+
     let mut arguments = Vec::with_capacity(expr.arguments.len());
     for arg in expr.arguments.iter() {
         arguments.push(evaluate_expression(env, arg, titles, object)?);
     }
+
+    // User function body is evaluated with the arguments values as the only columns
+    if let Some(function) = env.user_function(function_name).cloned() {
+        if env.call_depth >= MAX_CALL_DEPTH {
+            return Err(format!(
+                "Function `{}` exceeded the maximum call depth {}",
+                function_name, MAX_CALL_DEPTH
+            ));
+        }
+
+        env.call_depth += 1;
+        let result = evaluate_expression(env, &function.body, &function.parameters, &arguments);
+        env.call_depth -= 1;
+        return result;
+    }
+
+    if is_null_passed_to_strict_parameter(env, function_name, &arguments) {
+        return Ok(Box::new(NullValue));
+    }
+
+    apply_session_time_zone(env, function_name, &mut arguments);
+
+    if let Some(function) = env.fallible_function(function_name) {
+        return function(&arguments);
+    }
+
     let function = env.std_function(function_name).unwrap();
     Ok(function(&arguments))
 }
 
+fn evaluate_higher_order_call(
+    env: &mut Environment,
+    function: HigherOrderFunction,
+    expr: &CallExpr,
+    titles: &[String],
+    object: &Vec<Box<dyn Value>>,
+) -> Result<Box<dyn Value>, String> {
+    let (lambda, arguments_exprs) = expr.arguments.split_last().unwrap();
+    let lambda = lambda.as_any().downcast_ref::<LambdaExpr>().unwrap();
+
+    let mut arguments = Vec::with_capacity(arguments_exprs.len());
+    for arg in arguments_exprs.iter() {
+        arguments.push(evaluate_expression(env, arg, titles, object)?);
+    }
+
+    if is_null_passed_to_strict_parameter(env, &expr.function_name, &arguments) {
+        return Ok(Box::new(NullValue));
+    }
+
+    // Lambda parameters are inserted before the titles to shadow the columns with the same names
+    let parameters_count = lambda.parameters.len();
+    let mut lambda_titles = Vec::with_capacity(parameters_count + titles.len());
+    lambda_titles.extend_from_slice(&lambda.parameters);
+    lambda_titles.extend_from_slice(titles);
+
+    let mut lambda_object: Vec<Box<dyn Value>> = Vec::with_capacity(lambda_titles.len());
+    for _ in 0..parameters_count {
+        lambda_object.push(Box::new(NullValue));
+    }
+    lambda_object.extend_from_slice(object);
+
+    let mut lambda_call = |values: &[Box<dyn Value>]| {
+        for (index, value) in values.iter().take(parameters_count).enumerate() {
+            lambda_object[index] = value.clone();
+        }
+        evaluate_expression(env, &lambda.body, &lambda_titles, &lambda_object)
+    };
+
+    function(&arguments, &mut lambda_call)
+}
+
+/// Return true if NULL is passed to a parameter that doesn't accept it, in that case
+/// the function is not called and the result is NULL
+fn is_null_passed_to_strict_parameter(
+    env: &Environment,
+    function_name: &str,
+    arguments: &[Box<dyn Value>],
+) -> bool {
+    if !arguments.iter().any(|argument| argument.is_null()) {
+        return false;
+    }
+
+    let Some(signature) = env.std_signature(function_name) else {
+        return false;
+    };
+
+    for (index, argument) in arguments.iter().enumerate() {
+        if !argument.is_null() {
+            continue;
+        }
+
+        let parameter = signature
+            .parameters
+            .get(index)
+            .or(signature.parameters.last());
+        if parameter.is_some_and(|parameter| !is_null_accepted_by(parameter)) {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[allow(clippy::borrowed_box)]
+fn is_null_accepted_by(parameter: &Box<dyn DataType>) -> bool {
+    if let Some(varargs) = parameter.as_any().downcast_ref::<VarargsType>() {
+        return is_null_accepted_by(&varargs.base);
+    }
+
+    if let Some(optional) = parameter.as_any().downcast_ref::<OptionType>() {
+        return optional.base.as_ref().is_none_or(is_null_accepted_by);
+    }
+
+    parameter.is_any()
+        || parameter.is_null()
+        || parameter.as_any().downcast_ref::<DynamicType>().is_some()
+        || parameter.is_variant_with(|variant| variant.is_any() || variant.is_null())
+}
+
+/// Date time without time zone arguments are passed as `DateTimeTz` in the session time zone
+/// to the parameters that accept it, so the extracted fields like the hour are in that time zone,
+/// functions with dynamic return type keep the argument type like `date_trunc`
+fn apply_session_time_zone(
+    env: &Environment,
+    function_name: &str,
+    arguments: &mut [Box<dyn Value>],
+) {
+    if !arguments.iter().any(|argument| argument.is_date_time()) {
+        return;
+    }
+
+    let Some(signature) = env.std_signature(function_name) else {
+        return;
+    };
+
+    if signature
+        .return_type
+        .as_any()
+        .downcast_ref::<DynamicType>()
+        .is_some()
+    {
+        return;
+    }
+
+    let time_zone = env.session_time_zone();
+    for (index, argument) in arguments.iter_mut().enumerate() {
+        let Some(timestamp) = argument.as_date_time() else {
+            continue;
+        };
+
+        let parameter = signature
+            .parameters
+            .get(index)
+            .or(signature.parameters.last());
+        if parameter.is_some_and(is_date_time_tz_accepted_by) {
+            *argument = Box::new(DateTimeTzValue::new(timestamp, time_zone));
+        }
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+fn is_date_time_tz_accepted_by(parameter: &Box<dyn DataType>) -> bool {
+    if let Some(varargs) = parameter.as_any().downcast_ref::<VarargsType>() {
+        return is_date_time_tz_accepted_by(&varargs.base);
+    }
+
+    if let Some(optional) = parameter.as_any().downcast_ref::<OptionType>() {
+        return optional
+            .base
+            .as_ref()
+            .is_some_and(is_date_time_tz_accepted_by);
+    }
+
+    parameter.is_date_time_tz() || parameter.is_variant_with(|variant| variant.is_date_time_tz())
+}
+
 fn evaluate_benchmark_call(
     env: &mut Environment,
     expr: &BenchmarkCallExpr,
@@ -508,6 +840,10 @@ fn evaluate_between(
     let value = evaluate_expression(env, &expr.value, titles, object)?;
     let range_start = evaluate_expression(env, &expr.range_start, titles, object)?;
     let range_end = evaluate_expression(env, &expr.range_end, titles, object)?;
+    if value.is_null() || range_start.is_null() || range_end.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
     let comparing_result = match expr.kind {
         BetweenKind::Symmetric => {
             let (start, end) = if let Some(order) = range_start.compare(&range_end) {
@@ -560,12 +896,28 @@ fn evaluate_in(
     object: &Vec<Box<dyn Value>>,
 ) -> Result<Box<dyn Value>, String> {
     let argument = evaluate_expression(env, &expr.argument, titles, object)?;
+    if argument.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
+    let mut has_null_value = false;
     for value_expr in &expr.values {
         let value = evaluate_expression(env, value_expr, titles, object)?;
+        if value.is_null() {
+            has_null_value = true;
+            continue;
+        }
+
         if argument.equals(&value) {
             return Ok(Box::new(BoolValue::new(!expr.has_not_keyword)));
         }
     }
+
+    // The argument may be equal to the unknown NULL value
+    if has_null_value {
+        return Ok(Box::new(NullValue));
+    }
+
     Ok(Box::new(BoolValue::new(expr.has_not_keyword)))
 }
 
@@ -588,7 +940,77 @@ fn evaluate_cast(
     object: &Vec<Box<dyn Value>>,
 ) -> Result<Box<dyn Value>, String> {
     let value = evaluate_expression(env, &expr.value, titles, object)?;
-    value.cast_op(&expr.result_type)
+    cast_value(env, value, &expr.result_type)
+}
+
+fn evaluate_try_cast(
+    env: &mut Environment,
+    expr: &TryCastExpr,
+    titles: &[String],
+    object: &Vec<Box<dyn Value>>,
+) -> Result<Box<dyn Value>, String> {
+    // Only the conversion errors are ignored, errors while evaluating the value are still reported
+    let value = evaluate_expression(env, &expr.value, titles, object)?;
+    match cast_value(env, value, &expr.result_type) {
+        Ok(value) => Ok(value),
+        Err(_) => Ok(Box::new(NullValue)),
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+fn cast_value(
+    env: &mut Environment,
+    value: Box<dyn Value>,
+    result_type: &Box<dyn DataType>,
+) -> Result<Box<dyn Value>, String> {
+    if value.is_null() {
+        return Ok(value);
+    }
+
+    // Date time without time zone and text without offset are interpreted in the session time zone
+    if result_type.is_date_time_tz() {
+        let time_zone = env.session_time_zone();
+        if let Some(timestamp) = value.as_date_time() {
+            return Ok(Box::new(DateTimeTzValue::new(timestamp, time_zone)));
+        }
+
+        if let Some(text) = value.as_text() {
+            return match DateTimeTzValue::parse(&text, time_zone) {
+                Some(date_time) => Ok(Box::new(date_time)),
+                None => Err(format!(
+                    "Invalid input syntax for type datetimetz: `{}`",
+                    text
+                )),
+            };
+        }
+    }
+
+    value.cast_op(result_type)
+}
+
+fn evaluate_at_time_zone(
+    env: &mut Environment,
+    expr: &AtTimeZoneExpr,
+    titles: &[String],
+    object: &Vec<Box<dyn Value>>,
+) -> Result<Box<dyn Value>, String> {
+    let value = evaluate_expression(env, &expr.value, titles, object)?;
+    let time_zone = evaluate_expression(env, &expr.time_zone, titles, object)?;
+    if value.is_null() || time_zone.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
+    let time_zone_name = time_zone.literal();
+    let Some(time_zone) = parse_time_zone(&time_zone_name) else {
+        return Err(format!("Unknown time zone `{}`", time_zone_name));
+    };
+
+    let timestamp = match value.as_date_time_tz() {
+        Some((timestamp, _)) => timestamp,
+        None => value.as_date_time().unwrap(),
+    };
+
+    Ok(Box::new(DateTimeTzValue::new(timestamp, time_zone)))
 }
 
 fn evaluate_grouping(
@@ -612,5 +1034,502 @@ fn evaluate_member_access(
         let member_name = &expr.member_name;
         return Ok(composite_value.members.get(member_name).unwrap().clone());
     }
+
+    // Member of missing composite like the null element of `LEFT JOIN UNNEST(...)` is null
+    if value.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
     Err("Invalid value for Member access expression".to_owned())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    use gitql_ast::expression::SymbolFlag;
+    use gitql_ast::operator::GroupComparisonOperator;
+    use gitql_ast::types::any::AnyType;
+    use gitql_ast::types::enumeration::EnumType;
+    use gitql_ast::types::integer::IntType;
+    use gitql_ast::types::json::JsonType;
+    use gitql_ast::types::semver::SemVerType;
+    use gitql_ast::Decimal;
+    use gitql_ast::Interval;
+    use gitql_core::schema::Schema;
+    use gitql_core::signature::UserFunction;
+    use gitql_core::values::bytes::BytesValue;
+    use gitql_core::values::date::DateValue;
+    use gitql_core::values::datetime::DateTimeValue;
+    use gitql_core::values::datetimetz::parse_time_zone;
+    use gitql_core::values::decimal::DecimalValue;
+    use gitql_core::values::enumeration::EnumValue;
+    use gitql_core::values::multirange::MultiRangeValue;
+    use gitql_core::values::range::RangeValue;
+    use gitql_core::values::time::TimeValue;
+
+    const COMPARISON_OPERATORS: [ComparisonOperator; 6] = [
+        ComparisonOperator::Greater,
+        ComparisonOperator::GreaterEqual,
+        ComparisonOperator::Less,
+        ComparisonOperator::LessEqual,
+        ComparisonOperator::Equal,
+        ComparisonOperator::NotEqual,
+    ];
+
+    const ARITHMETIC_OPERATORS: [ArithmeticOperator; 6] = [
+        ArithmeticOperator::Plus,
+        ArithmeticOperator::Minus,
+        ArithmeticOperator::Star,
+        ArithmeticOperator::Slash,
+        ArithmeticOperator::Modulus,
+        ArithmeticOperator::Exponentiation,
+    ];
+
+    const BITWISE_OPERATORS: [BinaryBitwiseOperator; 5] = [
+        BinaryBitwiseOperator::Or,
+        BinaryBitwiseOperator::And,
+        BinaryBitwiseOperator::Xor,
+        BinaryBitwiseOperator::RightShift,
+        BinaryBitwiseOperator::LeftShift,
+    ];
+
+    /// One value of each Value implementation
+    fn sample_values() -> Vec<Box<dyn Value>> {
+        let enum_type = EnumType {
+            name: "mood".to_string(),
+            labels: vec!["sad".to_string(), "happy".to_string()],
+        };
+
+        let range = RangeValue::new(
+            Box::new(IntValue::new(1)),
+            Box::new(IntValue::new(5)),
+            Box::new(IntType),
+        );
+
+        vec![
+            Box::new(IntValue::new(1)),
+            Box::new(FloatValue::new(1.5)),
+            Box::new(DecimalValue::new(Decimal::parse("1.50").unwrap())),
+            Box::new(TextValue::new("text".to_string())),
+            Box::new(BoolValue::new(true)),
+            Box::new(DateValue::new(1_700_000_000)),
+            Box::new(TimeValue {
+                value: "12:00:00".to_string(),
+            }),
+            Box::new(DateTimeValue::new(1_700_000_000)),
+            Box::new(DateTimeTzValue::new(
+                1_700_000_000,
+                parse_time_zone("UTC").unwrap(),
+            )),
+            Box::new(IntervalValue::new(Interval {
+                days: 1,
+                ..Interval::default()
+            })),
+            Box::new(BytesValue::new(vec![1, 2, 3])),
+            TextValue::new("[1, 2]".to_string())
+                .cast_op(&(Box::new(JsonType) as Box<dyn DataType>))
+                .unwrap(),
+            TextValue::new("1.2.3".to_string())
+                .cast_op(&(Box::new(SemVerType) as Box<dyn DataType>))
+                .unwrap(),
+            Box::new(ArrayValue::new(
+                vec![Box::new(IntValue::new(1))],
+                Box::new(IntType),
+            )),
+            Box::new(
+                CompositeValue::empty("pair".to_string())
+                    .add_member("first".to_string(), Box::new(IntValue::new(1))),
+            ),
+            Box::new(EnumValue::new("happy", enum_type).unwrap()),
+            Box::new(range.clone()),
+            Box::new(MultiRangeValue::new(vec![range], Box::new(IntType))),
+            Box::new(NullValue),
+        ]
+    }
+
+    fn symbol(name: &str) -> Box<dyn Expr> {
+        Box::new(SymbolExpr {
+            value: name.to_string(),
+            expr_type: Box::new(AnyType),
+            flag: SymbolFlag::None,
+        })
+    }
+
+    /// Evaluate the expression with `lhs` and `rhs` columns
+    fn evaluate(
+        expression: Box<dyn Expr>,
+        lhs: Box<dyn Value>,
+        rhs: Box<dyn Value>,
+    ) -> Result<Box<dyn Value>, String> {
+        let schema = Schema {
+            tables_fields_names: HashMap::default(),
+            tables_fields_types: HashMap::default(),
+        };
+        let mut env = Environment::new(schema);
+        let titles = vec!["lhs".to_string(), "rhs".to_string()];
+        evaluate_expression(&mut env, &expression, &titles, &vec![lhs, rhs])
+    }
+
+    fn nullable_bool(value: Option<bool>) -> Box<dyn Value> {
+        match value {
+            Some(value) => Box::new(BoolValue::new(value)),
+            None => Box::new(NullValue),
+        }
+    }
+
+    #[allow(clippy::borrowed_box)]
+    fn as_nullable_bool(value: &Box<dyn Value>) -> Option<bool> {
+        if value.is_null() {
+            return None;
+        }
+        Some(value.as_bool().expect("Expect boolean or null value"))
+    }
+
+    #[test]
+    fn test_comparison_with_null_is_null() {
+        for value in sample_values() {
+            for operator in COMPARISON_OPERATORS {
+                for (lhs, rhs) in [
+                    (value.clone(), Box::new(NullValue) as Box<dyn Value>),
+                    (Box::new(NullValue) as Box<dyn Value>, value.clone()),
+                ] {
+                    let expression = Box::new(ComparisonExpr {
+                        left: symbol("lhs"),
+                        operator: operator.clone(),
+                        right: symbol("rhs"),
+                    });
+                    let result = evaluate(expression, lhs, rhs).unwrap();
+                    assert!(result.is_null(), "{} compared with NULL", value.literal());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_null_safe_equal_with_null() {
+        for value in sample_values() {
+            let expression = Box::new(ComparisonExpr {
+                left: symbol("lhs"),
+                operator: ComparisonOperator::NullSafeEqual,
+                right: symbol("rhs"),
+            });
+            let result = evaluate(expression, value.clone(), Box::new(NullValue)).unwrap();
+            assert_eq!(
+                result.as_bool(),
+                Some(value.is_null()),
+                "{}",
+                value.literal()
+            );
+        }
+    }
+
+    #[test]
+    fn test_group_comparison_with_null_is_null() {
+        for value in sample_values() {
+            let expression = Box::new(GroupComparisonExpr {
+                left: symbol("lhs"),
+                comparison_operator: ComparisonOperator::Equal,
+                group_operator: GroupComparisonOperator::Any,
+                right: symbol("rhs"),
+            });
+            let result = evaluate(expression, Box::new(NullValue), value.clone()).unwrap();
+            assert!(result.is_null(), "NULL = ANY({})", value.literal());
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_with_null_is_null() {
+        for value in sample_values() {
+            for operator in ARITHMETIC_OPERATORS {
+                for (lhs, rhs) in [
+                    (value.clone(), Box::new(NullValue) as Box<dyn Value>),
+                    (Box::new(NullValue) as Box<dyn Value>, value.clone()),
+                ] {
+                    let expression = Box::new(ArithmeticExpr {
+                        left: symbol("lhs"),
+                        operator: operator.clone(),
+                        right: symbol("rhs"),
+                        result_type: Box::new(AnyType),
+                    });
+                    let result = evaluate(expression, lhs, rhs).unwrap();
+                    assert!(result.is_null(), "{} with NULL operand", value.literal());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitwise_with_null_is_null() {
+        for value in sample_values() {
+            for operator in BITWISE_OPERATORS {
+                let expression = Box::new(BitwiseExpr {
+                    left: symbol("lhs"),
+                    operator: operator.clone(),
+                    right: symbol("rhs"),
+                    result_type: Box::new(AnyType),
+                });
+                let result = evaluate(expression, value.clone(), Box::new(NullValue)).unwrap();
+                assert!(result.is_null(), "{} with NULL operand", value.literal());
+            }
+        }
+    }
+
+    #[test]
+    fn test_prefix_unary_with_null_is_null() {
+        for operator in [
+            PrefixUnaryOperator::Negative,
+            PrefixUnaryOperator::Bang,
+            PrefixUnaryOperator::Not,
+        ] {
+            let expression = Box::new(UnaryExpr {
+                right: symbol("rhs"),
+                operator,
+                result_type: Box::new(AnyType),
+            });
+            let result = evaluate(expression, Box::new(NullValue), Box::new(NullValue)).unwrap();
+            assert!(result.is_null());
+        }
+    }
+
+    #[test]
+    fn test_pattern_and_contains_with_null_is_null() {
+        for value in sample_values() {
+            let expressions: Vec<Box<dyn Expr>> = vec![
+                Box::new(ContainsExpr {
+                    left: symbol("lhs"),
+                    right: symbol("rhs"),
+                }),
+                Box::new(ContainedByExpr {
+                    left: symbol("lhs"),
+                    right: symbol("rhs"),
+                }),
+                Box::new(LikeExpr {
+                    input: symbol("lhs"),
+                    pattern: symbol("rhs"),
+                }),
+                Box::new(GlobExpr {
+                    input: symbol("lhs"),
+                    pattern: symbol("rhs"),
+                }),
+                Box::new(RegexExpr {
+                    input: symbol("lhs"),
+                    pattern: symbol("rhs"),
+                }),
+            ];
+
+            for expression in expressions {
+                let result = evaluate(expression, value.clone(), Box::new(NullValue)).unwrap();
+                assert!(result.is_null(), "{} with NULL operand", value.literal());
+            }
+        }
+    }
+
+    #[test]
+    fn test_logical_three_valued_truth_table() {
+        let values = [Some(true), Some(false), None];
+        for lhs in values {
+            for rhs in values {
+                let expected_and = match (lhs, rhs) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                };
+
+                let expected_or = match (lhs, rhs) {
+                    (Some(true), _) | (_, Some(true)) => Some(true),
+                    (Some(false), Some(false)) => Some(false),
+                    _ => None,
+                };
+
+                let expected_xor = match (lhs, rhs) {
+                    (Some(lhs), Some(rhs)) => Some(lhs ^ rhs),
+                    _ => None,
+                };
+
+                for (operator, expected) in [
+                    (BinaryLogicalOperator::And, expected_and),
+                    (BinaryLogicalOperator::Or, expected_or),
+                    (BinaryLogicalOperator::Xor, expected_xor),
+                ] {
+                    let expression = Box::new(LogicalExpr {
+                        left: symbol("lhs"),
+                        operator,
+                        right: symbol("rhs"),
+                    });
+                    let result =
+                        evaluate(expression, nullable_bool(lhs), nullable_bool(rhs)).unwrap();
+                    assert_eq!(as_nullable_bool(&result), expected, "{:?}, {:?}", lhs, rhs);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_logical_short_circuit() {
+        // The right hand side references unknown column so evaluating it is an error
+        for (operator, value) in [
+            (BinaryLogicalOperator::And, false),
+            (BinaryLogicalOperator::Or, true),
+        ] {
+            let expression = Box::new(LogicalExpr {
+                left: symbol("lhs"),
+                operator,
+                right: symbol("unknown"),
+            });
+            let result = evaluate(
+                expression,
+                Box::new(BoolValue::new(value)),
+                Box::new(NullValue),
+            );
+            assert_eq!(result.unwrap().as_bool(), Some(value));
+        }
+
+        // Overlap operator `&&` between ranges must evaluate both sides
+        let range: Box<dyn Value> = Box::new(RangeValue::new(
+            Box::new(IntValue::new(1)),
+            Box::new(IntValue::new(5)),
+            Box::new(IntType),
+        ));
+        let expression = Box::new(LogicalExpr {
+            left: symbol("lhs"),
+            operator: BinaryLogicalOperator::And,
+            right: symbol("rhs"),
+        });
+        let result = evaluate(expression, range.clone(), range).unwrap();
+        assert_eq!(result.as_bool(), Some(true));
+    }
+
+    #[test]
+    fn test_in_with_null() {
+        let in_expression = || {
+            Box::new(InExpr {
+                argument: symbol("lhs"),
+                values: vec![
+                    symbol("rhs"),
+                    Box::new(NumberExpr {
+                        value: Number::Int(2),
+                    }),
+                ],
+                values_type: Box::new(IntType),
+                has_not_keyword: false,
+            })
+        };
+
+        let null_argument = evaluate(
+            in_expression(),
+            Box::new(NullValue),
+            Box::new(IntValue::new(1)),
+        );
+        assert!(null_argument.unwrap().is_null());
+
+        let found = evaluate(
+            in_expression(),
+            Box::new(IntValue::new(2)),
+            Box::new(NullValue),
+        );
+        assert_eq!(found.unwrap().as_bool(), Some(true));
+
+        let not_found = evaluate(
+            in_expression(),
+            Box::new(IntValue::new(3)),
+            Box::new(NullValue),
+        );
+        assert!(not_found.unwrap().is_null());
+    }
+
+    #[test]
+    fn test_between_with_null_is_null() {
+        let expression = Box::new(BetweenExpr {
+            value: symbol("lhs"),
+            range_start: symbol("rhs"),
+            range_end: Box::new(NumberExpr {
+                value: Number::Int(10),
+            }),
+            kind: BetweenKind::Asymmetric,
+        });
+        let result = evaluate(expression, Box::new(IntValue::new(5)), Box::new(NullValue));
+        assert!(result.unwrap().is_null());
+    }
+
+    #[test]
+    fn test_cast_null_is_null() {
+        for value in sample_values() {
+            let expression = Box::new(CastExpr {
+                value: symbol("rhs"),
+                result_type: value.data_type(),
+            });
+            let result = evaluate(expression, value.clone(), Box::new(NullValue)).unwrap();
+            assert!(
+                result.is_null(),
+                "NULL casted to {}",
+                value.data_type().literal()
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_cast_returns_null_on_error() {
+        let expression = || {
+            Box::new(TryCastExpr {
+                value: symbol("lhs"),
+                result_type: Box::new(IntType),
+            })
+        };
+
+        let valid = evaluate(
+            expression(),
+            Box::new(TextValue::new("12".to_string())),
+            Box::new(NullValue),
+        );
+        assert_eq!(valid.unwrap().as_int(), Some(12));
+
+        let invalid = evaluate(
+            expression(),
+            Box::new(TextValue::new("abc".to_string())),
+            Box::new(NullValue),
+        );
+        assert!(invalid.unwrap().is_null());
+
+        // Errors while evaluating the value itself are still reported
+        let unknown_column = evaluate(
+            Box::new(TryCastExpr {
+                value: symbol("unknown"),
+                result_type: Box::new(IntType),
+            }),
+            Box::new(NullValue),
+            Box::new(NullValue),
+        );
+        assert!(unknown_column.is_err());
+    }
+
+    #[test]
+    fn test_user_function_call_depth_limit() {
+        let schema = Schema {
+            tables_fields_names: HashMap::default(),
+            tables_fields_types: HashMap::default(),
+        };
+        let mut env = Environment::new(schema);
+
+        // Function that calls itself can't be defined by the parser, but the engine must not overflow the stack
+        let call = || CallExpr {
+            function_name: "f".to_string(),
+            arguments: vec![],
+            return_type: Box::new(IntType),
+        };
+        let function = UserFunction {
+            parameters: vec![],
+            body: Box::new(call()),
+            dependencies: vec!["f".to_string()],
+        };
+        env.user_functions
+            .insert("f".to_string(), Rc::new(function));
+
+        let result = evaluate_call(&mut env, &call(), &[], &vec![]);
+        assert!(result.is_err_and(|error| error.contains("maximum call depth")));
+        assert_eq!(env.call_depth, 0);
+    }
+}
@@ -1,11 +1,15 @@
 use std::cmp;
 use std::collections::HashMap;
+use std::time::Instant;
 
 use gitql_ast::expression::Expr;
 use gitql_ast::expression::ExprKind;
+use gitql_ast::expression::SymbolExpr;
+use gitql_ast::statement::AggregateFunction;
 use gitql_ast::statement::AggregateValue;
 use gitql_ast::statement::AggregationsStatement;
 use gitql_ast::statement::DoStatement;
+use gitql_ast::statement::GQLQuery;
 use gitql_ast::statement::GlobalVariableStatement;
 use gitql_ast::statement::GroupByStatement;
 use gitql_ast::statement::HavingStatement;
@@ -13,25 +17,40 @@ use gitql_ast::statement::IntoStatement;
 use gitql_ast::statement::LimitStatement;
 use gitql_ast::statement::OffsetStatement;
 use gitql_ast::statement::OrderByStatement;
+use gitql_ast::statement::QualifyStatement;
 use gitql_ast::statement::SelectStatement;
 use gitql_ast::statement::Statement;
 use gitql_ast::statement::StatementKind::*;
 use gitql_ast::statement::WhereStatement;
 use gitql_ast::statement::WindowFunctionsStatement;
 use gitql_core::environment::Environment;
+use gitql_core::environment::MAX_CALL_DEPTH;
+use gitql_core::environment::SORT_MERGE_JOIN_MIN_ROWS_VARIABLE;
+use gitql_core::environment::TIME_ZONE_VARIABLE;
 use gitql_core::object::GitQLObject;
 use gitql_core::object::Group;
 use gitql_core::object::Row;
+use gitql_core::values::datetimetz::parse_time_zone;
+use gitql_core::values::integer::IntValue;
 use gitql_core::values::null::NullValue;
+use gitql_core::values::text::TextValue;
 use gitql_core::values::Value;
 
 use crate::data_provider::DataProvider;
+use crate::engine::evaluate_select_query;
+use crate::engine::EvaluationResult;
 use crate::engine_evaluator::evaluate_expression;
+use crate::engine_explain::describe_projection;
+use crate::engine_explain::describe_scan;
+use crate::engine_explain::QueryProfile;
+use crate::engine_explain::StageStats;
 use crate::engine_filter::apply_filter_operation;
 use crate::engine_group::execute_group_by_statement;
 use crate::engine_join::apply_join_operation;
+use crate::engine_ordering::compare_ordering_values;
 use crate::engine_ordering::execute_order_by_statement;
 use crate::engine_output_into::execute_into_statement;
+use crate::engine_unnest::apply_unnest_operation;
 use crate::engine_window_functions::execute_window_functions_statement;
 
 #[allow(clippy::borrowed_box)]
@@ -62,6 +81,7 @@ pub fn execute_statement(
                 data_provider,
                 gitql_object,
                 hidden_selection,
+                None,
             )
         }
         Where => {
@@ -75,6 +95,13 @@ pub fn execute_statement(
                 .unwrap();
             execute_having_statement(env, statement, gitql_object)
         }
+        Qualify => {
+            let statement = statement
+                .as_any()
+                .downcast_ref::<QualifyStatement>()
+                .unwrap();
+            execute_qualify_statement(env, statement, gitql_object)
+        }
         Limit => {
             let statement = statement.as_any().downcast_ref::<LimitStatement>().unwrap();
             execute_limit_statement(statement, gitql_object)
@@ -151,13 +178,14 @@ fn execute_do_statement(
 }
 
 #[allow(clippy::borrowed_box)]
-fn execute_select_statement(
+pub(crate) fn execute_select_statement(
     env: &mut Environment,
     statement: &SelectStatement,
     alias_table: &HashMap<String, String>,
     data_provider: &Box<dyn DataProvider>,
     gitql_object: &mut GitQLObject,
     hidden_selections: &HashMap<String, Vec<String>>,
+    mut profile: Option<&mut QueryProfile>,
 ) -> Result<(), String> {
     let mut selected_rows_per_table: HashMap<String, Vec<Row>> = HashMap::new();
     let mut hidden_selection_count_per_table: HashMap<String, usize> = HashMap::new();
@@ -190,12 +218,22 @@ fn execute_select_statement(
         }
 
         // Call the provider only if table name is not empty
+        let scan_start = Instant::now();
         let selected_rows: Vec<Row> = if table_name.is_empty() {
             vec![Row { values: vec![] }]
+        } else if let Some(view) = env.views.get(table_name).cloned() {
+            select_view_rows(env, data_provider, view, selected_columns)?
         } else {
             data_provider.provide(table_name, selected_columns)?
         };
 
+        if let Some(profile) = profile.as_deref_mut() {
+            let rows_count = selected_rows.len();
+            let details = describe_scan(table_name, selected_columns);
+            let stats = StageStats::new(scan_start, rows_count, rows_count);
+            profile.push("scan", details, stats);
+        }
+
         selected_rows_per_table.insert(table_name.to_string(), selected_rows);
 
         // Append hidden selection in the right position
@@ -220,11 +258,25 @@ fn execute_select_statement(
         &statement.table_selections,
         &mut selected_rows_per_table,
         &hidden_selection_count_per_table,
-        &gitql_object.titles,
+        &mut gitql_object.titles,
+        profile.as_deref_mut(),
     )?;
 
+    // Expand the rows by the elements of `UNNEST` values if exists
+    if !statement.unnests.is_empty() {
+        apply_unnest_operation(
+            env,
+            &statement.unnests,
+            alias_table,
+            &gitql_object.titles,
+            &mut selected_rows,
+            profile.as_deref_mut(),
+        )?;
+    }
+
     // Execute Selected expressions if exists
     if !statement.selected_expr.is_empty() {
+        let projection_start = Instant::now();
         execute_expression_selection(
             env,
             &mut selected_rows,
@@ -232,10 +284,17 @@ fn execute_select_statement(
             &statement.selected_expr_titles,
             &statement.selected_expr,
         )?;
+
+        if let (Some(profile), Some(details)) = (profile, describe_projection(statement)) {
+            let rows_count = selected_rows.len();
+            let stats = StageStats::new(projection_start, rows_count, rows_count);
+            profile.push("projection", details, stats);
+        }
     }
 
     let main_group = Group {
         rows: selected_rows,
+        rolled_up_columns: vec![],
     };
 
     gitql_object.groups.push(main_group);
@@ -243,8 +302,53 @@ fn execute_select_statement(
     Ok(())
 }
 
+/// Evaluate the view query and map the result columns to the selected columns by name
+#[allow(clippy::borrowed_box)]
+fn select_view_rows(
+    env: &mut Environment,
+    data_provider: &Box<dyn DataProvider>,
+    view: GQLQuery,
+    selected_columns: &[String],
+) -> Result<Vec<Row>, String> {
+    if env.call_depth >= MAX_CALL_DEPTH {
+        return Err(format!(
+            "View query exceeded the maximum nesting depth {}",
+            MAX_CALL_DEPTH
+        ));
+    }
+
+    env.call_depth += 1;
+    let result = evaluate_select_query(env, data_provider, view, None);
+    env.call_depth -= 1;
+
+    let EvaluationResult::SelectedGroups(view_object) = result? else {
+        return Ok(vec![]);
+    };
+
+    let columns_indexes: Vec<Option<usize>> = selected_columns
+        .iter()
+        .map(|column| view_object.titles.iter().position(|title| title.eq(column)))
+        .collect();
+
+    let mut rows: Vec<Row> = vec![];
+    for group in view_object.groups {
+        for row in group.rows {
+            let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(columns_indexes.len());
+            for index in columns_indexes.iter() {
+                match index {
+                    Some(index) => values.push(row.values[*index].clone()),
+                    None => values.push(Box::new(NullValue)),
+                }
+            }
+            rows.push(Row { values });
+        }
+    }
+
+    Ok(rows)
+}
+
 #[inline(always)]
-fn execute_expression_selection(
+pub(crate) fn execute_expression_selection(
     env: &mut Environment,
     selected_rows: &mut [Row],
     object_titles: &[String],
@@ -331,6 +435,30 @@ fn execute_having_statement(
     Ok(())
 }
 
+fn execute_qualify_statement(
+    env: &mut Environment,
+    statement: &QualifyStatement,
+    gitql_object: &mut GitQLObject,
+) -> Result<(), String> {
+    if gitql_object.is_empty() {
+        return Ok(());
+    }
+
+    if gitql_object.len() > 1 {
+        gitql_object.flat()
+    }
+
+    // Window functions are already evaluated, so the condition can filter by their values
+    apply_filter_operation(
+        env,
+        &statement.condition,
+        &gitql_object.titles,
+        &mut gitql_object.groups[0].rows,
+    )?;
+
+    Ok(())
+}
+
 fn execute_limit_statement(
     statement: &LimitStatement,
     gitql_object: &mut GitQLObject,
@@ -378,11 +506,18 @@ fn execute_aggregation_functions_statement(
     alias_table: &HashMap<String, String>,
     is_query_has_group_by: bool,
 ) -> Result<(), String> {
-    // Make sure you have at least one aggregation function to calculate
     let aggregations_map = &statement.aggregations;
-    if aggregations_map.is_empty() {
-        return Ok(());
-    }
+
+    // Nested aggregations expressions are generated before the outer ones,
+    // so they must be evaluated first in the order of the generated names
+    let mut aggregations_expressions: Vec<(&String, &Box<dyn Expr>)> = aggregations_map
+        .iter()
+        .filter_map(|(name, aggregation)| match aggregation {
+            AggregateValue::Expression(expr) => Some((name, expr)),
+            _ => None,
+        })
+        .collect();
+    aggregations_expressions.sort_by_key(|(name, _)| (name.len(), name.to_string()));
 
     // We should run aggregation function for each group
     for group in &mut gitql_object.groups {
@@ -393,7 +528,7 @@ fn execute_aggregation_functions_statement(
 
         // Resolve all aggregations functions first
         for (result_column_name, aggregation) in aggregations_map {
-            if let AggregateValue::Function(function, arguments) = aggregation {
+            if let AggregateValue::Function(function) = aggregation {
                 // Get alias name if exists or column name by default
                 let column_name = resolve_actual_column_name(alias_table, result_column_name);
                 let column_index = gitql_object
@@ -402,29 +537,8 @@ fn execute_aggregation_functions_statement(
                     .position(|r| r.eq(&column_name))
                     .unwrap();
 
-                // Evaluate the Arguments to Values
-                let mut group_arguments: Vec<Vec<Box<dyn Value>>> =
-                    Vec::with_capacity(group.rows.len());
-                for object in &mut group.rows {
-                    let mut row_values: Vec<Box<dyn Value>> =
-                        Vec::with_capacity(object.values.len());
-                    for argument in arguments {
-                        let value = evaluate_expression(
-                            env,
-                            argument,
-                            &gitql_object.titles,
-                            &object.values,
-                        )?;
-
-                        row_values.push(value);
-                    }
-
-                    group_arguments.push(row_values);
-                }
-
-                // Get the target aggregation function
-                let aggregation_function = env.aggregation_function(function).unwrap();
-                let result = &aggregation_function(&group_arguments);
+                let result =
+                    execute_aggregation_function(env, function, &gitql_object.titles, &group.rows)?;
 
                 // Insert the calculated value in the group objects
                 for object in &mut group.rows {
@@ -437,10 +551,9 @@ fn execute_aggregation_functions_statement(
             }
         }
 
-        // Resolve aggregations expressions
+        // Resolve grouping functions, each grouped column is a bit that is set if it rolled up
         for (result_column_name, aggregation) in aggregations_map {
-            if let AggregateValue::Expression(expr) = aggregation {
-                // Get alias name if exists or column name by default
+            if let AggregateValue::Grouping(arguments) = aggregation {
                 let column_name = resolve_actual_column_name(alias_table, result_column_name);
                 let column_index = gitql_object
                     .titles
@@ -448,10 +561,19 @@ fn execute_aggregation_functions_statement(
                     .position(|r| r.eq(&column_name))
                     .unwrap();
 
-                // Insert the calculated value in the group objects
-                for object in group.rows.iter_mut() {
-                    let result =
-                        evaluate_expression(env, expr, &gitql_object.titles, &object.values)?;
+                let mut grouping: i64 = 0;
+                for argument in arguments {
+                    grouping <<= 1;
+                    let symbol = argument.as_any().downcast_ref::<SymbolExpr>().unwrap();
+                    let argument_index =
+                        gitql_object.titles.iter().position(|r| r.eq(&symbol.value));
+                    if argument_index.is_some_and(|i| group.rolled_up_columns.contains(&i)) {
+                        grouping |= 1;
+                    }
+                }
+
+                let result: Box<dyn Value> = Box::new(IntValue::new(grouping));
+                for object in &mut group.rows {
                     if column_index < object.values.len() {
                         object.values[column_index] = result.clone();
                     } else {
@@ -461,6 +583,36 @@ fn execute_aggregation_functions_statement(
             }
         }
 
+        // Rolled up columns have no single value in this group
+        for column_index in group.rolled_up_columns.iter() {
+            for object in &mut group.rows {
+                if *column_index < object.values.len() {
+                    object.values[*column_index] = Box::new(NullValue);
+                }
+            }
+        }
+
+        // Resolve aggregations expressions
+        for (result_column_name, expr) in aggregations_expressions.iter() {
+            // Get alias name if exists or column name by default
+            let column_name = resolve_actual_column_name(alias_table, result_column_name);
+            let column_index = gitql_object
+                .titles
+                .iter()
+                .position(|r| r.eq(&column_name))
+                .unwrap();
+
+            // Insert the calculated value in the group objects
+            for object in group.rows.iter_mut() {
+                let result = evaluate_expression(env, expr, &gitql_object.titles, &object.values)?;
+                if column_index < object.values.len() {
+                    object.values[column_index] = result.clone();
+                } else {
+                    object.values.push(result.clone());
+                }
+            }
+        }
+
         // In case of group by statement is executed
         // Remove all elements expect the first one
         if is_query_has_group_by {
@@ -471,11 +623,130 @@ fn execute_aggregation_functions_statement(
     Ok(())
 }
 
+fn execute_aggregation_function(
+    env: &mut Environment,
+    function: &AggregateFunction,
+    titles: &[String],
+    rows: &[Row],
+) -> Result<Box<dyn Value>, String> {
+    // Evaluate the Arguments and the ordering arguments to Values for the rows that match the filter
+    let mut group_arguments: Vec<Vec<Box<dyn Value>>> = Vec::with_capacity(rows.len());
+    let mut group_ordering_values: Vec<Vec<Box<dyn Value>>> = vec![];
+    for object in rows {
+        if let Some(filter) = &function.filter {
+            let condition = evaluate_expression(env, filter, titles, &object.values)?;
+            if !condition.as_bool().unwrap_or(false) {
+                continue;
+            }
+        }
+
+        let mut row_values: Vec<Box<dyn Value>> = Vec::with_capacity(function.arguments.len());
+        for argument in &function.arguments {
+            row_values.push(evaluate_expression(env, argument, titles, &object.values)?);
+        }
+
+        if let Some(ordering) = &function.ordering {
+            let mut ordering_values = Vec::with_capacity(ordering.arguments.len());
+            for argument in &ordering.arguments {
+                ordering_values.push(evaluate_expression(env, argument, titles, &object.values)?);
+            }
+            group_ordering_values.push(ordering_values);
+        }
+
+        group_arguments.push(row_values);
+    }
+
+    if let Some(ordering) = &function.ordering {
+        let mut indices: Vec<usize> = (0..group_arguments.len()).collect();
+        indices.sort_by(|a, b| {
+            compare_ordering_values(
+                ordering,
+                &group_ordering_values[*a],
+                &group_ordering_values[*b],
+            )
+        });
+
+        let mut unordered_arguments: Vec<Option<Vec<Box<dyn Value>>>> =
+            group_arguments.into_iter().map(Some).collect();
+        group_arguments = indices
+            .into_iter()
+            .map(|index| unordered_arguments[index].take().unwrap())
+            .collect();
+    }
+
+    // Keep only the first occurrence of each arguments values
+    if function.is_distinct {
+        let mut distinct_arguments: Vec<Vec<Box<dyn Value>>> = vec![];
+        for row_values in group_arguments {
+            let is_duplicated = distinct_arguments.iter().any(|other_values| {
+                row_values
+                    .iter()
+                    .zip(other_values.iter())
+                    .all(|(value, other)| value.equals(other))
+            });
+
+            if !is_duplicated {
+                distinct_arguments.push(row_values);
+            }
+        }
+        group_arguments = distinct_arguments;
+    }
+
+    // Apply the function on each row and join the results with the separator
+    if let Some(separator) = &function.separator {
+        let mut values = Vec::with_capacity(group_arguments.len());
+        for row_values in group_arguments.chunks(1) {
+            values.push(
+                call_aggregation_function(env, &function.function_name, row_values)?.literal(),
+            );
+        }
+        return Ok(Box::new(TextValue {
+            value: values.join(separator),
+        }));
+    }
+
+    call_aggregation_function(env, &function.function_name, &group_arguments)
+}
+
+/// Call the fallible or the standard aggregation function by name with the group values
+pub(crate) fn call_aggregation_function(
+    env: &Environment,
+    function_name: &str,
+    group_values: &[Vec<Box<dyn Value>>],
+) -> Result<Box<dyn Value>, String> {
+    if let Some(function) = env.fallible_aggregation_function(function_name) {
+        return function(group_values);
+    }
+
+    let function = env.aggregation_function(function_name).unwrap();
+    Ok(function(group_values))
+}
+
 pub fn execute_global_variable_statement(
     env: &mut Environment,
     statement: &GlobalVariableStatement,
 ) -> Result<(), String> {
     let value = evaluate_expression(env, &statement.value, &[], &vec![])?;
+    if statement.name == TIME_ZONE_VARIABLE {
+        let time_zone_name = value.literal();
+        let Some(time_zone) = parse_time_zone(&time_zone_name) else {
+            return Err(format!("Unknown time zone `{}`", time_zone_name));
+        };
+
+        let time_zone = Box::new(TextValue::new(time_zone.name().to_string()));
+        env.globals.insert(statement.name.to_string(), time_zone);
+        return Ok(());
+    }
+
+    if statement.name == SORT_MERGE_JOIN_MIN_ROWS_VARIABLE
+        && value.as_int().is_some_and(|rows| rows < 0)
+    {
+        return Err(format!(
+            "System variable `{}` must be greater than or equal to zero",
+            statement.name
+        ));
+    }
+
     env.globals.insert(statement.name.to_string(), value);
     Ok(())
 }
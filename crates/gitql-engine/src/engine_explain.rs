@@ -0,0 +1,933 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use std::time::Instant;
+
+use gitql_ast::expression::*;
+use gitql_ast::operator::ArithmeticOperator;
+use gitql_ast::operator::ArrowOperator;
+use gitql_ast::operator::BinaryBitwiseOperator;
+use gitql_ast::operator::BinaryLogicalOperator;
+use gitql_ast::operator::ComparisonOperator;
+use gitql_ast::operator::GroupComparisonOperator;
+use gitql_ast::operator::PrefixUnaryOperator;
+use gitql_ast::statement::AggregateFunction;
+use gitql_ast::statement::AggregateValue;
+use gitql_ast::statement::AggregationsStatement;
+use gitql_ast::statement::Distinct;
+use gitql_ast::statement::ExplainStatement;
+use gitql_ast::statement::GQLQuery;
+use gitql_ast::statement::GroupByStatement;
+use gitql_ast::statement::HavingStatement;
+use gitql_ast::statement::IntoStatement;
+use gitql_ast::statement::Join;
+use gitql_ast::statement::JoinKind;
+use gitql_ast::statement::JoinOperand;
+use gitql_ast::statement::LimitStatement;
+use gitql_ast::statement::NullsOrderPolicy;
+use gitql_ast::statement::OffsetStatement;
+use gitql_ast::statement::OrderByStatement;
+use gitql_ast::statement::QualifyStatement;
+use gitql_ast::statement::SelectStatement;
+use gitql_ast::statement::SortingOrder;
+use gitql_ast::statement::Statement;
+use gitql_ast::statement::TableSelection;
+use gitql_ast::statement::UnnestSelection;
+use gitql_ast::statement::WhereStatement;
+use gitql_ast::statement::WindowFrameBound;
+use gitql_ast::statement::WindowFrameClause;
+use gitql_ast::statement::WindowFrameKind;
+use gitql_ast::statement::WindowFunctionsStatement;
+use gitql_ast::statement::WindowValue;
+use gitql_core::environment::Environment;
+use gitql_core::object::GitQLObject;
+use gitql_core::object::Group;
+use gitql_core::object::Row;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+use crate::data_provider::DataProvider;
+use crate::engine::evaluate_select_query;
+use crate::engine::EvaluationResult;
+use crate::engine::FIXED_LOGICAL_PLAN;
+use crate::engine_executor::resolve_actual_column_name;
+use crate::engine_join::plan_join_strategy;
+use crate::engine_stream::create_rows_stream;
+use crate::engine_stream::is_streamable_query;
+
+/// Time and number of rows that enter and leave one stage of the query plan
+#[derive(Clone, Copy, Default)]
+pub(crate) struct StageStats {
+    pub duration: Duration,
+    pub input_rows: usize,
+    pub output_rows: usize,
+}
+
+impl StageStats {
+    pub(crate) fn new(start: Instant, input_rows: usize, output_rows: usize) -> Self {
+        StageStats {
+            duration: start.elapsed(),
+            input_rows,
+            output_rows,
+        }
+    }
+
+    /// Merge the stats of the same stage executed on another batch of rows
+    pub(crate) fn merge(&mut self, other: StageStats) {
+        self.duration += other.duration;
+        self.input_rows += other.input_rows;
+        self.output_rows += other.output_rows;
+    }
+}
+
+/// One stage of the query plan, with the execution stats if the query is analyzed
+pub(crate) struct PlanStage {
+    pub name: &'static str,
+    pub details: String,
+    pub stats: Option<StageStats>,
+}
+
+/// The stages of the query plan collected while executing the query
+#[derive(Default)]
+pub(crate) struct QueryProfile {
+    pub stages: Vec<PlanStage>,
+}
+
+impl QueryProfile {
+    pub(crate) fn push(&mut self, name: &'static str, details: String, stats: StageStats) {
+        self.stages.push(PlanStage {
+            name,
+            details,
+            stats: Some(stats),
+        });
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+pub(crate) fn evaluate_explain_query(
+    env: &mut Environment,
+    data_provider: &Box<dyn DataProvider>,
+    statement: ExplainStatement,
+) -> Result<EvaluationResult, String> {
+    let query = statement.query;
+    let stages = if !statement.analyze {
+        plan_query_stages(env, &query)
+    } else if is_streamable_query(env, &query) {
+        analyze_streamable_query(env, data_provider, query)?
+    } else {
+        let mut profile = QueryProfile::default();
+        evaluate_select_query(env, data_provider, query, Some(&mut profile))?;
+        profile.stages
+    };
+
+    let mut gitql_object = GitQLObject::default();
+    gitql_object.titles.push("stage".to_owned());
+    gitql_object.titles.push("details".to_owned());
+    if statement.analyze {
+        gitql_object.titles.push("time".to_owned());
+        gitql_object.titles.push("input_rows".to_owned());
+        gitql_object.titles.push("output_rows".to_owned());
+    }
+
+    let mut rows: Vec<Row> = Vec::with_capacity(stages.len());
+    for stage in stages {
+        let mut values: Vec<Box<dyn Value>> = vec![
+            Box::new(TextValue::new(stage.name.to_string())),
+            Box::new(TextValue::new(stage.details)),
+        ];
+
+        if let Some(stats) = stage.stats {
+            values.push(Box::new(TextValue::new(format!("{:?}", stats.duration))));
+            values.push(Box::new(IntValue::new(stats.input_rows as i64)));
+            values.push(Box::new(IntValue::new(stats.output_rows as i64)));
+        }
+
+        rows.push(Row { values });
+    }
+
+    gitql_object.groups.push(Group {
+        rows,
+        rolled_up_columns: vec![],
+    });
+    Ok(EvaluationResult::SelectedGroups(gitql_object))
+}
+
+#[allow(clippy::borrowed_box)]
+fn analyze_streamable_query(
+    env: &mut Environment,
+    data_provider: &Box<dyn DataProvider>,
+    query: GQLQuery,
+) -> Result<Vec<PlanStage>, String> {
+    let mut stages = plan_query_stages(env, &query);
+
+    let mut stream = create_rows_stream(env, data_provider, query)?;
+    for batch in stream.by_ref() {
+        batch?;
+    }
+
+    for stage in stages.iter_mut() {
+        stage.stats = Some(stream.stage_stats(stage.name));
+    }
+
+    Ok(stages)
+}
+
+/// Describe the stages that the engine will execute for this query without executing it
+fn plan_query_stages(env: &Environment, query: &GQLQuery) -> Vec<PlanStage> {
+    let mut stages: Vec<PlanStage> = vec![];
+    let statements = &query.statements;
+    let Some(statement) = statements.get("select") else {
+        return stages;
+    };
+
+    let select = statement
+        .as_any()
+        .downcast_ref::<SelectStatement>()
+        .unwrap();
+
+    let mut push_stage = |name: &'static str, details: String| {
+        stages.push(PlanStage {
+            name,
+            details,
+            stats: None,
+        })
+    };
+
+    // Streamable queries load the rows in batches with filter, offset and limit pushed down into the scan
+    if is_streamable_query(env, query) {
+        let table_selection = &select.table_selections[0];
+        let columns = selected_columns_with_hidden(table_selection, &query.hidden_selections);
+        push_stage(
+            "scan",
+            describe_stream_scan(&table_selection.table_name, &columns),
+        );
+
+        if let Some(details) = describe_projection(select) {
+            push_stage("projection", details);
+        }
+
+        if let Some(statement) = statements.get("where") {
+            let details = describe_statement("where", statement);
+            push_stage("where", format!("{}, pushed down into the scan", details));
+        }
+
+        if let Some(statement) = statements.get("offset") {
+            push_stage("offset", describe_statement("offset", statement));
+        }
+
+        if let Some(statement) = statements.get("limit") {
+            let details = describe_statement("limit", statement);
+            push_stage(
+                "limit",
+                format!("{}, stop the scan after reaching it", details),
+            );
+        }
+
+        return stages;
+    }
+
+    for table_selection in &select.table_selections {
+        let columns = selected_columns_with_hidden(table_selection, &query.hidden_selections);
+        push_stage("scan", describe_scan(&table_selection.table_name, &columns));
+    }
+
+    // Resolve the columns of each side of the join in the same order of the engine
+    let mut left_hidden: Vec<String> = vec![];
+    let mut left_visible: Vec<String> = vec![];
+    for join in &select.joins {
+        let (outer, inner) = match &join.operand {
+            JoinOperand::OuterAndInner(outer, inner) => (Some(outer), inner),
+            JoinOperand::Inner(inner) => (None, inner),
+        };
+
+        if let Some(outer) = outer {
+            let (hidden, visible) = table_columns(select, outer, query);
+            left_hidden.extend(hidden);
+            left_visible.extend(visible);
+        }
+
+        let (right_hidden, right_visible) = table_columns(select, inner, query);
+        let strategy = plan_join_strategy(
+            env,
+            join,
+            &left_hidden,
+            &left_visible,
+            &right_hidden,
+            &right_visible,
+        );
+        push_stage("join", describe_join(join, inner, &strategy));
+
+        left_hidden.extend(right_hidden);
+        left_visible.extend(right_visible);
+    }
+
+    for unnest in &select.unnests {
+        push_stage("unnest", describe_unnest(unnest));
+    }
+
+    if let Some(details) = describe_projection(select) {
+        push_stage("projection", details);
+    }
+
+    for logical_node_name in FIXED_LOGICAL_PLAN {
+        if logical_node_name == "select" {
+            continue;
+        }
+
+        if let Some(statement) = statements.get(logical_node_name) {
+            push_stage(
+                logical_node_name,
+                describe_statement(logical_node_name, statement),
+            );
+        }
+    }
+
+    if let Some(details) = describe_distinct(&select.distinct) {
+        push_stage("distinct", details);
+    }
+
+    if let Some(statement) = statements.get("into") {
+        push_stage("into", describe_statement("into", statement));
+    }
+
+    stages
+}
+
+/// Return the hidden and visible columns of the table in the same order of the joined row
+fn table_columns(
+    select: &SelectStatement,
+    table_name: &str,
+    query: &GQLQuery,
+) -> (Vec<String>, Vec<String>) {
+    let Some(table_selection) = select
+        .table_selections
+        .iter()
+        .find(|selection| selection.table_name.eq(table_name))
+    else {
+        return (vec![], vec![]);
+    };
+
+    let mut hidden = vec![];
+    let mut visible = vec![];
+    let columns = selected_columns_with_hidden(table_selection, &query.hidden_selections);
+    let hidden_count = columns.len() - table_selection.columns_names.len();
+    for (index, column) in columns.iter().enumerate() {
+        let title = resolve_actual_column_name(&query.alias_table, column);
+        if index < hidden_count {
+            hidden.push(title);
+        } else {
+            visible.push(title);
+        }
+    }
+    (hidden, visible)
+}
+
+/// Return the columns that will be requested from the provider, hidden selections are first
+pub(crate) fn selected_columns_with_hidden(
+    table_selection: &TableSelection,
+    hidden_selections: &HashMap<String, Vec<String>>,
+) -> Vec<String> {
+    let mut selected_columns = table_selection.columns_names.to_owned();
+    if let Some(table_hidden_selection) = hidden_selections.get(&table_selection.table_name) {
+        for hidden_selection in table_hidden_selection {
+            if !selected_columns.contains(hidden_selection) {
+                selected_columns.insert(0, hidden_selection.to_string());
+            }
+        }
+    }
+    selected_columns
+}
+
+pub(crate) fn describe_scan(table_name: &str, columns: &[String]) -> String {
+    if table_name.is_empty() {
+        return "No table, one empty row".to_string();
+    }
+    format!("Load all rows of `{}` ({})", table_name, columns.join(", "))
+}
+
+fn describe_stream_scan(table_name: &str, columns: &[String]) -> String {
+    format!(
+        "Stream rows of `{}` in batches ({})",
+        table_name,
+        columns.join(", ")
+    )
+}
+
+/// Describe the selected expressions, return None if all of them are columns or references
+/// to values that calculated in later stages such as aggregations and window functions
+pub(crate) fn describe_projection(select: &SelectStatement) -> Option<String> {
+    let mut expressions = Vec::with_capacity(select.selected_expr.len());
+    for (index, expr) in select.selected_expr.iter().enumerate() {
+        if expr.kind() == ExprKind::Symbol {
+            continue;
+        }
+
+        expressions.push(format!(
+            "{} AS {}",
+            expression_literal(expr),
+            select.selected_expr_titles[index]
+        ));
+    }
+
+    if expressions.is_empty() {
+        return None;
+    }
+
+    Some(expressions.join(", "))
+}
+
+pub(crate) fn describe_unnest(unnest: &UnnestSelection) -> String {
+    let join = if unnest.is_outer { "LEFT JOIN " } else { "" };
+    let mut details = format!(
+        "{}UNNEST({}) AS {}",
+        join,
+        expression_literal(&unnest.expression),
+        unnest.element_column
+    );
+
+    if let Some(ordinality_column) = &unnest.ordinality_column {
+        details = format!("{} WITH ORDINALITY AS {}", details, ordinality_column);
+    }
+    details
+}
+
+pub(crate) fn describe_join(join: &Join, table_name: &str, strategy: &str) -> String {
+    let kind = match join.kind {
+        JoinKind::Cross => "CROSS JOIN",
+        JoinKind::Inner => "INNER JOIN",
+        JoinKind::Left => "LEFT JOIN",
+        JoinKind::Right => "RIGHT JOIN",
+        JoinKind::Full => "FULL JOIN",
+        JoinKind::Default => "JOIN",
+    };
+
+    let condition = if !join.using_columns.is_empty() {
+        format!(" USING ({})", join.using_columns.join(", "))
+    } else if let Some(predicate) = &join.predicate {
+        format!(" ON {}", expression_literal(predicate))
+    } else {
+        String::new()
+    };
+
+    format!("{} `{}`{}, {}", kind, table_name, condition, strategy)
+}
+
+pub(crate) fn describe_distinct(distinct: &Distinct) -> Option<String> {
+    match distinct {
+        Distinct::None => None,
+        Distinct::DistinctAll => Some("DISTINCT".to_string()),
+        Distinct::DistinctOn(fields) => Some(format!("DISTINCT ON ({})", fields.join(", "))),
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+pub(crate) fn describe_statement(name: &str, statement: &Box<dyn Statement>) -> String {
+    let statement = statement.as_any();
+    match name {
+        "where" => {
+            let statement = statement.downcast_ref::<WhereStatement>().unwrap();
+            format!("Filter by {}", expression_literal(&statement.condition))
+        }
+        "group" => {
+            let statement = statement.downcast_ref::<GroupByStatement>().unwrap();
+            let values = expressions_literal(&statement.values);
+            if statement.grouping_sets.len() > 1 {
+                format!(
+                    "Group by {} with grouping sets {}",
+                    values,
+                    describe_grouping_sets(statement)
+                )
+            } else {
+                format!("Group by {}", values)
+            }
+        }
+        "aggregation" => {
+            let statement = statement.downcast_ref::<AggregationsStatement>().unwrap();
+            let mut aggregations: Vec<(&String, &AggregateValue)> =
+                statement.aggregations.iter().collect();
+            aggregations.sort_by(|a, b| a.0.cmp(b.0));
+
+            let mut values = Vec::with_capacity(aggregations.len());
+            for (name, aggregation) in aggregations {
+                let value = match aggregation {
+                    AggregateValue::Expression(expr) => expression_literal(expr),
+                    AggregateValue::Function(function) => describe_aggregate_function(function),
+                    AggregateValue::Grouping(arguments) => {
+                        format!("GROUPING({})", expressions_literal(arguments))
+                    }
+                };
+                values.push(format!("{} AS {}", value, name));
+            }
+            values.join(", ")
+        }
+        "having" => {
+            let statement = statement.downcast_ref::<HavingStatement>().unwrap();
+            format!(
+                "Filter groups by {}",
+                expression_literal(&statement.condition)
+            )
+        }
+        "qualify" => {
+            let statement = statement.downcast_ref::<QualifyStatement>().unwrap();
+            format!(
+                "Filter window results by {}",
+                expression_literal(&statement.condition)
+            )
+        }
+        "window_functions" => {
+            let statement = statement
+                .downcast_ref::<WindowFunctionsStatement>()
+                .unwrap();
+            let mut window_values: Vec<(&String, &WindowValue)> =
+                statement.window_values.iter().collect();
+            window_values.sort_by(|a, b| a.0.cmp(b.0));
+
+            let mut values = Vec::with_capacity(window_values.len());
+            for (name, window_value) in window_values {
+                let value = match window_value {
+                    WindowValue::Expression(expr) => expression_literal(expr),
+                    WindowValue::Function(function) => {
+                        let definition = &function.window_definition;
+                        let mut clauses = vec![];
+                        if let Some(partitioning) = &definition.partitioning_clause {
+                            clauses.push(format!(
+                                "PARTITION BY {}",
+                                expression_literal(&partitioning.expr)
+                            ));
+                        }
+
+                        if let Some(ordering) = &definition.ordering_clause {
+                            clauses.push(describe_order_by(&ordering.order_by));
+                        }
+
+                        if let Some(frame) = &definition.frame_clause {
+                            clauses.push(describe_window_frame(frame));
+                        }
+
+                        format!(
+                            "{}({}) OVER ({})",
+                            function.function_name,
+                            expressions_literal(&function.arguments),
+                            clauses.join(" ")
+                        )
+                    }
+                };
+                values.push(format!("{} AS {}", value, name));
+            }
+            values.join(", ")
+        }
+        "order" => {
+            let statement = statement.downcast_ref::<OrderByStatement>().unwrap();
+            describe_order_by(statement)
+        }
+        "offset" => {
+            let statement = statement.downcast_ref::<OffsetStatement>().unwrap();
+            format!("Skip {} rows", statement.count)
+        }
+        "limit" => {
+            let statement = statement.downcast_ref::<LimitStatement>().unwrap();
+            format!("Take {} rows", statement.count)
+        }
+        "into" => {
+            let statement = statement.downcast_ref::<IntoStatement>().unwrap();
+            format!("Write rows into `{}`", statement.file_path)
+        }
+        _ => String::new(),
+    }
+}
+
+fn describe_aggregate_function(function: &AggregateFunction) -> String {
+    let mut arguments = expressions_literal(&function.arguments);
+    if function.is_distinct {
+        arguments = format!("DISTINCT {}", arguments);
+    }
+
+    if let Some(ordering) = &function.ordering {
+        arguments = format!("{} {}", arguments, describe_order_by(ordering));
+    }
+
+    if let Some(separator) = &function.separator {
+        arguments = format!("{} SEPARATOR '{}'", arguments, separator);
+    }
+
+    let mut literal = format!("{}({})", function.function_name, arguments);
+    if let Some(filter) = &function.filter {
+        literal = format!("{} FILTER (WHERE {})", literal, expression_literal(filter));
+    }
+    literal
+}
+
+fn describe_grouping_sets(statement: &GroupByStatement) -> String {
+    let mut sets = Vec::with_capacity(statement.grouping_sets.len());
+    for grouping_set in statement.grouping_sets.iter() {
+        let values: Vec<String> = grouping_set
+            .iter()
+            .map(|index| expression_literal(&statement.values[*index]))
+            .collect();
+        sets.push(format!("({})", values.join(", ")));
+    }
+    sets.join(", ")
+}
+
+fn describe_window_frame(frame: &WindowFrameClause) -> String {
+    let kind = match frame.kind {
+        WindowFrameKind::Rows => "ROWS",
+        WindowFrameKind::Range => "RANGE",
+    };
+
+    let describe_bound = |bound: &WindowFrameBound| match bound {
+        WindowFrameBound::UnboundedPreceding => "UNBOUNDED PRECEDING".to_string(),
+        WindowFrameBound::Preceding(offset) => {
+            format!("{} PRECEDING", expression_literal(offset))
+        }
+        WindowFrameBound::CurrentRow => "CURRENT ROW".to_string(),
+        WindowFrameBound::Following(offset) => {
+            format!("{} FOLLOWING", expression_literal(offset))
+        }
+        WindowFrameBound::UnboundedFollowing => "UNBOUNDED FOLLOWING".to_string(),
+    };
+
+    format!(
+        "{} BETWEEN {} AND {}",
+        kind,
+        describe_bound(&frame.start),
+        describe_bound(&frame.end)
+    )
+}
+
+fn describe_order_by(statement: &OrderByStatement) -> String {
+    let mut arguments = Vec::with_capacity(statement.arguments.len());
+    for (index, argument) in statement.arguments.iter().enumerate() {
+        let order = match statement.sorting_orders[index] {
+            SortingOrder::Ascending => "ASC",
+            SortingOrder::Descending => "DESC",
+        };
+
+        let nulls = match statement.nulls_order_policies[index] {
+            NullsOrderPolicy::NullsFirst => "NULLS FIRST",
+            NullsOrderPolicy::NullsLast => "NULLS LAST",
+        };
+
+        arguments.push(format!(
+            "{} {} {}",
+            expression_literal(argument),
+            order,
+            nulls
+        ));
+    }
+    format!("ORDER BY {}", arguments.join(", "))
+}
+
+fn expressions_literal(expressions: &[Box<dyn Expr>]) -> String {
+    expressions
+        .iter()
+        .map(expression_literal)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Format the expression as GitQL source code
+#[allow(clippy::borrowed_box)]
+pub(crate) fn expression_literal(expr: &Box<dyn Expr>) -> String {
+    match expr.kind() {
+        ExprKind::Assignment => {
+            let expr = expr.as_any().downcast_ref::<AssignmentExpr>().unwrap();
+            format!("{} := {}", expr.symbol, expression_literal(&expr.value))
+        }
+        ExprKind::String => {
+            let expr = expr.as_any().downcast_ref::<StringExpr>().unwrap();
+            format!("\"{}\"", expr.value)
+        }
+        ExprKind::Symbol => {
+            let expr = expr.as_any().downcast_ref::<SymbolExpr>().unwrap();
+            expr.value.to_string()
+        }
+        ExprKind::Array => {
+            let expr = expr.as_any().downcast_ref::<ArrayExpr>().unwrap();
+            format!("[{}]", expressions_literal(&expr.values))
+        }
+        ExprKind::Row => {
+            let expr = expr.as_any().downcast_ref::<RowExpr>().unwrap();
+            format!("ROW({})", expressions_literal(&expr.values))
+        }
+        ExprKind::GlobalVariable => {
+            let expr = expr.as_any().downcast_ref::<GlobalVariableExpr>().unwrap();
+            expr.name.to_string()
+        }
+        ExprKind::Placeholder => {
+            let expr = expr.as_any().downcast_ref::<PlaceholderExpr>().unwrap();
+            if expr.name.starts_with(|c: char| c.is_ascii_digit()) {
+                return format!("${}", expr.name);
+            }
+            format!(":{}", expr.name)
+        }
+        ExprKind::Number => {
+            let expr = expr.as_any().downcast_ref::<NumberExpr>().unwrap();
+            match expr.value {
+                Number::Int(value) => value.to_string(),
+                Number::Float(value) => value.to_string(),
+            }
+        }
+        ExprKind::Interval => {
+            let expr = expr.as_any().downcast_ref::<IntervalExpr>().unwrap();
+            format!("INTERVAL '{}'", expr.interval)
+        }
+        ExprKind::Boolean => {
+            let expr = expr.as_any().downcast_ref::<BooleanExpr>().unwrap();
+            if expr.is_true { "TRUE" } else { "FALSE" }.to_string()
+        }
+        ExprKind::PrefixUnary => {
+            let expr = expr.as_any().downcast_ref::<UnaryExpr>().unwrap();
+            let operator = match expr.operator {
+                PrefixUnaryOperator::Negative => "-",
+                PrefixUnaryOperator::Bang => "!",
+                PrefixUnaryOperator::Not => "NOT ",
+            };
+            format!("{}{}", operator, expression_literal(&expr.right))
+        }
+        ExprKind::Index => {
+            let expr = expr.as_any().downcast_ref::<IndexExpr>().unwrap();
+            format!(
+                "{}[{}]",
+                expression_literal(&expr.collection),
+                expression_literal(&expr.index)
+            )
+        }
+        ExprKind::Arrow => {
+            let expr = expr.as_any().downcast_ref::<ArrowExpr>().unwrap();
+            let operator = match expr.operator {
+                ArrowOperator::Arrow => "->",
+                ArrowOperator::LongArrow => "->>",
+            };
+            format!(
+                "{} {} {}",
+                expression_literal(&expr.left),
+                operator,
+                expression_literal(&expr.right)
+            )
+        }
+        ExprKind::Slice => {
+            let expr = expr.as_any().downcast_ref::<SliceExpr>().unwrap();
+            let start = expr
+                .start
+                .as_ref()
+                .map_or(String::new(), expression_literal);
+            let end = expr.end.as_ref().map_or(String::new(), expression_literal);
+            format!(
+                "{}[{}:{}]",
+                expression_literal(&expr.collection),
+                start,
+                end
+            )
+        }
+        ExprKind::Arithmetic => {
+            let expr = expr.as_any().downcast_ref::<ArithmeticExpr>().unwrap();
+            let operator = match expr.operator {
+                ArithmeticOperator::Plus => "+",
+                ArithmeticOperator::Minus => "-",
+                ArithmeticOperator::Star => "*",
+                ArithmeticOperator::Slash => "/",
+                ArithmeticOperator::Modulus => "%",
+                ArithmeticOperator::Exponentiation => "^",
+            };
+            binary_literal(&expr.left, operator, &expr.right)
+        }
+        ExprKind::Comparison => {
+            let expr = expr.as_any().downcast_ref::<ComparisonExpr>().unwrap();
+            binary_literal(&expr.left, comparison_literal(&expr.operator), &expr.right)
+        }
+        ExprKind::GroupComparison => {
+            let expr = expr.as_any().downcast_ref::<GroupComparisonExpr>().unwrap();
+            let group = match expr.group_operator {
+                GroupComparisonOperator::All => "ALL",
+                GroupComparisonOperator::Any => "ANY",
+            };
+            format!(
+                "{} {} {} ({})",
+                expression_literal(&expr.left),
+                comparison_literal(&expr.comparison_operator),
+                group,
+                expression_literal(&expr.right)
+            )
+        }
+        ExprKind::Contains => {
+            let expr = expr.as_any().downcast_ref::<ContainsExpr>().unwrap();
+            binary_literal(&expr.left, "@>", &expr.right)
+        }
+        ExprKind::ContainedBy => {
+            let expr = expr.as_any().downcast_ref::<ContainedByExpr>().unwrap();
+            binary_literal(&expr.left, "<@", &expr.right)
+        }
+        ExprKind::Like => {
+            let expr = expr.as_any().downcast_ref::<LikeExpr>().unwrap();
+            binary_literal(&expr.input, "LIKE", &expr.pattern)
+        }
+        ExprKind::Regex => {
+            let expr = expr.as_any().downcast_ref::<RegexExpr>().unwrap();
+            binary_literal(&expr.input, "REGEXP", &expr.pattern)
+        }
+        ExprKind::Glob => {
+            let expr = expr.as_any().downcast_ref::<GlobExpr>().unwrap();
+            binary_literal(&expr.input, "GLOB", &expr.pattern)
+        }
+        ExprKind::Logical => {
+            let expr = expr.as_any().downcast_ref::<LogicalExpr>().unwrap();
+            let operator = match expr.operator {
+                BinaryLogicalOperator::Or => "OR",
+                BinaryLogicalOperator::And => "AND",
+                BinaryLogicalOperator::Xor => "XOR",
+            };
+            binary_literal(&expr.left, operator, &expr.right)
+        }
+        ExprKind::Bitwise => {
+            let expr = expr.as_any().downcast_ref::<BitwiseExpr>().unwrap();
+            let operator = match expr.operator {
+                BinaryBitwiseOperator::Or => "|",
+                BinaryBitwiseOperator::And => "&",
+                BinaryBitwiseOperator::Xor => "#",
+                BinaryBitwiseOperator::RightShift => ">>",
+                BinaryBitwiseOperator::LeftShift => "<<",
+            };
+            binary_literal(&expr.left, operator, &expr.right)
+        }
+        ExprKind::Call => {
+            let expr = expr.as_any().downcast_ref::<CallExpr>().unwrap();
+            format!(
+                "{}({})",
+                expr.function_name,
+                expressions_literal(&expr.arguments)
+            )
+        }
+        ExprKind::Lambda => {
+            let expr = expr.as_any().downcast_ref::<LambdaExpr>().unwrap();
+            let body = expression_literal(&expr.body);
+            if expr.parameters.len() == 1 {
+                return format!("{} -> {}", expr.parameters[0], body);
+            }
+            format!("({}) -> {}", expr.parameters.join(", "), body)
+        }
+        ExprKind::BenchmarkCall => {
+            let expr = expr.as_any().downcast_ref::<BenchmarkCallExpr>().unwrap();
+            format!(
+                "BENCHMARK({}, {})",
+                expression_literal(&expr.count),
+                expression_literal(&expr.expression)
+            )
+        }
+        ExprKind::Between => {
+            let expr = expr.as_any().downcast_ref::<BetweenExpr>().unwrap();
+            let kind = match expr.kind {
+                BetweenKind::Symmetric => "SYMMETRIC ",
+                BetweenKind::Asymmetric => "",
+            };
+            format!(
+                "{} BETWEEN {}{} AND {}",
+                expression_literal(&expr.value),
+                kind,
+                expression_literal(&expr.range_start),
+                expression_literal(&expr.range_end)
+            )
+        }
+        ExprKind::Case => {
+            let expr = expr.as_any().downcast_ref::<CaseExpr>().unwrap();
+            let mut literal = "CASE".to_string();
+            for (condition, value) in expr.conditions.iter().zip(expr.values.iter()) {
+                literal.push_str(&format!(
+                    " WHEN {} THEN {}",
+                    expression_literal(condition),
+                    expression_literal(value)
+                ));
+            }
+
+            if let Some(default_value) = &expr.default_value {
+                literal.push_str(&format!(" ELSE {}", expression_literal(default_value)));
+            }
+
+            literal.push_str(" END");
+            literal
+        }
+        ExprKind::In => {
+            let expr = expr.as_any().downcast_ref::<InExpr>().unwrap();
+            let operator = if expr.has_not_keyword { "NOT IN" } else { "IN" };
+            format!(
+                "{} {} ({})",
+                expression_literal(&expr.argument),
+                operator,
+                expressions_literal(&expr.values)
+            )
+        }
+        ExprKind::IsNull => {
+            let expr = expr.as_any().downcast_ref::<IsNullExpr>().unwrap();
+            let operator = if expr.has_not {
+                "IS NOT NULL"
+            } else {
+                "IS NULL"
+            };
+            format!("{} {}", expression_literal(&expr.argument), operator)
+        }
+        ExprKind::Null => "NULL".to_string(),
+        ExprKind::Cast => {
+            let expr = expr.as_any().downcast_ref::<CastExpr>().unwrap();
+            format!(
+                "CAST({} AS {})",
+                expression_literal(&expr.value),
+                expr.result_type.literal()
+            )
+        }
+        ExprKind::TryCast => {
+            let expr = expr.as_any().downcast_ref::<TryCastExpr>().unwrap();
+            format!(
+                "TRY_CAST({} AS {})",
+                expression_literal(&expr.value),
+                expr.result_type.literal()
+            )
+        }
+        ExprKind::AtTimeZone => {
+            let expr = expr.as_any().downcast_ref::<AtTimeZoneExpr>().unwrap();
+            format!(
+                "{} AT TIME ZONE {}",
+                expression_literal(&expr.value),
+                expression_literal(&expr.time_zone)
+            )
+        }
+        ExprKind::Grouping => {
+            let expr = expr.as_any().downcast_ref::<GroupExpr>().unwrap();
+            format!("({})", expression_literal(&expr.expr))
+        }
+        ExprKind::MemberAccess => {
+            let expr = expr.as_any().downcast_ref::<MemberAccessExpr>().unwrap();
+            let composite = expression_literal(&expr.composite);
+            if expr
+                .composite
+                .as_any()
+                .downcast_ref::<GroupExpr>()
+                .is_some()
+            {
+                return format!("{}.{}", composite, expr.member_name);
+            }
+            format!("({}).{}", composite, expr.member_name)
+        }
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+fn binary_literal(left: &Box<dyn Expr>, operator: &str, right: &Box<dyn Expr>) -> String {
+    format!(
+        "{} {} {}",
+        expression_literal(left),
+        operator,
+        expression_literal(right)
+    )
+}
+
+fn comparison_literal(operator: &ComparisonOperator) -> &'static str {
+    match operator {
+        ComparisonOperator::Greater => ">",
+        ComparisonOperator::GreaterEqual => ">=",
+        ComparisonOperator::Less => "<",
+        ComparisonOperator::LessEqual => "<=",
+        ComparisonOperator::Equal => "=",
+        ComparisonOperator::NotEqual => "!=",
+        ComparisonOperator::NullSafeEqual => "<=>",
+    }
+}
@@ -1,7 +1,6 @@
 use gitql_ast::expression::Expr;
 use gitql_core::environment::Environment;
 use gitql_core::object::Row;
-use gitql_core::values::boolean::BoolValue;
 
 use crate::engine_evaluator::evaluate_expression;
 
@@ -16,10 +15,9 @@ pub(crate) fn apply_filter_operation(
     let mut positions_to_delete = vec![];
     for (index, row) in rows.iter().enumerate() {
         let expression = evaluate_expression(env, condition, titles, &row.values)?;
-        if let Some(bool_value) = expression.as_any().downcast_ref::<BoolValue>() {
-            if !bool_value.value {
-                positions_to_delete.push(index);
-            }
+        // Keep only rows with true condition, NULL is unknown so the row is removed too
+        if !expression.as_bool().unwrap_or(false) {
+            positions_to_delete.push(index);
         }
     }
 
@@ -4,8 +4,8 @@ use std::hash::DefaultHasher;
 use std::hash::Hash;
 use std::hash::Hasher;
 
+use gitql_ast::expression::SymbolExpr;
 use gitql_ast::statement::GroupByStatement;
-use gitql_core::combinations_generator::generate_list_of_all_combinations;
 use gitql_core::environment::Environment;
 use gitql_core::object::GitQLObject;
 use gitql_core::object::Group;
@@ -26,26 +26,35 @@ pub(crate) fn execute_group_by_statement(
         return Ok(());
     }
 
-    // Mapping each unique value to it group index
+    // Mapping each unique value of each grouping set to it group index
     let mut groups_map: HashMap<u64, usize> = HashMap::new();
+    let mut groups_per_set: Vec<Vec<Group>> = vec![vec![]; statement.grouping_sets.len()];
 
-    // Track current group index
-    let mut next_group_index = 0;
-    let values_count = statement.values.len();
+    // Resolve the positions of the grouped columns that are rolled up in each grouping set
+    let mut rolled_up_columns_per_set: Vec<Vec<usize>> =
+        Vec::with_capacity(statement.grouping_sets.len());
+    for grouping_set in statement.grouping_sets.iter() {
+        let mut rolled_up_columns = vec![];
+        for (index, value) in statement.values.iter().enumerate() {
+            if grouping_set.contains(&index) {
+                continue;
+            }
 
-    let is_roll_up_enabled = statement.has_with_roll_up;
-    let indexes_combinations = if is_roll_up_enabled {
-        generate_list_of_all_combinations(values_count)
-    } else {
-        vec![(0..values_count).collect()]
-    };
+            if let Some(symbol) = value.as_any().downcast_ref::<SymbolExpr>() {
+                if let Some(position) = gitql_object.titles.iter().position(|t| t.eq(&symbol.value))
+                {
+                    rolled_up_columns.push(position);
+                }
+            }
+        }
+        rolled_up_columns_per_set.push(rolled_up_columns);
+    }
 
-    // For each row should check the group by values combinations to build multi groups
+    // For each row should check the grouping sets to build multi groups
     for row in main_group.rows.iter() {
-        // Create all combination of values for each row
-        for indexes in indexes_combinations.iter() {
-            let mut row_values: Vec<String> = Vec::with_capacity(indexes.len());
-            for index in indexes {
+        for (set_index, grouping_set) in statement.grouping_sets.iter().enumerate() {
+            let mut row_values: Vec<String> = Vec::with_capacity(grouping_set.len());
+            for index in grouping_set {
                 let value = evaluate_expression(
                     env,
                     &statement.values[*index],
@@ -55,33 +64,33 @@ pub(crate) fn execute_group_by_statement(
                 row_values.push(value.literal());
             }
 
-            // Compute the hash for row of values
+            // Compute the hash for row of values in this grouping set
             let mut hasher = DefaultHasher::new();
+            set_index.hash(&mut hasher);
             row_values.hash(&mut hasher);
             let values_hash = hasher.finish();
 
-            // Push a new group for this unique value and update the next index
+            let set_groups = &mut groups_per_set[set_index];
+
+            // Push a new group for this unique value
             if let Vacant(e) = groups_map.entry(values_hash) {
-                e.insert(next_group_index);
-                next_group_index += 1;
-                gitql_object.groups.push(Group {
+                e.insert(set_groups.len());
+                set_groups.push(Group {
                     rows: vec![row.clone()],
+                    rolled_up_columns: rolled_up_columns_per_set[set_index].clone(),
                 });
                 continue;
             }
 
             // If there is an existing group for this value, append current object to it
-            let index = *groups_map.get(&values_hash).unwrap();
-            let target_group = &mut gitql_object.groups[index];
-            target_group.rows.push(row.clone());
+            let index = groups_map[&values_hash];
+            set_groups[index].rows.push(row.clone());
         }
     }
 
-    // If the group by elements is one and ROLLUP is enabled
-    // For example: SELECT ... FROM <TABLE> GROUP BY X WITH ROLLUP
-    // Should append the the main group at the end
-    if is_roll_up_enabled && indexes_combinations.len() == 1 && indexes_combinations[0].len() == 1 {
-        gitql_object.groups.push(main_group);
+    // Groups are ordered by their grouping sets, for example the subtotals after the main groups
+    for set_groups in groups_per_set {
+        gitql_object.groups.extend(set_groups);
     }
 
     Ok(())
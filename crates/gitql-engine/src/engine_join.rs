@@ -1,26 +1,152 @@
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::time::Instant;
 
+use gitql_ast::expression::ComparisonExpr;
+use gitql_ast::expression::Expr;
+use gitql_ast::expression::ExprKind;
+use gitql_ast::expression::GroupExpr;
+use gitql_ast::expression::LogicalExpr;
+use gitql_ast::expression::SymbolExpr;
+use gitql_ast::operator::BinaryLogicalOperator;
+use gitql_ast::operator::ComparisonOperator;
 use gitql_ast::statement::Join;
 use gitql_ast::statement::JoinKind;
 use gitql_ast::statement::JoinOperand;
 use gitql_ast::statement::TableSelection;
+use gitql_ast::types::DataType;
 use gitql_core::environment::Environment;
 use gitql_core::object::Row;
 use gitql_core::values::boolean::BoolValue;
+use gitql_core::values::datetimetz::DateTimeTzValue;
+use gitql_core::values::decimal::DecimalValue;
+use gitql_core::values::float::FloatValue;
 use gitql_core::values::null::NullValue;
+use gitql_core::values::semver::SemVerValue;
 use gitql_core::values::Value;
 
 use crate::engine_evaluator::evaluate_expression;
+use crate::engine_explain::describe_join;
+use crate::engine_explain::QueryProfile;
+use crate::engine_explain::StageStats;
+
+/// The algorithm used to match the rows of two tables
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum JoinStrategy {
+    /// Evaluate the predicate for every pair of rows
+    NestedLoop,
+    /// Build a hash table from the right rows keys and probe it with the left rows keys
+    Hash,
+    /// Sort both sides by the keys and merge the rows with equal keys
+    SortMerge,
+}
+
+impl JoinStrategy {
+    pub(crate) fn literal(&self) -> &'static str {
+        match self {
+            JoinStrategy::NestedLoop => "nested loop join",
+            JoinStrategy::Hash => "hash join",
+            JoinStrategy::SortMerge => "sort-merge join",
+        }
+    }
+}
+
+/// Positions of the left and right values inside the joined row
+/// The joined row layout is [Left hidden, Right hidden, Left visible, Right visible]
+struct JoinLayout {
+    left_hidden_count: usize,
+    right_hidden_count: usize,
+    left_width: usize,
+    right_width: usize,
+}
+
+enum JoinSide {
+    Left(usize),
+    Right(usize),
+}
+
+impl JoinLayout {
+    /// Map index in the left row to the index in the joined row
+    fn left_position(&self, index: usize) -> usize {
+        if index < self.left_hidden_count {
+            index
+        } else {
+            index + self.right_hidden_count
+        }
+    }
+
+    /// Map index in the right row to the index in the joined row
+    fn right_position(&self, index: usize) -> usize {
+        if index < self.right_hidden_count {
+            self.left_hidden_count + index
+        } else {
+            self.left_width + index
+        }
+    }
+
+    /// Map index in the joined row to the index in the left or right row
+    fn resolve_side(&self, index: usize) -> Option<JoinSide> {
+        let hidden_count = self.left_hidden_count + self.right_hidden_count;
+        let left_visible_count = self.left_width - self.left_hidden_count;
+        if index < self.left_hidden_count {
+            return Some(JoinSide::Left(index));
+        }
+
+        if index < hidden_count {
+            return Some(JoinSide::Right(index - self.left_hidden_count));
+        }
+
+        if index < hidden_count + left_visible_count {
+            return Some(JoinSide::Left(index - self.right_hidden_count));
+        }
+
+        if index < self.left_width + self.right_width {
+            return Some(JoinSide::Right(index - self.left_width));
+        }
+
+        None
+    }
+}
+
+/// Positions of one table values inside the joined row of all tables
+/// The final joined row layout is [Hidden of each table, Visible of each table]
+struct TableLayout {
+    hidden_offset: usize,
+    visible_offset: usize,
+    hidden_count: usize,
+    width: usize,
+}
+
+impl TableLayout {
+    /// Map index in the table row to the index in the final joined row
+    fn final_position(&self, index: usize) -> usize {
+        if index < self.hidden_count {
+            self.hidden_offset + index
+        } else {
+            self.visible_offset + index - self.hidden_count
+        }
+    }
+
+    /// Find the index of column in the table row
+    fn column_index(&self, titles: &[String], column: &str) -> Option<usize> {
+        (0..self.width).find(|index| titles[self.final_position(*index)].eq(column))
+    }
+}
 
 #[inline(always)]
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn apply_join_operation(
     env: &mut Environment,
     all_rows: &mut Vec<Row>,
-    joins: &Vec<Join>,
-    tables_selections: &Vec<TableSelection>,
+    joins: &[Join],
+    tables_selections: &[TableSelection],
     selected_rows_per_table: &mut HashMap<String, Vec<Row>>,
     hidden_selection_per_table: &HashMap<String, usize>,
-    titles: &[String],
+    titles: &mut Vec<String>,
+    mut profile: Option<&mut QueryProfile>,
 ) -> Result<(), String> {
     // If no join, just merge them, can be optimized to append only the first value in the map
     if joins.is_empty() {
@@ -33,18 +159,55 @@ pub(crate) fn apply_join_operation(
         return Ok(());
     }
 
+    // Number of values in each table row, used to pad unmatched rows with nulls
+    let mut width_per_table: HashMap<&str, usize> = HashMap::new();
+    for table_selection in tables_selections {
+        let table_name = table_selection.table_name.as_str();
+        let hidden_count = *hidden_selection_per_table.get(table_name).unwrap_or(&0);
+        width_per_table.insert(
+            table_name,
+            table_selection.columns_names.len() + hidden_count,
+        );
+    }
+
+    // Offsets of each table values in the final joined row
+    let total_hidden_count: usize = hidden_selection_per_table.values().sum();
+    let mut tables_layouts: Vec<TableLayout> = Vec::with_capacity(tables_selections.len());
+    let mut hidden_offset = 0;
+    let mut visible_offset = total_hidden_count;
+    for table_selection in tables_selections {
+        let table_name = table_selection.table_name.as_str();
+        let hidden_count = *hidden_selection_per_table.get(table_name).unwrap_or(&0);
+        let width = *width_per_table.get(table_name).unwrap_or(&0);
+        tables_layouts.push(TableLayout {
+            hidden_offset,
+            visible_offset,
+            hidden_count,
+            width,
+        });
+        hidden_offset += hidden_count;
+        visible_offset += width - hidden_count;
+    }
+
+    // Positions of the right side columns of `USING` that will be merged with the left side
+    let mut merged_columns_positions: Vec<usize> = vec![];
+
     let mut current_tables_rows: Vec<Row> = vec![];
     let mut all_rows_hidden_count = 0;
+    let mut all_rows_width = 0;
 
     // Apply join operator depend on the join type
-    for join in joins {
+    for (join_index, join) in joins.iter().enumerate() {
+        let join_start = Instant::now();
         let mut current_join_rows: Vec<Row> = vec![];
 
         let left_rows: &Vec<Row>;
         let left_hidden_count: usize;
+        let left_width: usize;
 
         let right_rows: &Vec<Row>;
         let right_hidden_count: usize;
+        let right_width: usize;
 
         match &join.operand {
             JoinOperand::OuterAndInner(outer, inner) => {
@@ -52,6 +215,10 @@ pub(crate) fn apply_join_operation(
                 right_hidden_count = *hidden_selection_per_table.get(inner).unwrap_or(&0);
                 all_rows_hidden_count += left_hidden_count + right_hidden_count;
 
+                left_width = *width_per_table.get(outer.as_str()).unwrap_or(&0);
+                right_width = *width_per_table.get(inner.as_str()).unwrap_or(&0);
+                all_rows_width += left_width + right_width;
+
                 left_rows = selected_rows_per_table.get(outer).unwrap();
                 right_rows = selected_rows_per_table.get(inner).unwrap();
             }
@@ -61,6 +228,10 @@ pub(crate) fn apply_join_operation(
                 right_hidden_count = *hidden_selection_per_table.get(inner).unwrap_or(&0);
                 all_rows_hidden_count += right_hidden_count;
 
+                left_width = all_rows_width;
+                right_width = *width_per_table.get(inner.as_str()).unwrap_or(&0);
+                all_rows_width += right_width;
+
                 left_rows = &current_tables_rows;
                 right_rows = selected_rows_per_table.get(inner).unwrap();
             }
@@ -71,75 +242,558 @@ pub(crate) fn apply_join_operation(
             continue;
         }
 
-        // Perform nested loops straight forward join algorithm
-        for outer in left_rows {
-            for inner in right_rows {
-                let row_len = outer.values.len() + inner.values.len();
-                let mut joined_row: Vec<Box<dyn Value>> = Vec::with_capacity(row_len);
-                joined_row.append(&mut outer.values.clone());
+        let layout = JoinLayout {
+            left_hidden_count,
+            right_hidden_count,
+            left_width,
+            right_width,
+        };
 
-                let inner_rows = inner.values.clone();
-                let inner_hidden_values = &inner_rows[0..right_hidden_count];
-                joined_row.splice(
-                    left_hidden_count..left_hidden_count,
-                    inner_hidden_values.to_vec(),
-                );
+        let using_keys = resolve_using_join_keys(
+            env,
+            join,
+            join_index,
+            &tables_layouts,
+            titles,
+            &mut merged_columns_positions,
+        )?;
 
-                let inner_other_values = &inner_rows[right_hidden_count..];
-                joined_row.extend_from_slice(inner_other_values);
+        let (strategy, keys) = if using_keys.is_empty() {
+            select_join_strategy(join, &layout, titles)
+        } else {
+            (JoinStrategy::Hash, using_keys)
+        };
+        let sort_merge_join_min_rows = env.sort_merge_join_min_rows();
+        let strategy = if strategy == JoinStrategy::Hash
+            && left_rows.len() >= sort_merge_join_min_rows
+            && right_rows.len() >= sort_merge_join_min_rows
+            && keys.iter().all(|key| key.is_orderable)
+        {
+            JoinStrategy::SortMerge
+        } else {
+            strategy
+        };
+
+        // For each left row, the indices of the right rows that may match it in ascending order
+        let candidates = match strategy {
+            JoinStrategy::NestedLoop => None,
+            JoinStrategy::Hash => Some(hash_join_candidates(&keys, left_rows, right_rows)),
+            JoinStrategy::SortMerge => {
+                Some(sort_merge_join_candidates(&keys, left_rows, right_rows))
+            }
+        };
+
+        let all_right_indices: Vec<usize> = if candidates.is_none() {
+            (0..right_rows.len()).collect()
+        } else {
+            vec![]
+        };
+
+        let mut is_right_row_matched = vec![false; right_rows.len()];
+        for (left_index, outer) in left_rows.iter().enumerate() {
+            let mut is_left_row_matched = false;
+            let right_indices = match &candidates {
+                Some(candidates) => &candidates[left_index],
+                None => &all_right_indices,
+            };
+
+            for right_index in right_indices {
+                let inner = &right_rows[*right_index];
+
+                // Make sure `USING` columns are equals not only have the same hash
+                if !join.using_columns.is_empty()
+                    && !keys.iter().all(|key| {
+                        outer.values[key.left_index].equals(&inner.values[key.right_index])
+                    })
+                {
+                    continue;
+                }
+
+                let mut joined_row = build_joined_row(
+                    &outer.values,
+                    &inner.values,
+                    left_hidden_count,
+                    right_hidden_count,
+                );
 
                 // If join has predicate, insert the joined row only if the predicate value is true
                 if let Some(predicate) = &join.predicate {
                     let predicate_value = evaluate_expression(env, predicate, titles, &joined_row)?;
-                    if let Some(bool_value) = predicate_value.as_any().downcast_ref::<BoolValue>() {
-                        if bool_value.value {
-                            current_join_rows.push(Row { values: joined_row });
-                            continue;
-                        }
-                    }
+                    let is_matched = predicate_value
+                        .as_any()
+                        .downcast_ref::<BoolValue>()
+                        .is_some_and(|bool_value| bool_value.value);
 
-                    // For LEFT and RIGHT Join only if the predicate is false we need to create new joined row
-                    // The new joined row will have nulls as LEFT table row values if the join type is `RIGHT OUTER` or
-                    // Nulls as RGIHT table row values if the join type is `LEFT OUTER`
-                    match join.kind {
-                        JoinKind::Left => {
-                            let mut left_joined_row: Vec<Box<dyn Value>> =
-                                Vec::with_capacity(row_len);
-                            // Push the LEFT values row
-                            left_joined_row.append(&mut outer.values.clone());
-                            // Push (N * NULL) values as RIGHT values row
-                            for _ in 0..inner.values.len() {
-                                left_joined_row.push(Box::new(NullValue));
-                            }
-                        }
-                        JoinKind::Right => {
-                            let mut right_joined_row: Vec<Box<dyn Value>> =
-                                Vec::with_capacity(row_len);
-                            // Push (N * NULL) values as LEFT values row
-                            for _ in 0..outer.values.len() {
-                                right_joined_row.push(Box::new(NullValue));
-                            }
-                            // Push the RIGHT values row
-                            right_joined_row.append(&mut inner.values.clone());
-                        }
-                        _ => {}
+                    if !is_matched {
+                        continue;
                     }
-                    continue;
                 }
 
-                // If the condition has no predicate, just insert it
+                is_left_row_matched = true;
+                is_right_row_matched[*right_index] = true;
+                if !join.using_columns.is_empty() {
+                    merge_using_columns(&mut joined_row, &keys, &layout);
+                }
+                current_join_rows.push(Row { values: joined_row });
+            }
+
+            // For LEFT and FULL Join if the left row has no match, we need to create new joined row
+            // with Nulls as RIGHT table row values
+            if matches!(join.kind, JoinKind::Left | JoinKind::Full) && !is_left_row_matched {
+                let nulls = null_values(right_width);
+                let joined_row =
+                    build_joined_row(&outer.values, &nulls, left_hidden_count, right_hidden_count);
                 current_join_rows.push(Row { values: joined_row });
             }
         }
 
+        // For RIGHT and FULL Join every right row with no match, we need to create new joined row
+        // with Nulls as LEFT table row values
+        if matches!(join.kind, JoinKind::Right | JoinKind::Full) {
+            let nulls = null_values(left_width);
+            for (right_index, inner) in right_rows.iter().enumerate() {
+                if !is_right_row_matched[right_index] {
+                    let mut joined_row = build_joined_row(
+                        &nulls,
+                        &inner.values,
+                        left_hidden_count,
+                        right_hidden_count,
+                    );
+                    if !join.using_columns.is_empty() {
+                        merge_using_columns(&mut joined_row, &keys, &layout);
+                    }
+                    current_join_rows.push(Row { values: joined_row });
+                }
+            }
+        }
+
+        if let Some(profile) = profile.as_deref_mut() {
+            let inner = match &join.operand {
+                JoinOperand::OuterAndInner(_, inner) => inner,
+                JoinOperand::Inner(inner) => inner,
+            };
+
+            let strategy = describe_join_strategy(strategy, keys.len());
+            let details = describe_join(join, inner, &strategy);
+            let input_rows = left_rows.len() + right_rows.len();
+            let stats = StageStats::new(join_start, input_rows, current_join_rows.len());
+            profile.push("join", details, stats);
+        }
+
         // Clear the previous join rows if exists
         current_tables_rows.clear();
         // Set the current tables rows as the result of the join
         current_tables_rows.append(&mut current_join_rows);
     }
 
+    // Remove the right side of `USING` columns because they are merged into the left side
+    if !merged_columns_positions.is_empty() {
+        merged_columns_positions.sort_unstable();
+        merged_columns_positions.dedup();
+        for position in merged_columns_positions.iter().rev() {
+            titles.remove(*position);
+            for row in current_tables_rows.iter_mut() {
+                row.values.remove(*position);
+            }
+        }
+    }
+
     // Push the result to the all_rows ref
     all_rows.append(&mut current_tables_rows);
 
     Ok(())
 }
+
+/// Resolve the join keys of `USING` columns, and register the position of the right side columns to be merged
+fn resolve_using_join_keys(
+    env: &Environment,
+    join: &Join,
+    join_index: usize,
+    tables_layouts: &[TableLayout],
+    titles: &[String],
+    merged_columns_positions: &mut Vec<usize>,
+) -> Result<Vec<JoinKey>, String> {
+    let mut keys = vec![];
+    if join.using_columns.is_empty() {
+        return Ok(keys);
+    }
+
+    // The right side is the table joined in this step, and all the previous tables are the left side
+    let right_table = &tables_layouts[join_index + 1];
+    let left_tables = &tables_layouts[..join_index + 1];
+    let left_hidden_count = right_table.hidden_offset;
+    let total_hidden_count = tables_layouts[0].visible_offset;
+
+    for column in join.using_columns.iter() {
+        let left_index = left_tables.iter().find_map(|table| {
+            table.column_index(titles, column).map(|index| {
+                if index < table.hidden_count {
+                    table.hidden_offset + index
+                } else {
+                    left_hidden_count + table.visible_offset - total_hidden_count + index
+                        - table.hidden_count
+                }
+            })
+        });
+
+        let right_index = right_table.column_index(titles, column);
+        let (Some(left_index), Some(right_index)) = (left_index, right_index) else {
+            return Err(format!("Can't resolve join column `{}`", column));
+        };
+
+        let is_orderable = env
+            .schema
+            .tables_fields_types
+            .get(column.as_str())
+            .is_some_and(is_orderable_type);
+
+        keys.push(JoinKey {
+            left_index,
+            right_index,
+            is_orderable,
+        });
+
+        merged_columns_positions.push(right_table.final_position(right_index));
+    }
+
+    Ok(keys)
+}
+
+/// Set the value of the `USING` columns to the right value if the left one is null
+fn merge_using_columns(joined_row: &mut [Box<dyn Value>], keys: &[JoinKey], layout: &JoinLayout) {
+    for key in keys {
+        let left_position = layout.left_position(key.left_index);
+        if joined_row[left_position].is_null() {
+            let right_position = layout.right_position(key.right_index);
+            joined_row[left_position] = joined_row[right_position].clone();
+        }
+    }
+}
+
+/// Pair of values indices in the left and right rows that must be equal to satisfy the join predicate
+struct JoinKey {
+    left_index: usize,
+    right_index: usize,
+    is_orderable: bool,
+}
+
+/// Describe the join strategy that will be used without executing the join, the rows count is unknown
+/// so sort-merge join is mentioned as the alternative for large inputs
+pub(crate) fn plan_join_strategy(
+    env: &Environment,
+    join: &Join,
+    left_hidden: &[String],
+    left_visible: &[String],
+    right_hidden: &[String],
+    right_visible: &[String],
+) -> String {
+    if !join.using_columns.is_empty() {
+        return describe_join_strategy(JoinStrategy::Hash, join.using_columns.len());
+    }
+
+    let layout = JoinLayout {
+        left_hidden_count: left_hidden.len(),
+        right_hidden_count: right_hidden.len(),
+        left_width: left_hidden.len() + left_visible.len(),
+        right_width: right_hidden.len() + right_visible.len(),
+    };
+
+    let titles = [left_hidden, right_hidden, left_visible, right_visible].concat();
+    let (strategy, keys) = select_join_strategy(join, &layout, &titles);
+    let description = describe_join_strategy(strategy, keys.len());
+    if strategy == JoinStrategy::Hash && keys.iter().all(|key| key.is_orderable) {
+        return format!(
+            "{} (sort-merge join if both sides have at least {} rows)",
+            description,
+            env.sort_merge_join_min_rows()
+        );
+    }
+    description
+}
+
+fn describe_join_strategy(strategy: JoinStrategy, keys_count: usize) -> String {
+    match strategy {
+        JoinStrategy::NestedLoop => strategy.literal().to_string(),
+        _ if keys_count == 1 => format!("{} on 1 key", strategy.literal()),
+        _ => format!("{} on {} keys", strategy.literal(), keys_count),
+    }
+}
+
+/// Select the join algorithm depending on the predicate shape
+///
+/// Equi join predicates (`a = b`, optionally combined with other conditions by `AND`)
+/// are matched with hash join, any other predicate shape fallback to nested loops
+fn select_join_strategy(
+    join: &Join,
+    layout: &JoinLayout,
+    titles: &[String],
+) -> (JoinStrategy, Vec<JoinKey>) {
+    let mut keys = vec![];
+    if let Some(predicate) = &join.predicate {
+        collect_equi_join_keys(predicate, layout, titles, &mut keys);
+    }
+
+    if keys.is_empty() {
+        return (JoinStrategy::NestedLoop, keys);
+    }
+
+    (JoinStrategy::Hash, keys)
+}
+
+#[allow(clippy::borrowed_box)]
+fn collect_equi_join_keys(
+    expr: &Box<dyn Expr>,
+    layout: &JoinLayout,
+    titles: &[String],
+    keys: &mut Vec<JoinKey>,
+) {
+    match expr.kind() {
+        ExprKind::Grouping => {
+            let group = expr.as_any().downcast_ref::<GroupExpr>().unwrap();
+            collect_equi_join_keys(&group.expr, layout, titles, keys);
+        }
+        ExprKind::Logical => {
+            // Each side of `AND` must be true, so any of them can be used as join keys
+            let logical = expr.as_any().downcast_ref::<LogicalExpr>().unwrap();
+            if logical.operator == BinaryLogicalOperator::And {
+                collect_equi_join_keys(&logical.left, layout, titles, keys);
+                collect_equi_join_keys(&logical.right, layout, titles, keys);
+            }
+        }
+        ExprKind::Comparison => {
+            let comparison = expr.as_any().downcast_ref::<ComparisonExpr>().unwrap();
+            if comparison.operator != ComparisonOperator::Equal {
+                return;
+            }
+
+            let lhs_type = comparison.left.expr_type();
+            if !lhs_type.equals(&comparison.right.expr_type()) {
+                return;
+            }
+
+            let lhs = resolve_symbol_side(&comparison.left, layout, titles);
+            let rhs = resolve_symbol_side(&comparison.right, layout, titles);
+            let (left_index, right_index) = match (lhs, rhs) {
+                (Some(JoinSide::Left(left)), Some(JoinSide::Right(right))) => (left, right),
+                (Some(JoinSide::Right(right)), Some(JoinSide::Left(left))) => (left, right),
+                _ => return,
+            };
+
+            let is_orderable = is_orderable_type(&lhs_type);
+            keys.push(JoinKey {
+                left_index,
+                right_index,
+                is_orderable,
+            });
+        }
+        _ => {}
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+fn is_orderable_type(data_type: &Box<dyn DataType>) -> bool {
+    data_type.is_int()
+        || data_type.is_float()
+        || data_type.is_text()
+        || data_type.is_bool()
+        || data_type.is_date()
+        || data_type.is_time()
+        || data_type.is_date_time()
+}
+
+#[allow(clippy::borrowed_box)]
+fn resolve_symbol_side(
+    expr: &Box<dyn Expr>,
+    layout: &JoinLayout,
+    titles: &[String],
+) -> Option<JoinSide> {
+    let symbol = expr.as_any().downcast_ref::<SymbolExpr>()?;
+    let index = titles.iter().position(|title| title.eq(&symbol.value))?;
+    layout.resolve_side(index)
+}
+
+fn hash_join_candidates(
+    keys: &[JoinKey],
+    left_rows: &[Row],
+    right_rows: &[Row],
+) -> Vec<Vec<usize>> {
+    // Build the hash table from the right rows keys
+    let mut right_rows_map: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (index, row) in right_rows.iter().enumerate() {
+        if let Some(hash) = hash_row_keys(keys.iter().map(|key| &row.values[key.right_index])) {
+            right_rows_map.entry(hash).or_default().push(index);
+        }
+    }
+
+    // Probe the hash table with the left rows keys
+    let mut candidates: Vec<Vec<usize>> = Vec::with_capacity(left_rows.len());
+    for row in left_rows {
+        let hash = hash_row_keys(keys.iter().map(|key| &row.values[key.left_index]));
+        let right_indices = hash
+            .and_then(|hash| right_rows_map.get(&hash))
+            .cloned()
+            .unwrap_or_default();
+        candidates.push(right_indices);
+    }
+
+    candidates
+}
+
+/// Compute the hash of the keys literals, or None if any of them can't match any other key
+fn hash_row_keys<'a>(values: impl Iterator<Item = &'a Box<dyn Value>>) -> Option<u64> {
+    let mut literals: Vec<String> = vec![];
+    for value in values {
+        if is_unmatchable_key(value) {
+            return None;
+        }
+        literals.push(canonical_key_literal(value));
+    }
+
+    let mut hasher = DefaultHasher::new();
+    literals.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+/// Null and NaN keys are not equal to any other key, even to themselves
+#[allow(clippy::borrowed_box)]
+fn is_unmatchable_key(value: &Box<dyn Value>) -> bool {
+    value.is_null()
+        || value
+            .as_any()
+            .downcast_ref::<FloatValue>()
+            .is_some_and(|float| float.value.is_nan())
+}
+
+/// Literal of the key that is the same for all equal values, unlike the value literal that
+/// can be different for equal values like `-0.0` and `0.0` or `1.0` and `1.00` decimals
+#[allow(clippy::borrowed_box)]
+fn canonical_key_literal(value: &Box<dyn Value>) -> String {
+    let any = value.as_any();
+    if let Some(float) = any.downcast_ref::<FloatValue>() {
+        // Negative zero is equal to zero
+        return (float.value + 0.0).to_string();
+    }
+
+    if let Some(decimal) = any.downcast_ref::<DecimalValue>() {
+        return decimal.value.normalize().to_string();
+    }
+
+    if let Some(date_time) = any.downcast_ref::<DateTimeTzValue>() {
+        return date_time.value.to_string();
+    }
+
+    if let Some(semver) = any.downcast_ref::<SemVerValue>() {
+        let mut version = semver.version.clone();
+        version.build.clear();
+        return version.to_string();
+    }
+
+    value.literal()
+}
+
+fn sort_merge_join_candidates(
+    keys: &[JoinKey],
+    left_rows: &[Row],
+    right_rows: &[Row],
+) -> Vec<Vec<usize>> {
+    let left_keys = |index: usize| {
+        keys.iter()
+            .map(move |key| &left_rows[index].values[key.left_index])
+    };
+    let right_keys = |index: usize| {
+        keys.iter()
+            .map(move |key| &right_rows[index].values[key.right_index])
+    };
+
+    // Rows with null or NaN keys never match so they are not part of the merge, this also
+    // keeps the keys comparison a total order
+    let mut left_sorted: Vec<usize> = (0..left_rows.len())
+        .filter(|index| !left_keys(*index).any(is_unmatchable_key))
+        .collect();
+    left_sorted.sort_by(|a, b| compare_row_keys(left_keys(*a), left_keys(*b)));
+
+    let mut right_sorted: Vec<usize> = (0..right_rows.len())
+        .filter(|index| !right_keys(*index).any(is_unmatchable_key))
+        .collect();
+    right_sorted.sort_by(|a, b| compare_row_keys(right_keys(*a), right_keys(*b)));
+
+    let mut candidates: Vec<Vec<usize>> = vec![vec![]; left_rows.len()];
+    let mut left_position = 0;
+    let mut right_position = 0;
+    while left_position < left_sorted.len() && right_position < right_sorted.len() {
+        let left_index = left_sorted[left_position];
+        let right_index = right_sorted[right_position];
+        match compare_row_keys(left_keys(left_index), right_keys(right_index)) {
+            Ordering::Less => left_position += 1,
+            Ordering::Greater => right_position += 1,
+            Ordering::Equal => {
+                // Find the end of the run of equal keys on both sides
+                let left_run_end = left_sorted[left_position..]
+                    .iter()
+                    .position(|index| {
+                        compare_row_keys(left_keys(*index), left_keys(left_index)).is_ne()
+                    })
+                    .map_or(left_sorted.len(), |offset| left_position + offset);
+
+                let right_run_end = right_sorted[right_position..]
+                    .iter()
+                    .position(|index| {
+                        compare_row_keys(right_keys(*index), right_keys(right_index)).is_ne()
+                    })
+                    .map_or(right_sorted.len(), |offset| right_position + offset);
+
+                let right_run = &right_sorted[right_position..right_run_end];
+                for left_index in &left_sorted[left_position..left_run_end] {
+                    candidates[*left_index].extend_from_slice(right_run);
+                }
+
+                left_position = left_run_end;
+                right_position = right_run_end;
+            }
+        }
+    }
+
+    // Keep the same rows order as the nested loops join
+    for right_indices in candidates.iter_mut() {
+        right_indices.sort_unstable();
+    }
+
+    candidates
+}
+
+fn compare_row_keys<'a>(
+    lhs: impl Iterator<Item = &'a Box<dyn Value>>,
+    mut rhs: impl Iterator<Item = &'a Box<dyn Value>>,
+) -> Ordering {
+    for lhs_value in lhs {
+        let rhs_value = rhs.next().unwrap();
+        let ordering = lhs_value.compare(rhs_value).unwrap_or(Ordering::Equal);
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Build joined row with layout [Left hidden, Right hidden, Left visible, Right visible]
+fn build_joined_row(
+    left: &[Box<dyn Value>],
+    right: &[Box<dyn Value>],
+    left_hidden_count: usize,
+    right_hidden_count: usize,
+) -> Vec<Box<dyn Value>> {
+    let mut joined_row: Vec<Box<dyn Value>> = Vec::with_capacity(left.len() + right.len());
+    joined_row.extend_from_slice(&left[..left_hidden_count]);
+    joined_row.extend_from_slice(&right[..right_hidden_count]);
+    joined_row.extend_from_slice(&left[left_hidden_count..]);
+    joined_row.extend_from_slice(&right[right_hidden_count..]);
+    joined_row
+}
+
+fn null_values(count: usize) -> Vec<Box<dyn Value>> {
+    let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(count);
+    for _ in 0..count {
+        values.push(Box::new(NullValue));
+    }
+    values
+}
@@ -52,55 +52,66 @@ pub(crate) fn execute_order_by_statement(
     }
 
     main_group.rows.sort_by(|a, b| {
-        for arg_index in 0..arguments_len {
-            let argument = &statement.arguments[arg_index];
-            // No need to compare if the ordering argument is constants
-            if argument.is_const() {
-                continue;
-            }
+        // Use the Memory address of A, B as Map keys
+        let a_addr = a.values.as_ptr() as usize;
+        let b_addr = b.values.as_ptr() as usize;
+
+        // Get pre evaluated values from the eval map using addr as key
+        let a_values = eval_map.get(&a_addr).unwrap();
+        let b_values = eval_map.get(&b_addr).unwrap();
+        compare_ordering_values(statement, a_values, b_values)
+    });
 
-            // Use the Memory address of A, B as Map keys
-            let a_addr = a.values.as_ptr() as usize;
-            let b_addr = b.values.as_ptr() as usize;
-
-            // Get pre evaluated values from the eval map using addr as key, arg index as offset
-            let a_value = &eval_map.get(&a_addr).unwrap()[arg_index];
-            let b_value = &eval_map.get(&b_addr).unwrap()[arg_index];
-
-            let null_ordering_policy = &statement.nulls_order_policies[arg_index];
-            if a_value.is_null() {
-                return if null_ordering_policy.eq(&NullsOrderPolicy::NullsFirst) {
-                    Ordering::Less
-                } else {
-                    Ordering::Greater
-                };
-            }
+    Ok(())
+}
 
-            if b_value.is_null() {
-                return if null_ordering_policy.eq(&NullsOrderPolicy::NullsFirst) {
-                    Ordering::Greater
-                } else {
-                    Ordering::Less
-                };
-            }
+/// Compare two rows using their pre evaluated values of the ordering arguments
+pub(crate) fn compare_ordering_values(
+    statement: &OrderByStatement,
+    a_values: &[Box<dyn Value>],
+    b_values: &[Box<dyn Value>],
+) -> Ordering {
+    for arg_index in 0..statement.arguments.len() {
+        let argument = &statement.arguments[arg_index];
+        // No need to compare if the ordering argument is constants
+        if argument.is_const() {
+            continue;
+        }
 
-            // Calculate the ordering
-            if let Some(order) = a_value.compare(b_value) {
-                if order == Ordering::Equal {
-                    continue;
-                }
-
-                // Reverse the order if DESC order
-                return if statement.sorting_orders[arg_index] == SortingOrder::Descending {
-                    order.reverse()
-                } else {
-                    order
-                };
-            }
+        let a_value = &a_values[arg_index];
+        let b_value = &b_values[arg_index];
+
+        let null_ordering_policy = &statement.nulls_order_policies[arg_index];
+        if a_value.is_null() {
+            return if null_ordering_policy.eq(&NullsOrderPolicy::NullsFirst) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
         }
 
-        Ordering::Equal
-    });
+        if b_value.is_null() {
+            return if null_ordering_policy.eq(&NullsOrderPolicy::NullsFirst) {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+        }
 
-    Ok(())
+        // Calculate the ordering
+        if let Some(order) = a_value.compare(b_value) {
+            if order == Ordering::Equal {
+                continue;
+            }
+
+            // Reverse the order if DESC order
+            return if statement.sorting_orders[arg_index] == SortingOrder::Descending {
+                order.reverse()
+            } else {
+                order
+            };
+        }
+    }
+
+    Ordering::Equal
 }
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use gitql_ast::expression::Expr;
+use gitql_ast::statement::Distinct;
+use gitql_ast::statement::GQLQuery;
+use gitql_ast::statement::LimitStatement;
+use gitql_ast::statement::OffsetStatement;
+use gitql_ast::statement::SelectStatement;
+use gitql_ast::statement::WhereStatement;
+use gitql_core::environment::Environment;
+use gitql_core::object::Row;
+
+use crate::data_provider::DataProvider;
+use crate::data_provider::RowsBatchIterator;
+use crate::engine_executor::execute_expression_selection;
+use crate::engine_executor::resolve_actual_column_name;
+use crate::engine_explain::selected_columns_with_hidden;
+use crate::engine_explain::StageStats;
+use crate::engine_filter::apply_filter_operation;
+
+/// Statements that can be executed on each batch of rows independently
+const STREAMABLE_STATEMENTS: [&str; 4] = ["select", "where", "offset", "limit"];
+
+/// Pull based stream of the selected rows, rows are loaded from the provider and pass through
+/// projection, filter, offset and limit one batch at a time only when the next batch is requested
+pub struct RowsStream<'a> {
+    /// Titles of the result rows without hidden selections
+    pub titles: Vec<String>,
+
+    env: &'a mut Environment,
+    batches: RowsBatchIterator<'a>,
+
+    object_titles: Vec<String>,
+    selected_expr_titles: Vec<String>,
+    selected_expr: Vec<Box<dyn Expr>>,
+    condition: Option<Box<dyn Expr>>,
+
+    remaining_offset: usize,
+    remaining_limit: Option<usize>,
+    hidden_positions: Vec<usize>,
+    is_finished: bool,
+
+    scan_stats: StageStats,
+    projection_stats: StageStats,
+    where_stats: StageStats,
+    offset_stats: StageStats,
+    limit_stats: StageStats,
+}
+
+impl Iterator for RowsStream<'_> {
+    type Item = Result<Vec<Row>, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.is_finished {
+            // No need to load more rows from the provider after reaching the limit
+            if self.remaining_limit == Some(0) {
+                self.is_finished = true;
+                break;
+            }
+
+            let scan_start = Instant::now();
+            let rows = match self.batches.next() {
+                Some(Ok(rows)) => {
+                    let stats = StageStats::new(scan_start, rows.len(), rows.len());
+                    self.scan_stats.merge(stats);
+                    rows
+                }
+                Some(Err(error)) => {
+                    self.is_finished = true;
+                    return Some(Err(error));
+                }
+                None => {
+                    self.is_finished = true;
+                    break;
+                }
+            };
+
+            match self.execute_batch(rows) {
+                Ok(rows) if rows.is_empty() => continue,
+                Ok(rows) => return Some(Ok(rows)),
+                Err(error) => {
+                    self.is_finished = true;
+                    return Some(Err(error));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl RowsStream<'_> {
+    fn execute_batch(&mut self, mut rows: Vec<Row>) -> Result<Vec<Row>, String> {
+        // Execute Selected expressions if exists
+        if !self.selected_expr.is_empty() {
+            let projection_start = Instant::now();
+            execute_expression_selection(
+                self.env,
+                &mut rows,
+                &self.object_titles,
+                &self.selected_expr_titles,
+                &self.selected_expr,
+            )?;
+
+            let stats = StageStats::new(projection_start, rows.len(), rows.len());
+            self.projection_stats.merge(stats);
+        }
+
+        if let Some(condition) = &self.condition {
+            let where_start = Instant::now();
+            let input_rows = rows.len();
+            apply_filter_operation(self.env, condition, &self.object_titles, &mut rows)?;
+            self.where_stats
+                .merge(StageStats::new(where_start, input_rows, rows.len()));
+        }
+
+        if self.remaining_offset > 0 {
+            let offset_start = Instant::now();
+            let input_rows = rows.len();
+            let skipped_count = self.remaining_offset.min(rows.len());
+            rows.drain(0..skipped_count);
+            self.remaining_offset -= skipped_count;
+            self.offset_stats
+                .merge(StageStats::new(offset_start, input_rows, rows.len()));
+        }
+
+        if let Some(remaining_limit) = &mut self.remaining_limit {
+            let limit_start = Instant::now();
+            let input_rows = rows.len();
+            rows.truncate(*remaining_limit);
+            *remaining_limit -= rows.len();
+            self.limit_stats
+                .merge(StageStats::new(limit_start, input_rows, rows.len()));
+        }
+
+        // Remove Hidden Selection from the rows, positions are sorted from last to first
+        if !self.hidden_positions.is_empty() {
+            for row in rows.iter_mut() {
+                for position in self.hidden_positions.iter() {
+                    row.values.remove(*position);
+                }
+            }
+        }
+
+        Ok(rows)
+    }
+
+    /// Return the time and rows count of one stage for all the batches executed so far
+    pub(crate) fn stage_stats(&self, stage: &str) -> StageStats {
+        match stage {
+            "scan" => self.scan_stats,
+            "projection" => self.projection_stats,
+            "where" => self.where_stats,
+            "offset" => self.offset_stats,
+            "limit" => self.limit_stats,
+            _ => StageStats::default(),
+        }
+    }
+}
+
+/// Check if the query can be executed as a stream of rows, without blocking statements
+/// such as joins, group by, aggregations, window functions, ordering, distinct or views
+pub(crate) fn is_streamable_query(env: &Environment, query: &GQLQuery) -> bool {
+    if query.has_aggregation_function || query.has_group_by_statement {
+        return false;
+    }
+
+    if !query
+        .statements
+        .keys()
+        .all(|key| STREAMABLE_STATEMENTS.contains(key))
+    {
+        return false;
+    }
+
+    let Some(statement) = query.statements.get("select") else {
+        return false;
+    };
+
+    let select = statement
+        .as_any()
+        .downcast_ref::<SelectStatement>()
+        .unwrap();
+
+    select.joins.is_empty()
+        && select.unnests.is_empty()
+        && select.table_selections.len() == 1
+        && !select.table_selections[0].table_name.is_empty()
+        && !env.is_view(&select.table_selections[0].table_name)
+        && matches!(select.distinct, Distinct::None)
+}
+
+#[allow(clippy::borrowed_box)]
+pub(crate) fn create_rows_stream<'a>(
+    env: &'a mut Environment,
+    data_provider: &'a Box<dyn DataProvider>,
+    query: GQLQuery,
+) -> Result<RowsStream<'a>, String> {
+    let statements = query.statements;
+    let select = statements["select"]
+        .as_any()
+        .downcast_ref::<SelectStatement>()
+        .unwrap();
+
+    let table_selection = &select.table_selections[0];
+    let table_name = &table_selection.table_name;
+
+    // Insert Hidden selection items for this table first
+    let hidden_selections_map: HashMap<String, Vec<String>> = query.hidden_selections;
+    let selected_columns = selected_columns_with_hidden(table_selection, &hidden_selections_map);
+
+    let mut object_titles: Vec<String> = Vec::with_capacity(selected_columns.len());
+    for selected_column in selected_columns.iter() {
+        object_titles.push(resolve_actual_column_name(
+            &query.alias_table,
+            selected_column,
+        ));
+    }
+
+    let hidden_selections: Vec<&String> = hidden_selections_map.values().flatten().collect();
+    let mut titles: Vec<String> = vec![];
+    let mut hidden_positions: Vec<usize> = vec![];
+    for (index, title) in object_titles.iter().enumerate() {
+        if hidden_selections.contains(&title) {
+            hidden_positions.push(index);
+        } else {
+            titles.push(title.to_string());
+        }
+    }
+    hidden_positions.reverse();
+
+    let condition = statements.get("where").map(|statement| {
+        let statement = statement.as_any().downcast_ref::<WhereStatement>().unwrap();
+        statement.condition.clone()
+    });
+
+    let remaining_offset = statements.get("offset").map_or(0, |statement| {
+        let statement = statement
+            .as_any()
+            .downcast_ref::<OffsetStatement>()
+            .unwrap();
+        statement.count
+    });
+
+    let remaining_limit = statements.get("limit").map(|statement| {
+        let statement = statement.as_any().downcast_ref::<LimitStatement>().unwrap();
+        statement.count
+    });
+
+    let batches = data_provider.provide_batches(table_name, &selected_columns)?;
+
+    Ok(RowsStream {
+        titles,
+        env,
+        batches,
+        object_titles,
+        selected_expr_titles: select.selected_expr_titles.to_owned(),
+        selected_expr: select.selected_expr.to_owned(),
+        condition,
+        remaining_offset,
+        remaining_limit,
+        hidden_positions,
+        is_finished: false,
+        scan_stats: StageStats::default(),
+        projection_stats: StageStats::default(),
+        where_stats: StageStats::default(),
+        offset_stats: StageStats::default(),
+        limit_stats: StageStats::default(),
+    })
+}
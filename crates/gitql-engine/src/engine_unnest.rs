@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use gitql_ast::statement::UnnestSelection;
+use gitql_core::environment::Environment;
+use gitql_core::object::Row;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::null::NullValue;
+use gitql_core::values::Value;
+
+use crate::engine_evaluator::evaluate_expression;
+use crate::engine_executor::resolve_actual_column_name;
+use crate::engine_explain::describe_unnest;
+use crate::engine_explain::QueryProfile;
+use crate::engine_explain::StageStats;
+
+/// Expand each row into one row for each element of the `UNNEST` values
+pub(crate) fn apply_unnest_operation(
+    env: &mut Environment,
+    unnests: &[UnnestSelection],
+    alias_table: &HashMap<String, String>,
+    titles: &[String],
+    rows: &mut Vec<Row>,
+    mut profile: Option<&mut QueryProfile>,
+) -> Result<(), String> {
+    for unnest in unnests {
+        let unnest_start = Instant::now();
+        let input_rows = rows.len();
+
+        let element_name = resolve_actual_column_name(alias_table, &unnest.element_column);
+        let element_index = titles.iter().position(|title| title.eq(&element_name));
+
+        let ordinality_index = unnest.ordinality_column.as_ref().and_then(|name| {
+            let ordinality_name = resolve_actual_column_name(alias_table, name);
+            titles.iter().position(|title| title.eq(&ordinality_name))
+        });
+
+        let mut expanded_rows: Vec<Row> = Vec::with_capacity(rows.len());
+        for mut row in rows.drain(..) {
+            // Columns that defined by `UNNEST` are not provided by the tables if there is no table
+            if row.values.len() < titles.len() {
+                row.values.resize_with(titles.len(), || Box::new(NullValue));
+            }
+
+            let value = evaluate_expression(env, &unnest.expression, titles, &row.values)?;
+            let elements = if value.is_null() {
+                vec![]
+            } else {
+                value.unnest_op()?
+            };
+
+            // Keep the row with `NULL` element only for `LEFT JOIN UNNEST`
+            if elements.is_empty() {
+                if unnest.is_outer {
+                    expanded_rows.push(row);
+                }
+                continue;
+            }
+
+            for (index, element) in elements.into_iter().enumerate() {
+                let mut expanded_row = row.clone();
+                if let Some(element_index) = element_index {
+                    expanded_row.values[element_index] = element;
+                }
+
+                if let Some(ordinality_index) = ordinality_index {
+                    let ordinality: Box<dyn Value> = Box::new(IntValue::new(index as i64 + 1));
+                    expanded_row.values[ordinality_index] = ordinality;
+                }
+
+                expanded_rows.push(expanded_row);
+            }
+        }
+
+        *rows = expanded_rows;
+
+        if let Some(profile) = profile.as_deref_mut() {
+            let stats = StageStats::new(unnest_start, input_rows, rows.len());
+            profile.push("unnest", describe_unnest(unnest), stats);
+        }
+    }
+
+    Ok(())
+}
@@ -1,14 +1,29 @@
 use std::collections::HashMap;
 
+use gitql_ast::expression::Expr;
 use gitql_ast::statement::GroupByStatement;
+use gitql_ast::statement::SortingOrder;
 use gitql_ast::statement::WindowDefinition;
+use gitql_ast::statement::WindowFrameBound;
+use gitql_ast::statement::WindowFrameClause;
+use gitql_ast::statement::WindowFrameKind;
 use gitql_ast::statement::WindowFunctionKind;
 use gitql_ast::statement::WindowFunctionsStatement;
 use gitql_ast::statement::WindowValue;
 use gitql_core::environment::Environment;
 use gitql_core::object::GitQLObject;
+use gitql_core::object::Row;
+use gitql_core::values::date::DateValue;
+use gitql_core::values::datetime::DateTimeValue;
+use gitql_core::values::datetimetz::DateTimeTzValue;
+use gitql_core::values::float::FloatValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::interval::IntervalValue;
+use gitql_core::values::null::NullValue;
+use gitql_core::values::Value;
 
 use crate::engine_evaluator::evaluate_expression;
+use crate::engine_executor::call_aggregation_function;
 use crate::engine_executor::resolve_actual_column_name;
 use crate::engine_group::execute_group_by_statement;
 use crate::engine_ordering::execute_order_by_statement;
@@ -47,19 +62,51 @@ pub(crate) fn execute_window_functions_statement(
                 &function.window_definition,
             )?;
 
+            // Pure window functions receive missing optional arguments as Null
+            // followed by the values of the window ordering arguments
+            let is_pure_window_function =
+                matches!(function.kind, WindowFunctionKind::PureWindowFunction);
+            let mut args_len = function.arguments.len();
+            let mut ordering_arguments: &[Box<dyn Expr>] = &[];
+            if is_pure_window_function {
+                if let Some(signature) = env.window_function_signature(&function.function_name) {
+                    args_len = args_len.max(signature.parameters.len());
+                }
+
+                if let Some(ordering_clause) = &function.window_definition.ordering_clause {
+                    ordering_arguments = &ordering_clause.order_by.arguments;
+                }
+            }
+
             // Run window function on each group
-            let args_len = function.arguments.len();
             for frame_index in 0..gitql_object.len() {
                 let mut frame_values = Vec::with_capacity(rows_len);
                 let frame = &mut gitql_object.groups[frame_index];
                 for row in frame.rows.iter_mut() {
-                    let mut row_selected_values = Vec::with_capacity(args_len);
+                    let mut row_selected_values =
+                        Vec::with_capacity(args_len + ordering_arguments.len());
                     for argument in function.arguments.iter() {
                         let argument =
                             evaluate_expression(env, argument, &gitql_object.titles, &row.values)?;
                         row_selected_values.push(argument);
                     }
 
+                    if is_pure_window_function {
+                        while row_selected_values.len() < args_len {
+                            row_selected_values.push(Box::new(NullValue));
+                        }
+
+                        for argument in ordering_arguments.iter() {
+                            let argument = evaluate_expression(
+                                env,
+                                argument,
+                                &gitql_object.titles,
+                                &row.values,
+                            )?;
+                            row_selected_values.push(argument);
+                        }
+                    }
+
                     frame_values.push(row_selected_values);
                 }
 
@@ -70,16 +117,48 @@ pub(crate) fn execute_window_functions_statement(
                 // Evaluate function for this frame
                 match function.kind {
                     WindowFunctionKind::AggregatedWindowFunction => {
-                        let aggregation_function =
-                            env.aggregation_function(&function.function_name).unwrap();
-                        let aggregated_value = aggregation_function(&frame_values);
-                        for row in frame.rows.iter_mut() {
-                            row.values[column_index] = aggregated_value.clone();
+                        let function_name = &function.function_name;
+
+                        // Without frame clause the aggregation is evaluated on the whole partition
+                        let Some(frame_clause) = &function.window_definition.frame_clause else {
+                            let aggregated_value =
+                                call_aggregation_function(env, function_name, &frame_values)?;
+                            for row in frame.rows.iter_mut() {
+                                row.values[column_index] = aggregated_value.clone();
+                            }
+                            continue;
+                        };
+
+                        let frame_bounds = resolve_window_frame_bounds(
+                            env,
+                            frame_clause,
+                            &function.window_definition,
+                            &gitql_object.titles,
+                            &frame.rows,
+                        )?;
+
+                        for (index, (start, end)) in frame_bounds.into_iter().enumerate() {
+                            frame.rows[index].values[column_index] = if start < end {
+                                call_aggregation_function(
+                                    env,
+                                    function_name,
+                                    &frame_values[start..end],
+                                )?
+                            } else {
+                                Box::new(NullValue)
+                            };
                         }
                     }
                     WindowFunctionKind::PureWindowFunction => {
-                        let window_function = env.window_function(&function.function_name).unwrap();
-                        let window_values = window_function(&frame_values);
+                        let window_values =
+                            match env.fallible_window_function(&function.function_name) {
+                                Some(window_function) => window_function(&frame_values)?,
+                                None => {
+                                    let window_function =
+                                        env.window_function(&function.function_name).unwrap();
+                                    window_function(&frame_values)
+                                }
+                            };
                         for (index, value) in window_values.iter().enumerate() {
                             frame.rows[index].values[column_index] = value.clone();
                         }
@@ -124,7 +203,7 @@ fn apply_window_definition_on_gitql_object(
     if let Some(partition_by) = &window_definition.partitioning_clause {
         let group_by = GroupByStatement {
             values: vec![partition_by.expr.clone()],
-            has_with_roll_up: false,
+            grouping_sets: vec![vec![0]],
         };
         execute_group_by_statement(env, &group_by, gitql_object)?;
     }
@@ -136,6 +215,198 @@ fn apply_window_definition_on_gitql_object(
         }
     }
 
-    // TODO: Convert groups into window frames
     Ok(())
 }
+
+/// Resolve the start (inclusive) and the end (exclusive) indices of the window frame
+/// for each row in the partition, rows are expected to be already ordered
+fn resolve_window_frame_bounds(
+    env: &mut Environment,
+    frame_clause: &WindowFrameClause,
+    window_definition: &WindowDefinition,
+    titles: &[String],
+    rows: &[Row],
+) -> Result<Vec<(usize, usize)>, String> {
+    let rows_len = rows.len();
+    let ordering = window_definition.ordering_clause.as_ref();
+
+    // Evaluate the ordering values of each row to find peers and range offsets
+    let mut ordering_values: Vec<Vec<Box<dyn Value>>> = Vec::with_capacity(rows_len);
+    for row in rows.iter() {
+        let mut row_ordering_values = vec![];
+        if let Some(ordering) = ordering {
+            for argument in ordering.order_by.arguments.iter() {
+                row_ordering_values.push(evaluate_expression(env, argument, titles, &row.values)?);
+            }
+        }
+        ordering_values.push(row_ordering_values);
+    }
+
+    // Peer rows have equal ordering values, without ordering all rows are peers
+    let mut peers_bounds: Vec<(usize, usize)> = Vec::with_capacity(rows_len);
+    let mut peers_start = 0;
+    while peers_start < rows_len {
+        let mut peers_end = peers_start + 1;
+        while peers_end < rows_len
+            && ordering_values[peers_start]
+                .iter()
+                .zip(ordering_values[peers_end].iter())
+                .all(|(first, second)| first.equals(second))
+        {
+            peers_end += 1;
+        }
+
+        for _ in peers_start..peers_end {
+            peers_bounds.push((peers_start, peers_end));
+        }
+        peers_start = peers_end;
+    }
+
+    // Range keys are the first ordering value as number, and negated for descending order
+    // so keys of non null values are always sorted ascending
+    let mut range_keys: Vec<Option<f64>> = Vec::with_capacity(rows_len);
+    if frame_clause.kind == WindowFrameKind::Range {
+        let is_descending = ordering.is_some_and(|ordering| {
+            ordering.order_by.sorting_orders[0] == SortingOrder::Descending
+        });
+
+        for values in ordering_values.iter() {
+            let key = values.first().and_then(|value| range_key(value.as_ref()));
+            range_keys.push(key.map(|key| if is_descending { -key } else { key }));
+        }
+    }
+
+    let mut frame_bounds = Vec::with_capacity(rows_len);
+    for (index, row) in rows.iter().enumerate() {
+        let start = resolve_window_frame_bound(
+            env,
+            frame_clause,
+            &frame_clause.start,
+            true,
+            index,
+            &peers_bounds,
+            &range_keys,
+            titles,
+            row,
+        )?;
+
+        let end = resolve_window_frame_bound(
+            env,
+            frame_clause,
+            &frame_clause.end,
+            false,
+            index,
+            &peers_bounds,
+            &range_keys,
+            titles,
+            row,
+        )?;
+
+        frame_bounds.push((start, end.max(start)));
+    }
+
+    Ok(frame_bounds)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn resolve_window_frame_bound(
+    env: &mut Environment,
+    frame_clause: &WindowFrameClause,
+    bound: &WindowFrameBound,
+    is_start: bool,
+    index: usize,
+    peers_bounds: &[(usize, usize)],
+    range_keys: &[Option<f64>],
+    titles: &[String],
+    row: &Row,
+) -> Result<usize, String> {
+    let rows_len = peers_bounds.len();
+    let (offset, is_preceding) = match bound {
+        WindowFrameBound::UnboundedPreceding => return Ok(0),
+        WindowFrameBound::UnboundedFollowing => return Ok(rows_len),
+        WindowFrameBound::CurrentRow => {
+            return Ok(match (&frame_clause.kind, is_start) {
+                (WindowFrameKind::Rows, true) => index,
+                (WindowFrameKind::Rows, false) => index + 1,
+                (WindowFrameKind::Range, true) => peers_bounds[index].0,
+                (WindowFrameKind::Range, false) => peers_bounds[index].1,
+            });
+        }
+        WindowFrameBound::Preceding(offset) => (offset, true),
+        WindowFrameBound::Following(offset) => (offset, false),
+    };
+
+    let offset = evaluate_expression(env, offset, titles, &row.values)?;
+    let offset = range_key(offset.as_ref())
+        .filter(|offset| *offset >= 0.0)
+        .ok_or("Window frame offset must not be null or negative")?;
+
+    if frame_clause.kind == WindowFrameKind::Rows {
+        let offset = offset as usize;
+        let position = if is_preceding {
+            index.saturating_sub(offset)
+        } else {
+            index.saturating_add(offset)
+        };
+        let position = if is_start { position } else { position + 1 };
+        return Ok(position.min(rows_len));
+    }
+
+    // Offset from a row with Null ordering value select only its peers
+    let Some(current_key) = range_keys[index] else {
+        let (peers_start, peers_end) = peers_bounds[index];
+        return Ok(if is_start { peers_start } else { peers_end });
+    };
+
+    let target_key = if is_preceding {
+        current_key - offset
+    } else {
+        current_key + offset
+    };
+
+    // Rows with non null keys are contiguous and sorted ascending
+    let keys_start = range_keys.iter().position(|key| key.is_some()).unwrap();
+    let keys_end = keys_start
+        + range_keys[keys_start..]
+            .iter()
+            .position(|key| key.is_none())
+            .unwrap_or(rows_len - keys_start);
+
+    let keys = &range_keys[keys_start..keys_end];
+    let position = if is_start {
+        keys.partition_point(|key| key.unwrap() < target_key)
+    } else {
+        keys.partition_point(|key| key.unwrap() <= target_key)
+    };
+    Ok(keys_start + position)
+}
+
+/// Numeric representation of the range ordering values and offsets,
+/// Date, DateTime and DateTimeTz are represented as seconds and Interval as number of seconds
+fn range_key(value: &dyn Value) -> Option<f64> {
+    if let Some(value) = value.as_any().downcast_ref::<IntValue>() {
+        return Some(value.value as f64);
+    }
+
+    if let Some(value) = value.as_any().downcast_ref::<FloatValue>() {
+        return Some(value.value);
+    }
+
+    if let Some(value) = value.as_any().downcast_ref::<DateTimeValue>() {
+        return Some(value.value as f64);
+    }
+
+    if let Some(value) = value.as_any().downcast_ref::<DateValue>() {
+        return Some(value.timestamp as f64);
+    }
+
+    if let Some(value) = value.as_any().downcast_ref::<DateTimeTzValue>() {
+        return Some(value.value as f64);
+    }
+
+    if let Some(value) = value.as_any().downcast_ref::<IntervalValue>() {
+        return Some(value.interval.to_seconds() as f64);
+    }
+
+    None
+}
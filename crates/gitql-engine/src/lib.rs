@@ -3,9 +3,13 @@ pub mod engine;
 pub mod engine_distinct;
 pub mod engine_evaluator;
 pub mod engine_executor;
+pub mod engine_explain;
 pub mod engine_filter;
 pub mod engine_group;
 pub mod engine_join;
 pub mod engine_ordering;
 pub mod engine_output_into;
+pub mod engine_stream;
+pub mod engine_unnest;
 pub mod engine_window_functions;
+pub mod prepared_query;
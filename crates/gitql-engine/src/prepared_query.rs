@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use gitql_ast::statement::Query;
+use gitql_ast::types::DataType;
+use gitql_core::environment::Environment;
+use gitql_core::values::Value;
+use gitql_parser::diagnostic::Diagnostic;
+use gitql_parser::parser;
+use gitql_parser::tokenizer::Tokenizer;
+
+use crate::data_provider::DataProvider;
+use crate::engine::evaluate;
+use crate::engine::EvaluationResult;
+
+/// Parsed queries that can be executed many times with different parameters values
+///
+/// The placeholders types are resolved from the values bound while parsing,
+/// if the next execution binds values with other types the query is parsed again
+pub struct PreparedQuery {
+    query: String,
+    queries: Vec<Query>,
+    parameters_types: HashMap<String, Box<dyn DataType>>,
+}
+
+impl PreparedQuery {
+    /// Tokenize and parse the query using the parameters bound in the environment
+    pub fn new(env: &mut Environment, query: &str) -> Result<Self, Box<Diagnostic>> {
+        let queries = parse_query(env, query)?;
+        Ok(PreparedQuery {
+            query: query.to_string(),
+            queries,
+            parameters_types: parameters_types(env),
+        })
+    }
+
+    /// Bind the parameters values and evaluate the queries
+    #[allow(clippy::borrowed_box)]
+    pub fn execute(
+        &mut self,
+        env: &mut Environment,
+        data_provider: &Box<dyn DataProvider>,
+        parameters: HashMap<String, Box<dyn Value>>,
+    ) -> Result<Vec<EvaluationResult>, String> {
+        let types_changed = parameters.iter().any(|(name, value)| {
+            let value_type = value.data_type();
+            !value_type.is_null()
+                && self
+                    .parameters_types
+                    .get(name)
+                    .is_none_or(|expected_type| !expected_type.equals(&value_type))
+        });
+
+        env.parameters.extend(parameters);
+
+        if types_changed {
+            self.queries = parse_query(env, &self.query)
+                .map_err(|diagnostic| diagnostic.message().to_string())?;
+            self.parameters_types = parameters_types(env);
+        }
+
+        evaluate(env, data_provider, self.queries.clone())
+    }
+}
+
+fn parse_query(env: &mut Environment, query: &str) -> Result<Vec<Query>, Box<Diagnostic>> {
+    let tokens = Tokenizer::tokenize(query.to_string())?;
+    parser::parse_gql(tokens, env)
+}
+
+fn parameters_types(env: &Environment) -> HashMap<String, Box<dyn DataType>> {
+    env.parameters
+        .iter()
+        .filter(|(_, value)| !value.data_type().is_null())
+        .map(|(name, value)| (name.to_string(), value.data_type()))
+        .collect()
+}
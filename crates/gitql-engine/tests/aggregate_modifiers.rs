@@ -0,0 +1,173 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::text::TextType;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::null::NullValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+fn create_session() -> TestSession {
+    let commits = [
+        ("first", "a@gql", 3, Some("A")),
+        ("second", "b@gql", 1, Some("M")),
+        ("third", "a@gql", 5, Some("A")),
+        ("fourth", "c@gql", 2, None),
+    ];
+
+    let rows: Vec<Vec<Box<dyn Value>>> = commits
+        .iter()
+        .enumerate()
+        .map(
+            |(index, (title, email, insertions, mode))| -> Vec<Box<dyn Value>> {
+                let mode: Box<dyn Value> = match mode {
+                    Some(mode) => Box::new(TextValue::new(mode.to_string())),
+                    None => Box::new(NullValue),
+                };
+                vec![
+                    Box::new(TextValue::new(title.to_string())),
+                    Box::new(TextValue::new(email.to_string())),
+                    Box::new(IntValue::new(*insertions)),
+                    mode,
+                    Box::new(IntValue::new(index as i64)),
+                ]
+            },
+        )
+        .collect();
+
+    let provider = MemoryDataProvider::default().with_table(
+        "commits",
+        vec![
+            ("title", Box::new(TextType)),
+            ("author_email", Box::new(TextType)),
+            ("insertions", Box::new(IntType)),
+            ("mode", Box::new(TextType)),
+            ("position", Box::new(IntType)),
+        ],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+#[test]
+fn test_aggregation_distinct() {
+    let mut session = create_session();
+    let cases = [
+        ("SELECT COUNT(author_email) FROM commits", "4"),
+        ("SELECT COUNT(DISTINCT author_email) FROM commits", "3"),
+        ("SELECT SUM(DISTINCT insertions) FROM commits", "11"),
+        (
+            "SELECT GROUP_CONCAT(DISTINCT author_email ORDER BY author_email SEPARATOR ',') FROM commits",
+            "a@gql,b@gql,c@gql",
+        ),
+    ];
+
+    for (query, expected) in cases {
+        assert_eq!(
+            session.execute_value(query),
+            Ok(expected.to_string()),
+            "{query}"
+        );
+    }
+}
+
+#[test]
+fn test_aggregation_order_by_and_separator() {
+    let mut session = create_session();
+    let cases = [
+        (
+            "SELECT GROUP_CONCAT(title ORDER BY position SEPARATOR ', ') FROM commits",
+            "first, second, third, fourth",
+        ),
+        (
+            "SELECT GROUP_CONCAT(title ORDER BY insertions DESC SEPARATOR ', ') FROM commits",
+            "third, first, fourth, second",
+        ),
+        (
+            "SELECT GROUP_CONCAT(title ORDER BY author_email, position DESC SEPARATOR '|') FROM commits",
+            "third|first|second|fourth",
+        ),
+        (
+            "SELECT GROUP_CONCAT(title SEPARATOR '') FROM commits WHERE insertions > 2",
+            "firstthird",
+        ),
+    ];
+
+    for (query, expected) in cases {
+        assert_eq!(
+            session.execute_value(query),
+            Ok(expected.to_string()),
+            "{query}"
+        );
+    }
+}
+
+#[test]
+fn test_aggregation_filter() {
+    let mut session = create_session();
+    let cases = [
+        ("SELECT SUM(insertions) FILTER (WHERE mode = 'A') FROM commits", "8"),
+        ("SELECT COUNT(title) FILTER (WHERE mode IS NULL) FROM commits", "1"),
+        (
+            "SELECT COUNT(DISTINCT author_email) FILTER (WHERE insertions > 1) FROM commits",
+            "2",
+        ),
+        (
+            "SELECT GROUP_CONCAT(title ORDER BY position DESC SEPARATOR ',') FILTER (WHERE mode = 'A') FROM commits",
+            "third,first",
+        ),
+    ];
+
+    for (query, expected) in cases {
+        assert_eq!(
+            session.execute_value(query),
+            Ok(expected.to_string()),
+            "{query}"
+        );
+    }
+}
+
+#[test]
+fn test_aggregation_filter_per_group() {
+    let mut session = create_session();
+    let mut rows = session
+        .execute("SELECT author_email, SUM(insertions) FILTER (WHERE mode = 'A') AS added FROM commits GROUP BY author_email")
+        .unwrap();
+    rows.sort();
+
+    let expected: Vec<Vec<String>> = [["a@gql", "8"], ["b@gql", "0"], ["c@gql", "0"]]
+        .iter()
+        .map(|row| row.iter().map(|value| value.to_string()).collect())
+        .collect();
+    assert_eq!(rows, expected);
+}
+
+#[test]
+fn test_aggregation_modifiers_errors() {
+    let mut session = create_session();
+    let cases = [
+        (
+            "SELECT COUNT(title SEPARATOR ',') FROM commits",
+            "`SEPARATOR` can only be used with `group_concat` function",
+        ),
+        (
+            "SELECT GROUP_CONCAT(title SEPARATOR 1) FROM commits",
+            "Expect String after `SEPARATOR` keyword",
+        ),
+        (
+            "SELECT COUNT(title) FILTER (WHERE insertions) FROM commits",
+            "Expect `FILTER` condition to be type Boolean",
+        ),
+        (
+            "SELECT COUNT(title) FILTER (WHERE COUNT(title) > 1) FROM commits",
+            "Aggregation functions are not allowed in `FILTER` condition",
+        ),
+    ];
+
+    for (query, expected) in cases {
+        let error = session.execute(query).unwrap_err();
+        assert!(error.contains(expected), "{query}: {error}");
+    }
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+
+fn execute_value(query: &str) -> Result<String, String> {
+    TestSession::new(MemoryDataProvider::default()).execute_value(query)
+}
+
+#[test]
+fn test_hex_round_trip() {
+    let result = execute_value("SELECT encode(decode('00ff7f80', 'hex'), 'hex')");
+    assert_eq!(result, Ok("00ff7f80".to_string()));
+
+    let result = execute_value("SELECT decode('\\x68656c6c6f', 'hex')::text");
+    assert_eq!(result, Ok("hello".to_string()));
+}
+
+#[test]
+fn test_base64_round_trip() {
+    let result = execute_value("SELECT encode('hello'::bytes, 'base64')");
+    assert_eq!(result, Ok("aGVsbG8=".to_string()));
+
+    let result = execute_value("SELECT encode(decode('AP9/gA==', 'base64'), 'hex')");
+    assert_eq!(result, Ok("00ff7f80".to_string()));
+}
+
+#[test]
+fn test_invalid_encoded_text() {
+    let result = execute_value("SELECT encode(decode('zz', 'hex'), 'hex')");
+    assert!(result.is_err_and(|error| error.contains("Invalid `hex` encoded text")));
+
+    let result = execute_value("SELECT decode('abc', 'hex')");
+    assert!(result.is_err());
+
+    let result = execute_value("SELECT decode('@@', 'base64')");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_unknown_encoding_format() {
+    let result = execute_value("SELECT upper(encode(CAST('a' AS BYTES), 'bogus'))");
+    assert!(result.is_err_and(|error| error.contains("Unknown encoding format `bogus`")));
+
+    let result = execute_value("SELECT decode('00', 'bogus')");
+    assert!(result.is_err_and(|error| error.contains("Unknown encoding format `bogus`")));
+}
+
+#[test]
+fn test_hashing() {
+    let result = execute_value("SELECT md5('abc')");
+    assert_eq!(result, Ok("900150983cd24fb0d6963f7d28e17f72".to_string()));
+
+    let result = execute_value("SELECT sha1('abc'::bytes)");
+    assert_eq!(
+        result,
+        Ok("a9993e364706816aba3e25717850c26c9cd0d89d".to_string())
+    );
+
+    let result = execute_value("SELECT sha256('')");
+    assert_eq!(
+        result,
+        Ok("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string())
+    );
+}
+
+#[test]
+fn test_substring_bounds() {
+    let result = execute_value("SELECT substring('hello'::bytes, 2, 3)");
+    assert_eq!(result, Ok("\\x656c6c".to_string()));
+
+    let result = execute_value("SELECT substring('hello'::bytes, 0, 100)");
+    assert_eq!(result, Ok("\\x68656c6c6f".to_string()));
+
+    let result = execute_value("SELECT substring('hello'::bytes, 10, 2)");
+    assert_eq!(result, Ok("\\x".to_string()));
+
+    let result = execute_value("SELECT substring('hello'::bytes, 2, -1)");
+    assert_eq!(result, Ok("\\x".to_string()));
+}
+
+#[test]
+fn test_length() {
+    let result = execute_value("SELECT length(decode('/w==', 'base64'))");
+    assert_eq!(result, Ok("1".to_string()));
+}
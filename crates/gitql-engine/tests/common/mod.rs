@@ -0,0 +1,164 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use gitql_ast::types::DataType;
+use gitql_core::environment::Environment;
+use gitql_core::object::Row;
+use gitql_core::schema::Schema;
+use gitql_core::values::null::NullValue;
+use gitql_core::values::Value;
+use gitql_engine::data_provider::DataProvider;
+use gitql_engine::engine;
+use gitql_engine::engine::EvaluationResult;
+use gitql_engine::prepared_query::PreparedQuery;
+use gitql_parser::parser;
+use gitql_parser::tokenizer::Tokenizer;
+use gitql_std::aggregation::aggregation_function_signatures;
+use gitql_std::aggregation::aggregation_functions;
+use gitql_std::aggregation::fallible_aggregation_functions;
+use gitql_std::standard::fallible_functions;
+use gitql_std::standard::higher_order_functions;
+use gitql_std::standard::standard_function_signatures;
+use gitql_std::standard::standard_functions;
+use gitql_std::window::fallible_window_functions;
+use gitql_std::window::window_function_signatures;
+use gitql_std::window::window_functions;
+
+/// In memory table with the columns names, types and rows
+struct MemoryTable {
+    columns: Vec<(&'static str, Box<dyn DataType>)>,
+    rows: Vec<Vec<Box<dyn Value>>>,
+}
+
+/// Data provider for the tests that provides the rows from in memory tables
+#[derive(Default)]
+pub struct MemoryDataProvider {
+    tables: HashMap<&'static str, MemoryTable>,
+}
+
+impl MemoryDataProvider {
+    pub fn with_table(
+        mut self,
+        name: &'static str,
+        columns: Vec<(&'static str, Box<dyn DataType>)>,
+        rows: Vec<Vec<Box<dyn Value>>>,
+    ) -> Self {
+        self.tables.insert(name, MemoryTable { columns, rows });
+        self
+    }
+
+    /// Create environment with the standard library and the schema of the tables
+    pub fn create_environment(&self) -> Environment {
+        let mut tables_fields_names = HashMap::new();
+        let mut tables_fields_types = HashMap::new();
+        for (name, table) in self.tables.iter() {
+            let mut fields_names = vec![];
+            for (column, data_type) in table.columns.iter() {
+                fields_names.push(*column);
+                tables_fields_types.insert(*column, data_type.clone());
+            }
+            tables_fields_names.insert(*name, fields_names);
+        }
+
+        let schema = Schema {
+            tables_fields_names,
+            tables_fields_types,
+        };
+
+        let mut env = Environment::new(schema);
+        env.with_standard_functions(&standard_function_signatures(), standard_functions());
+        env.with_fallible_functions(fallible_functions());
+        env.with_higher_order_functions(higher_order_functions());
+        env.with_aggregation_functions(&aggregation_function_signatures(), aggregation_functions());
+        env.with_fallible_aggregation_functions(fallible_aggregation_functions());
+        env.with_window_functions(&window_function_signatures(), window_functions());
+        env.with_fallible_window_functions(fallible_window_functions());
+        env
+    }
+}
+
+impl DataProvider for MemoryDataProvider {
+    fn provide(&self, table: &str, selected_columns: &[String]) -> Result<Vec<Row>, String> {
+        let table = self
+            .tables
+            .get(table)
+            .ok_or(format!("Unknown table `{}`", table))?;
+
+        let mut rows = Vec::with_capacity(table.rows.len());
+        for values in table.rows.iter() {
+            let mut row_values: Vec<Box<dyn Value>> = Vec::with_capacity(selected_columns.len());
+            // Columns that are not in the table are evaluated later by the engine
+            for column in selected_columns {
+                match table.columns.iter().position(|(name, _)| name == column) {
+                    Some(index) => row_values.push(values[index].clone()),
+                    None => row_values.push(Box::new(NullValue)),
+                }
+            }
+            rows.push(Row { values: row_values });
+        }
+        Ok(rows)
+    }
+}
+
+/// Environment and data provider shared between the queries of the test
+pub struct TestSession {
+    pub env: Environment,
+    provider: Box<dyn DataProvider>,
+}
+
+impl TestSession {
+    pub fn new(provider: MemoryDataProvider) -> Self {
+        TestSession {
+            env: provider.create_environment(),
+            provider: Box::new(provider),
+        }
+    }
+
+    /// Tokenize, parse and evaluate the queries, the result is the literals of the visible columns
+    /// of the last select query rows, or the literal of the last `DO` query value
+    pub fn execute(&mut self, query: &str) -> Result<Vec<Vec<String>>, String> {
+        let tokens = Tokenizer::tokenize(query.to_string())
+            .map_err(|diagnostic| diagnostic.message().to_string())?;
+        let queries = parser::parse_gql(tokens, &mut self.env)
+            .map_err(|diagnostic| diagnostic.message().to_string())?;
+
+        let results = engine::evaluate(&mut self.env, &self.provider, queries)?;
+        Ok(results_literals(results))
+    }
+
+    /// Execute the prepared query with the parameters, the result is the same as [`TestSession::execute`]
+    pub fn execute_prepared(
+        &mut self,
+        prepared_query: &mut PreparedQuery,
+        parameters: HashMap<String, Box<dyn Value>>,
+    ) -> Result<Vec<Vec<String>>, String> {
+        let results = prepared_query.execute(&mut self.env, &self.provider, parameters)?;
+        Ok(results_literals(results))
+    }
+
+    /// Execute the queries and return the literal of the first column of the first row
+    pub fn execute_value(&mut self, query: &str) -> Result<String, String> {
+        let rows = self.execute(query)?;
+        Ok(rows[0][0].to_string())
+    }
+}
+
+fn results_literals(results: Vec<EvaluationResult>) -> Vec<Vec<String>> {
+    let mut output = vec![];
+    for result in results {
+        match result {
+            EvaluationResult::SelectedGroups(mut object) => {
+                object.flat();
+                output = object.groups[0]
+                    .rows
+                    .iter()
+                    .map(|row| row.values.iter().map(|value| value.literal()).collect())
+                    .collect();
+            }
+            EvaluationResult::Do(value) => output = vec![vec![value.literal()]],
+            _ => {}
+        }
+    }
+    output
+}
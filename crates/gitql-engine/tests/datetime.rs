@@ -0,0 +1,145 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::datetime::DateTimeType;
+use gitql_core::values::datetime::DateTimeValue;
+use gitql_core::values::Value;
+
+/// Commit at `2024-03-03 20:30:00` UTC which is Monday `2024-03-04 05:30:00` in Tokyo
+fn create_commits_session() -> TestSession {
+    let rows: Vec<Vec<Box<dyn Value>>> = vec![vec![Box::new(DateTimeValue::new(1709497800))]];
+    let provider = MemoryDataProvider::default().with_table(
+        "commits",
+        vec![("datetime", Box::new(DateTimeType))],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+fn execute_value(query: &str) -> Result<String, String> {
+    TestSession::new(MemoryDataProvider::default()).execute_value(query)
+}
+
+#[test]
+fn test_date_trunc_unknown_field() {
+    let result = execute_value("SELECT date(date_trunc('bogus', now()))");
+    assert!(result.is_err_and(|error| error.contains("unknown field `bogus`")));
+
+    let result = execute_value("SELECT day(date_trunc('bogus', current_date()))");
+    assert!(result.is_err_and(|error| error.contains("unknown field `bogus`")));
+}
+
+#[test]
+fn test_date_trunc() {
+    let result = execute_value("SELECT date_trunc('month', '2024-03-15 10:20:30'::datetime)");
+    assert_eq!(result, Ok("2024-03-01 00:00:00.000".to_string()));
+}
+
+#[test]
+fn test_date_part_unknown_field() {
+    let result = execute_value("SELECT date_part('bogus', now())");
+    assert!(result.is_err_and(|error| error.contains("unknown field `bogus`")));
+
+    let result = execute_value("SELECT date_part('dow', INTERVAL '1 day')");
+    assert!(result.is_err_and(|error| error.contains("unknown field `dow`")));
+}
+
+#[test]
+fn test_parse_datetime_invalid_text() {
+    let result = execute_value("SELECT parse_datetime('garbage')");
+    assert!(result.is_err_and(|error| error.contains("Invalid date time `garbage`")));
+}
+
+#[test]
+fn test_parse_datetime_git_formats() {
+    let cases = [
+        ("1700000000 +0000", "2023-11-14 22:13:20.000"),
+        ("1700000000 +0200", "2023-11-14 22:13:20.000"),
+        ("@1700000000", "2023-11-14 22:13:20.000"),
+        ("@0", "1970-01-01 00:00:00.000"),
+        ("Fri, 1 Mar 2024 12:00:00 +0200", "2024-03-01 10:00:00.000"),
+        ("Fri, 1 Mar 2024 12:00:00 GMT", "2024-03-01 12:00:00.000"),
+        ("Fri, 1 Mar 2024 12:00:00 EST", "2024-03-01 17:00:00.000"),
+        (
+            "Fri, 1 Mar 2024 12:00:00 +0100 (CET)",
+            "2024-03-01 11:00:00.000",
+        ),
+        ("Fri Mar 1 12:00:00 2024 +0200", "2024-03-01 10:00:00.000"),
+    ];
+
+    for (text, expected) in cases {
+        let result = execute_value(&format!("SELECT parse_datetime('{text}')"));
+        assert_eq!(result, Ok(expected.to_string()), "{text}");
+    }
+}
+
+#[test]
+fn test_parse_datetime_relative_git_formats() {
+    let result = execute_value("SELECT parse_datetime('2 weeks ago') < now()");
+    assert_eq!(result, Ok("true".to_string()));
+
+    let result =
+        execute_value("SELECT parse_datetime('2 weeks ago') > parse_datetime('15 days ago')");
+    assert_eq!(result, Ok("true".to_string()));
+}
+
+#[test]
+fn test_parse_datetime_invalid_git_formats() {
+    for text in [
+        "20240301",
+        "@",
+        "@17000x",
+        "1700000000 +9999",
+        "1700000000 +0000 extra",
+    ] {
+        let result = execute_value(&format!("SELECT parse_datetime('{text}')"));
+        assert!(
+            result
+                .as_ref()
+                .is_err_and(|error| error.contains("Invalid date time")),
+            "{text}: {result:?}"
+        );
+    }
+}
+
+#[test]
+fn test_strftime_invalid_format() {
+    let result = execute_value("SELECT strftime('%Q', now())");
+    assert!(result.is_err_and(|error| error.contains("Invalid `strftime` format")));
+}
+
+#[test]
+fn test_date_time_functions_use_utc_by_default() {
+    let mut session = create_commits_session();
+    let result = session.execute(
+        "SELECT hour(datetime), dayname(datetime), weekofyear(datetime), date(datetime) FROM commits",
+    );
+    let expected = vec![vec!["20", "Sunday", "9", "2024-03-03"]];
+    assert_eq!(result, Ok(to_strings(expected)));
+}
+
+#[test]
+fn test_date_time_functions_use_session_time_zone() {
+    let mut session = create_commits_session();
+    let result = session.execute(
+        "SET @@time_zone = 'Asia/Tokyo'; SELECT hour(datetime), dayname(datetime), weekofyear(datetime), date(datetime), EXTRACT(hour FROM datetime) FROM commits",
+    );
+    let expected = vec![vec!["5", "Monday", "10", "2024-03-04", "5"]];
+    assert_eq!(result, Ok(to_strings(expected)));
+}
+
+#[test]
+fn test_date_trunc_keeps_date_time_in_session_time_zone() {
+    let mut session = create_commits_session();
+    let result = session.execute_value(
+        "SET @@time_zone = 'Asia/Tokyo'; SELECT date_trunc('day', datetime) FROM commits",
+    );
+    assert_eq!(result, Ok("2024-03-03 00:00:00.000".to_string()));
+}
+
+fn to_strings(rows: Vec<Vec<&str>>) -> Vec<Vec<String>> {
+    rows.iter()
+        .map(|row| row.iter().map(|value| value.to_string()).collect())
+        .collect()
+}
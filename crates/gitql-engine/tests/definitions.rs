@@ -0,0 +1,166 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::text::TextType;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+fn create_session() -> TestSession {
+    let rows: Vec<Vec<Box<dyn Value>>> = vec![
+        vec![Box::new(TextValue::new("first".to_string()))],
+        vec![Box::new(TextValue::new("second".to_string()))],
+    ];
+    let provider = MemoryDataProvider::default().with_table(
+        "commits",
+        vec![("title", Box::new(TextType))],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+#[test]
+fn test_replace_function() {
+    let mut session = create_session();
+    let result = session.execute_value(
+        "CREATE FUNCTION f(x INT) RETURNS INT AS x + 1; CREATE OR REPLACE FUNCTION f(x INT) RETURNS INT AS x * 2; SELECT f(3)",
+    );
+    assert_eq!(result, Ok("6".to_string()));
+}
+
+#[test]
+fn test_function_parameters_are_scoped_to_body() {
+    let mut session = create_session();
+    let result = session.execute("CREATE FUNCTION f(x INT) RETURNS INT AS x + 1; SELECT x");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_reject_recursive_function() {
+    let mut session = create_session();
+    let result = session.execute(
+        "CREATE FUNCTION b(x INT) RETURNS INT AS x; CREATE FUNCTION a(x INT) RETURNS INT AS b(x); CREATE OR REPLACE FUNCTION b(x INT) RETURNS INT AS a(x)",
+    );
+    assert!(result.unwrap_err().contains("can't call itself"));
+
+    // The original definition is kept after the rejected replacement
+    assert_eq!(session.execute_value("SELECT a(1)"), Ok("1".to_string()));
+}
+
+#[test]
+fn test_reject_recursive_view() {
+    let mut session = create_session();
+    let result = session.execute(
+        "CREATE VIEW v AS SELECT title FROM commits; CREATE OR REPLACE VIEW v AS SELECT title FROM v",
+    );
+    assert!(result.unwrap_err().contains("can't select from itself"));
+
+    let result = session.execute(
+        "CREATE VIEW w AS SELECT title FROM v; CREATE OR REPLACE VIEW v AS SELECT title FROM w",
+    );
+    assert!(result.unwrap_err().contains("can't select from itself"));
+
+    assert_eq!(
+        session.execute_value("SELECT COUNT() FROM w"),
+        Ok("2".to_string())
+    );
+}
+
+#[test]
+fn test_create_composite_type() {
+    let mut session = create_session();
+    let result = session.execute(
+        "CREATE TYPE change AS (title TEXT, size INT); SELECT title, (ROW(title, 1)::change).title, (ROW('a', 2)::change).size FROM commits",
+    );
+    let expected = vec![
+        vec!["first".to_string(), "first".to_string(), "2".to_string()],
+        vec!["second".to_string(), "second".to_string(), "2".to_string()],
+    ];
+    assert_eq!(result, Ok(expected));
+
+    assert_eq!(
+        session.execute_value("SELECT (ROW('a', 1)).f2"),
+        Ok("1".to_string())
+    );
+
+    let result = session.execute("SELECT ROW('a')::change");
+    assert!(result.unwrap_err().contains("Unsupported `CAST`"));
+}
+
+#[test]
+fn test_create_enum_type() {
+    let mut session = create_session();
+    let result = session.execute_value(
+        "CREATE TYPE mood AS ENUM ('sad', 'ok', 'happy'); SELECT 'ok'::mood < 'happy'::mood",
+    );
+    assert_eq!(result, Ok("true".to_string()));
+
+    let cases = [
+        ("SELECT 'happy'::mood > 'sad'", "true"),
+        ("SELECT CAST('sad' AS mood)::TEXT", "sad"),
+        ("SELECT TRY_CAST('bad' AS mood)", "Null"),
+    ];
+    for (query, expected) in cases {
+        assert_eq!(
+            session.execute_value(query),
+            Ok(expected.to_string()),
+            "{query}"
+        );
+    }
+
+    let result = session.execute("SELECT 'bad'::mood");
+    assert!(result
+        .unwrap_err()
+        .contains("Invalid input value for enum `mood`: `bad`"));
+
+    let result = session.execute("SELECT title, title::mood FROM commits");
+    assert!(result
+        .unwrap_err()
+        .contains("Invalid input value for enum `mood`: `first`"));
+}
+
+#[test]
+fn test_create_type_errors() {
+    let mut session = create_session();
+    let cases = [
+        (
+            "CREATE TYPE t AS (a INT, a TEXT)",
+            "Member `a` is defined more than once",
+        ),
+        (
+            "CREATE TYPE t AS ENUM ('x', 'y', 'x')",
+            "Enum label `x` is defined more than once",
+        ),
+        (
+            "CREATE TYPE int AS (a INT)",
+            "Can't redefine built in type `int`",
+        ),
+        (
+            "CREATE TYPE text AS ENUM ('a')",
+            "Can't redefine built in type `text`",
+        ),
+        (
+            "CREATE TYPE t AS ()",
+            "Composite type must have at least one member",
+        ),
+        (
+            "CREATE TYPE t AS ENUM ()",
+            "Enum type must have at least one label",
+        ),
+    ];
+
+    for (query, expected) in cases {
+        let error = session.execute(query).unwrap_err();
+        assert!(error.contains(expected), "{query}: {error}");
+    }
+}
+
+#[test]
+fn test_replace_type() {
+    let mut session = create_session();
+    let result = session.execute("CREATE TYPE t AS (a INT); CREATE TYPE t AS (b INT)");
+    assert!(result.unwrap_err().contains("Type `t` is already defined"));
+
+    let result = session.execute_value("CREATE OR REPLACE TYPE t AS (b INT); SELECT (ROW(5)::t).b");
+    assert_eq!(result, Ok("5".to_string()));
+}
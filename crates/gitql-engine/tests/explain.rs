@@ -0,0 +1,119 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::text::TextType;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+fn create_session() -> TestSession {
+    let commits = [("a", "first", 1), ("a", "second", 2), ("b", "third", 3)];
+    let rows: Vec<Vec<Box<dyn Value>>> = commits
+        .iter()
+        .map(|(author, title, time)| -> Vec<Box<dyn Value>> {
+            vec![
+                Box::new(TextValue::new(author.to_string())),
+                Box::new(TextValue::new(title.to_string())),
+                Box::new(IntValue::new(*time)),
+            ]
+        })
+        .collect();
+
+    let refs: Vec<Vec<Box<dyn Value>>> = vec![vec![Box::new(TextValue::new("a".to_string()))]];
+    let provider = MemoryDataProvider::default()
+        .with_table(
+            "commits",
+            vec![
+                ("author_name", Box::new(TextType)),
+                ("title", Box::new(TextType)),
+                ("time", Box::new(IntType)),
+            ],
+            rows,
+        )
+        .with_table("refs", vec![("name", Box::new(TextType))], refs);
+    TestSession::new(provider)
+}
+
+/// Return the details and the rest of the columns of the first stage with this name
+fn stage(stages: &[Vec<String>], name: &str) -> Vec<String> {
+    let stage = stages.iter().find(|stage| stage[0] == name).unwrap();
+    stage[1..].to_vec()
+}
+
+#[test]
+fn explain_filter_and_limit() {
+    let mut session = create_session();
+    let query = "EXPLAIN SELECT title FROM commits WHERE author_name = 'a' AND time BETWEEN 1 AND 2 LIMIT 1";
+    let stages = session.execute(query).unwrap();
+    assert_eq!(
+        stage(&stages, "where"),
+        vec!["Filter by author_name = \"a\" AND time BETWEEN 1 AND 2, pushed down into the scan"]
+    );
+    assert_eq!(
+        stage(&stages, "limit"),
+        vec!["Take 1 rows, stop the scan after reaching it"]
+    );
+}
+
+#[test]
+fn explain_expressions_literals() {
+    let mut session = create_session();
+    let query = "EXPLAIN SELECT title FROM commits WHERE CASE WHEN time > 1 THEN title ELSE NULL END IS NOT NULL ORDER BY time DESC";
+    let stages = session.execute(query).unwrap();
+    assert_eq!(
+        stage(&stages, "where"),
+        vec!["Filter by CASE WHEN time > 1 THEN title ELSE NULL END IS NOT NULL"]
+    );
+    assert_eq!(
+        stage(&stages, "order"),
+        vec!["ORDER BY time DESC NULLS FIRST"]
+    );
+
+    let query = "EXPLAIN SELECT title FROM commits WHERE author_name IN ('a', 'b') AND title LIKE '%s' AND [1, 2][1] = -time";
+    let stages = session.execute(query).unwrap();
+    let details = &stage(&stages, "where")[0];
+    assert!(details.starts_with(
+        "Filter by author_name IN (\"a\", \"b\") AND title LIKE \"%s\" AND [1, 2][1] = -time"
+    ));
+    assert!(!details.contains('?'));
+}
+
+#[test]
+fn explain_group_by() {
+    let mut session = create_session();
+    let query = "EXPLAIN SELECT author_name, COUNT(title) FROM commits GROUP BY author_name";
+    let stages = session.execute(query).unwrap();
+    assert_eq!(stage(&stages, "group"), vec!["Group by author_name"]);
+    assert_eq!(
+        stage(&stages, "aggregation"),
+        vec!["count(title) AS column_0"]
+    );
+}
+
+#[test]
+fn explain_join_strategy() {
+    let mut session = create_session();
+    let query = "EXPLAIN SELECT author_name, name FROM commits JOIN refs ON author_name = name";
+    let stages = session.execute(query).unwrap();
+    assert_eq!(
+        stage(&stages, "join"),
+        vec!["JOIN `refs` ON author_name = name, hash join on 1 key (sort-merge join if both sides have at least 100000 rows)"]
+    );
+}
+
+#[test]
+fn explain_analyze_group_reports_groups_count() {
+    let mut session = create_session();
+    let query =
+        "EXPLAIN ANALYZE SELECT author_name, COUNT(title) FROM commits GROUP BY author_name";
+    let stages = session.execute(query).unwrap();
+
+    // Columns after the details are time, input rows and output rows
+    let group = stage(&stages, "group");
+    assert_eq!(group[2..], ["3".to_string(), "2".to_string()]);
+
+    let aggregation = stage(&stages, "aggregation");
+    assert_eq!(aggregation[3], "2");
+}
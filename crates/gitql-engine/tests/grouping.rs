@@ -0,0 +1,70 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::text::TextType;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+fn create_session() -> TestSession {
+    let commits = [("a", "gql"), ("a", "gql"), ("a", "lsp"), ("b", "gql")];
+    let rows: Vec<Vec<Box<dyn Value>>> = commits
+        .iter()
+        .map(|(author, repo)| -> Vec<Box<dyn Value>> {
+            vec![
+                Box::new(TextValue::new(author.to_string())),
+                Box::new(TextValue::new(repo.to_string())),
+            ]
+        })
+        .collect();
+
+    let provider = MemoryDataProvider::default().with_table(
+        "commits",
+        vec![
+            ("author_name", Box::new(TextType)),
+            ("repo", Box::new(TextType)),
+        ],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+fn sorted(mut rows: Vec<Vec<String>>) -> Vec<Vec<String>> {
+    rows.sort();
+    rows
+}
+
+#[test]
+fn test_with_rollup_groups_by_values_prefixes() {
+    let mut session = create_session();
+    let rows = session
+        .execute("SELECT author_name, repo, COUNT() AS count, GROUPING(author_name) AS ga, GROUPING(repo) AS gr FROM commits GROUP BY author_name, repo WITH ROLLUP")
+        .unwrap();
+
+    let expected: Vec<Vec<String>> = [
+        ["Null", "Null", "4", "1", "1"],
+        ["a", "Null", "3", "0", "1"],
+        ["a", "gql", "2", "0", "0"],
+        ["a", "lsp", "1", "0", "0"],
+        ["b", "Null", "1", "0", "1"],
+        ["b", "gql", "1", "0", "0"],
+    ]
+    .iter()
+    .map(|row| row.iter().map(|value| value.to_string()).collect())
+    .collect();
+    assert_eq!(sorted(rows), expected);
+}
+
+#[test]
+fn test_with_rollup_one_value_has_grand_total() {
+    let mut session = create_session();
+    let rows = session
+        .execute("SELECT author_name, COUNT() AS count, GROUPING(author_name) AS ga FROM commits GROUP BY author_name WITH ROLLUP")
+        .unwrap();
+
+    let expected: Vec<Vec<String>> = [["Null", "4", "1"], ["a", "3", "0"], ["b", "1", "0"]]
+        .iter()
+        .map(|row| row.iter().map(|value| value.to_string()).collect())
+        .collect();
+    assert_eq!(sorted(rows), expected);
+}
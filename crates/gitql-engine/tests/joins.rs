@@ -0,0 +1,271 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::decimal::DecimalType;
+use gitql_ast::types::float::FloatType;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::text::TextType;
+use gitql_ast::Decimal;
+use gitql_core::values::decimal::DecimalValue;
+use gitql_core::values::float::FloatValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+type JoinRow = (i64, f64, (i128, u32), i64, &'static str);
+
+fn rows(values: &[JoinRow]) -> Vec<Vec<Box<dyn Value>>> {
+    values
+        .iter()
+        .map(|(key, float, decimal, id, tag)| -> Vec<Box<dyn Value>> {
+            vec![
+                Box::new(IntValue::new(*key)),
+                Box::new(FloatValue::new(*float)),
+                Box::new(DecimalValue::new(Decimal::new(decimal.0, decimal.1))),
+                Box::new(IntValue::new(*id)),
+                Box::new(TextValue::new(tag.to_string())),
+            ]
+        })
+        .collect()
+}
+
+const LHS_ROWS: [JoinRow; 3] = [
+    (1, -0.0, (10, 1), 1, "a"),
+    (2, 1.5, (250, 2), 2, "b"),
+    (3, 3.0, (300, 2), 3, "c"),
+];
+
+const RHS_ROWS: [JoinRow; 3] = [
+    (10, 0.0, (100, 2), 1, "a"),
+    (20, 1.5, (25, 1), 2, "x"),
+    (40, 4.0, (4, 0), 4, "d"),
+];
+
+fn create_session() -> TestSession {
+    create_session_with_rows(&LHS_ROWS, &RHS_ROWS)
+}
+
+fn create_session_with_rows(lhs_rows: &[JoinRow], rhs_rows: &[JoinRow]) -> TestSession {
+    let decimal_type = || Box::new(DecimalType::new(5, 2));
+    let provider = MemoryDataProvider::default()
+        .with_table(
+            "lhs",
+            vec![
+                ("l_id", Box::new(IntType)),
+                ("l_float", Box::new(FloatType)),
+                ("l_decimal", decimal_type()),
+                ("id", Box::new(IntType)),
+                ("tag", Box::new(TextType)),
+            ],
+            rows(lhs_rows),
+        )
+        .with_table(
+            "rhs",
+            vec![
+                ("r_id", Box::new(IntType)),
+                ("r_float", Box::new(FloatType)),
+                ("r_decimal", decimal_type()),
+                ("id", Box::new(IntType)),
+                ("tag", Box::new(TextType)),
+            ],
+            rows(rhs_rows),
+        );
+    TestSession::new(provider)
+}
+
+fn sorted(mut rows: Vec<Vec<String>>) -> Vec<Vec<String>> {
+    rows.sort();
+    rows
+}
+
+fn expected(rows: &[&[&str]]) -> Vec<Vec<String>> {
+    sorted(
+        rows.iter()
+            .map(|row| row.iter().map(|value| value.to_string()).collect())
+            .collect(),
+    )
+}
+
+/// Execute the query with equi join predicate that uses hash join, and the same query with
+/// `OR FALSE` that forces nested loops join, both must return the same rows
+/// The join keys must be selected after the id of each table, only the ids are returned
+fn assert_same_as_nested_loop(session: &mut TestSession, query: &str) -> Vec<Vec<String>> {
+    let hash_rows = sorted(session.execute(query).unwrap());
+    let nested_loop_rows = sorted(session.execute(&format!("{} OR FALSE", query)).unwrap());
+    assert_eq!(hash_rows, nested_loop_rows);
+    let ids = hash_rows
+        .into_iter()
+        .map(|row| vec![row[0].to_string(), row[2].to_string()]);
+    sorted(ids.collect())
+}
+
+#[test]
+fn hash_join_matches_negative_zero() {
+    let mut session = create_session();
+    let query = "SELECT l_id, l_float, r_id, r_float FROM lhs JOIN rhs ON l_float = r_float";
+    let rows = assert_same_as_nested_loop(&mut session, query);
+    assert_eq!(rows, expected(&[&["1", "10"], &["2", "20"]]));
+}
+
+#[test]
+fn hash_join_matches_decimal_with_different_scale() {
+    let mut session = create_session();
+    let query =
+        "SELECT l_id, l_decimal, r_id, r_decimal FROM lhs JOIN rhs ON l_decimal = r_decimal";
+    let rows = assert_same_as_nested_loop(&mut session, query);
+    assert_eq!(rows, expected(&[&["1", "10"], &["2", "20"]]));
+}
+
+#[test]
+fn hash_join_outer_joins_same_as_nested_loop() {
+    let mut session = create_session();
+
+    let query = "SELECT l_id, l_float, r_id, r_float FROM lhs LEFT JOIN rhs ON l_float = r_float";
+    let rows = assert_same_as_nested_loop(&mut session, query);
+    assert_eq!(
+        rows,
+        expected(&[&["1", "10"], &["2", "20"], &["3", "Null"]])
+    );
+
+    let query = "SELECT l_id, l_float, r_id, r_float FROM lhs RIGHT JOIN rhs ON l_float = r_float";
+    let rows = assert_same_as_nested_loop(&mut session, query);
+    assert_eq!(
+        rows,
+        expected(&[&["1", "10"], &["2", "20"], &["Null", "40"]])
+    );
+
+    let query =
+        "SELECT l_id, l_decimal, r_id, r_decimal FROM lhs FULL JOIN rhs ON l_decimal = r_decimal";
+    let rows = assert_same_as_nested_loop(&mut session, query);
+    let full_rows = [
+        &["1", "10"][..],
+        &["2", "20"],
+        &["3", "Null"],
+        &["Null", "40"],
+    ];
+    assert_eq!(rows, expected(&full_rows));
+}
+
+#[test]
+fn hash_join_with_multiple_keys_same_as_nested_loop() {
+    let mut session = create_session();
+    let query = "SELECT l_id, l_float, r_id, r_float FROM lhs JOIN rhs ON l_float = r_float AND l_id * 10 = r_id";
+    let rows = assert_same_as_nested_loop(&mut session, query);
+    assert_eq!(rows, expected(&[&["1", "10"], &["2", "20"]]));
+}
+
+#[test]
+fn full_join_using_merges_columns() {
+    let mut session = create_session();
+    let query = "SELECT id, l_id, r_id FROM lhs FULL JOIN rhs USING (id)";
+    let rows = sorted(session.execute(query).unwrap());
+    let expected_rows = [
+        &["1", "1", "10"][..],
+        &["2", "2", "20"],
+        &["3", "3", "Null"],
+        &["4", "Null", "40"],
+    ];
+    assert_eq!(rows, expected(&expected_rows));
+}
+
+#[test]
+fn natural_join_matches_all_common_columns() {
+    let mut session = create_session();
+    let query = "SELECT id, tag, l_id, r_id FROM lhs NATURAL JOIN rhs";
+    let rows = sorted(session.execute(query).unwrap());
+    assert_eq!(rows, expected(&[&["1", "a", "1", "10"]]));
+
+    let query = "SELECT id, tag, l_id, r_id FROM lhs NATURAL LEFT JOIN rhs";
+    let rows = sorted(session.execute(query).unwrap());
+    let expected_rows = [
+        &["1", "a", "1", "10"][..],
+        &["2", "b", "2", "Null"],
+        &["3", "c", "3", "Null"],
+    ];
+    assert_eq!(rows, expected(&expected_rows));
+}
+
+/// Details of the join stage reported by `EXPLAIN ANALYZE`
+fn analyzed_join_details(session: &mut TestSession, query: &str) -> String {
+    let stages = session
+        .execute(&format!("EXPLAIN ANALYZE {}", query))
+        .unwrap();
+    let join_stage = stages.iter().find(|stage| stage[0] == "join").unwrap();
+    join_stage[1].to_string()
+}
+
+#[test]
+fn sort_merge_join_same_as_nested_loop() {
+    // NaN keys are not equal to any key, so they must not break the sort or match each other
+    let lhs_rows = [
+        LHS_ROWS[2],
+        (4, f64::NAN, (1, 0), 5, "e"),
+        LHS_ROWS[1],
+        (5, f64::NAN, (2, 0), 6, "f"),
+        LHS_ROWS[0],
+    ];
+    let rhs_rows = [RHS_ROWS.as_slice(), &[(50, f64::NAN, (1, 0), 5, "e")]].concat();
+    let mut session = create_session_with_rows(&lhs_rows, &rhs_rows);
+    let queries = [
+        "SELECT l_id, l_float, r_id, r_float FROM lhs JOIN rhs ON l_float = r_float",
+        "SELECT l_id, l_float, r_id, r_float FROM lhs LEFT JOIN rhs ON l_float = r_float",
+        "SELECT l_id, l_float, r_id, r_float FROM lhs RIGHT JOIN rhs ON l_float = r_float",
+        "SELECT l_id, l_float, r_id, r_float FROM lhs FULL JOIN rhs ON l_float = r_float",
+    ];
+
+    let hash_rows: Vec<Vec<Vec<String>>> = queries
+        .iter()
+        .map(|query| assert_same_as_nested_loop(&mut session, query))
+        .collect();
+    assert!(analyzed_join_details(&mut session, queries[0]).contains("hash join"));
+
+    session
+        .execute("SET @@sort_merge_join_min_rows = 0")
+        .unwrap();
+    assert!(analyzed_join_details(&mut session, queries[0]).contains("sort-merge join"));
+
+    for (query, hash_rows) in queries.iter().zip(hash_rows) {
+        assert_eq!(assert_same_as_nested_loop(&mut session, query), hash_rows);
+    }
+
+    let rows = assert_same_as_nested_loop(&mut session, queries[3]);
+    assert_eq!(
+        rows,
+        expected(&[
+            &["1", "10"],
+            &["2", "20"],
+            &["3", "Null"],
+            &["4", "Null"],
+            &["5", "Null"],
+            &["Null", "40"],
+            &["Null", "50"],
+        ])
+    );
+}
+
+#[test]
+fn sort_merge_join_with_multiple_keys_same_as_nested_loop() {
+    let mut session = create_session();
+    session
+        .execute("SET @@sort_merge_join_min_rows = 0")
+        .unwrap();
+    let query = "SELECT l_id, l_float, r_id, r_float FROM lhs JOIN rhs ON l_float = r_float AND l_id * 10 = r_id";
+    let rows = assert_same_as_nested_loop(&mut session, query);
+    assert_eq!(rows, expected(&[&["1", "10"], &["2", "20"]]));
+}
+
+#[test]
+fn sort_merge_join_min_rows_validation() {
+    let mut session = create_session();
+    assert!(session
+        .execute("SET @@sort_merge_join_min_rows = 'ten'")
+        .is_err());
+    assert!(session
+        .execute("SET @@sort_merge_join_min_rows = -1")
+        .is_err());
+    assert_eq!(
+        session.execute_value("SELECT @@sort_merge_join_min_rows"),
+        Ok("100000".to_string())
+    );
+}
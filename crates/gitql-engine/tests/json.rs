@@ -0,0 +1,162 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::text::TextType;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+const PACKAGE: &str = r#"CAST('{"name": "gql", "keywords": ["git", "sql"], "stars": 5, "meta": {"v": null}}' AS json)"#;
+
+fn create_session() -> TestSession {
+    let packages = [
+        (
+            "v1",
+            r#"{"name": "gql", "version": "1.0.0", "dependencies": {"a": "1"}}"#,
+        ),
+        (
+            "v2",
+            r#"{"name": "gql", "version": "2.0.0", "dependencies": {"a": "2", "b": "1"}}"#,
+        ),
+    ];
+
+    let rows: Vec<Vec<Box<dyn Value>>> = packages
+        .iter()
+        .enumerate()
+        .map(|(index, (tag, content))| -> Vec<Box<dyn Value>> {
+            vec![
+                Box::new(TextValue::new(tag.to_string())),
+                Box::new(TextValue::new(content.to_string())),
+                Box::new(IntValue::new(index as i64)),
+            ]
+        })
+        .collect();
+
+    let provider = MemoryDataProvider::default().with_table(
+        "packages",
+        vec![
+            ("tag", Box::new(TextType)),
+            ("content", Box::new(TextType)),
+            ("position", Box::new(IntType)),
+        ],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+#[test]
+fn test_json_access_operators() {
+    let mut session = create_session();
+    let cases = [
+        (format!("SELECT {PACKAGE} -> 'name'"), "\"gql\""),
+        (format!("SELECT {PACKAGE} ->> 'name'"), "gql"),
+        (format!("SELECT {PACKAGE} -> 'keywords' -> 1"), "\"sql\""),
+        (format!("SELECT {PACKAGE} -> 'keywords' ->> 0"), "git"),
+        (format!("SELECT {PACKAGE} -> 'keywords' -> -1"), "\"sql\""),
+        (format!("SELECT {PACKAGE} ->> 'meta'"), "{\"v\":null}"),
+        (format!("SELECT {PACKAGE} -> 'meta' -> 'v'"), "null"),
+        (format!("SELECT {PACKAGE} -> 'missing'"), "Null"),
+        (format!("SELECT {PACKAGE} -> 'keywords' -> 5"), "Null"),
+    ];
+
+    for (query, expected) in cases {
+        assert_eq!(
+            session.execute_value(&query),
+            Ok(expected.to_string()),
+            "{query}"
+        );
+    }
+}
+
+#[test]
+fn test_json_functions() {
+    let mut session = create_session();
+    let cases = [
+        (
+            format!("SELECT JSON_EXTRACT({PACKAGE}, '$.keywords[1]')"),
+            "\"sql\"",
+        ),
+        (
+            format!("SELECT JSON_EXTRACT({PACKAGE}, '$.meta.v')"),
+            "null",
+        ),
+        (
+            format!("SELECT JSON_EXTRACT({PACKAGE}, '$.missing')"),
+            "Null",
+        ),
+        (
+            format!("SELECT JSON_ARRAY_LENGTH({PACKAGE} -> 'keywords')"),
+            "2",
+        ),
+        (format!("SELECT JSON_ARRAY_LENGTH({PACKAGE})"), "Null"),
+        ("SELECT JSON_ARRAY_LENGTH('[1, 2, 3]')".to_string(), "3"),
+        (
+            format!("SELECT JSON_OBJECT_KEYS({PACKAGE})"),
+            "[keywords, meta, name, stars]",
+        ),
+        ("SELECT TO_JSON(1)".to_string(), "1"),
+        ("SELECT TO_JSON('a')".to_string(), "\"a\""),
+        ("SELECT TO_JSON([1, 2])".to_string(), "[1,2]"),
+    ];
+
+    for (query, expected) in cases {
+        assert_eq!(
+            session.execute_value(&query),
+            Ok(expected.to_string()),
+            "{query}"
+        );
+    }
+}
+
+#[test]
+fn test_json_from_text_column() {
+    let mut session = create_session();
+    let rows = session
+        .execute("SELECT tag, content, CAST(content AS json) ->> 'version', JSON_OBJECT_KEYS(CAST(content AS json) -> 'dependencies') FROM packages")
+        .unwrap();
+
+    let values: Vec<[&str; 3]> = rows
+        .iter()
+        .map(|row| [row[0].as_str(), row[2].as_str(), row[3].as_str()])
+        .collect();
+    assert_eq!(
+        values,
+        vec![["v1", "1.0.0", "[a]"], ["v2", "2.0.0", "[a, b]"]]
+    );
+}
+
+#[test]
+fn test_json_agg() {
+    let mut session = create_session();
+    let value = session.execute_value("SELECT JSON_AGG(tag) FROM packages");
+    assert_eq!(value, Ok("[\"v1\",\"v2\"]".to_string()));
+
+    let value = session.execute_value("SELECT JSON_AGG(position) FROM packages");
+    assert_eq!(value, Ok("[0,1]".to_string()));
+}
+
+#[test]
+fn test_json_errors() {
+    let mut session = create_session();
+    let error = session.execute("SELECT CAST('nope' AS json)").unwrap_err();
+    assert!(
+        error.contains("Invalid input syntax for type json"),
+        "{error}"
+    );
+
+    let error = session
+        .execute(&format!("SELECT {PACKAGE} -> true"))
+        .unwrap_err();
+    assert!(
+        error.contains("Operator `->` can't be performed"),
+        "{error}"
+    );
+
+    let error = session.execute("SELECT 1 ->> 'a'").unwrap_err();
+    assert!(
+        error.contains("Operator `->>` can't be performed"),
+        "{error}"
+    );
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::text::TextType;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+fn create_session() -> TestSession {
+    let branches = [("main", 4), ("dev", 12)];
+    let rows: Vec<Vec<Box<dyn Value>>> = branches
+        .iter()
+        .map(|(name, commit_count)| -> Vec<Box<dyn Value>> {
+            vec![
+                Box::new(TextValue::new(name.to_string())),
+                Box::new(IntValue::new(*commit_count)),
+            ]
+        })
+        .collect();
+
+    let provider = MemoryDataProvider::default().with_table(
+        "branches",
+        vec![
+            ("name", Box::new(TextType)),
+            ("commit_count", Box::new(IntType)),
+        ],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+#[test]
+fn test_higher_order_array_functions() {
+    let mut session = create_session();
+    let cases = [
+        (
+            "SELECT ARRAY_FILTER([1, 5, 12, 20], x -> x > 10)",
+            "[12, 20]",
+        ),
+        ("SELECT ARRAY_FILTER([1, 5], x -> x > 10)", "[]"),
+        (
+            "SELECT ARRAY_TRANSFORM([1, 2, 3], x -> x * 10)",
+            "[10, 20, 30]",
+        ),
+        (
+            "SELECT ARRAY_TRANSFORM([1, 2], x -> x > 1)",
+            "[false, true]",
+        ),
+        ("SELECT ARRAY_ANY([1, 5, 12], x -> x > 10)", "true"),
+        ("SELECT ARRAY_ANY([1, 5], x -> x > 10)", "false"),
+        ("SELECT ARRAY_ALL([11, 12], x -> x > 10)", "true"),
+        ("SELECT ARRAY_ALL([11, 5], x -> x > 10)", "false"),
+        (
+            "SELECT ARRAY_REDUCE([1, 2, 3], 0, (acc, x) -> acc + x)",
+            "6",
+        ),
+        (
+            "SELECT ARRAY_REDUCE([1, 2, 3], 10, (acc, x) -> acc * x)",
+            "60",
+        ),
+    ];
+
+    for (query, expected) in cases {
+        assert_eq!(
+            session.execute_value(query),
+            Ok(expected.to_string()),
+            "{query}"
+        );
+    }
+}
+
+#[test]
+fn test_lambda_body_uses_row_columns() {
+    let mut session = create_session();
+    let rows = session
+        .execute("SELECT name, ARRAY_FILTER([1, 5, 10], x -> x > commit_count), ARRAY_ANY([1, 5, 10], x -> x > commit_count) FROM branches")
+        .unwrap();
+
+    let expected: Vec<Vec<String>> = [["main", "[5, 10]", "true"], ["dev", "[]", "false"]]
+        .iter()
+        .map(|row| row.iter().map(|value| value.to_string()).collect())
+        .collect();
+    assert_eq!(rows, expected);
+}
+
+#[test]
+fn test_lambda_parameter_shadows_column() {
+    let mut session = create_session();
+    let rows = session
+        .execute("SELECT ARRAY_TRANSFORM([1, 2], commit_count -> commit_count + 1) FROM branches")
+        .unwrap();
+    assert_eq!(rows, vec![vec!["[2, 3]".to_string()]; 2]);
+}
+
+#[test]
+fn test_lambda_errors() {
+    let mut session = create_session();
+    let cases = [
+        (
+            "SELECT ARRAY_FILTER([1, 2], 1)",
+            "Function `array_filter` expects lambda expression as argument",
+        ),
+        (
+            "SELECT ARRAY_FILTER([1, 2], (x, y) -> x > y)",
+            "Lambda argument of function `array_filter` expects `1` parameters but got `2`",
+        ),
+        (
+            "SELECT ARRAY_FILTER([1, 2], x -> x + 1)",
+            "Lambda argument of function `array_filter` expects return type `Boolean` but got `Int`",
+        ),
+        (
+            "SELECT ARRAY_REDUCE([1, 2], 0, (x, x) -> x)",
+            "Lambda parameter `x` is defined more than once",
+        ),
+    ];
+
+    for (query, expected) in cases {
+        let error = session.execute(query).unwrap_err();
+        assert!(error.contains(expected), "{query}: {error}");
+    }
+}
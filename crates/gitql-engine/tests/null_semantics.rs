@@ -0,0 +1,71 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+
+fn create_session() -> TestSession {
+    TestSession::new(MemoryDataProvider::default())
+}
+
+fn select_value(query: &str) -> String {
+    create_session().execute_value(query).unwrap()
+}
+
+#[test]
+fn test_null_comparison_is_unknown() {
+    assert_eq!(select_value("SELECT NULL = NULL"), "Null");
+    assert_eq!(select_value("SELECT 1 = NULL"), "Null");
+    assert_eq!(select_value("SELECT NULL < 1"), "Null");
+}
+
+#[test]
+fn test_null_safe_equal() {
+    assert_eq!(select_value("SELECT NULL <=> NULL"), "true");
+    assert_eq!(select_value("SELECT NULL <=> 1"), "false");
+    assert_eq!(select_value("SELECT 1 <=> 1"), "true");
+}
+
+#[test]
+fn test_null_in_list() {
+    assert_eq!(select_value("SELECT 1 IN (1, NULL)"), "true");
+    assert_eq!(select_value("SELECT 2 IN (1, NULL)"), "Null");
+    assert_eq!(select_value("SELECT NULL IN (1, NULL)"), "Null");
+}
+
+#[test]
+fn test_null_logical_operators() {
+    assert_eq!(select_value("SELECT NULL AND false"), "false");
+    assert_eq!(select_value("SELECT NULL AND true"), "Null");
+    assert_eq!(select_value("SELECT NULL OR true"), "true");
+    assert_eq!(select_value("SELECT NOT NULL"), "Null");
+}
+
+#[test]
+fn test_null_arithmetic() {
+    assert_eq!(select_value("SELECT NULL + 1"), "Null");
+    assert_eq!(select_value("SELECT 2 * NULL"), "Null");
+    assert_eq!(select_value("SELECT -NULL"), "Null");
+}
+
+#[test]
+fn test_null_argument_of_strict_function() {
+    assert_eq!(select_value("SELECT upper(NULL)"), "Null");
+    assert_eq!(select_value("SELECT isnull(NULL)"), "true");
+}
+
+#[test]
+fn test_coalesce() {
+    assert_eq!(select_value("SELECT coalesce(NULL, NULL, 3)"), "3");
+    assert_eq!(select_value("SELECT coalesce(NULL, NULL)"), "Null");
+    assert!(create_session()
+        .execute("SELECT coalesce(1, 'a')")
+        .is_err_and(|error| error.contains("expected type `...Int`")));
+}
+
+#[test]
+fn test_nullif() {
+    assert_eq!(select_value("SELECT nullif(1, NULL)"), "1");
+    assert_eq!(select_value("SELECT nullif(1, 1)"), "Null");
+    assert_eq!(select_value("SELECT upper(nullif('a', 'a'))"), "Null");
+    assert_eq!(select_value("SELECT upper(nullif('a', 'b'))"), "A");
+}
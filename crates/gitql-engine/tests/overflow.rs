@@ -0,0 +1,39 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+
+fn execute_error(query: &str) -> String {
+    TestSession::new(MemoryDataProvider::default())
+        .execute(query)
+        .unwrap_err()
+}
+
+#[test]
+fn test_integer_operators_overflow() {
+    let cases = [
+        ("SELECT 9223372036854775807 + 1", "+"),
+        ("SELECT -9223372036854775807 - 2", "-"),
+        ("SELECT (-9223372036854775807 - 1) / -1", "/"),
+        ("SELECT -(-9223372036854775807 - 1)", "-"),
+    ];
+
+    for (query, operator) in cases {
+        let error = execute_error(query);
+        assert!(error.contains("Integer overflow"), "{query}: {error}");
+        assert!(error.contains(&format!("`{operator}`")), "{query}: {error}");
+    }
+}
+
+#[test]
+fn test_abs_overflow() {
+    let error = execute_error("SELECT ABS(-9223372036854775807 - 1)");
+    assert!(error.contains("Integer overflow"), "{error}");
+
+    let mut session = TestSession::new(MemoryDataProvider::default());
+    let value = session
+        .execute_value("SELECT ABS(-9223372036854775807)")
+        .unwrap();
+    assert_eq!(value, "9223372036854775807");
+    assert_eq!(session.execute_value("SELECT ABS(-1.5)").unwrap(), "1.5");
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::text::TextType;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::null::NullValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+use gitql_engine::prepared_query::PreparedQuery;
+use gitql_parser::token::TokenKind;
+use gitql_parser::tokenizer::Tokenizer;
+
+fn create_session() -> TestSession {
+    let commits = [("a", "first", 1), ("a", "second", 2), ("b", "third", 3)];
+    let rows: Vec<Vec<Box<dyn Value>>> = commits
+        .iter()
+        .map(|(author, title, time)| -> Vec<Box<dyn Value>> {
+            vec![
+                Box::new(TextValue::new(author.to_string())),
+                Box::new(TextValue::new(title.to_string())),
+                Box::new(IntValue::new(*time)),
+            ]
+        })
+        .collect();
+
+    let provider = MemoryDataProvider::default().with_table(
+        "commits",
+        vec![
+            ("author_name", Box::new(TextType)),
+            ("title", Box::new(TextType)),
+            ("time", Box::new(IntType)),
+        ],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+fn parameter(name: &str, value: Box<dyn Value>) -> HashMap<String, Box<dyn Value>> {
+    HashMap::from([(name.to_string(), value)])
+}
+
+#[test]
+fn tokenize_positional_placeholder() {
+    let tokens = Tokenizer::tokenize("SELECT $12".to_string()).ok().unwrap();
+    assert!(tokens[1].kind == TokenKind::Placeholder("12".to_string()));
+}
+
+#[test]
+fn tokenize_positional_placeholder_without_position() {
+    assert!(Tokenizer::tokenize("SELECT $".to_string()).is_err());
+    assert!(Tokenizer::tokenize("SELECT $name".to_string()).is_err());
+}
+
+#[test]
+fn tokenize_named_placeholder() {
+    let tokens = Tokenizer::tokenize("SELECT :author".to_string())
+        .ok()
+        .unwrap();
+    assert!(tokens[1].kind == TokenKind::Colon);
+    assert!(tokens[2].kind == TokenKind::Symbol("author".to_string()));
+}
+
+#[test]
+fn execute_prepared_query_with_same_type() {
+    let mut session = create_session();
+    session.env.bind_parameter(
+        "author".to_string(),
+        Box::new(TextValue::new("a".to_string())),
+    );
+
+    let query = "SELECT title FROM commits WHERE author_name = :author ORDER BY title";
+    let mut prepared_query = PreparedQuery::new(&mut session.env, query).ok().unwrap();
+
+    let rows = session.execute_prepared(&mut prepared_query, HashMap::new());
+    assert_eq!(
+        rows,
+        Ok(vec![vec!["first".to_string()], vec!["second".to_string()]])
+    );
+
+    let author = Box::new(TextValue::new("b".to_string()));
+    let rows = session.execute_prepared(&mut prepared_query, parameter("author", author));
+    assert_eq!(rows, Ok(vec![vec!["third".to_string()]]));
+}
+
+#[test]
+fn execute_prepared_query_with_different_type() {
+    let mut session = create_session();
+    session
+        .env
+        .bind_parameter("1".to_string(), Box::new(IntValue::new(2)));
+
+    let mut prepared_query = PreparedQuery::new(&mut session.env, "SELECT $1, typeof($1)")
+        .ok()
+        .unwrap();
+
+    let rows = session.execute_prepared(&mut prepared_query, HashMap::new());
+    assert_eq!(rows, Ok(vec![vec!["2".to_string(), "Int".to_string()]]));
+
+    let value = Box::new(TextValue::new("two".to_string()));
+    let rows = session.execute_prepared(&mut prepared_query, parameter("1", value));
+    assert_eq!(rows, Ok(vec![vec!["two".to_string(), "Text".to_string()]]));
+
+    let value = Box::new(IntValue::new(3));
+    let rows = session.execute_prepared(&mut prepared_query, parameter("1", value));
+    assert_eq!(rows, Ok(vec![vec!["3".to_string(), "Int".to_string()]]));
+}
+
+#[test]
+fn execute_prepared_query_with_null_keeps_type() {
+    let mut session = create_session();
+    session
+        .env
+        .bind_parameter("time".to_string(), Box::new(IntValue::new(2)));
+
+    let query = "SELECT title FROM commits WHERE time > :time ORDER BY title";
+    let mut prepared_query = PreparedQuery::new(&mut session.env, query).ok().unwrap();
+
+    let rows =
+        session.execute_prepared(&mut prepared_query, parameter("time", Box::new(NullValue)));
+    assert_eq!(rows, Ok(vec![]));
+
+    let time = Box::new(IntValue::new(1));
+    let rows = session.execute_prepared(&mut prepared_query, parameter("time", time));
+    assert_eq!(
+        rows,
+        Ok(vec![vec!["second".to_string()], vec!["third".to_string()]])
+    );
+}
+
+#[test]
+fn prepare_query_with_missing_parameter() {
+    let mut session = create_session();
+    assert!(PreparedQuery::new(&mut session.env, "SELECT :missing").is_err());
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::text::TextType;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+fn create_session() -> TestSession {
+    let commits = [("a", "first", 1), ("a", "second", 2), ("b", "third", 3)];
+    let rows: Vec<Vec<Box<dyn Value>>> = commits
+        .iter()
+        .map(|(author, title, time)| -> Vec<Box<dyn Value>> {
+            vec![
+                Box::new(TextValue::new(author.to_string())),
+                Box::new(TextValue::new(title.to_string())),
+                Box::new(IntValue::new(*time)),
+            ]
+        })
+        .collect();
+
+    let provider = MemoryDataProvider::default().with_table(
+        "commits",
+        vec![
+            ("author_name", Box::new(TextType)),
+            ("title", Box::new(TextType)),
+            ("time", Box::new(IntType)),
+        ],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+fn titles(rows: Vec<Vec<String>>) -> Vec<String> {
+    let mut titles: Vec<String> = rows.into_iter().map(|row| row[0].to_string()).collect();
+    titles.sort();
+    titles
+}
+
+#[test]
+fn test_qualify_with_selected_window_function() {
+    let mut session = create_session();
+    let rows = session
+        .execute("SELECT title, row_number() OVER (PARTITION BY author_name ORDER BY time DESC) AS rn FROM commits QUALIFY rn = 1")
+        .unwrap();
+    assert_eq!(titles(rows), vec!["second", "third"]);
+}
+
+#[test]
+fn test_qualify_with_inline_window_function() {
+    let mut session = create_session();
+    let rows = session
+        .execute("SELECT title FROM commits QUALIFY row_number() OVER (PARTITION BY author_name ORDER BY time DESC) = 1")
+        .unwrap();
+    assert_eq!(rows.iter().map(|row| row.len()).max(), Some(1));
+    assert_eq!(titles(rows), vec!["second", "third"]);
+}
+
+#[test]
+fn test_qualify_with_inline_aggregated_window_function() {
+    let mut session = create_session();
+    let rows = session
+        .execute(
+            "SELECT title FROM commits QUALIFY count(title) OVER (PARTITION BY author_name) > 1",
+        )
+        .unwrap();
+    assert_eq!(titles(rows), vec!["first", "second"]);
+}
+
+#[test]
+fn test_window_function_after_select_outside_qualify() {
+    let mut session = create_session();
+    let result = session.execute("SELECT title FROM commits WHERE row_number() OVER () = 1");
+    assert!(result.is_err());
+}
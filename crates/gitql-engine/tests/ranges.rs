@@ -0,0 +1,119 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::integer::IntType;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::Value;
+
+fn create_session() -> TestSession {
+    let spans = [(1, 3), (2, 5), (5, 7), (10, 12)];
+    let rows: Vec<Vec<Box<dyn Value>>> = spans
+        .iter()
+        .map(|(start, end)| -> Vec<Box<dyn Value>> {
+            vec![
+                Box::new(IntValue::new(*start)),
+                Box::new(IntValue::new(*end)),
+            ]
+        })
+        .collect();
+
+    let provider = MemoryDataProvider::default().with_table(
+        "spans",
+        vec![
+            ("span_start", Box::new(IntType)),
+            ("span_end", Box::new(IntType)),
+        ],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+#[test]
+fn test_range_adjacent_operator() {
+    let mut session = create_session();
+    let cases = [
+        ("SELECT int4range(1, 3) -|- int4range(3, 5)", "true"),
+        ("SELECT int4range(3, 5) -|- int4range(1, 3)", "true"),
+        ("SELECT int4range(1, 3) -|- int4range(4, 5)", "false"),
+        ("SELECT int4range(1, 4) -|- int4range(3, 5)", "false"),
+        ("SELECT floatrange(1.5, 2.5) -|- floatrange(2.5, 3)", "true"),
+    ];
+
+    for (query, expected) in cases {
+        assert_eq!(session.execute_value(query).unwrap(), expected, "{query}");
+    }
+}
+
+#[test]
+fn test_range_adjacent_operator_type_mismatch() {
+    let mut session = create_session();
+    let error = session
+        .execute("SELECT int4range(1, 3) -|- floatrange(3, 5)")
+        .unwrap_err();
+    assert!(error.contains("Operator `-|-`"), "{error}");
+
+    let error = session.execute("SELECT 1 -|- 2").unwrap_err();
+    assert!(error.contains("Operator `-|-`"), "{error}");
+}
+
+#[test]
+fn test_floatrange_and_numrange_are_float_ranges() {
+    let mut session = create_session();
+    let value = session.execute_value("SELECT floatrange(1, 2.5)").unwrap();
+    assert_eq!(value, "1..2.5");
+
+    let value = session
+        .execute_value("SELECT floatrange(1, 2) -|- numrange(2, 3)")
+        .unwrap();
+    assert_eq!(value, "true");
+}
+
+#[test]
+fn test_multirange_merges_overlapping_and_adjacent_ranges() {
+    let mut session = create_session();
+    let value = session
+        .execute_value("SELECT multirange(int4range(1, 3)) + multirange(int4range(2, 5))")
+        .unwrap();
+    assert_eq!(value, "{1..5}");
+
+    let value = session
+        .execute_value("SELECT multirange(int4range(1, 3)) + multirange(int4range(3, 5))")
+        .unwrap();
+    assert_eq!(value, "{1..5}");
+
+    let value = session
+        .execute_value("SELECT multirange(int4range(6, 8)) + multirange(int4range(1, 3))")
+        .unwrap();
+    assert_eq!(value, "{1..3, 6..8}");
+}
+
+#[test]
+fn test_multirange_difference() {
+    let mut session = create_session();
+    let value = session
+        .execute_value("SELECT multirange(int4range(1, 10)) - multirange(int4range(3, 5))")
+        .unwrap();
+    assert_eq!(value, "{1..3, 5..10}");
+
+    let value = session
+        .execute_value("SELECT multirange(int4range(1, 10)) - multirange(int4range(0, 20))")
+        .unwrap();
+    assert_eq!(value, "{}");
+}
+
+#[test]
+fn test_range_agg_merges_rows_ranges() {
+    let mut session = create_session();
+    let value = session
+        .execute_value("SELECT range_agg(int4range(span_start, span_end)) FROM spans")
+        .unwrap();
+    assert_eq!(value, "{1..7, 10..12}");
+
+    let value = session
+        .execute_value(
+            "SELECT multirange(int4range(0, 15)) - range_agg(int4range(span_start, span_end)) FROM spans",
+        )
+        .unwrap();
+    assert_eq!(value, "{0..1, 7..10, 12..15}");
+}
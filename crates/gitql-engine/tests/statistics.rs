@@ -0,0 +1,116 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::integer::IntType;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::null::NullValue;
+use gitql_core::values::Value;
+
+/// Insertions 2, 4, 4, 4, 5, 5, 7, 9 and Null with deletions equal to twice the insertions plus one
+fn create_session() -> TestSession {
+    let mut rows: Vec<Vec<Box<dyn Value>>> = [2, 4, 4, 4, 5, 5, 7, 9]
+        .iter()
+        .map(|insertions| -> Vec<Box<dyn Value>> {
+            vec![
+                Box::new(IntValue::new(*insertions)),
+                Box::new(IntValue::new(insertions * 2 + 1)),
+            ]
+        })
+        .collect();
+    rows.push(vec![Box::new(NullValue), Box::new(NullValue)]);
+
+    let provider = MemoryDataProvider::default().with_table(
+        "diffs",
+        vec![
+            ("insertions", Box::new(IntType)),
+            ("deletions", Box::new(IntType)),
+        ],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+fn select_float(query: &str) -> f64 {
+    let value = create_session().execute_value(query).unwrap();
+    value.parse::<f64>().unwrap()
+}
+
+fn assert_close(query: &str, expected: f64) {
+    let value = select_float(query);
+    assert!((value - expected).abs() < 1e-9, "{query}: {value}");
+}
+
+#[test]
+fn test_median_and_percentiles() {
+    assert_close("SELECT median(insertions) FROM diffs", 4.5);
+    assert_close("SELECT percentile_cont(insertions, 0.25) FROM diffs", 4.0);
+    assert_close("SELECT percentile_cont(insertions, 0.9) FROM diffs", 7.6);
+    assert_close("SELECT percentile_cont(insertions, 1.0) FROM diffs", 9.0);
+
+    let mut session = create_session();
+    let value = session
+        .execute_value("SELECT percentile_disc(insertions, 0.5) FROM diffs")
+        .unwrap();
+    assert_eq!(value, "4");
+
+    let value = session
+        .execute_value("SELECT percentile_disc(insertions, 0.0) FROM diffs")
+        .unwrap();
+    assert_eq!(value, "2");
+}
+
+#[test]
+fn test_percentile_fraction_out_of_range() {
+    for query in [
+        "SELECT percentile_cont(insertions, 1.5) FROM diffs",
+        "SELECT percentile_disc(insertions, -0.1) FROM diffs",
+    ] {
+        let error = create_session().execute(query).unwrap_err();
+        assert!(error.contains("is not between 0 and 1"), "{query}: {error}");
+    }
+}
+
+#[test]
+fn test_mode() {
+    let value = create_session()
+        .execute_value("SELECT mode(insertions) FROM diffs")
+        .unwrap();
+    assert_eq!(value, "4");
+}
+
+#[test]
+fn test_variance_and_standard_deviation() {
+    assert_close("SELECT var_pop(insertions) FROM diffs", 4.0);
+    assert_close("SELECT stddev_pop(insertions) FROM diffs", 2.0);
+    assert_close("SELECT var_samp(insertions) FROM diffs", 32.0 / 7.0);
+    assert_close("SELECT variance(insertions) FROM diffs", 32.0 / 7.0);
+    assert_close(
+        "SELECT stddev(insertions) FROM diffs",
+        (32.0f64 / 7.0).sqrt(),
+    );
+}
+
+#[test]
+fn test_correlation_and_covariance() {
+    assert_close("SELECT corr(deletions, insertions) FROM diffs", 1.0);
+    assert_close("SELECT covar_pop(deletions, insertions) FROM diffs", 8.0);
+    assert_close(
+        "SELECT covar_samp(deletions, insertions) FROM diffs",
+        64.0 / 7.0,
+    );
+}
+
+#[test]
+fn test_sample_statistics_of_one_value_are_null() {
+    let mut session = create_session();
+    let value = session
+        .execute_value("SELECT var_samp(insertions) FROM diffs WHERE insertions = 2")
+        .unwrap();
+    assert_eq!(value, "Null");
+
+    let value = session
+        .execute_value("SELECT var_pop(insertions) FROM diffs WHERE insertions = 2")
+        .unwrap();
+    assert_eq!(value, "0");
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::array::ArrayType;
+use gitql_ast::types::composite::CompositeType;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::text::TextType;
+use gitql_core::values::array::ArrayValue;
+use gitql_core::values::composite::CompositeValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+fn change_type() -> CompositeType {
+    CompositeType::empty("Change".to_string())
+        .add_member("path".to_string(), Box::new(TextType))
+        .add_member("insertions".to_string(), Box::new(IntType))
+}
+
+fn change(path: &str, insertions: i64) -> Box<dyn Value> {
+    Box::new(
+        CompositeValue::empty("Change".to_string())
+            .add_member(
+                "path".to_string(),
+                Box::new(TextValue::new(path.to_string())),
+            )
+            .add_member(
+                "insertions".to_string(),
+                Box::new(IntValue::new(insertions)),
+            ),
+    )
+}
+
+fn create_session() -> TestSession {
+    let commits = [
+        ("a1", vec![change("src/main.rs", 3), change("README.md", 1)]),
+        ("b2", vec![]),
+        ("c3", vec![change("Cargo.toml", 2)]),
+    ];
+
+    let rows: Vec<Vec<Box<dyn Value>>> = commits
+        .into_iter()
+        .map(|(commit_id, changes)| -> Vec<Box<dyn Value>> {
+            vec![
+                Box::new(TextValue::new(commit_id.to_string())),
+                Box::new(ArrayValue::new(changes, Box::new(change_type()))),
+            ]
+        })
+        .collect();
+
+    let provider = MemoryDataProvider::default().with_table(
+        "diffs",
+        vec![
+            ("commit_id", Box::new(TextType)),
+            ("changes", Box::new(ArrayType::new(Box::new(change_type())))),
+        ],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+fn to_strings(rows: Vec<Vec<&str>>) -> Vec<Vec<String>> {
+    rows.iter()
+        .map(|row| row.iter().map(|value| value.to_string()).collect())
+        .collect()
+}
+
+#[test]
+fn test_unnest_lateral_join_with_and_without_as() {
+    let expected = to_strings(vec![
+        vec!["a1", "src/main.rs"],
+        vec!["a1", "README.md"],
+        vec!["c3", "Cargo.toml"],
+    ]);
+
+    for query in [
+        "SELECT commit_id, (c).path FROM diffs, UNNEST(changes) AS c",
+        "SELECT commit_id, (c).path FROM diffs, UNNEST(changes) c",
+        "SELECT commit_id, (c).path FROM diffs CROSS JOIN UNNEST(changes) c",
+    ] {
+        let mut session = create_session();
+        assert_eq!(session.execute(query), Ok(expected.clone()), "{query}");
+    }
+}
+
+#[test]
+fn test_unnest_left_join_keeps_empty_collections() {
+    let mut session = create_session();
+    let result = session.execute(
+        "SELECT commit_id, (c).insertions FROM diffs LEFT JOIN UNNEST(changes) c ORDER BY commit_id",
+    );
+    let expected = vec![
+        vec!["a1", "3"],
+        vec!["a1", "1"],
+        vec!["b2", "Null"],
+        vec!["c3", "2"],
+    ];
+    assert_eq!(result, Ok(to_strings(expected)));
+}
+
+#[test]
+fn test_unnest_with_ordinality() {
+    let mut session = create_session();
+    let result = session.execute(
+        "SELECT commit_id, n, (c).path FROM diffs, UNNEST(changes) c WITH ORDINALITY n WHERE n = 2",
+    );
+    assert_eq!(result, Ok(to_strings(vec![vec!["a1", "2", "README.md"]])));
+
+    let mut session = create_session();
+    let result =
+        session.execute("SELECT `unnest`, `ordinality` FROM UNNEST([10, 20]) WITH ORDINALITY");
+    assert_eq!(
+        result,
+        Ok(to_strings(vec![vec!["10", "1"], vec!["20", "2"]]))
+    );
+}
+
+#[test]
+fn test_unnest_qualified_member_access_is_rejected() {
+    let mut session = create_session();
+    let error = session
+        .execute("SELECT commit_id, c.path FROM diffs, UNNEST(changes) c")
+        .unwrap_err();
+    assert!(
+        error.contains("composite value between `(` and `)`"),
+        "{error}"
+    );
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::datetime::DateTimeType;
+use gitql_ast::types::integer::IntType;
+use gitql_core::values::datetime::DateTimeValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::Value;
+
+const DAY: i64 = 24 * 60 * 60;
+
+/// Commits on the days 0, 1, 1, 3 and 10 with insertions 1, 2, 4, 8 and 16
+fn create_session() -> TestSession {
+    let commits = [(0, 1), (1, 2), (1, 4), (3, 8), (10, 16)];
+    let rows: Vec<Vec<Box<dyn Value>>> = commits
+        .iter()
+        .map(|(day, insertions)| -> Vec<Box<dyn Value>> {
+            vec![
+                Box::new(DateTimeValue::new(1704067200 + day * DAY)),
+                Box::new(IntValue::new(*day)),
+                Box::new(IntValue::new(*insertions)),
+            ]
+        })
+        .collect();
+
+    let provider = MemoryDataProvider::default().with_table(
+        "commits",
+        vec![
+            ("datetime", Box::new(DateTimeType)),
+            ("day", Box::new(IntType)),
+            ("insertions", Box::new(IntType)),
+        ],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+/// Execute the query that select the insertions and the window value, ordered by the insertions
+fn window_values(query: &str) -> Vec<String> {
+    let mut rows = create_session().execute(query).unwrap();
+    rows.sort_by_key(|row| row[0].parse::<i64>().unwrap());
+    rows.into_iter().map(|row| row[1].to_string()).collect()
+}
+
+#[test]
+fn test_rows_frame_bounds() {
+    let values = window_values("SELECT insertions, SUM(insertions) OVER (ORDER BY insertions ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM commits");
+    assert_eq!(values, vec!["1", "3", "6", "12", "24"]);
+
+    let values = window_values("SELECT insertions, SUM(insertions) OVER (ORDER BY insertions ROWS BETWEEN CURRENT ROW AND 1 FOLLOWING) FROM commits");
+    assert_eq!(values, vec!["3", "6", "12", "24", "16"]);
+
+    let values = window_values("SELECT insertions, SUM(insertions) OVER (ORDER BY insertions ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) FROM commits");
+    assert_eq!(values, vec!["31", "31", "31", "31", "31"]);
+
+    let values = window_values("SELECT insertions, SUM(insertions) OVER (ORDER BY insertions ROWS BETWEEN 3 FOLLOWING AND 4 FOLLOWING) FROM commits");
+    assert_eq!(values, vec!["24", "16", "Null", "Null", "Null"]);
+}
+
+#[test]
+fn test_range_frame_number_offset() {
+    let values = window_values("SELECT insertions, SUM(insertions) OVER (ORDER BY day RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM commits");
+    assert_eq!(values, vec!["1", "7", "7", "8", "16"]);
+
+    let values = window_values("SELECT insertions, SUM(insertions) OVER (ORDER BY day DESC RANGE BETWEEN 2 PRECEDING AND CURRENT ROW) FROM commits");
+    assert_eq!(values, vec!["7", "14", "14", "8", "16"]);
+}
+
+#[test]
+fn test_range_frame_current_row_includes_peers() {
+    let values = window_values(
+        "SELECT insertions, SUM(insertions) OVER (ORDER BY day RANGE UNBOUNDED PRECEDING) FROM commits",
+    );
+    assert_eq!(values, vec!["1", "7", "7", "15", "31"]);
+}
+
+#[test]
+fn test_range_frame_interval_offset() {
+    let values = window_values("SELECT insertions, SUM(insertions) OVER (ORDER BY datetime RANGE BETWEEN INTERVAL '2 days' PRECEDING AND CURRENT ROW) FROM commits");
+    assert_eq!(values, vec!["1", "7", "7", "14", "16"]);
+}
+
+#[test]
+fn test_range_frame_date_time_tz_ordering() {
+    let values = window_values("SELECT insertions, SUM(insertions) OVER (ORDER BY datetime AT TIME ZONE 'Asia/Tokyo' RANGE BETWEEN INTERVAL '2 days' PRECEDING AND CURRENT ROW) FROM commits");
+    assert_eq!(values, vec!["1", "7", "7", "14", "16"]);
+}
+
+#[test]
+fn test_range_frame_offset_type_mismatch() {
+    let result = create_session().execute(
+        "SELECT SUM(insertions) OVER (ORDER BY datetime RANGE BETWEEN 2 PRECEDING AND CURRENT ROW) FROM commits",
+    );
+    assert!(result.is_err_and(|error| error.contains("`RANGE` offset with type")));
+}
+
+#[test]
+fn test_frame_start_after_end() {
+    let result = create_session().execute(
+        "SELECT SUM(insertions) OVER (ORDER BY day ROWS BETWEEN 1 FOLLOWING AND 1 PRECEDING) FROM commits",
+    );
+    assert!(result.is_err_and(|error| error.contains("frame start can't be after the frame end")));
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::MemoryDataProvider;
+use common::TestSession;
+use gitql_ast::types::datetime::DateTimeType;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::text::TextType;
+use gitql_core::values::datetime::DateTimeValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+/// Commits with ids 1 to 5, the insertions 10, 20, 20, 30, 30 and author `a` or `b`
+fn create_session() -> TestSession {
+    let commits = [
+        (1, 10, "a"),
+        (2, 20, "a"),
+        (3, 20, "b"),
+        (4, 30, "a"),
+        (5, 30, "b"),
+    ];
+    let rows: Vec<Vec<Box<dyn Value>>> = commits
+        .iter()
+        .map(|(id, insertions, author)| -> Vec<Box<dyn Value>> {
+            vec![
+                Box::new(IntValue::new(*id)),
+                Box::new(IntValue::new(*insertions)),
+                Box::new(TextValue::new(author.to_string())),
+                Box::new(DateTimeValue::new(1704067200 + id * 60)),
+            ]
+        })
+        .collect();
+
+    let provider = MemoryDataProvider::default().with_table(
+        "commits",
+        vec![
+            ("id", Box::new(IntType)),
+            ("insertions", Box::new(IntType)),
+            ("author_name", Box::new(TextType)),
+            ("datetime", Box::new(DateTimeType)),
+        ],
+        rows,
+    );
+    TestSession::new(provider)
+}
+
+/// Execute the query that select the id and the window value, ordered by the id
+fn window_values(query: &str) -> Vec<String> {
+    let mut rows = create_session().execute(query).unwrap();
+    rows.sort_by_key(|row| row[0].parse::<i64>().unwrap());
+    rows.into_iter().map(|row| row[1].to_string()).collect()
+}
+
+#[test]
+fn test_rank_and_dense_rank() {
+    let values = window_values("SELECT id, rank() OVER (ORDER BY insertions) FROM commits");
+    assert_eq!(values, vec!["1", "2", "2", "4", "4"]);
+
+    let values = window_values("SELECT id, dense_rank() OVER (ORDER BY insertions) FROM commits");
+    assert_eq!(values, vec!["1", "2", "2", "3", "3"]);
+
+    let values = window_values(
+        "SELECT id, rank() OVER (PARTITION BY author_name ORDER BY insertions) FROM commits",
+    );
+    assert_eq!(values, vec!["1", "2", "1", "3", "2"]);
+}
+
+#[test]
+fn test_ntile() {
+    let values = window_values("SELECT id, ntile(2) OVER (ORDER BY id) FROM commits");
+    assert_eq!(values, vec!["1", "1", "1", "2", "2"]);
+
+    let values = window_values("SELECT id, ntile(10) OVER (ORDER BY id) FROM commits");
+    assert_eq!(values, vec!["1", "2", "3", "4", "5"]);
+
+    let values = window_values("SELECT id, ntile(NULL) OVER (ORDER BY id) FROM commits");
+    assert_eq!(values, vec!["Null", "Null", "Null", "Null", "Null"]);
+}
+
+#[test]
+fn test_ntile_invalid_buckets_count() {
+    for buckets in ["0", "-1"] {
+        let query = format!("SELECT id, ntile({buckets}) OVER (ORDER BY id) FROM commits");
+        let error = create_session().execute(&query).unwrap_err();
+        assert!(error.contains("must be greater than zero"), "{error}");
+    }
+}
+
+#[test]
+fn test_lag_and_lead() {
+    let values = window_values("SELECT id, lag(insertions) OVER (ORDER BY id) FROM commits");
+    assert_eq!(values, vec!["Null", "10", "20", "20", "30"]);
+
+    let values = window_values("SELECT id, lead(insertions, 2) OVER (ORDER BY id) FROM commits");
+    assert_eq!(values, vec!["20", "30", "30", "Null", "Null"]);
+
+    let values = window_values("SELECT id, lag(insertions, 1, 0) OVER (ORDER BY id) FROM commits");
+    assert_eq!(values, vec!["0", "10", "20", "20", "30"]);
+
+    let values = window_values(
+        "SELECT id, lead(id, 1, -1) OVER (PARTITION BY author_name ORDER BY id) FROM commits",
+    );
+    assert_eq!(values, vec!["2", "4", "5", "-1", "-1"]);
+}
+
+#[test]
+fn test_lag_and_lead_default_must_match_value_type() {
+    for function in ["lag", "lead"] {
+        let query =
+            format!("SELECT id, {function}(datetime, 1, 5) OVER (ORDER BY id) FROM commits");
+        let error = create_session().execute(&query).unwrap_err();
+        assert!(error.contains("argument number 2"), "{error}");
+    }
+
+    let values = window_values("SELECT id, lag(datetime, 1, NULL) OVER (ORDER BY id) FROM commits");
+    assert_eq!(values[0], "Null");
+}
@@ -18,6 +18,10 @@ pub struct ParserContext {
     pub hidden_selections: Vec<String>,
 
     pub selected_tables: Vec<String>,
+    pub join_using_columns: HashMap<String, Vec<String>>,
+    pub unnest_columns: Vec<String>,
+    pub lambda_parameters: Vec<String>,
+    pub called_user_functions: Vec<String>,
     pub projection_names: Vec<String>,
     pub projection_locations: Vec<SourceLocation>,
 
@@ -30,6 +34,8 @@ pub struct ParserContext {
 
     pub inside_selections: bool,
     pub inside_having: bool,
+    pub inside_qualify: bool,
     pub inside_order_by: bool,
     pub inside_over_clauses: bool,
+    pub inside_aggregation_clauses: bool,
 }
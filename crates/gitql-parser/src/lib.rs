@@ -8,6 +8,7 @@ pub mod tokenizer;
 
 pub(crate) mod parse_cast;
 pub(crate) mod parse_comparisons;
+pub(crate) mod parse_create;
 pub(crate) mod parse_function_call;
 pub(crate) mod parse_interval;
 pub(crate) mod parse_type;
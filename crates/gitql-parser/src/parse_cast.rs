@@ -1,14 +1,18 @@
+use gitql_ast::expression::AtTimeZoneExpr;
 use gitql_ast::expression::CastExpr;
 use gitql_ast::expression::Expr;
+use gitql_ast::expression::StringExpr;
+use gitql_ast::expression::TryCastExpr;
 use gitql_ast::types::DataType;
 use gitql_core::environment::Environment;
+use gitql_core::values::datetimetz::parse_time_zone;
 
 use crate::context::ParserContext;
 use crate::diagnostic::Diagnostic;
 use crate::parse_type::parse_type;
 use crate::parser::consume_token_or_error;
+use crate::parser::parse_arrow_expression;
 use crate::parser::parse_expression;
-use crate::parser::parse_index_or_slice_expression;
 use crate::token::SourceLocation;
 use crate::token::Token;
 use crate::token::TokenKind;
@@ -19,35 +23,81 @@ pub(crate) fn parse_cast_operator_expression(
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
-    let expr = parse_index_or_slice_expression(context, env, tokens, position)?;
+    let mut expr = parse_arrow_expression(context, env, tokens, position)?;
+
+    loop {
+        if *position < tokens.len() && tokens[*position].kind == TokenKind::ColonColon {
+            // Consume `::` Token
+            let colon_colon_token = &tokens[*position];
+            *position += 1;
+
+            let target_type = parse_type(env, tokens, position)?;
+            let cast = cast_expression_or_error(expr, target_type, colon_colon_token.location)?;
+            expr = Box::new(cast);
+            continue;
+        }
+
+        if is_at_time_zone_keywords(tokens, *position) {
+            let at_token_location = tokens[*position].location;
 
-    if *position < tokens.len() && tokens[*position].kind == TokenKind::ColonColon {
-        // Consume `::` Token
-        let colon_colon_token = &tokens[*position];
-        *position += 1;
+            // Consume `AT TIME ZONE` Tokens
+            *position += 3;
+
+            let time_zone = parse_arrow_expression(context, env, tokens, position)?;
+            expr = at_time_zone_expression_or_error(expr, time_zone, at_token_location)?;
+            continue;
+        }
 
-        let target_type = parse_type(env, tokens, position)?;
-        return cast_expression_or_error(expr, target_type, colon_colon_token.location);
+        break;
     }
 
     Ok(expr)
 }
 
+/// `AT TIME ZONE` words are not reserved keywords to keep them valid as names
+fn is_at_time_zone_keywords(tokens: &[Token], position: usize) -> bool {
+    let is_symbol = |index: usize, keyword: &str| matches!(tokens.get(index), Some(token) if matches!(&token.kind, TokenKind::Symbol(name) if name.eq_ignore_ascii_case(keyword)));
+    is_symbol(position, "at") && is_symbol(position + 1, "time") && is_symbol(position + 2, "zone")
+}
+
+fn at_time_zone_expression_or_error(
+    value: Box<dyn Expr>,
+    time_zone: Box<dyn Expr>,
+    location: SourceLocation,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    let value_type = value.expr_type();
+    if !value_type.is_date_time() && !value_type.is_date_time_tz() {
+        return Err(Diagnostic::error(&format!(
+            "Operator `AT TIME ZONE` can't be performed on type `{}`",
+            value_type.literal()
+        ))
+        .add_help("Value must be a DateTime or DateTimeTz")
+        .with_location(location)
+        .as_boxed());
+    }
+
+    check_time_zone_expression(&time_zone, location)?;
+    Ok(Box::new(AtTimeZoneExpr { value, time_zone }))
+}
+
 pub(crate) fn parse_cast_call_expression(
     context: &mut ParserContext,
     env: &mut Environment,
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
-    let cast_token_location =
-        consume_token_or_error(tokens, position, TokenKind::Cast, "Expect 'CAST' Keyword")?
-            .location;
+    // Consume `CAST` or `TRY_CAST` keyword
+    let cast_token = &tokens[*position];
+    let is_try_cast = cast_token.kind == TokenKind::TryCast;
+    let keyword = cast_token.to_string();
+    let cast_token_location = cast_token.location;
+    *position += 1;
 
     consume_token_or_error(
         tokens,
         position,
         TokenKind::LeftParen,
-        "Expect '(' after 'CAST' Keyword",
+        &format!("Expect '(' after '{}' Keyword", keyword),
     )?;
 
     let expr = parse_expression(context, env, tokens, position)?;
@@ -56,7 +106,7 @@ pub(crate) fn parse_cast_call_expression(
         tokens,
         position,
         TokenKind::As,
-        "Expect 'AS' keyword after 'CAST' expression value",
+        &format!("Expect 'AS' keyword after '{}' expression value", keyword),
     )?;
 
     let target_type = parse_type(env, tokens, position)?;
@@ -65,26 +115,44 @@ pub(crate) fn parse_cast_call_expression(
         tokens,
         position,
         TokenKind::RightParen,
-        "Expect ')' at the end of 'CAST' expression",
+        &format!("Expect ')' at the end of '{}' expression", keyword),
     )?;
 
-    cast_expression_or_error(expr, target_type, cast_token_location)
+    let cast = cast_expression_or_error(expr, target_type, cast_token_location)?;
+    if is_try_cast {
+        return Ok(Box::new(TryCastExpr {
+            value: cast.value,
+            result_type: cast.result_type,
+        }));
+    }
+
+    Ok(Box::new(cast))
 }
 
 fn cast_expression_or_error(
     expr: Box<dyn Expr>,
     target_type: Box<dyn DataType>,
     location: SourceLocation,
-) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+) -> Result<CastExpr, Box<Diagnostic>> {
     let value_type = expr.expr_type();
     let value_expected_types = value_type.can_perform_explicit_cast_op_to();
 
     // If it's supported to cast this value to result type, just return CastExpr
     if value_expected_types.contains(&target_type) {
-        return Ok(Box::new(CastExpr {
+        return Ok(CastExpr {
             value: expr,
             result_type: target_type,
-        }));
+        });
+    }
+
+    // If the value can be implicit casted to the result type, it can be explicit casted too,
+    // and text values are checked against the enum labels when they are casted
+    if target_type.has_implicit_cast_from(&expr) || (target_type.is_enum() && value_type.is_text())
+    {
+        return Ok(CastExpr {
+            value: expr,
+            result_type: target_type,
+        });
     }
 
     // Check if it possible to implicit cast the value to one of the expected type of result type
@@ -98,10 +166,10 @@ fn cast_expression_or_error(
                 result_type: expected_type.clone(),
             });
 
-            return Ok(Box::new(CastExpr {
+            return Ok(CastExpr {
                 value: casting,
                 result_type: target_type,
-            }));
+            });
         }
     }
 
@@ -113,3 +181,33 @@ fn cast_expression_or_error(
     .with_location(location)
     .as_boxed())
 }
+
+/// Make sure the time zone value is a text and a known time zone if it's a literal
+#[allow(clippy::borrowed_box)]
+pub(crate) fn check_time_zone_expression(
+    time_zone: &Box<dyn Expr>,
+    location: SourceLocation,
+) -> Result<(), Box<Diagnostic>> {
+    let time_zone_type = time_zone.expr_type();
+    if !time_zone_type.is_text() {
+        return Err(Diagnostic::error(&format!(
+            "Time zone must be a Text but got `{}`",
+            time_zone_type.literal()
+        ))
+        .with_location(location)
+        .as_boxed());
+    }
+
+    if let Some(string_expr) = time_zone.as_any().downcast_ref::<StringExpr>() {
+        if parse_time_zone(&string_expr.value).is_none() {
+            return Err(
+                Diagnostic::error(&format!("Unknown time zone `{}`", string_expr.value))
+                    .add_help("Use IANA time zone name like `UTC` or `Europe/Berlin`")
+                    .with_location(location)
+                    .as_boxed(),
+            );
+        }
+    }
+
+    Ok(())
+}
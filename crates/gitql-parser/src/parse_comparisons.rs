@@ -63,6 +63,16 @@ pub(crate) fn parse_comparison_expression(
         let lhs_type = lhs.expr_type();
         let rhs_type = rhs.expr_type();
 
+        // NULL operand can be compared with any type, the result is NULL except for `<=>`
+        if lhs_type.is_null() || rhs_type.is_null() {
+            return Ok(create_comparison_expression(
+                lhs,
+                rhs,
+                comparison_operator(&operator.kind),
+                optional_group_op,
+            ));
+        }
+
         // Parse and Check sides for `=` operator
         if operator.kind == TokenKind::Equal {
             let expected_rhs_types = if has_group_op {
@@ -518,10 +528,15 @@ pub(crate) fn parse_comparison_expression(
 
         // Parse and Check sides for `<=>` operator
         if operator.kind == TokenKind::NullSafeEqual {
+            // Without NULL operands `<=>` is the same as `=`
             let expected_rhs_types = if has_group_op {
                 lhs_type.can_perform_group_null_safe_eq_op_with()
             } else {
-                lhs_type.can_perform_null_safe_eq_op_with()
+                [
+                    lhs_type.can_perform_null_safe_eq_op_with(),
+                    lhs_type.can_perform_eq_op_with(),
+                ]
+                .concat()
             };
 
             // Can perform this operator between LHS and RHS
@@ -559,7 +574,11 @@ pub(crate) fn parse_comparison_expression(
             let expected_lhs_types = if has_group_op {
                 rhs_type.can_perform_group_null_safe_eq_op_with()
             } else {
-                rhs_type.can_perform_null_safe_eq_op_with()
+                [
+                    rhs_type.can_perform_null_safe_eq_op_with(),
+                    rhs_type.can_perform_eq_op_with(),
+                ]
+                .concat()
             };
 
             for expected_type in expected_lhs_types.iter() {
@@ -593,6 +612,18 @@ pub(crate) fn parse_comparison_expression(
     Ok(lhs)
 }
 
+fn comparison_operator(kind: &TokenKind) -> ComparisonOperator {
+    match kind {
+        TokenKind::Greater => ComparisonOperator::Greater,
+        TokenKind::GreaterEqual => ComparisonOperator::GreaterEqual,
+        TokenKind::Less => ComparisonOperator::Less,
+        TokenKind::LessEqual => ComparisonOperator::LessEqual,
+        TokenKind::BangEqual => ComparisonOperator::NotEqual,
+        TokenKind::NullSafeEqual => ComparisonOperator::NullSafeEqual,
+        _ => ComparisonOperator::Equal,
+    }
+}
+
 fn parse_optional_group_operator(
     tokens: &[Token],
     position: &mut usize,
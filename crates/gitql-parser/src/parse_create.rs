@@ -0,0 +1,656 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use gitql_ast::expression::CastExpr;
+use gitql_ast::statement::GQLQuery;
+use gitql_ast::statement::Query;
+use gitql_ast::statement::SelectStatement;
+use gitql_ast::types::composite::CompositeType;
+use gitql_ast::types::enumeration::EnumType;
+use gitql_ast::types::DataType;
+use gitql_core::environment::intern_name;
+use gitql_core::environment::Environment;
+use gitql_core::signature::Signature;
+use gitql_core::signature::UserFunction;
+
+use crate::context::ParserContext;
+use crate::diagnostic::Diagnostic;
+use crate::parse_type::parse_type;
+use crate::parser::calculate_safe_location;
+use crate::parser::consume_conditional_token_or_errors;
+use crate::parser::consume_token_or_error;
+use crate::parser::is_current_token;
+use crate::parser::parse_expression;
+use crate::parser::parse_select_query;
+use crate::token::Token;
+use crate::token::TokenKind;
+
+/// Parse `CREATE [OR REPLACE] FUNCTION`, `CREATE [OR REPLACE] VIEW` or `CREATE [OR REPLACE] TYPE` statement
+/// and register the definition in the environment for the rest of the session
+pub(crate) fn parse_create_definition(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<(), Box<Diagnostic>> {
+    // Consume `CREATE` keyword
+    *position += 1;
+
+    // Check for optional `OR REPLACE`, `REPLACE` is not a keyword because it's also a function name
+    let mut or_replace = false;
+    if is_current_token(tokens, position, TokenKind::OrKeyword) {
+        // Consume `OR` keyword
+        *position += 1;
+
+        consume_conditional_token_or_errors(
+            tokens,
+            position,
+            |token| matches!(&token.kind, TokenKind::Symbol(name) if name.eq_ignore_ascii_case("replace")),
+            "Expect `REPLACE` after `CREATE OR`",
+        )?;
+
+        or_replace = true;
+    }
+
+    if is_current_token(tokens, position, TokenKind::Function) {
+        return parse_create_function(env, tokens, position, or_replace);
+    }
+
+    if is_current_token(tokens, position, TokenKind::View) {
+        return parse_create_view(env, tokens, position, or_replace);
+    }
+
+    if matches!(&tokens.get(*position).map(|token| &token.kind), Some(TokenKind::Symbol(name)) if name.eq_ignore_ascii_case("type"))
+    {
+        return parse_create_type(env, tokens, position, or_replace);
+    }
+
+    Err(
+        Diagnostic::error("Expect `FUNCTION`, `VIEW` or `TYPE` after `CREATE` keyword")
+            .with_location(calculate_safe_location(tokens, *position))
+            .as_boxed(),
+    )
+}
+
+fn parse_create_function(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    or_replace: bool,
+) -> Result<(), Box<Diagnostic>> {
+    // Consume `FUNCTION` keyword
+    *position += 1;
+
+    let name_token = consume_conditional_token_or_errors(
+        tokens,
+        position,
+        |token| matches!(token.kind, TokenKind::Symbol(_)),
+        "Expect function name after `CREATE FUNCTION`",
+    )?;
+
+    let function_name = name_token.to_string();
+    let function_name_location = name_token.location;
+
+    // Make sure the name is not used by built in function
+    if (env.is_std_function(&function_name) && !env.is_user_function(&function_name))
+        || env.is_higher_order_function(&function_name)
+        || env.is_aggregation_function(&function_name)
+        || env.is_window_function(&function_name)
+    {
+        return Err(Diagnostic::error(&format!(
+            "Can't redefine built in function `{}`",
+            function_name
+        ))
+        .with_location(function_name_location)
+        .as_boxed());
+    }
+
+    if env.is_user_function(&function_name) && !or_replace {
+        return Err(
+            Diagnostic::error(&format!("Function `{}` is already defined", function_name))
+                .add_help("Use `CREATE OR REPLACE FUNCTION` to replace the current definition")
+                .with_location(function_name_location)
+                .as_boxed(),
+        );
+    }
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` after function name",
+    )?;
+
+    let mut parameters: Vec<String> = vec![];
+    let mut parameters_types: Vec<Box<dyn DataType>> = vec![];
+    while *position < tokens.len() && tokens[*position].kind != TokenKind::RightParen {
+        let parameter_token = consume_conditional_token_or_errors(
+            tokens,
+            position,
+            |token| matches!(token.kind, TokenKind::Symbol(_)),
+            "Expect parameter name",
+        )?;
+
+        let parameter_name = parameter_token.to_string();
+        if parameters.contains(&parameter_name) {
+            return Err(Diagnostic::error(&format!(
+                "Parameter `{}` is defined more than once",
+                parameter_name
+            ))
+            .with_location(parameter_token.location)
+            .as_boxed());
+        }
+
+        let parameter_type = parse_type(env, tokens, position)?;
+        parameters.push(parameter_name);
+        parameters_types.push(parameter_type);
+
+        if is_current_token(tokens, position, TokenKind::Comma) {
+            *position += 1;
+        } else {
+            break;
+        }
+    }
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` after function parameters",
+    )?;
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::Returns,
+        "Expect `RETURNS` and type after function parameters",
+    )?;
+
+    let return_type = parse_type(env, tokens, position)?;
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::As,
+        "Expect `AS` and expression after function return type",
+    )?;
+
+    if *position >= tokens.len() || tokens[*position].kind == TokenKind::Semicolon {
+        return Err(Diagnostic::error("Expect expression as function body")
+            .with_location(calculate_safe_location(tokens, *position))
+            .as_boxed());
+    }
+
+    // Parameters are resolved from the function scope, body can't reference tables columns
+    let body_location = tokens[*position].location;
+    let mut context = ParserContext {
+        lambda_parameters: parameters.clone(),
+        ..ParserContext::default()
+    };
+
+    // Type check the body in a scoped copy so the parameters are not visible to the next statements
+    let outer_scopes = env.scopes.clone();
+    for (name, data_type) in parameters.iter().zip(parameters_types.iter()) {
+        env.define(name.to_string(), data_type.clone());
+    }
+
+    let body_result = parse_expression(&mut context, env, tokens, position);
+    env.scopes = outer_scopes;
+    let mut body = body_result?;
+
+    if is_function_reachable(env, &function_name, &context.called_user_functions) {
+        return Err(Diagnostic::error(&format!(
+            "Function `{}` can't call itself directly or through other functions",
+            function_name
+        ))
+        .with_location(body_location)
+        .as_boxed());
+    }
+
+    if !context.projection_names.is_empty() {
+        return Err(Diagnostic::error(&format!(
+            "Unresolved name `{}` in function body",
+            context.projection_names[0]
+        ))
+        .add_note("Function body can only reference its parameters")
+        .with_location(context.projection_locations[0])
+        .as_boxed());
+    }
+
+    if !context.aggregations.is_empty() || !context.window_functions.is_empty() {
+        return Err(Diagnostic::error(
+            "Can't use aggregation or window functions inside function body",
+        )
+        .with_location(body_location)
+        .as_boxed());
+    }
+
+    let body_type = body.expr_type();
+    if !return_type.equals(&body_type) {
+        if !return_type.has_implicit_cast_from(&body) {
+            return Err(Diagnostic::error(&format!(
+                "Function `{}` expects return type `{}` but body type is `{}`",
+                function_name,
+                return_type.literal(),
+                body_type.literal()
+            ))
+            .with_location(body_location)
+            .as_boxed());
+        }
+
+        body = Box::new(CastExpr {
+            value: body,
+            result_type: return_type.clone(),
+        });
+    }
+
+    let signature = Signature::new(parameters_types, return_type);
+    env.std_signatures
+        .insert(intern_name(&function_name), signature);
+
+    let function = UserFunction {
+        parameters,
+        body,
+        dependencies: context.called_user_functions,
+    };
+    env.user_functions.insert(function_name, Rc::new(function));
+
+    Ok(())
+}
+
+fn parse_create_view(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    or_replace: bool,
+) -> Result<(), Box<Diagnostic>> {
+    // Consume `VIEW` keyword
+    *position += 1;
+
+    let name_token = consume_conditional_token_or_errors(
+        tokens,
+        position,
+        |token| matches!(token.kind, TokenKind::Symbol(_)),
+        "Expect view name after `CREATE VIEW`",
+    )?;
+
+    let view_name = name_token.to_string();
+    let view_name_location = name_token.location;
+
+    if env
+        .schema
+        .tables_fields_names
+        .contains_key(view_name.as_str())
+    {
+        if !env.is_view(&view_name) {
+            return Err(Diagnostic::error(&format!(
+                "Can't create view with existing table name `{}`",
+                view_name
+            ))
+            .with_location(view_name_location)
+            .as_boxed());
+        }
+
+        if !or_replace {
+            return Err(
+                Diagnostic::error(&format!("View `{}` is already defined", view_name))
+                    .add_help("Use `CREATE OR REPLACE VIEW` to replace the current definition")
+                    .with_location(view_name_location)
+                    .as_boxed(),
+            );
+        }
+    }
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::As,
+        "Expect `AS` and select query after view name",
+    )?;
+
+    if !is_current_token(tokens, position, TokenKind::Select) {
+        return Err(Diagnostic::error("Expect `SELECT` query after `AS`")
+            .with_location(calculate_safe_location(tokens, *position))
+            .as_boxed());
+    }
+
+    let query_location = tokens[*position].location;
+    let Query::Select(query) = parse_select_query(env, tokens, position)? else {
+        unreachable!()
+    };
+
+    if query.statements.contains_key("into") {
+        return Err(Diagnostic::error("View query can't have `INTO` statement")
+            .with_location(query_location)
+            .as_boxed());
+    }
+
+    if is_view_reachable(env, &view_name, &query) {
+        return Err(Diagnostic::error(&format!(
+            "View `{}` can't select from itself directly or through other views",
+            view_name
+        ))
+        .with_location(query_location)
+        .as_boxed());
+    }
+
+    // Resolve the visible columns names and types of the view
+    let hidden_selections: Vec<&String> = query.hidden_selections.values().flatten().collect();
+    let mut columns: Vec<(String, Box<dyn DataType>)> = vec![];
+    let select_statement = query.statements.get("select").unwrap();
+    let select_statement = select_statement
+        .as_any()
+        .downcast_ref::<SelectStatement>()
+        .unwrap();
+
+    for table_selection in &select_statement.table_selections {
+        for column_name in &table_selection.columns_names {
+            if hidden_selections.contains(&column_name) {
+                continue;
+            }
+
+            let title = match query.alias_table.get(column_name) {
+                Some(alias) => alias.to_string(),
+                None => column_name.to_string(),
+            };
+
+            if title.starts_with("column_")
+                && !env.schema.tables_fields_types.contains_key(title.as_str())
+            {
+                return Err(Diagnostic::error(&format!(
+                    "View `{}` column expression must have a name",
+                    view_name
+                ))
+                .add_help("Use `AS` to give the expression a name")
+                .with_location(query_location)
+                .as_boxed());
+            }
+
+            let column_type = match env.scopes.get(&title) {
+                Some(data_type) => data_type.clone(),
+                None => match env.schema.tables_fields_types.get(title.as_str()) {
+                    Some(data_type) => data_type.clone(),
+                    None => continue,
+                },
+            };
+
+            columns.push((title, column_type));
+        }
+    }
+
+    // Columns types are shared between tables, so the same name must have the same type
+    for (name, data_type) in columns.iter() {
+        if let Some(current_type) = env.schema.tables_fields_types.get(name.as_str()) {
+            if !current_type.equals(data_type) {
+                return Err(Diagnostic::error(&format!(
+                    "View column `{}` has type `{}` but it's already defined with type `{}`",
+                    name,
+                    data_type.literal(),
+                    current_type.literal()
+                ))
+                .add_help("Use `AS` to give the column a different name")
+                .with_location(query_location)
+                .as_boxed());
+            }
+        }
+    }
+
+    let mut fields_names: Vec<&'static str> = Vec::with_capacity(columns.len());
+    for (name, data_type) in columns {
+        let name = intern_name(&name);
+        if !env.schema.tables_fields_types.contains_key(name) {
+            env.schema.tables_fields_types.insert(name, data_type);
+        }
+        fields_names.push(name);
+    }
+
+    env.schema
+        .tables_fields_names
+        .insert(intern_name(&view_name), fields_names);
+    env.views.insert(view_name, query);
+    Ok(())
+}
+
+fn parse_create_type(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    or_replace: bool,
+) -> Result<(), Box<Diagnostic>> {
+    // Consume `TYPE` keyword
+    *position += 1;
+
+    let name_token = consume_conditional_token_or_errors(
+        tokens,
+        position,
+        |token| matches!(token.kind, TokenKind::Symbol(_)),
+        "Expect type name after `CREATE TYPE`",
+    )?;
+
+    let type_name = name_token.to_string();
+    let type_name_location = name_token.location;
+
+    // Only user defined composite and enum types can be replaced
+    if let Some(current_type) = env.types_table.lookup(&type_name) {
+        if !current_type.is_composite() && !current_type.is_enum() {
+            return Err(Diagnostic::error(&format!(
+                "Can't redefine built in type `{}`",
+                type_name
+            ))
+            .with_location(type_name_location)
+            .as_boxed());
+        }
+
+        if !or_replace {
+            return Err(
+                Diagnostic::error(&format!("Type `{}` is already defined", type_name))
+                    .add_help("Use `CREATE OR REPLACE TYPE` to replace the current definition")
+                    .with_location(type_name_location)
+                    .as_boxed(),
+            );
+        }
+    }
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::As,
+        "Expect `AS` after type name",
+    )?;
+
+    let is_enum = matches!(&tokens.get(*position).map(|token| &token.kind), Some(TokenKind::Symbol(name)) if name.eq_ignore_ascii_case("enum"));
+    let data_type: Box<dyn DataType> = if is_enum {
+        // Consume `ENUM` keyword
+        *position += 1;
+        let labels = parse_enum_labels(tokens, position)?;
+        Box::new(EnumType::new(type_name.to_string(), labels))
+    } else {
+        let composite_type = CompositeType::empty(type_name.to_string());
+        Box::new(parse_composite_members(
+            env,
+            tokens,
+            position,
+            composite_type,
+        )?)
+    };
+
+    env.types_table.register(intern_name(&type_name), data_type);
+    Ok(())
+}
+
+/// Return true if the function is called by one of the functions or the functions they call
+fn is_function_reachable(env: &Environment, function_name: &str, calls: &[String]) -> bool {
+    let mut visited: HashSet<&str> = HashSet::new();
+    let mut pending: Vec<&str> = calls.iter().map(|name| name.as_str()).collect();
+    while let Some(name) = pending.pop() {
+        if name == function_name {
+            return true;
+        }
+
+        if !visited.insert(name) {
+            continue;
+        }
+
+        if let Some(function) = env.user_function(name) {
+            pending.extend(function.dependencies.iter().map(|name| name.as_str()));
+        }
+    }
+    false
+}
+
+/// Return true if the view is selected by the query or by the views it selects from
+fn is_view_reachable(env: &Environment, view_name: &str, query: &GQLQuery) -> bool {
+    let mut visited: HashSet<&str> = HashSet::new();
+    let mut pending: Vec<&str> = query_tables_names(query);
+    while let Some(name) = pending.pop() {
+        if name == view_name {
+            return true;
+        }
+
+        if !visited.insert(name) {
+            continue;
+        }
+
+        if let Some(view) = env.views.get(name) {
+            pending.extend(query_tables_names(view));
+        }
+    }
+    false
+}
+
+fn query_tables_names(query: &GQLQuery) -> Vec<&str> {
+    let Some(statement) = query.statements.get("select") else {
+        return vec![];
+    };
+
+    let Some(select_statement) = statement.as_any().downcast_ref::<SelectStatement>() else {
+        return vec![];
+    };
+
+    select_statement
+        .table_selections
+        .iter()
+        .map(|table_selection| table_selection.table_name.as_str())
+        .collect()
+}
+
+/// Parse `(member type, ...)` of composite type
+fn parse_composite_members(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    mut composite_type: CompositeType,
+) -> Result<CompositeType, Box<Diagnostic>> {
+    let left_paren_location = calculate_safe_location(tokens, *position);
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` or `ENUM` after `AS`",
+    )?;
+
+    while *position < tokens.len() && tokens[*position].kind != TokenKind::RightParen {
+        let member_token = consume_conditional_token_or_errors(
+            tokens,
+            position,
+            |token| matches!(token.kind, TokenKind::Symbol(_)),
+            "Expect member name",
+        )?;
+
+        let member_name = member_token.to_string();
+        if composite_type.members.contains_key(&member_name) {
+            return Err(Diagnostic::error(&format!(
+                "Member `{}` is defined more than once",
+                member_name
+            ))
+            .with_location(member_token.location)
+            .as_boxed());
+        }
+
+        let member_type = parse_type(env, tokens, position)?;
+        composite_type = composite_type.add_member(member_name, member_type);
+
+        if is_current_token(tokens, position, TokenKind::Comma) {
+            *position += 1;
+        } else {
+            break;
+        }
+    }
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` after type members",
+    )?;
+
+    if composite_type.members.is_empty() {
+        return Err(
+            Diagnostic::error("Composite type must have at least one member")
+                .with_location(left_paren_location)
+                .as_boxed(),
+        );
+    }
+
+    Ok(composite_type)
+}
+
+/// Parse `('label', ...)` of enum type
+fn parse_enum_labels(
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Vec<String>, Box<Diagnostic>> {
+    let left_paren_location = calculate_safe_location(tokens, *position);
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` after `ENUM`",
+    )?;
+
+    let mut labels: Vec<String> = vec![];
+    while *position < tokens.len() && tokens[*position].kind != TokenKind::RightParen {
+        let label_token = consume_conditional_token_or_errors(
+            tokens,
+            position,
+            |token| matches!(token.kind, TokenKind::String(_)),
+            "Expect enum label as string",
+        )?;
+
+        let label = match &label_token.kind {
+            TokenKind::String(label) => label.to_string(),
+            _ => unreachable!(),
+        };
+
+        if labels.contains(&label) {
+            return Err(Diagnostic::error(&format!(
+                "Enum label `{}` is defined more than once",
+                label
+            ))
+            .with_location(label_token.location)
+            .as_boxed());
+        }
+
+        labels.push(label);
+
+        if is_current_token(tokens, position, TokenKind::Comma) {
+            *position += 1;
+        } else {
+            break;
+        }
+    }
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` after enum labels",
+    )?;
+
+    if labels.is_empty() {
+        return Err(Diagnostic::error("Enum type must have at least one label")
+            .with_location(left_paren_location)
+            .as_boxed());
+    }
+
+    Ok(labels)
+}
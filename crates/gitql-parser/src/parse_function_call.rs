@@ -1,30 +1,44 @@
 use gitql_ast::expression::CallExpr;
+use gitql_ast::expression::CastExpr;
 use gitql_ast::expression::Expr;
+use gitql_ast::expression::LambdaExpr;
+use gitql_ast::expression::StringExpr;
 use gitql_ast::expression::SymbolExpr;
 use gitql_ast::expression::SymbolFlag;
+use gitql_ast::statement::AggregateFunction;
 use gitql_ast::statement::AggregateValue;
 use gitql_ast::statement::OrderByStatement;
 use gitql_ast::statement::WindowDefinition;
+use gitql_ast::statement::WindowFrameBound;
+use gitql_ast::statement::WindowFrameClause;
+use gitql_ast::statement::WindowFrameKind;
 use gitql_ast::statement::WindowFunction;
 use gitql_ast::statement::WindowFunctionKind;
 use gitql_ast::statement::WindowOrderingClause;
 use gitql_ast::statement::WindowPartitioningClause;
 use gitql_ast::statement::WindowValue;
+use gitql_ast::types::dynamic::DynamicType;
+use gitql_ast::types::lambda::LambdaType;
+use gitql_ast::types::DataType;
 use gitql_core::environment::Environment;
 
 use crate::context::ParserContext;
 use crate::diagnostic::Diagnostic;
+use crate::parser::calculate_safe_location;
 use crate::parser::consume_token_or_error;
+use crate::parser::expression_literal;
 use crate::parser::is_current_token;
 use crate::parser::is_current_token_with_condition;
 use crate::parser::parse_expression;
 use crate::parser::parse_member_access_expression;
 use crate::parser::parse_order_by_statement;
 use crate::parser::parse_zero_or_more_values_with_comma_between;
+use crate::token::SourceLocation;
 use crate::token::Token;
 use crate::token::TokenKind;
 use crate::type_checker::check_function_call_arguments;
-use crate::type_checker::resolve_dynamic_data_type;
+use crate::type_checker::resolve_lambda_parameter_type;
+use crate::type_checker::resolve_return_data_type;
 
 pub(crate) fn parse_function_call_expression(
     context: &mut ParserContext,
@@ -44,8 +58,60 @@ pub(crate) fn parse_function_call_expression(
         // Consume function name
         *position += 1;
 
-        // Check if this function is a Standard library functions
-        if env.is_std_function(function_name) {
+        // Check if this function is a Higher order function that accept lambda argument
+        if env.is_higher_order_function(function_name) {
+            let Some(signature) = env.std_signature(function_name.as_str()).cloned() else {
+                return Err(Diagnostic::error(&format!(
+                    "Can't find signature for function with name {}",
+                    function_name
+                ))
+                .with_location(function_name_location)
+                .as_boxed());
+            };
+
+            let mut arguments = parse_higher_order_function_arguments(
+                context,
+                env,
+                tokens,
+                position,
+                &signature.parameters,
+                function_name,
+                function_name_location,
+            )?;
+
+            check_function_call_arguments(
+                &mut arguments,
+                &signature.parameters,
+                function_name.to_string(),
+                function_name_location,
+            )?;
+
+            let return_type =
+                resolve_return_data_type(&signature.parameters, &arguments, &signature.return_type);
+
+            // Register function name with return type after resolving it
+            env.define(function_name.to_string(), return_type.clone());
+
+            return Ok(Box::new(CallExpr {
+                function_name: function_name.to_string(),
+                arguments,
+                return_type,
+            }));
+        }
+
+        // `EXTRACT(field FROM value)` has special syntax for the `date_part` function arguments
+        if function_name == "extract" {
+            return parse_extract_call_expression(
+                context,
+                env,
+                tokens,
+                position,
+                function_name_location,
+            );
+        }
+
+        // Check if this function is a Standard library or user defined functions
+        if env.is_std_function(function_name) || env.is_user_function(function_name) {
             let mut arguments = parse_zero_or_more_values_with_comma_between(
                 context,
                 env,
@@ -54,6 +120,14 @@ pub(crate) fn parse_function_call_expression(
                 "Std function",
             )?;
 
+            if env.is_user_function(function_name)
+                && !context.called_user_functions.contains(function_name)
+            {
+                context
+                    .called_user_functions
+                    .push(function_name.to_string());
+            }
+
             if let Some(signature) = env.std_signature(function_name.as_str()) {
                 check_function_call_arguments(
                     &mut arguments,
@@ -62,7 +136,7 @@ pub(crate) fn parse_function_call_expression(
                     function_name_location,
                 )?;
 
-                let return_type = resolve_dynamic_data_type(
+                let return_type = resolve_return_data_type(
                     &signature.parameters,
                     &arguments,
                     &signature.return_type,
@@ -89,13 +163,19 @@ pub(crate) fn parse_function_call_expression(
 
         // Check if this function is an Aggregation functions
         if env.is_aggregation_function(function_name) {
-            let mut arguments = parse_zero_or_more_values_with_comma_between(
-                context,
-                env,
-                tokens,
-                position,
-                "Aggregation function",
-            )?;
+            let aggregation_arguments =
+                parse_aggregation_function_arguments(context, env, tokens, position)?;
+            let mut arguments = aggregation_arguments.arguments;
+
+            if aggregation_arguments.separator.is_some() && function_name != "group_concat" {
+                return Err(Diagnostic::error(
+                    "`SEPARATOR` can only be used with `group_concat` function",
+                )
+                .with_location(function_name_location)
+                .as_boxed());
+            }
+
+            let filter = parse_aggregation_filter_clause(context, env, tokens, position)?;
 
             if let Some(signature) = env.aggregation_signature(function_name.as_str()) {
                 // Perform type checking and implicit casting if needed for function arguments
@@ -109,7 +189,7 @@ pub(crate) fn parse_function_call_expression(
                 let column_name = context.name_generator.generate_column_name();
                 context.hidden_selections.push(column_name.to_string());
 
-                let return_type = resolve_dynamic_data_type(
+                let return_type = resolve_return_data_type(
                     &signature.parameters,
                     &arguments,
                     &signature.return_type,
@@ -121,7 +201,10 @@ pub(crate) fn parse_function_call_expression(
                 let is_used_as_window_function =
                     *position < tokens.len() && matches!(tokens[*position].kind, TokenKind::Over);
 
-                if is_used_as_window_function && context.has_select_statement {
+                if is_used_as_window_function
+                    && context.has_select_statement
+                    && !context.inside_qualify
+                {
                     return Err(Diagnostic::error(
                         "Window function can't called after `SELECT` statement",
                     )
@@ -131,6 +214,18 @@ pub(crate) fn parse_function_call_expression(
 
                 let mut flag = SymbolFlag::AggregationReference;
                 if is_used_as_window_function {
+                    if aggregation_arguments.is_distinct
+                        || aggregation_arguments.ordering.is_some()
+                        || aggregation_arguments.separator.is_some()
+                        || filter.is_some()
+                    {
+                        return Err(Diagnostic::error(
+                            "Aggregated window function can't have `DISTINCT`, `ORDER BY`, `SEPARATOR` or `FILTER`",
+                        )
+                        .with_location(function_name_location)
+                        .as_boxed());
+                    }
+
                     // Consume `OVER` token
                     *position += 1;
 
@@ -150,7 +245,14 @@ pub(crate) fn parse_function_call_expression(
 
                     flag = SymbolFlag::WindowReference;
                 } else {
-                    let function = AggregateValue::Function(function_name.to_string(), arguments);
+                    let function = AggregateValue::Function(AggregateFunction {
+                        function_name: function_name.to_string(),
+                        arguments,
+                        is_distinct: aggregation_arguments.is_distinct,
+                        ordering: aggregation_arguments.ordering,
+                        separator: aggregation_arguments.separator,
+                        filter,
+                    });
                     context.aggregations.insert(column_name.clone(), function);
                 }
 
@@ -201,7 +303,7 @@ pub(crate) fn parse_function_call_expression(
                 .as_boxed());
             }
 
-            if context.has_select_statement {
+            if context.has_select_statement && !context.inside_qualify {
                 return Err(Diagnostic::error(
                     "Window function can't called after `SELECT` statement",
                 )
@@ -219,12 +321,13 @@ pub(crate) fn parse_function_call_expression(
                 )?;
 
                 // Make sure Window function is called in the right place only
-                if !(context.inside_selections || context.inside_order_by) {
+                if !(context.inside_selections || context.inside_qualify || context.inside_order_by)
+                {
                     return Err(Diagnostic::error(
-                        "Window function can only be called inside Select selection or Order by",
+                        "Window function can only be called inside Select selection, Qualify or Order by",
                     )
                     .add_note("Window functions evaluated later right before `ORDER BY`")
-                    .add_help("You can call Window function in Select selection or Order by")
+                    .add_help("You can call Window function in Select selection, Qualify or Order by")
                     .with_location(function_name_location)
                     .as_boxed());
                 }
@@ -232,7 +335,7 @@ pub(crate) fn parse_function_call_expression(
                 let column_name = context.name_generator.generate_column_name();
                 context.hidden_selections.push(column_name.to_string());
 
-                let return_type = resolve_dynamic_data_type(
+                let return_type = resolve_return_data_type(
                     &signature.parameters,
                     &arguments,
                     &signature.return_type,
@@ -292,6 +395,486 @@ pub(crate) fn parse_function_call_expression(
     parse_member_access_expression(context, env, tokens, position)
 }
 
+/// Fields that can be extracted from date time or interval using `EXTRACT(field FROM value)`
+const EXTRACT_FIELDS: [&str; 17] = [
+    "millennium",
+    "century",
+    "decade",
+    "year",
+    "isoyear",
+    "quarter",
+    "month",
+    "week",
+    "day",
+    "dow",
+    "isodow",
+    "doy",
+    "hour",
+    "minute",
+    "second",
+    "epoch",
+    "timezone",
+];
+
+/// Parse `EXTRACT(field FROM value)` as a call to `date_part('field', value)`
+fn parse_extract_call_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    extract_location: SourceLocation,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` after `EXTRACT`",
+    )?;
+
+    let field = match tokens.get(*position).map(|token| &token.kind) {
+        Some(TokenKind::Symbol(field)) | Some(TokenKind::String(field)) => field.to_lowercase(),
+        _ => {
+            return Err(Diagnostic::error(
+                "Expect field name like `year` or `hour` after `EXTRACT(`",
+            )
+            .with_location(calculate_safe_location(tokens, *position))
+            .as_boxed())
+        }
+    };
+
+    if !EXTRACT_FIELDS.contains(&field.as_str()) {
+        return Err(
+            Diagnostic::error(&format!("Unknown field `{}` for `EXTRACT`", field))
+                .add_help(&format!("Expect one of {}", EXTRACT_FIELDS.join(", ")))
+                .with_location(tokens[*position].location)
+                .as_boxed(),
+        );
+    }
+
+    // Consume field name
+    *position += 1;
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::From,
+        "Expect `FROM` after `EXTRACT` field name",
+    )?;
+
+    let value = parse_expression(context, env, tokens, position)?;
+    if let Some(value_literal) = expression_literal(&value) {
+        if !context.lambda_parameters.contains(&value_literal) {
+            context.hidden_selections.push(value_literal);
+        }
+    }
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` at the end of `EXTRACT` expression",
+    )?;
+
+    let function_name = "date_part";
+    let Some(signature) = env.std_signature(function_name).cloned() else {
+        return Err(Diagnostic::error(&format!(
+            "Can't find signature for function with name {}",
+            function_name
+        ))
+        .with_location(extract_location)
+        .as_boxed());
+    };
+
+    let mut arguments: Vec<Box<dyn Expr>> = vec![Box::new(StringExpr { value: field }), value];
+    check_function_call_arguments(
+        &mut arguments,
+        &signature.parameters,
+        function_name.to_string(),
+        extract_location,
+    )?;
+
+    Ok(Box::new(CallExpr {
+        function_name: function_name.to_string(),
+        arguments,
+        return_type: signature.return_type.clone(),
+    }))
+}
+
+struct AggregationFunctionArguments {
+    arguments: Vec<Box<dyn Expr>>,
+    is_distinct: bool,
+    ordering: Option<OrderByStatement>,
+    separator: Option<String>,
+}
+
+/// Parse aggregation function arguments with optional modifiers
+/// `(DISTINCT <arguments> ORDER BY <ordering> SEPARATOR <string>)`
+fn parse_higher_order_function_arguments(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    parameters: &[Box<dyn DataType>],
+    function_name: &str,
+    function_name_location: SourceLocation,
+) -> Result<Vec<Box<dyn Expr>>, Box<Diagnostic>> {
+    // Consume `(` token at the start of list of arguments
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` after Higher order function",
+    )?;
+
+    let mut arguments: Vec<Box<dyn Expr>> = vec![];
+    while *position < tokens.len() && tokens[*position].kind != TokenKind::RightParen {
+        // Lambda parameters types depend on the previous arguments, so they must be valid first
+        for (index, argument) in arguments.iter().enumerate() {
+            let parameter_type = &parameters[index];
+            if parameter_type.as_any().is::<DynamicType>() {
+                continue;
+            }
+
+            let argument_type = argument.expr_type();
+            if !parameter_type.equals(&argument_type) {
+                return Err(Diagnostic::error(&format!(
+                    "Function `{}` argument number {} with type `{}` don't match expected type `{}`",
+                    function_name,
+                    index,
+                    argument_type.literal(),
+                    parameter_type.literal()
+                ))
+                .with_location(function_name_location)
+                .as_boxed());
+            }
+        }
+
+        let argument = match resolve_lambda_parameter_type(parameters, &arguments) {
+            Some(lambda_type) => {
+                parse_lambda_expression(context, env, tokens, position, lambda_type, function_name)?
+            }
+            None => parse_expression(context, env, tokens, position)?,
+        };
+
+        if let Some(argument_literal) = expression_literal(&argument) {
+            if !context.lambda_parameters.contains(&argument_literal) {
+                context.hidden_selections.push(argument_literal);
+            }
+        }
+
+        arguments.push(argument);
+
+        if *position < tokens.len() && tokens[*position].kind == TokenKind::Comma {
+            *position += 1;
+        } else {
+            break;
+        }
+    }
+
+    // Consume `)` token at the end of arguments
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` at the end of Higher order function arguments",
+    )?;
+
+    Ok(arguments)
+}
+
+fn parse_lambda_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    lambda_type: LambdaType,
+    function_name: &str,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    let lambda_location = calculate_safe_location(tokens, *position);
+    let mut parameters: Vec<String> = vec![];
+
+    // Lambda parameters can be one symbol `x ->` or list of symbols between parenthesis `(x, y) ->`
+    if is_current_token(tokens, position, TokenKind::LeftParen) {
+        // Consume `(`
+        *position += 1;
+
+        while *position < tokens.len() {
+            if let TokenKind::Symbol(name) = &tokens[*position].kind {
+                if parameters.contains(name) {
+                    return Err(Diagnostic::error(&format!(
+                        "Lambda parameter `{}` is defined more than once",
+                        name
+                    ))
+                    .with_location(tokens[*position].location)
+                    .as_boxed());
+                }
+
+                parameters.push(name.to_string());
+
+                // Consume parameter name
+                *position += 1;
+            } else {
+                break;
+            }
+
+            if is_current_token(tokens, position, TokenKind::Comma) {
+                *position += 1;
+            } else {
+                break;
+            }
+        }
+
+        consume_token_or_error(
+            tokens,
+            position,
+            TokenKind::RightParen,
+            "Expect `)` after lambda parameters",
+        )?;
+    } else if *position < tokens.len() {
+        if let TokenKind::Symbol(name) = &tokens[*position].kind {
+            parameters.push(name.to_string());
+
+            // Consume parameter name
+            *position += 1;
+        }
+    }
+
+    if parameters.is_empty() || !is_current_token(tokens, position, TokenKind::RightArrow) {
+        return Err(Diagnostic::error(&format!(
+            "Function `{}` expects lambda expression as argument",
+            function_name
+        ))
+        .add_help("Lambda expression has one or more parameters and body, for example `x -> x > 1`")
+        .with_location(lambda_location)
+        .as_boxed());
+    }
+
+    if parameters.len() != lambda_type.parameters.len() {
+        return Err(Diagnostic::error(&format!(
+            "Lambda argument of function `{}` expects `{}` parameters but got `{}`",
+            function_name,
+            lambda_type.parameters.len(),
+            parameters.len()
+        ))
+        .with_location(lambda_location)
+        .as_boxed());
+    }
+
+    // Consume `->`
+    *position += 1;
+
+    // Define the parameters in the current scope while parsing the body,
+    // and restore the shadowed symbols types after it
+    let mut shadowed_types = Vec::with_capacity(parameters.len());
+    for (name, data_type) in parameters.iter().zip(lambda_type.parameters.iter()) {
+        shadowed_types.push(env.scopes.insert(name.to_string(), data_type.clone()));
+        context.lambda_parameters.push(name.to_string());
+    }
+
+    let body_result = parse_expression(context, env, tokens, position);
+
+    for (name, shadowed_type) in parameters.iter().zip(shadowed_types) {
+        context.lambda_parameters.pop();
+        match shadowed_type {
+            Some(data_type) => env.scopes.insert(name.to_string(), data_type),
+            None => env.scopes.remove(name),
+        };
+    }
+
+    let mut body = body_result?;
+    let body_type = body.expr_type();
+    let mut return_type = lambda_type.return_type;
+    if return_type.is_any() {
+        return_type = body_type;
+    } else if !return_type.equals(&body_type) {
+        if !return_type.has_implicit_cast_from(&body) {
+            return Err(Diagnostic::error(&format!(
+                "Lambda argument of function `{}` expects return type `{}` but got `{}`",
+                function_name,
+                return_type.literal(),
+                body_type.literal()
+            ))
+            .with_location(lambda_location)
+            .as_boxed());
+        }
+
+        body = Box::new(CastExpr {
+            value: body,
+            result_type: return_type.clone(),
+        });
+    }
+
+    Ok(Box::new(LambdaExpr {
+        parameters,
+        body,
+        lambda_type: Box::new(LambdaType::new(lambda_type.parameters, return_type)),
+    }))
+}
+
+fn parse_aggregation_function_arguments(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<AggregationFunctionArguments, Box<Diagnostic>> {
+    // Consume `(` token at the start of arguments
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` after Aggregation function",
+    )?;
+
+    let is_distinct = is_current_token(tokens, position, TokenKind::Distinct);
+    if is_distinct {
+        // Consume `DISTINCT` keyword
+        *position += 1;
+    }
+
+    let mut arguments: Vec<Box<dyn Expr>> = vec![];
+    while *position < tokens.len()
+        && !matches!(
+            tokens[*position].kind,
+            TokenKind::RightParen | TokenKind::Order | TokenKind::Separator
+        )
+    {
+        let argument = parse_expression(context, env, tokens, position)?;
+        if let Some(argument_literal) = expression_literal(&argument) {
+            context.hidden_selections.push(argument_literal);
+        }
+
+        arguments.push(argument);
+
+        if is_current_token(tokens, position, TokenKind::Comma) {
+            *position += 1;
+        } else {
+            break;
+        }
+    }
+
+    if is_distinct && arguments.is_empty() {
+        return Err(
+            Diagnostic::error("Expect at least one argument after `DISTINCT` keyword")
+                .with_location(tokens[*position - 1].location)
+                .as_boxed(),
+        );
+    }
+
+    let mut ordering = None;
+    if is_current_token(tokens, position, TokenKind::Order) {
+        let is_inside_order_by = context.inside_order_by;
+        context.inside_aggregation_clauses = true;
+        let order_by = parse_order_by_statement(context, env, tokens, position)?
+            .as_any()
+            .downcast_ref::<OrderByStatement>()
+            .unwrap()
+            .to_owned();
+        context.inside_aggregation_clauses = false;
+        context.inside_order_by = is_inside_order_by;
+        ordering = Some(order_by);
+    }
+
+    let mut separator = None;
+    if is_current_token(tokens, position, TokenKind::Separator) {
+        // Consume `SEPARATOR` keyword
+        *position += 1;
+
+        if *position >= tokens.len() {
+            return Err(Diagnostic::error("Expect String after `SEPARATOR` keyword")
+                .with_location(tokens[*position - 1].location)
+                .as_boxed());
+        }
+
+        let TokenKind::String(value) = &tokens[*position].kind else {
+            return Err(Diagnostic::error("Expect String after `SEPARATOR` keyword")
+                .with_location(tokens[*position].location)
+                .as_boxed());
+        };
+
+        separator = Some(value.to_string());
+
+        // Consume separator string
+        *position += 1;
+    }
+
+    // Consume `)` token at the end of arguments
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` at the end of Aggregation function arguments",
+    )?;
+
+    Ok(AggregationFunctionArguments {
+        arguments,
+        is_distinct,
+        ordering,
+        separator,
+    })
+}
+
+/// Parse optional `FILTER (WHERE <condition>)` after aggregation function call
+fn parse_aggregation_filter_clause(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Option<Box<dyn Expr>>, Box<Diagnostic>> {
+    if !is_current_token(tokens, position, TokenKind::Filter) {
+        return Ok(None);
+    }
+
+    // Consume `FILTER` keyword
+    *position += 1;
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` after `FILTER` keyword",
+    )?;
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::Where,
+        "Expect `WHERE` keyword after `FILTER (`",
+    )?;
+
+    let condition_location = tokens[*position - 1].location;
+    let aggregations_count_before = context.aggregations.len();
+    context.inside_aggregation_clauses = true;
+    let condition = parse_expression(context, env, tokens, position)?;
+    context.inside_aggregation_clauses = false;
+    if aggregations_count_before != context.aggregations.len() {
+        return Err(Diagnostic::error(
+            "Aggregation functions are not allowed in `FILTER` condition",
+        )
+        .with_location(condition_location)
+        .as_boxed());
+    }
+
+    let condition_type = condition.expr_type();
+    if !condition_type.is_bool() {
+        return Err(Diagnostic::error(&format!(
+            "Expect `FILTER` condition to be type {} but got {}",
+            "Boolean",
+            condition_type.literal()
+        ))
+        .with_location(condition_location)
+        .as_boxed());
+    }
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` after `FILTER` condition",
+    )?;
+
+    Ok(Some(condition))
+}
+
 pub(crate) fn parse_over_window_definition(
     context: &mut ParserContext,
     env: &mut Environment,
@@ -308,6 +891,7 @@ pub(crate) fn parse_over_window_definition(
             name: Some(over_clause_name),
             partitioning_clause: None,
             ordering_clause: None,
+            frame_clause: None,
         });
     }
 
@@ -323,6 +907,7 @@ pub(crate) fn parse_over_window_definition(
         name: None,
         partitioning_clause: None,
         ordering_clause: None,
+        frame_clause: None,
     };
 
     context.inside_over_clauses = true;
@@ -383,9 +968,25 @@ pub(crate) fn parse_over_window_definition(
             continue;
         }
 
+        if matches!(tokens[*position].kind, TokenKind::Rows | TokenKind::Range) {
+            // Check if `ROWS` or `RANGE` is used more than one time
+            if window_definition.frame_clause.is_some() {
+                return Err(Diagnostic::error(
+                    "This window definition already has `ROWS` or `RANGE` frame",
+                )
+                .with_location(tokens[*position].location)
+                .as_boxed());
+            }
+
+            let frame_clause =
+                parse_window_frame_clause(context, env, &window_definition, tokens, position)?;
+            window_definition.frame_clause = Some(Box::new(frame_clause));
+            continue;
+        }
+
         context.inside_over_clauses = false;
         return Err(Diagnostic::error(
-            "`OVER` clause can only support `ORDER BY` or `PARTITION BY`  clauses",
+            "`OVER` clause can only support `PARTITION BY`, `ORDER BY`, `ROWS` or `RANGE` clauses",
         )
         .with_location(tokens[*position].location)
         .as_boxed());
@@ -403,3 +1004,193 @@ pub(crate) fn parse_over_window_definition(
 
     Ok(window_definition)
 }
+
+fn parse_window_frame_clause(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    window_definition: &WindowDefinition,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<WindowFrameClause, Box<Diagnostic>> {
+    let frame_location = tokens[*position].location;
+    let kind = if tokens[*position].kind == TokenKind::Rows {
+        WindowFrameKind::Rows
+    } else {
+        WindowFrameKind::Range
+    };
+
+    // Consume `ROWS` or `RANGE` keyword
+    *position += 1;
+
+    // Frame can be `BETWEEN <start> AND <end>` or `<start>` with `CURRENT ROW` as end
+    let (start, end) = if is_current_token(tokens, position, TokenKind::Between) {
+        // Consume `BETWEEN` keyword
+        *position += 1;
+
+        let start = parse_window_frame_bound(context, env, &kind, tokens, position)?;
+
+        consume_token_or_error(
+            tokens,
+            position,
+            TokenKind::AndKeyword,
+            "Expect `AND` keyword between window frame start and end",
+        )?;
+
+        let end = parse_window_frame_bound(context, env, &kind, tokens, position)?;
+        (start, end)
+    } else {
+        let start = parse_window_frame_bound(context, env, &kind, tokens, position)?;
+        (start, WindowFrameBound::CurrentRow)
+    };
+
+    if matches!(start, WindowFrameBound::UnboundedFollowing) {
+        return Err(
+            Diagnostic::error("Window frame start can't be `UNBOUNDED FOLLOWING`")
+                .with_location(frame_location)
+                .as_boxed(),
+        );
+    }
+
+    if matches!(end, WindowFrameBound::UnboundedPreceding) {
+        return Err(
+            Diagnostic::error("Window frame end can't be `UNBOUNDED PRECEDING`")
+                .with_location(frame_location)
+                .as_boxed(),
+        );
+    }
+
+    if start.order() > end.order() {
+        return Err(
+            Diagnostic::error("Window frame start can't be after the frame end")
+                .with_location(frame_location)
+                .as_boxed(),
+        );
+    }
+
+    // Range frame with offset need exactly one ordering argument to compare the offset with
+    if kind == WindowFrameKind::Range {
+        let mut offsets = vec![];
+        for bound in [&start, &end] {
+            if let WindowFrameBound::Preceding(offset) | WindowFrameBound::Following(offset) = bound
+            {
+                offsets.push(offset);
+            }
+        }
+
+        if !offsets.is_empty() {
+            let ordering_arguments = match &window_definition.ordering_clause {
+                Some(ordering) => &ordering.order_by.arguments,
+                None => &vec![],
+            };
+
+            if ordering_arguments.len() != 1 {
+                return Err(Diagnostic::error(
+                    "`RANGE` frame with offset requires exactly one `ORDER BY` argument",
+                )
+                .add_help("Make sure to declare `ORDER BY` before the window frame")
+                .with_location(frame_location)
+                .as_boxed());
+            }
+
+            let ordering_type = ordering_arguments[0].expr_type();
+            for offset in offsets {
+                let offset_type = offset.expr_type();
+                let is_valid_offset = if ordering_type.is_int() || ordering_type.is_float() {
+                    offset_type.is_int() || offset_type.is_float()
+                } else if ordering_type.is_date_time()
+                    || ordering_type.is_date_time_tz()
+                    || ordering_type.is_date()
+                {
+                    offset_type.is_interval()
+                } else {
+                    false
+                };
+
+                if !is_valid_offset {
+                    return Err(Diagnostic::error(&format!(
+                        "`RANGE` offset with type `{}` can't be used with `ORDER BY` type `{}`",
+                        offset_type.literal(),
+                        ordering_type.literal()
+                    ))
+                    .add_note("Number ordering expects number offset")
+                    .add_note("Date, DateTime and DateTimeTz ordering expects Interval offset")
+                    .with_location(frame_location)
+                    .as_boxed());
+                }
+            }
+        }
+    }
+
+    Ok(WindowFrameClause { kind, start, end })
+}
+
+fn parse_window_frame_bound(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    kind: &WindowFrameKind,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<WindowFrameBound, Box<Diagnostic>> {
+    if *position >= tokens.len() {
+        return Err(Diagnostic::error(
+            "Expect `UNBOUNDED`, `CURRENT ROW` or offset as window frame bound",
+        )
+        .with_location(tokens[tokens.len() - 1].location)
+        .as_boxed());
+    }
+
+    // Parse `UNBOUNDED PRECEDING` or `UNBOUNDED FOLLOWING`
+    if tokens[*position].kind == TokenKind::Unbounded {
+        *position += 1;
+
+        if is_current_token(tokens, position, TokenKind::Preceding) {
+            *position += 1;
+            return Ok(WindowFrameBound::UnboundedPreceding);
+        }
+
+        consume_token_or_error(
+            tokens,
+            position,
+            TokenKind::Following,
+            "Expect `PRECEDING` or `FOLLOWING` after `UNBOUNDED` keyword",
+        )?;
+        return Ok(WindowFrameBound::UnboundedFollowing);
+    }
+
+    // Parse `CURRENT ROW`
+    if tokens[*position].kind == TokenKind::Current {
+        *position += 1;
+        consume_token_or_error(
+            tokens,
+            position,
+            TokenKind::Row,
+            "Expect `ROW` keyword after `CURRENT`",
+        )?;
+        return Ok(WindowFrameBound::CurrentRow);
+    }
+
+    // Parse `<offset> PRECEDING` or `<offset> FOLLOWING`
+    let offset_location = tokens[*position].location;
+    let offset = parse_expression(context, env, tokens, position)?;
+    if *kind == WindowFrameKind::Rows && !offset.expr_type().is_int() {
+        return Err(Diagnostic::error(&format!(
+            "`ROWS` frame offset must be Integer but got `{}`",
+            offset.expr_type().literal()
+        ))
+        .with_location(offset_location)
+        .as_boxed());
+    }
+
+    if is_current_token(tokens, position, TokenKind::Preceding) {
+        *position += 1;
+        return Ok(WindowFrameBound::Preceding(offset));
+    }
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::Following,
+        "Expect `PRECEDING` or `FOLLOWING` after window frame offset",
+    )?;
+    Ok(WindowFrameBound::Following(offset))
+}
@@ -1,10 +1,14 @@
 use gitql_ast::types::array::ArrayType;
+use gitql_ast::types::decimal::DecimalType;
 use gitql_ast::types::DataType;
+use gitql_ast::DECIMAL_MAX_PRECISION;
 use gitql_core::environment::Environment;
 
 use crate::diagnostic::Diagnostic;
 use crate::parser::calculate_safe_location;
 use crate::parser::consume_conditional_token_or_errors;
+use crate::parser::consume_token_or_error;
+use crate::parser::is_current_token;
 use crate::token::Token;
 use crate::token::TokenKind;
 
@@ -53,6 +57,70 @@ fn parse_array_type(
     Ok(Box::new(ArrayType { base: base_type }))
 }
 
+/// Parse `(precision [, scale])` after decimal type name
+fn parse_decimal_type_modifiers(
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Box<dyn DataType>, Box<Diagnostic>> {
+    // Consume `(` token
+    let left_paren_location = tokens[*position].location;
+    *position += 1;
+
+    let precision = parse_type_modifier(tokens, position, "Expect decimal precision")?;
+    let scale = if is_current_token(tokens, position, TokenKind::Comma) {
+        // Consume `,` token
+        *position += 1;
+        parse_type_modifier(tokens, position, "Expect decimal scale after `,`")?
+    } else {
+        0
+    };
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` after decimal precision and scale",
+    )?;
+
+    if precision == 0 || precision > DECIMAL_MAX_PRECISION as i64 {
+        return Err(Diagnostic::error(&format!(
+            "Decimal precision must be between 1 and {}",
+            DECIMAL_MAX_PRECISION
+        ))
+        .with_location(left_paren_location)
+        .as_boxed());
+    }
+
+    if scale > precision {
+        return Err(Diagnostic::error(&format!(
+            "Decimal scale must be between 0 and the precision {}",
+            precision
+        ))
+        .with_location(left_paren_location)
+        .as_boxed());
+    }
+
+    Ok(Box::new(DecimalType::new(precision as u32, scale as u32)))
+}
+
+fn parse_type_modifier(
+    tokens: &[Token],
+    position: &mut usize,
+    message: &str,
+) -> Result<i64, Box<Diagnostic>> {
+    let token = consume_conditional_token_or_errors(
+        tokens,
+        position,
+        |token| matches!(token.kind, TokenKind::Integer(_)),
+        message,
+    )?;
+
+    match token.kind {
+        TokenKind::Integer(value) => Ok(value),
+        _ => unreachable!(),
+    }
+}
+
 fn parse_primitive_type(
     env: &mut Environment,
     tokens: &[Token],
@@ -68,6 +136,9 @@ fn parse_primitive_type(
 
     let type_literal = type_name_token.to_string();
     if let Some(data_type) = env.types_table.lookup(type_literal.as_str()) {
+        if data_type.is_decimal() && is_current_token(tokens, position, TokenKind::LeftParen) {
+            return parse_decimal_type_modifiers(tokens, position);
+        }
         return Ok(data_type);
     }
 
@@ -8,6 +8,7 @@ use gitql_ast::expression::BetweenExpr;
 use gitql_ast::expression::BitwiseExpr;
 use gitql_ast::expression::*;
 use gitql_ast::operator::ArithmeticOperator;
+use gitql_ast::operator::ArrowOperator;
 use gitql_ast::operator::BinaryBitwiseOperator;
 use gitql_ast::operator::BinaryLogicalOperator;
 use gitql_ast::operator::PrefixUnaryOperator;
@@ -16,15 +17,21 @@ use gitql_ast::types::any::AnyType;
 use gitql_ast::types::array::ArrayType;
 use gitql_ast::types::boolean::BoolType;
 use gitql_ast::types::composite::CompositeType;
+use gitql_ast::types::integer::IntType;
 use gitql_ast::types::undefined::UndefType;
 use gitql_ast::types::DataType;
+use gitql_core::combinations_generator::generate_list_of_all_combinations;
 use gitql_core::environment::Environment;
+use gitql_core::environment::SORT_MERGE_JOIN_MIN_ROWS_VARIABLE;
+use gitql_core::environment::TIME_ZONE_VARIABLE;
 
 use crate::context::ParserContext;
 use crate::diagnostic::Diagnostic;
+use crate::parse_cast::check_time_zone_expression;
 use crate::parse_cast::parse_cast_call_expression;
 use crate::parse_cast::parse_cast_operator_expression;
 use crate::parse_comparisons::parse_comparison_expression;
+use crate::parse_create::parse_create_definition;
 use crate::parse_function_call::parse_function_call_expression;
 use crate::parse_function_call::parse_over_window_definition;
 use crate::parse_interval::parse_interval_expression;
@@ -42,12 +49,22 @@ pub fn parse_gql(tokens: Vec<Token>, env: &mut Environment) -> Result<Vec<Query>
     while position < tokens.len() {
         env.clear_session();
 
+        // Definitions are registered directly in the environment and has no query to evaluate
+        if tokens[position].kind == TokenKind::Create {
+            parse_create_definition(env, &tokens, &mut position)?;
+            if is_current_token(&tokens, &position, TokenKind::Semicolon) {
+                position += 1;
+            }
+            continue;
+        }
+
         let query = match &tokens[position].kind {
             TokenKind::Do => parse_do_query(env, &tokens, &mut position),
             TokenKind::Set => parse_set_query(env, &tokens, &mut position),
             TokenKind::Select => parse_select_query(env, &tokens, &mut position),
             TokenKind::Describe => parse_describe_query(env, &tokens, &mut position),
             TokenKind::Show => parse_show_query(&tokens, &mut position),
+            TokenKind::Explain => parse_explain_query(env, &tokens, &mut position),
             _ => Err(un_expected_statement_error(&tokens, &mut position)),
         }?;
 
@@ -117,6 +134,15 @@ fn parse_set_query(
 
     let name = &tokens[*position].to_string();
 
+    // System variables are predefined and can't be declared
+    if name.starts_with("@@") && !env.globals_types.contains_key(name) {
+        return Err(
+            Diagnostic::error(&format!("Unknown system variable `{}`", name))
+                .with_location(tokens[*position].location)
+                .as_boxed(),
+        );
+    }
+
     // Consume variable name
     *position += 1;
 
@@ -144,6 +170,20 @@ fn parse_set_query(
         );
     }
 
+    if name == TIME_ZONE_VARIABLE {
+        check_time_zone_expression(&value, calculate_safe_location(tokens, *position - 1))?;
+    }
+
+    if name == SORT_MERGE_JOIN_MIN_ROWS_VARIABLE && !value.expr_type().is_int() {
+        return Err(Diagnostic::error(&format!(
+            "System variable `{}` must be an Int but got `{}`",
+            name,
+            value.expr_type().literal()
+        ))
+        .with_location(calculate_safe_location(tokens, *position - 1))
+        .as_boxed());
+    }
+
     env.define_global(name.to_string(), value.expr_type());
 
     Ok(Query::GlobalVariableDeclaration(GlobalVariableStatement {
@@ -207,7 +247,38 @@ fn parse_show_query(tokens: &[Token], position: &mut usize) -> Result<Query, Box
     Ok(Query::ShowTables)
 }
 
-fn parse_select_query(
+fn parse_explain_query(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Query, Box<Diagnostic>> {
+    // Consume `EXPLAIN` keyword
+    *position += 1;
+
+    // Consume optional `ANALYZE` keyword
+    let analyze = is_current_token(tokens, position, TokenKind::Analyze);
+    if analyze {
+        *position += 1;
+    }
+
+    if !is_current_token(tokens, position, TokenKind::Select) {
+        return Err(
+            Diagnostic::error("Expect `SELECT` query after `EXPLAIN` keyword")
+                .add_note("Only `SELECT` queries has execution plan")
+                .with_location(calculate_safe_location(tokens, *position - 1))
+                .as_boxed(),
+        );
+    }
+
+    let query = match parse_select_query(env, tokens, position)? {
+        Query::Select(query) => query,
+        _ => unreachable!(),
+    };
+
+    Ok(Query::Explain(ExplainStatement { query, analyze }))
+}
+
+pub(crate) fn parse_select_query(
     env: &mut Environment,
     tokens: &[Token],
     position: &mut usize,
@@ -278,6 +349,17 @@ fn parse_select_query(
                 let statement = parse_having_statement(&mut context, env, tokens, position)?;
                 statements.insert("having", statement);
             }
+            TokenKind::Qualify => {
+                if statements.contains_key("qualify") {
+                    return Err(Diagnostic::error("You already used `QUALIFY` statement")
+                        .add_note("Can't use more than one `QUALIFY` statement in the same query")
+                        .with_location(token.location)
+                        .as_boxed());
+                }
+
+                let statement = parse_qualify_statement(&mut context, env, tokens, position)?;
+                statements.insert("qualify", statement);
+            }
             TokenKind::Limit => {
                 if statements.contains_key("limit") {
                     return Err(Diagnostic::error("You already used `LIMIT` statement")
@@ -383,8 +465,27 @@ fn parse_select_query(
         }
     }
 
-    // If any aggregation function is used, add Aggregation Functions Node to the GitQL Query
-    if !context.aggregations.is_empty() {
+    type_check_grouping_arguments(&context, &statements)?;
+
+    if statements.contains_key("qualify") && context.window_functions.is_empty() {
+        return Err(Diagnostic::error(
+            "`QUALIFY` must be used in a query that has window functions",
+        )
+        .add_help("Try to use `WHERE` or `HAVING` to filter rows without window functions")
+        .as_boxed());
+    }
+
+    // If any aggregation function is used, add Aggregation Functions Node to the GitQL Query,
+    // also with many grouping sets because the aggregation stage resets the rolled up columns
+    let has_many_grouping_sets = statements.get("group").is_some_and(|statement| {
+        let statement = statement
+            .as_any()
+            .downcast_ref::<GroupByStatement>()
+            .unwrap();
+        statement.grouping_sets.len() > 1
+    });
+
+    if !context.aggregations.is_empty() || has_many_grouping_sets {
         let aggregation_functions = AggregationsStatement {
             aggregations: context.aggregations,
         };
@@ -430,16 +531,44 @@ fn parse_select_query(
         .cloned()
         .collect();
 
+    // Columns that defined by `UNNEST` are not part of the selected tables
+    let mut projection_names = vec![];
+    let mut projection_locations = vec![];
+    for (name, location) in context
+        .projection_names
+        .iter()
+        .zip(context.projection_locations.iter())
+    {
+        if !context.unnest_columns.contains(name) {
+            projection_names.push(name.to_string());
+            projection_locations.push(*location);
+        }
+    }
+
     type_check_projection_symbols(
         env,
         &context.selected_tables,
-        &context.projection_names,
-        &context.projection_locations,
+        &projection_names,
+        &projection_locations,
     )?;
 
-    let hidden_selection_per_table =
+    let mut hidden_selection_per_table =
         classify_hidden_selection(env, &context.selected_tables, &hidden_selections);
 
+    // Join `USING` columns must be selected from both sides to match and merge them
+    for (table, columns) in context.join_using_columns.iter() {
+        let table_hidden_selections = hidden_selection_per_table
+            .entry(table.to_string())
+            .or_default();
+        for column in columns {
+            if !context.selected_fields.contains(column)
+                && !table_hidden_selections.contains(column)
+            {
+                table_hidden_selections.push(column.to_string());
+            }
+        }
+    }
+
     Ok(Query::Select(GQLQuery {
         statements,
         has_aggregation_function: context.is_single_value_query,
@@ -449,6 +578,46 @@ fn parse_select_query(
     }))
 }
 
+/// Make sure that every `GROUPING(...)` argument is one of the `GROUP BY` columns
+fn type_check_grouping_arguments(
+    context: &ParserContext,
+    statements: &HashMap<&'static str, Box<dyn Statement>>,
+) -> Result<(), Box<Diagnostic>> {
+    for aggregation in context.aggregations.values() {
+        let AggregateValue::Grouping(arguments) = aggregation else {
+            continue;
+        };
+
+        let Some(statement) = statements.get("group") else {
+            return Err(Diagnostic::error(
+                "`GROUPING` must be used in a query that has `GROUP BY` statement",
+            )
+            .as_boxed());
+        };
+
+        let group_by = statement
+            .as_any()
+            .downcast_ref::<GroupByStatement>()
+            .unwrap();
+
+        for argument in arguments {
+            let argument_literal = expression_literal(argument);
+            if !group_by
+                .values
+                .iter()
+                .any(|value| expression_literal(value) == argument_literal)
+            {
+                return Err(Diagnostic::error(&format!(
+                    "`GROUPING` argument `{}` must be used in the `GROUP BY` statement",
+                    argument_literal.unwrap_or_default()
+                ))
+                .as_boxed());
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Classify hidden selection per table
 fn classify_hidden_selection(
     env: &mut Environment,
@@ -476,10 +645,12 @@ fn classify_hidden_selection(
         }
 
         // If this symbol is not column name, maybe generated column
-        if !is_resolved && !table_hidden_selections.is_empty() {
+        if !is_resolved {
+            // Without tables, generated columns such as `UNNEST` elements belong to the empty table
+            let table = tables.first().map_or("", |table| table.as_str());
             table_hidden_selections
-                .get_mut(&tables[0])
-                .unwrap()
+                .entry(table.to_string())
+                .or_default()
                 .push(hidden_selection.to_string());
         }
     }
@@ -507,6 +678,29 @@ fn parse_select_statement(
     // Parse `DISTINCT` or `DISTINCT ON(...)`
     let distinct = parse_select_distinct_option(context, tokens, position)?;
 
+    // Parse optional `FROM` with one or more tables, joins and unnest before the selections,
+    // so the columns that defined in `FROM` such as `UNNEST` elements can be used in the selections
+    let mut joins: Vec<Join> = vec![];
+    let mut unnests: Vec<UnnestSelection> = vec![];
+    let mut tables_to_select_from: Vec<String> = vec![];
+    let selections_position = *position;
+    let from_position = find_from_keyword_position(tokens, *position);
+    let mut from_end_position = *position;
+    if let Some(from_position) = from_position {
+        *position = from_position;
+        parse_from_option(
+            context,
+            env,
+            &mut tables_to_select_from,
+            &mut joins,
+            &mut unnests,
+            tokens,
+            position,
+        )?;
+        from_end_position = *position;
+        *position = selections_position;
+    }
+
     // Parse `*` or `expressions`
     let mut fields_names: Vec<String> = vec![];
     let mut selected_expr_titles: Vec<String> = vec![];
@@ -526,20 +720,13 @@ fn parse_select_statement(
     )?;
     context.inside_selections = false;
 
-    // Parse optional `FROM` with one or more tables and joins
-    let mut joins: Vec<Join> = vec![];
-    let mut tables_to_select_from: Vec<String> = vec![];
-    parse_from_option(
-        context,
-        env,
-        &mut tables_to_select_from,
-        &mut joins,
-        tokens,
-        position,
-    )?;
+    // Continue after the `FROM` if the selections are parsed until it
+    if from_position == Some(*position) {
+        *position = from_end_position;
+    }
 
     // Make sure Aggregated functions are used with tables only
-    if tables_to_select_from.is_empty() && !context.aggregations.is_empty() {
+    if tables_to_select_from.is_empty() && unnests.is_empty() && !context.aggregations.is_empty() {
         return Err(
             Diagnostic::error("Aggregations functions should be used only with tables")
                 .add_note("Try to select from one of the available tables in current schema")
@@ -549,7 +736,7 @@ fn parse_select_statement(
     }
 
     // Make sure `SELECT *` used with specific table
-    if is_select_all && tables_to_select_from.is_empty() {
+    if is_select_all && tables_to_select_from.is_empty() && unnests.is_empty() {
         return Err(
             Diagnostic::error("Expect `FROM` and table name after `SELECT *`")
                 .add_help("Select all must be used with valid table name")
@@ -575,6 +762,11 @@ fn parse_select_statement(
             &mut context.selected_fields,
             &mut fields_names,
         );
+
+        for unnest_column in context.unnest_columns.iter() {
+            fields_names.push(unnest_column.to_string());
+            context.selected_fields.push(unnest_column.to_string());
+        }
     }
 
     // Type check all selected fields has type registered in type table
@@ -588,6 +780,7 @@ fn parse_select_statement(
     Ok(Box::new(SelectStatement {
         table_selections,
         joins,
+        unnests,
         selected_expr_titles,
         selected_expr,
         distinct,
@@ -782,11 +975,27 @@ fn parse_select_all_or_expressions(
     Ok(())
 }
 
+/// Find the position of `FROM` keyword of the current select statement if exists
+fn find_from_keyword_position(tokens: &[Token], position: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (index, token) in tokens.iter().enumerate().skip(position) {
+        match token.kind {
+            TokenKind::LeftParen | TokenKind::LeftBracket => depth += 1,
+            TokenKind::RightParen | TokenKind::RightBracket => depth -= 1,
+            TokenKind::From if depth == 0 => return Some(index),
+            TokenKind::Semicolon => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
 fn parse_from_option(
     context: &mut ParserContext,
     env: &mut Environment,
     tables_to_select_from: &mut Vec<String>,
     joins: &mut Vec<Join>,
+    unnests: &mut Vec<UnnestSelection>,
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<(), Box<Diagnostic>> {
@@ -794,6 +1003,14 @@ fn parse_from_option(
         // Consume `From` keyword
         *position += 1;
 
+        // Select from the elements of `UNNEST` only without tables
+        if is_current_token(tokens, position, TokenKind::Unnest) {
+            unnests.push(parse_unnest_selection(
+                context, env, tokens, position, false,
+            )?);
+            return parse_lateral_unnest_selections(context, env, unnests, tokens, position);
+        }
+
         // Parse and consume Symbol as Table name
         let table_name = consume_conditional_token_or_errors(
             tokens,
@@ -823,27 +1040,47 @@ fn parse_from_option(
         // Parse Joins
         let mut number_previous_of_joins = 0;
         while is_join_or_join_type_token(tokens, position) {
-            let join_token = &tokens[*position];
+            // Parse optional `NATURAL` keyword before the join type
+            let is_natural_join = is_current_token(tokens, position, TokenKind::Natural);
+            if is_natural_join {
+                // Consume `NATURAL` keyword
+                *position += 1;
+            }
 
             // The default join type now is cross join because we don't support `ON` Condition
             let mut join_kind = JoinKind::Default;
-            if join_token.kind != TokenKind::Join {
-                join_kind = match join_token.kind {
+            if *position < tokens.len() && tokens[*position].kind != TokenKind::Join {
+                join_kind = match tokens[*position].kind {
                     TokenKind::Left => JoinKind::Left,
                     TokenKind::Right => JoinKind::Right,
+                    TokenKind::Full => JoinKind::Full,
                     TokenKind::Cross => JoinKind::Cross,
                     TokenKind::Inner => JoinKind::Inner,
-                    _ => JoinKind::Default,
+                    _ => {
+                        return Err(Diagnostic::error(
+                            "Expect `JOIN` keyword or join type after `NATURAL`",
+                        )
+                        .with_location(calculate_safe_location(tokens, *position))
+                        .as_boxed());
+                    }
                 };
 
-                // Consume Left, Right, Inner or Cross
+                if is_natural_join && join_kind == JoinKind::Cross {
+                    return Err(
+                        Diagnostic::error("`NATURAL` can't be used with `CROSS JOIN`")
+                            .with_location(tokens[*position].location)
+                            .as_boxed(),
+                    );
+                }
+
+                // Consume Left, Right, Full, Inner or Cross
                 *position += 1;
 
-                // Parse optional `OUTER` token after `LEFT` or `RIGHT` only
+                // Parse optional `OUTER` token after `LEFT`, `RIGHT` or `FULL` only
                 if is_current_token(tokens, position, TokenKind::Outer) {
-                    if !matches!(join_kind, JoinKind::Left | JoinKind::Right) {
+                    if !matches!(join_kind, JoinKind::Left | JoinKind::Right | JoinKind::Full) {
                         return Err(Diagnostic::error(
-                            "`OUTER` keyword used with LEFT or RIGHT JOIN only",
+                            "`OUTER` keyword used with LEFT, RIGHT or FULL JOIN only",
                         )
                         .with_location(calculate_safe_location(tokens, *position))
                         .as_boxed());
@@ -855,7 +1092,7 @@ fn parse_from_option(
 
                 if *position >= tokens.len() || tokens[*position].kind != TokenKind::Join {
                     return Err(Diagnostic::error(
-                        "Expect `JOIN` keyword after Cross, Left, Right, Inner",
+                        "Expect `JOIN` keyword after Cross, Left, Right, Full, Inner",
                     )
                     .with_location(calculate_safe_location(tokens, *position))
                     .as_boxed());
@@ -866,6 +1103,36 @@ fn parse_from_option(
             let join_location = tokens[*position].location;
             *position += 1;
 
+            // Join with the elements of `UNNEST` for each row
+            if is_current_token(tokens, position, TokenKind::Unnest) {
+                if is_natural_join
+                    || !matches!(
+                        join_kind,
+                        JoinKind::Default | JoinKind::Cross | JoinKind::Inner | JoinKind::Left
+                    )
+                {
+                    return Err(Diagnostic::error(
+                        "`UNNEST` can be used only with `CROSS`, `INNER` or `LEFT` JOIN",
+                    )
+                    .with_location(join_location)
+                    .as_boxed());
+                }
+
+                let is_outer = join_kind == JoinKind::Left;
+                unnests.push(parse_unnest_selection(
+                    context, env, tokens, position, is_outer,
+                )?);
+                continue;
+            }
+
+            if !unnests.is_empty() {
+                return Err(
+                    Diagnostic::error("Tables joins must be used before `UNNEST`")
+                        .with_location(join_location)
+                        .as_boxed(),
+                );
+            }
+
             if *position >= tokens.len() || !matches!(tokens[*position].kind, TokenKind::Symbol(_))
             {
                 return Err(Diagnostic::error("Expect table name after `JOIN` keyword")
@@ -885,6 +1152,19 @@ fn parse_from_option(
                 .as_boxed());
             }
 
+            if !env
+                .schema
+                .tables_fields_names
+                .contains_key(other_table_name.as_str())
+            {
+                return Err(Diagnostic::error("Unresolved table name")
+                    .add_help("You can use the `SHOW TABLES` query to get list of current tables")
+                    .add_help("Check the documentations to see available tables")
+                    .with_location(other_table.location)
+                    .as_boxed());
+            }
+
+            let previous_tables = tables_to_select_from.clone();
             tables_to_select_from.push(other_table_name.to_string());
             context.selected_tables.push(other_table_name.to_string());
             register_current_table_fields_types(env, other_table_name)?;
@@ -892,23 +1172,76 @@ fn parse_from_option(
             // Consume Other table name
             *position += 1;
 
-            // Parse the `ON` predicate
+            // Parse the `ON` predicate or `USING (...)` columns
             let mut predicate: Option<Box<dyn Expr>> = None;
-            if is_current_token(tokens, position, TokenKind::On) {
+            let mut using_columns: Vec<String> = vec![];
+            if is_natural_join {
+                if is_current_token(tokens, position, TokenKind::On)
+                    || is_current_token(tokens, position, TokenKind::Using)
+                {
+                    return Err(Diagnostic::error(
+                        "`NATURAL JOIN` can't have `ON` or `USING` condition",
+                    )
+                    .add_note("`NATURAL JOIN` matches the columns with the same name in both sides")
+                    .with_location(tokens[*position].location)
+                    .as_boxed());
+                }
+
+                // Match all columns that exists in the other table and any of the previous tables
+                let other_table_columns =
+                    &env.schema.tables_fields_names[other_table_name.as_str()];
+                for column in other_table_columns {
+                    if previous_tables.iter().any(|table| {
+                        env.schema.tables_fields_names[table.as_str()].contains(column)
+                    }) {
+                        using_columns.push(column.to_string());
+                    }
+                }
+            } else if is_current_token(tokens, position, TokenKind::On) {
                 // Consume `ON` keyword
                 *position += 1;
                 predicate = Some(parse_expression(context, env, tokens, position)?);
+            } else if is_current_token(tokens, position, TokenKind::Using) {
+                using_columns = parse_join_using_columns(
+                    env,
+                    tokens,
+                    position,
+                    &previous_tables,
+                    other_table_name,
+                )?;
             }
 
-            // Make sure user set predicate condition for LEFT or RIGHT JOIN
-            if predicate.is_none() && matches!(join_kind, JoinKind::Right | JoinKind::Left) {
+            // Make sure user set predicate condition for LEFT, RIGHT or FULL JOIN
+            if predicate.is_none()
+                && using_columns.is_empty()
+                && !is_natural_join
+                && matches!(join_kind, JoinKind::Right | JoinKind::Left | JoinKind::Full)
+            {
                 return Err(Diagnostic::error(
-                    "You must set predicate condition using `ON` Keyword for `LEFT` OR `RIGHT` JOINS",
+                    "You must set predicate condition using `ON` or `USING` Keyword for `LEFT`, `RIGHT` OR `FULL` JOINS",
                 )
                 .with_location(join_location)
                 .as_boxed());
             }
 
+            // Columns used to merge the two sides must be selected from every table that has them
+            for column in using_columns.iter() {
+                for table in previous_tables
+                    .iter()
+                    .chain(std::iter::once(other_table_name))
+                {
+                    if env.schema.tables_fields_names[table.as_str()].contains(&column.as_str()) {
+                        let table_columns = context
+                            .join_using_columns
+                            .entry(table.to_string())
+                            .or_default();
+                        if !table_columns.contains(column) {
+                            table_columns.push(column.to_string());
+                        }
+                    }
+                }
+            }
+
             let join_operand = if number_previous_of_joins == 0 {
                 JoinOperand::OuterAndInner(table_name.to_string(), other_table_name.to_string())
             } else {
@@ -919,14 +1252,269 @@ fn parse_from_option(
                 operand: join_operand,
                 kind: join_kind,
                 predicate,
+                using_columns,
             });
 
             number_previous_of_joins += 1;
         }
+
+        return parse_lateral_unnest_selections(context, env, unnests, tokens, position);
     }
     Ok(())
 }
 
+/// Parse zero or more `, UNNEST(...)` after the tables and joins
+fn parse_lateral_unnest_selections(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    unnests: &mut Vec<UnnestSelection>,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<(), Box<Diagnostic>> {
+    while is_current_token(tokens, position, TokenKind::Comma)
+        && *position + 1 < tokens.len()
+        && tokens[*position + 1].kind == TokenKind::Unnest
+    {
+        // Consume `,` token
+        *position += 1;
+        unnests.push(parse_unnest_selection(
+            context, env, tokens, position, false,
+        )?);
+    }
+    Ok(())
+}
+
+fn parse_unnest_selection(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    is_outer: bool,
+) -> Result<UnnestSelection, Box<Diagnostic>> {
+    // Consume `UNNEST` keyword
+    *position += 1;
+
+    // Consume `(` token
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` after `UNNEST` keyword",
+    )?;
+
+    let expression_location = calculate_safe_location(tokens, *position);
+    let expression = parse_expression(context, env, tokens, position)?;
+    if let Some(expression_literal) = expression_literal(&expression) {
+        context.hidden_selections.push(expression_literal);
+    }
+
+    // Consume `)` token
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` after `UNNEST` value",
+    )?;
+
+    let expression_type = expression.expr_type();
+    if !expression_type.can_perform_unnest_op() {
+        return Err(Diagnostic::error(&format!(
+            "`UNNEST` expects Array or collection value but got {}",
+            expression_type.literal()
+        ))
+        .with_location(expression_location)
+        .as_boxed());
+    }
+
+    // Parse optional `[AS] name` element name, `unnest` is the default name
+    let element_column = if is_unnest_column_alias(tokens, position) {
+        parse_unnest_column_name(context, env, tokens, position)?
+    } else {
+        let location = calculate_safe_location(tokens, *position - 1);
+        register_unnest_column_name(context, env, "unnest", location)?
+    };
+
+    // Parse optional `WITH ORDINALITY [AS name]`, `ordinality` is the default name
+    let mut ordinality_column = None;
+    if is_current_token(tokens, position, TokenKind::With) {
+        // Consume `WITH` keyword
+        *position += 1;
+
+        // Consume `ORDINALITY` keyword
+        consume_token_or_error(
+            tokens,
+            position,
+            TokenKind::Ordinality,
+            "Expect keyword `ORDINALITY` after `UNNEST(...) WITH`",
+        )?;
+
+        ordinality_column = Some(if is_unnest_column_alias(tokens, position) {
+            parse_unnest_column_name(context, env, tokens, position)?
+        } else {
+            let location = calculate_safe_location(tokens, *position - 1);
+            register_unnest_column_name(context, env, "ordinality", location)?
+        });
+    }
+
+    env.define(
+        element_column.to_string(),
+        expression_type.unnest_op_result_type(),
+    );
+
+    if let Some(ordinality_column) = &ordinality_column {
+        env.define(ordinality_column.to_string(), Box::new(IntType));
+    }
+
+    Ok(UnnestSelection {
+        expression,
+        element_column,
+        ordinality_column,
+        is_outer,
+    })
+}
+
+fn is_unnest_column_alias(tokens: &[Token], position: &usize) -> bool {
+    is_current_token(tokens, position, TokenKind::As)
+        || is_current_token_with_condition(tokens, position, |token| {
+            matches!(token.kind, TokenKind::Symbol(_))
+        })
+}
+
+fn parse_unnest_column_name(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<String, Box<Diagnostic>> {
+    // Consume optional `AS` keyword
+    if is_current_token(tokens, position, TokenKind::As) {
+        *position += 1;
+    }
+
+    let name = consume_conditional_token_or_errors(
+        tokens,
+        position,
+        |token| matches!(token.kind, TokenKind::Symbol(_)),
+        "Expect `Symbol` as `UNNEST` column name",
+    )?
+    .to_string();
+
+    let location = tokens[*position - 1].location;
+    register_unnest_column_name(context, env, &name, location)
+}
+
+fn register_unnest_column_name(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    name: &str,
+    location: SourceLocation,
+) -> Result<String, Box<Diagnostic>> {
+    if env.schema.tables_fields_types.contains_key(name) {
+        return Err(
+            Diagnostic::error("Can't use column name as `UNNEST` column name")
+                .add_help("Try to use `AS` to set a different name")
+                .with_location(location)
+                .as_boxed(),
+        );
+    }
+
+    if context.unnest_columns.iter().any(|column| column == name) {
+        return Err(Diagnostic::error(&format!(
+            "There is already `UNNEST` column with name `{}`",
+            name
+        ))
+        .add_help("Try to use `AS` to set a different name")
+        .with_location(location)
+        .as_boxed());
+    }
+
+    context.unnest_columns.push(name.to_string());
+    Ok(name.to_string())
+}
+
+fn parse_join_using_columns(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    previous_tables: &[String],
+    other_table_name: &str,
+) -> Result<Vec<String>, Box<Diagnostic>> {
+    // Consume `USING` keyword
+    *position += 1;
+
+    // Consume `(` token
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` after `USING` keyword",
+    )?;
+
+    let mut using_columns: Vec<String> = vec![];
+    while *position < tokens.len() && !is_current_token(tokens, position, TokenKind::RightParen) {
+        let column_token = consume_conditional_token_or_errors(
+            tokens,
+            position,
+            |token| matches!(token.kind, TokenKind::Symbol(_)),
+            "Expect column name in `USING` columns",
+        )?;
+
+        let column_name = column_token.to_string();
+        let column_location = column_token.location;
+        let is_in_other_table =
+            env.schema.tables_fields_names[other_table_name].contains(&column_name.as_str());
+        let is_in_previous_tables = previous_tables.iter().any(|table| {
+            env.schema.tables_fields_names[table.as_str()].contains(&column_name.as_str())
+        });
+
+        if !is_in_other_table || !is_in_previous_tables {
+            return Err(Diagnostic::error(&format!(
+                "Column `{}` in `USING` must exist in both sides of the join",
+                column_name
+            ))
+            .add_help("Check the documentations to see available fields for each tables")
+            .with_location(column_location)
+            .as_boxed());
+        }
+
+        if using_columns.contains(&column_name) {
+            return Err(Diagnostic::error(&format!(
+                "Column `{}` is used more than once in `USING`",
+                column_name
+            ))
+            .with_location(column_location)
+            .as_boxed());
+        }
+
+        using_columns.push(column_name);
+
+        if is_current_token(tokens, position, TokenKind::Comma) {
+            // Consume `,`
+            *position += 1;
+        } else {
+            break;
+        }
+    }
+
+    // Consume `)` token
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` after `USING` columns",
+    )?;
+
+    if using_columns.is_empty() {
+        return Err(
+            Diagnostic::error("`USING` must be used with one or more columns")
+                .with_location(calculate_safe_location(tokens, *position - 1))
+                .as_boxed(),
+        );
+    }
+
+    Ok(using_columns)
+}
+
 fn parse_where_statement(
     context: &mut ParserContext,
     env: &mut Environment,
@@ -1000,63 +1588,310 @@ fn parse_group_by_statement(
         "Expect keyword `BY` after keyword `group`",
     )?;
 
-    // Parse one or more expression
+    // Parse one or more grouping elements, each element is an expression, `CUBE (...)` or `GROUPING SETS (...)`
+    // and the final grouping sets are the cross product of the sets of all elements
     let mut values: Vec<Box<dyn Expr>> = vec![];
+    let mut grouping_sets: Vec<Vec<usize>> = vec![vec![]];
+    let mut has_only_expressions = true;
     while *position < tokens.len() {
-        values.push(parse_expression(context, env, tokens, position)?);
+        let element_sets = if is_current_token(tokens, position, TokenKind::Cube) {
+            has_only_expressions = false;
+            parse_group_by_cube(context, env, tokens, position, &mut values)?
+        } else if is_current_token(tokens, position, TokenKind::Grouping) {
+            has_only_expressions = false;
+            parse_group_by_grouping_sets(context, env, tokens, position, &mut values)?
+        } else {
+            let expression = parse_expression(context, env, tokens, position)?;
+            vec![vec![push_grouping_value(&mut values, expression)]]
+        };
+
+        let mut combined_sets = Vec::with_capacity(grouping_sets.len() * element_sets.len());
+        for set in grouping_sets.iter() {
+            for element_set in element_sets.iter() {
+                let mut combined_set = set.clone();
+                for index in element_set {
+                    if !combined_set.contains(index) {
+                        combined_set.push(*index);
+                    }
+                }
+                combined_sets.push(combined_set);
+            }
+        }
+        grouping_sets = combined_sets;
+
         if is_current_token(tokens, position, TokenKind::Comma) {
             // Consume Comma `,`
             *position += 1;
             continue;
         }
-        break;
-    }
-
-    let mut has_with_rollup = false;
-    if is_current_token(tokens, position, TokenKind::With) {
-        // Consume Comma `WITH``
-        *position += 1;
-
-        // Consume `Rollup` keyword
-        consume_token_or_error(
-            tokens,
-            position,
-            TokenKind::Rollup,
-            "Expect keyword `ROLLUP` after keyword `with`",
-        )?;
+        break;
+    }
+
+    if is_current_token(tokens, position, TokenKind::With) {
+        let with_location = tokens[*position].location;
+
+        // Consume Comma `WITH``
+        *position += 1;
+
+        // Consume `Rollup` keyword
+        consume_token_or_error(
+            tokens,
+            position,
+            TokenKind::Rollup,
+            "Expect keyword `ROLLUP` after keyword `with`",
+        )?;
+
+        if !has_only_expressions {
+            return Err(Diagnostic::error(
+                "`WITH ROLLUP` can't be used with `CUBE` or `GROUPING SETS`",
+            )
+            .add_help("Try to use `GROUPING SETS` to add the rollup sets")
+            .with_location(with_location)
+            .as_boxed());
+        }
+
+        // Group by every prefix of the values from the longest, and finally by nothing
+        grouping_sets = (0..=values.len())
+            .rev()
+            .map(|len| (0..len).collect())
+            .collect();
+    }
+
+    context.has_group_by_statement = true;
+    Ok(Box::new(GroupByStatement {
+        values,
+        grouping_sets,
+    }))
+}
+
+fn parse_group_by_cube(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    values: &mut Vec<Box<dyn Expr>>,
+) -> Result<Vec<Vec<usize>>, Box<Diagnostic>> {
+    // Consume `CUBE` keyword
+    *position += 1;
+
+    let mut indices = vec![];
+    for expression in parse_grouping_values_list(context, env, tokens, position, "`CUBE`")? {
+        indices.push(push_grouping_value(values, expression));
+    }
+
+    if indices.is_empty() {
+        return Err(Diagnostic::error("`CUBE` expects at least one value")
+            .with_location(calculate_safe_location(tokens, *position - 1))
+            .as_boxed());
+    }
+
+    // Group by every combination of the values, and finally by nothing
+    let mut sets = vec![];
+    for combination in generate_list_of_all_combinations(indices.len()) {
+        sets.push(combination.iter().map(|i| indices[*i]).collect());
+    }
+    sets.sort_by_key(|set: &Vec<usize>| std::cmp::Reverse(set.len()));
+    sets.push(vec![]);
+    Ok(sets)
+}
+
+fn parse_group_by_grouping_sets(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    values: &mut Vec<Box<dyn Expr>>,
+) -> Result<Vec<Vec<usize>>, Box<Diagnostic>> {
+    // Consume `GROUPING` keyword
+    *position += 1;
+
+    // Consume `SETS` keyword
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::Sets,
+        "Expect keyword `SETS` after keyword `grouping`",
+    )?;
+
+    // Consume `(` token
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` after `GROUPING SETS`",
+    )?;
+
+    // Each set is a list of values between `(` and `)`, or a single value
+    let mut sets = vec![];
+    while *position < tokens.len() && tokens[*position].kind != TokenKind::RightParen {
+        let mut set = vec![];
+        if is_current_token(tokens, position, TokenKind::LeftParen) {
+            for expression in
+                parse_grouping_values_list(context, env, tokens, position, "grouping set")?
+            {
+                let index = push_grouping_value(values, expression);
+                if !set.contains(&index) {
+                    set.push(index);
+                }
+            }
+        } else {
+            let expression = parse_expression(context, env, tokens, position)?;
+            set.push(push_grouping_value(values, expression));
+        }
+
+        sets.push(set);
+
+        if is_current_token(tokens, position, TokenKind::Comma) {
+            // Consume Comma `,`
+            *position += 1;
+            continue;
+        }
+        break;
+    }
+
+    // Consume `)` token
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` at the end of `GROUPING SETS`",
+    )?;
+
+    if sets.is_empty() {
+        return Err(
+            Diagnostic::error("`GROUPING SETS` expects at least one set")
+                .with_location(calculate_safe_location(tokens, *position - 1))
+                .as_boxed(),
+        );
+    }
+
+    Ok(sets)
+}
+
+fn parse_grouping_values_list(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    expression_name: &str,
+) -> Result<Vec<Box<dyn Expr>>, Box<Diagnostic>> {
+    // Consume `(` token at the start of list of values
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        &format!("Expect `(` after {}", expression_name),
+    )?;
+
+    let mut expressions = vec![];
+    while *position < tokens.len() && tokens[*position].kind != TokenKind::RightParen {
+        expressions.push(parse_expression(context, env, tokens, position)?);
+        if is_current_token(tokens, position, TokenKind::Comma) {
+            // Consume Comma `,`
+            *position += 1;
+            continue;
+        }
+        break;
+    }
+
+    // Consume `)` token at the end of values
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        &format!("Expect `)` at the end of {}", expression_name),
+    )?;
+
+    Ok(expressions)
+}
+
+/// Push the grouped value if it's not already grouped and return its index
+fn push_grouping_value(values: &mut Vec<Box<dyn Expr>>, expression: Box<dyn Expr>) -> usize {
+    if let Some(literal) = expression_literal(&expression) {
+        let existing_position = values
+            .iter()
+            .position(|value| expression_literal(value).is_some_and(|v| v == literal));
+        if let Some(index) = existing_position {
+            return index;
+        }
+    }
+
+    values.push(expression);
+    values.len() - 1
+}
+
+fn parse_having_statement(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Box<dyn Statement>, Box<Diagnostic>> {
+    context.inside_having = true;
+
+    // Consume `HAVING` token
+    *position += 1;
+
+    if *position >= tokens.len() {
+        return Err(
+            Diagnostic::error("Expect expression after `HAVING` keyword")
+                .add_help("Try to add boolean expression after `HAVING` keyword")
+                .add_note("`HAVING` statement expects expression as condition")
+                .with_location(calculate_safe_location(tokens, *position - 1))
+                .as_boxed(),
+        );
+    }
+
+    // Make sure HAVING condition expression has boolean type
+    let condition_location = tokens[*position].location;
+    let mut condition = parse_expression(context, env, tokens, position)?;
+
+    // Make sure that the condition type is boolean, or can implicit cast to boolean.
+    if !condition.expr_type().is_bool() {
+        let expected_type: Box<dyn DataType> = Box::new(BoolType);
+        if !expected_type.has_implicit_cast_from(&condition) {
+            return Err(Diagnostic::error(&format!(
+                "Expect `HAVING` condition to be type {} but got {}",
+                "Boolean",
+                condition.expr_type().literal()
+            ))
+            .add_note("`HAVING` statement condition must be Boolean")
+            .with_location(condition_location)
+            .as_boxed());
+        }
 
-        has_with_rollup = true;
+        // Implicit cast the condition to boolean
+        condition = Box::new(CastExpr {
+            value: condition,
+            result_type: expected_type.clone(),
+        })
     }
 
-    context.has_group_by_statement = true;
-    Ok(Box::new(GroupByStatement {
-        values,
-        has_with_roll_up: has_with_rollup,
-    }))
+    context.inside_having = false;
+    Ok(Box::new(HavingStatement { condition }))
 }
 
-fn parse_having_statement(
+fn parse_qualify_statement(
     context: &mut ParserContext,
     env: &mut Environment,
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Statement>, Box<Diagnostic>> {
-    context.inside_having = true;
+    context.inside_qualify = true;
 
-    // Consume `HAVING` token
+    // Consume `QUALIFY` token
     *position += 1;
 
     if *position >= tokens.len() {
         return Err(
-            Diagnostic::error("Expect expression after `HAVING` keyword")
-                .add_help("Try to add boolean expression after `HAVING` keyword")
-                .add_note("`HAVING` statement expects expression as condition")
+            Diagnostic::error("Expect expression after `QUALIFY` keyword")
+                .add_help("Try to add boolean expression after `QUALIFY` keyword")
+                .add_note("`QUALIFY` statement expects expression as condition")
                 .with_location(calculate_safe_location(tokens, *position - 1))
                 .as_boxed(),
         );
     }
 
-    // Make sure HAVING condition expression has boolean type
+    // Make sure QUALIFY condition expression has boolean type
     let condition_location = tokens[*position].location;
     let mut condition = parse_expression(context, env, tokens, position)?;
 
@@ -1065,11 +1900,11 @@ fn parse_having_statement(
         let expected_type: Box<dyn DataType> = Box::new(BoolType);
         if !expected_type.has_implicit_cast_from(&condition) {
             return Err(Diagnostic::error(&format!(
-                "Expect `HAVING` condition to be type {} but got {}",
+                "Expect `QUALIFY` condition to be type {} but got {}",
                 "Boolean",
                 condition.expr_type().literal()
             ))
-            .add_note("`HAVING` statement condition must be Boolean")
+            .add_note("`QUALIFY` statement condition must be Boolean")
             .with_location(condition_location)
             .as_boxed());
         }
@@ -1081,8 +1916,8 @@ fn parse_having_statement(
         })
     }
 
-    context.inside_having = false;
-    Ok(Box::new(HavingStatement { condition }))
+    context.inside_qualify = false;
+    Ok(Box::new(QualifyStatement { condition }))
 }
 
 fn parse_limit_statement(
@@ -1558,8 +2393,8 @@ pub(crate) fn parse_expression(
             let expr_type = expression.expr_type();
             env.define(column_name.to_string(), expr_type.clone());
 
-            // Register the new aggregation generated field if the this expression is after group by
-            if context.has_group_by_statement && !context.hidden_selections.contains(&column_name) {
+            // Register the new aggregation generated field, it may be nested in another expression
+            if !context.hidden_selections.contains(&column_name) {
                 context.hidden_selections.push(column_name.to_string());
             }
 
@@ -1580,8 +2415,11 @@ pub(crate) fn parse_expression(
             let expr_type = expression.expr_type();
             env.define(column_name.to_string(), expr_type.clone());
 
-            // Register the new window generated field if the this expression is after group by
-            if context.has_group_by_statement && !context.hidden_selections.contains(&column_name) {
+            // Register the new window generated field if the this expression is after group by,
+            // or after select statement such as `QUALIFY` condition
+            if (context.has_group_by_statement || context.has_select_statement)
+                && !context.hidden_selections.contains(&column_name)
+            {
                 context.hidden_selections.push(column_name.to_string());
             }
 
@@ -1645,7 +2483,7 @@ fn parse_regex_expression(
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
-    let lhs = parse_is_null_expression(context, env, tokens, position)?;
+    let lhs = parse_logical_or_expression(context, env, tokens, position)?;
 
     // Check for `REGEXP` or `NOT REGEXP`
     // <expr> REGEXP <expr> AND <expr>
@@ -1668,7 +2506,7 @@ fn parse_regex_expression(
             tokens[*position - 1].location
         };
 
-        let pattern = parse_is_null_expression(context, env, tokens, position)?;
+        let pattern = parse_logical_or_expression(context, env, tokens, position)?;
 
         let lhs_type = lhs.expr_type();
         let rhs_type = pattern.expr_type();
@@ -1762,7 +2600,7 @@ fn parse_in_expression(
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
-    let expression = parse_logical_or_expression(context, env, tokens, position)?;
+    let expression = parse_bitwise_or_expression(context, env, tokens, position)?;
 
     // Consume NOT if current token is `NOT` and next one is `IN`
     let has_not_keyword = if *position < tokens.len() - 1
@@ -1805,9 +2643,14 @@ fn parse_in_expression(
             .as_boxed());
         }
 
-        // Check that argument and values has the same type
+        // Check that argument and values has the same type, NULL is accepted with any type
         let values_type = values_type_result.unwrap();
-        if !values_type.is_any() && !expression.expr_type().equals(&values_type) {
+        let argument_type = expression.expr_type();
+        if !values_type.is_any()
+            && !values_type.is_null()
+            && !argument_type.is_null()
+            && !argument_type.equals(&values_type)
+        {
             return Err(Diagnostic::error(
                 "Argument and Values of In Expression must have the same type",
             )
@@ -1845,6 +2688,17 @@ fn parse_logical_or_expression(
         let lhs_type = lhs.expr_type();
         let rhs_type = rhs.expr_type();
 
+        // NULL operand is accepted with boolean and the result depends on the other operand value
+        if lhs_type.is_null() || rhs_type.is_null() {
+            lhs = Box::new(LogicalExpr {
+                left: lhs,
+                operator: BinaryLogicalOperator::Or,
+                right: rhs,
+            });
+
+            continue 'parse_expr;
+        }
+
         let expected_rhs_types = lhs_type.can_perform_logical_or_op_with();
 
         // Can perform this operator between LHS and RHS
@@ -1919,7 +2773,7 @@ fn parse_logical_and_expression(
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
-    let mut lhs = parse_bitwise_or_expression(context, env, tokens, position)?;
+    let mut lhs = parse_is_null_expression(context, env, tokens, position)?;
 
     'parse_expr: while is_logical_and_operator(tokens, position) {
         let operator = &tokens[*position];
@@ -1927,11 +2781,22 @@ fn parse_logical_and_expression(
         // Consume`AND` operator
         *position += 1;
 
-        let rhs = parse_bitwise_or_expression(context, env, tokens, position)?;
+        let rhs = parse_is_null_expression(context, env, tokens, position)?;
 
         let lhs_type = lhs.expr_type();
         let rhs_type = rhs.expr_type();
 
+        // NULL operand is accepted with boolean and the result depends on the other operand value
+        if lhs_type.is_null() || rhs_type.is_null() {
+            lhs = Box::new(LogicalExpr {
+                left: lhs,
+                operator: BinaryLogicalOperator::And,
+                right: rhs,
+            });
+
+            continue 'parse_expr;
+        }
+
         let expected_rhs_types = lhs_type.can_perform_logical_and_op_with();
 
         // Can perform this operator between LHS and RHS
@@ -2199,6 +3064,17 @@ fn parse_logical_xor_expression(
         let lhs_type = lhs.expr_type();
         let rhs_type = rhs.expr_type();
 
+        // NULL operand is accepted with boolean and the result depends on the other operand value
+        if lhs_type.is_null() || rhs_type.is_null() {
+            lhs = Box::new(LogicalExpr {
+                left: lhs,
+                operator: BinaryLogicalOperator::Xor,
+                right: rhs,
+            });
+
+            continue 'parse_expr;
+        }
+
         let expected_rhs_types = lhs_type.can_perform_logical_xor_op_with();
 
         // Can perform this operator between LHS and RHS
@@ -2419,7 +3295,7 @@ fn parse_contained_by_expression(
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
-    let lhs = parse_bitwise_shift_expression(context, env, tokens, position)?;
+    let lhs = parse_range_adjacent_expression(context, env, tokens, position)?;
 
     if is_current_token(tokens, position, TokenKind::ArrowRightAt) {
         let operator = &tokens[*position];
@@ -2427,7 +3303,7 @@ fn parse_contained_by_expression(
         // Consume `<@` token
         *position += 1;
 
-        let rhs = parse_bitwise_shift_expression(context, env, tokens, position)?;
+        let rhs = parse_range_adjacent_expression(context, env, tokens, position)?;
 
         let lhs_type = lhs.expr_type();
         let rhs_type = rhs.expr_type();
@@ -2471,6 +3347,45 @@ fn parse_contained_by_expression(
     Ok(lhs)
 }
 
+fn parse_range_adjacent_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    let mut lhs = parse_bitwise_shift_expression(context, env, tokens, position)?;
+
+    while is_current_token(tokens, position, TokenKind::MinusBarMinus) {
+        let operator = &tokens[*position];
+
+        // Consume `-|-` token
+        *position += 1;
+
+        let rhs = parse_bitwise_shift_expression(context, env, tokens, position)?;
+
+        let lhs_type = lhs.expr_type();
+        let rhs_type = rhs.expr_type();
+
+        if !lhs_type.is_range() || !lhs_type.equals(&rhs_type) {
+            return Err(Diagnostic::error(&format!(
+                "Operator `-|-` can't be performed between types `{}` and `{}`",
+                lhs_type, rhs_type
+            ))
+            .with_location(operator.location)
+            .as_boxed());
+        }
+
+        // Evaluate the operator as a call to the `range_adjacent` function
+        lhs = Box::new(CallExpr {
+            function_name: "range_adjacent".to_string(),
+            arguments: vec![lhs, rhs],
+            return_type: Box::new(BoolType),
+        });
+    }
+
+    Ok(lhs)
+}
+
 fn parse_bitwise_shift_expression(
     context: &mut ParserContext,
     env: &mut Environment,
@@ -2652,6 +3567,24 @@ fn parse_term_expression(
         let lhs_type = lhs.expr_type();
         let rhs_type = rhs.expr_type();
 
+        // NULL operand is accepted with any type and the result is NULL
+        if lhs_type.is_null() || rhs_type.is_null() {
+            let operator = if operator.kind == TokenKind::Plus {
+                ArithmeticOperator::Plus
+            } else {
+                ArithmeticOperator::Minus
+            };
+
+            lhs = Box::new(ArithmeticExpr {
+                left: lhs,
+                operator,
+                right: rhs,
+                result_type: null_operand_result_type(&lhs_type, &rhs_type),
+            });
+
+            continue 'parse_expr;
+        }
+
         // Parse and Check sides for `+` operator
         if operator.kind == TokenKind::Plus {
             let expected_rhs_types = lhs_type.can_perform_add_op_with();
@@ -2817,6 +3750,25 @@ fn parse_factor_expression(
         let lhs_type = lhs.expr_type();
         let rhs_type = rhs.expr_type();
 
+        // NULL operand is accepted with any type and the result is NULL
+        if lhs_type.is_null() || rhs_type.is_null() {
+            let operator = match operator.kind {
+                TokenKind::Star => ArithmeticOperator::Star,
+                TokenKind::Slash => ArithmeticOperator::Slash,
+                TokenKind::Percentage => ArithmeticOperator::Modulus,
+                _ => ArithmeticOperator::Exponentiation,
+            };
+
+            lhs = Box::new(ArithmeticExpr {
+                left: lhs,
+                operator,
+                right: rhs,
+                result_type: null_operand_result_type(&lhs_type, &rhs_type),
+            });
+
+            continue 'parse_expr;
+        }
+
         // Parse and Check sides for `*` operator
         if operator.kind == TokenKind::Star {
             let expected_rhs_types = lhs_type.can_perform_mul_op_with();
@@ -3134,6 +4086,16 @@ fn parse_like_expression(
         let lhs_type = lhs.expr_type();
         let rhs_type = pattern.expr_type();
 
+        // NULL input or pattern is accepted with text and the result is NULL
+        if lhs_type.is_null() || rhs_type.is_null() {
+            let expr = Box::new(LikeExpr {
+                input: lhs,
+                pattern,
+            });
+
+            return Ok(apply_not_keyword_if_exists(expr, has_not_keyword));
+        }
+
         // Can perform this operator between LHS and RHS
         let expected_rhs_types = lhs_type.can_perform_like_op_with();
         if expected_rhs_types.contains(&rhs_type) {
@@ -3235,6 +4197,61 @@ fn parse_glob_expression(
     Ok(lhs)
 }
 
+pub(crate) fn parse_arrow_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    let mut lhs = parse_index_or_slice_expression(context, env, tokens, position)?;
+
+    while is_current_token(tokens, position, TokenKind::RightArrow)
+        || is_current_token(tokens, position, TokenKind::LongRightArrow)
+    {
+        let operator = &tokens[*position];
+
+        // Consume `->` or `->>` Token
+        *position += 1;
+
+        let rhs = parse_index_or_slice_expression(context, env, tokens, position)?;
+        let lhs_type = lhs.expr_type();
+        let rhs_type = rhs.expr_type();
+
+        let (arrow_operator, expected_rhs_types) = if operator.kind == TokenKind::RightArrow {
+            (ArrowOperator::Arrow, lhs_type.can_perform_arrow_op_with())
+        } else {
+            (
+                ArrowOperator::LongArrow,
+                lhs_type.can_perform_long_arrow_op_with(),
+            )
+        };
+
+        if !expected_rhs_types.contains(&rhs_type) {
+            return Err(Diagnostic::error(&format!(
+                "Operator `{}` can't be performed between types `{}` and `{}`",
+                operator, lhs_type, rhs_type
+            ))
+            .with_location(operator.location)
+            .as_boxed());
+        }
+
+        let result_type = if arrow_operator == ArrowOperator::Arrow {
+            lhs_type.arrow_op_result_type(&rhs_type)
+        } else {
+            lhs_type.long_arrow_op_result_type(&rhs_type)
+        };
+
+        lhs = Box::new(ArrowExpr {
+            left: lhs,
+            right: rhs,
+            operator: arrow_operator,
+            result_type,
+        });
+    }
+
+    Ok(lhs)
+}
+
 pub(crate) fn parse_index_or_slice_expression(
     context: &mut ParserContext,
     env: &mut Environment,
@@ -3542,7 +4559,7 @@ fn parse_between_expression(
         };
 
         let kind = parse_between_expr_kind(tokens, position);
-        let range_start = parse_function_call_expression(context, env, tokens, position)?;
+        let mut range_start = parse_function_call_expression(context, env, tokens, position)?;
 
         // Consume `AND` token
         consume_token_or_error(
@@ -3552,12 +4569,42 @@ fn parse_between_expression(
             "Expect `AND` after `BETWEEN` range start",
         )?;
 
-        let range_end = parse_function_call_expression(context, env, tokens, position)?;
+        let mut range_end = parse_function_call_expression(context, env, tokens, position)?;
 
         let lhs_type = expression.expr_type();
+
+        // Implicit cast range start and end to the LHS type if possible, for example `'2 weeks ago'` to DateTime
+        if !lhs_type.equals(&range_start.expr_type())
+            && lhs_type.has_implicit_cast_from(&range_start)
+        {
+            range_start = Box::new(CastExpr {
+                value: range_start,
+                result_type: lhs_type.clone(),
+            });
+        }
+
+        if !lhs_type.equals(&range_end.expr_type()) && lhs_type.has_implicit_cast_from(&range_end) {
+            range_end = Box::new(CastExpr {
+                value: range_end,
+                result_type: lhs_type.clone(),
+            });
+        }
+
         let range_start_type = &range_start.expr_type();
         let range_end_type = &range_end.expr_type();
 
+        // NULL value or range bound is accepted with any type and the result is NULL
+        if lhs_type.is_null() || range_start_type.is_null() || range_end_type.is_null() {
+            let between_expr = Box::new(BetweenExpr {
+                value: expression,
+                range_start,
+                range_end,
+                kind,
+            });
+
+            return Ok(apply_not_keyword_if_exists(between_expr, has_not_keyword));
+        }
+
         // Make sure LHS and Range start and end types all are equals
         if !lhs_type.equals(range_start_type) || !lhs_type.equals(range_end_type) {
             return Err(Diagnostic::error(&format!(
@@ -3619,6 +4666,20 @@ fn parse_between_expr_kind(tokens: &[Token], position: &mut usize) -> BetweenKin
     BetweenKind::Asymmetric
 }
 
+/// Return the type of binary expression with NULL operand, the type of the other operand
+/// is used so the expression can be used with other expressions of the same type
+#[allow(clippy::borrowed_box)]
+fn null_operand_result_type(
+    lhs_type: &Box<dyn DataType>,
+    rhs_type: &Box<dyn DataType>,
+) -> Box<dyn DataType> {
+    if lhs_type.is_null() {
+        rhs_type.clone()
+    } else {
+        lhs_type.clone()
+    }
+}
+
 pub(crate) fn parse_zero_or_more_values_with_comma_between(
     context: &mut ParserContext,
     env: &mut Environment,
@@ -3638,7 +4699,9 @@ pub(crate) fn parse_zero_or_more_values_with_comma_between(
     while *position < tokens.len() && tokens[*position].kind != TokenKind::RightParen {
         let argument = parse_expression(context, env, tokens, position)?;
         if let Some(argument_literal) = expression_literal(&argument) {
-            context.hidden_selections.push(argument_literal);
+            if !context.lambda_parameters.contains(&argument_literal) {
+                context.hidden_selections.push(argument_literal);
+            }
         }
 
         arguments.push(argument);
@@ -3752,11 +4815,17 @@ fn parse_primary_expression(
         TokenKind::Symbol(_) => parse_symbol_expression(context, env, tokens, position),
         TokenKind::Array => parse_array_value_expression(context, env, tokens, position),
         TokenKind::LeftBracket => parse_array_value_expression(context, env, tokens, position),
+        TokenKind::Row => parse_row_expression(context, env, tokens, position),
         TokenKind::LeftParen => parse_group_expression(context, env, tokens, position),
         TokenKind::Case => parse_case_expression(context, env, tokens, position),
-        TokenKind::Cast => parse_cast_call_expression(context, env, tokens, position),
+        TokenKind::Cast | TokenKind::TryCast => {
+            parse_cast_call_expression(context, env, tokens, position)
+        }
         TokenKind::Benchmark => parse_benchmark_call_expression(context, env, tokens, position),
+        TokenKind::Grouping => parse_grouping_call_expression(context, env, tokens, position),
         TokenKind::GlobalVariable(_) => parse_global_variable_expression(env, tokens, position),
+        TokenKind::Placeholder(_) => parse_placeholder_expression(env, tokens, position),
+        TokenKind::Colon => parse_placeholder_expression(env, tokens, position),
         TokenKind::Interval => parse_interval_expression(tokens, position),
         TokenKind::String(str) => {
             *position += 1;
@@ -3848,6 +4917,19 @@ fn parse_symbol_expression(
     let mut value = tokens[*position].to_string();
     let location = tokens[*position].location;
 
+    // Lambda parameters are resolved from the lambda scope, not from the tables columns
+    if context.lambda_parameters.contains(&value) {
+        // Consume `Symbol` token
+        *position += 1;
+
+        let expr_type = resolve_symbol_type_or_undefine(env, &value);
+        return Ok(Box::new(SymbolExpr {
+            value,
+            expr_type,
+            flag: SymbolFlag::None,
+        }));
+    }
+
     // Collect projections only inside select statement
     if !context.has_select_statement {
         context.projection_names.push(value.to_string());
@@ -3862,8 +4944,16 @@ fn parse_symbol_expression(
         }
     }
 
-    // In case of using un selected column name inside OVER(....) clauses, mark it as hidden selection for now
-    if context.inside_over_clauses
+    // Elements of `UNNEST` are generated columns, so they must be selected even if used only inside expressions
+    if context.unnest_columns.contains(&value) && !context.hidden_selections.contains(&value) {
+        context.hidden_selections.push(value.to_string());
+    }
+
+    // In case of using un selected column name inside OVER(....) clauses, aggregation `ORDER BY` and `FILTER`
+    // or lambda body, mark it as hidden selection for now
+    if (context.inside_over_clauses
+        || context.inside_aggregation_clauses
+        || !context.lambda_parameters.is_empty())
         && env.schema.tables_fields_types.contains_key(&value.as_str())
         && !context.hidden_selections.contains(&value)
     {
@@ -3905,7 +4995,10 @@ fn parse_symbol_expression(
 
     // If this symbol is a reference to Aggregate value, make sure it's used in the right place
     if context.aggregations.contains_key(symbol_name)
-        && !(context.inside_selections || context.inside_having || context.inside_order_by)
+        && !(context.inside_selections
+            || context.inside_having
+            || context.inside_qualify
+            || context.inside_order_by)
     {
         return Err(Diagnostic::error(
             "Can't use the value of aggregation function outside selection or order by",
@@ -3916,10 +5009,10 @@ fn parse_symbol_expression(
 
     // If this symbol is a reference to Window function value, make sure it's used in the right place
     if context.window_functions.contains_key(symbol_name)
-        && !(context.inside_selections || context.inside_order_by)
+        && !(context.inside_selections || context.inside_qualify || context.inside_order_by)
     {
         return Err(Diagnostic::error(
-            "Can't use the value of window function outside selection or order by",
+            "Can't use the value of window function outside selection, qualify or order by",
         )
         .with_location(calculate_safe_location(tokens, *position))
         .as_boxed());
@@ -3999,6 +5092,36 @@ fn parse_array_value_expression(
     }))
 }
 
+/// Parse row constructor `ROW(value, ...)`, members are named `f1`, `f2`... until it's casted to composite type
+fn parse_row_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    // Consume `ROW` keyword
+    let row_location = tokens[*position].location;
+    *position += 1;
+
+    let values =
+        parse_zero_or_more_values_with_comma_between(context, env, tokens, position, "`ROW`")?;
+
+    if values.is_empty() {
+        return Err(
+            Diagnostic::error("`ROW` expression must have at least one value")
+                .with_location(row_location)
+                .as_boxed(),
+        );
+    }
+
+    let mut row_type = CompositeType::empty("record".to_string());
+    for (index, value) in values.iter().enumerate() {
+        row_type = row_type.add_member(format!("f{}", index + 1), value.expr_type());
+    }
+
+    Ok(Box::new(RowExpr { values, row_type }))
+}
+
 fn parse_group_expression(
     context: &mut ParserContext,
     env: &mut Environment,
@@ -4192,6 +5315,59 @@ fn parse_benchmark_call_expression(
     Ok(Box::new(BenchmarkCallExpr { expression, count }))
 }
 
+fn parse_grouping_call_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    let grouping_location = tokens[*position].location;
+
+    // Consume `GROUPING` token
+    *position += 1;
+
+    let arguments =
+        parse_zero_or_more_values_with_comma_between(context, env, tokens, position, "`GROUPING`")?;
+
+    if arguments.is_empty() {
+        return Err(
+            Diagnostic::error("`GROUPING` expects at least one argument")
+                .with_location(grouping_location)
+                .as_boxed(),
+        );
+    }
+
+    if arguments.iter().any(|argument| {
+        !matches!(
+            argument.as_any().downcast_ref::<SymbolExpr>(),
+            Some(symbol) if symbol.flag == SymbolFlag::None
+        )
+    }) {
+        return Err(
+            Diagnostic::error("`GROUPING` arguments must be grouped columns")
+                .add_help("Try to pass columns that used in the `GROUP BY` statement")
+                .with_location(grouping_location)
+                .as_boxed(),
+        );
+    }
+
+    // Grouping is calculated for each group in the aggregation stage, so it is registered
+    // as aggregation value with generated name and referenced by symbol
+    let column_name = context.name_generator.generate_column_name();
+    context.hidden_selections.push(column_name.to_string());
+    env.define(column_name.to_string(), Box::new(IntType));
+
+    context
+        .aggregations
+        .insert(column_name.clone(), AggregateValue::Grouping(arguments));
+
+    Ok(Box::new(SymbolExpr {
+        value: column_name,
+        expr_type: Box::new(IntType),
+        flag: SymbolFlag::AggregationReference,
+    }))
+}
+
 fn parse_global_variable_expression(
     env: &mut Environment,
     tokens: &[Token],
@@ -4207,6 +5383,51 @@ fn parse_global_variable_expression(
     Ok(Box::new(GlobalVariableExpr { name, result_type }))
 }
 
+fn parse_placeholder_expression(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    let location = tokens[*position].location;
+    let name = match &tokens[*position].kind {
+        TokenKind::Placeholder(position_number) => position_number.to_string(),
+        _ => {
+            // Consume `:` of the named placeholder
+            *position += 1;
+
+            match tokens.get(*position).map(|token| &token.kind) {
+                Some(TokenKind::Symbol(name)) => name.to_string(),
+                _ => {
+                    return Err(Diagnostic::error("Expect parameter name after `:`")
+                        .with_location(calculate_safe_location(tokens, *position))
+                        .as_boxed())
+                }
+            }
+        }
+    };
+
+    // Consume the placeholder position or name
+    *position += 1;
+
+    // Placeholder type is resolved from the bound value, so the value must be bound before parsing
+    let Some(value) = env.parameters.get(&name) else {
+        let literal = match tokens[*position - 1].kind {
+            TokenKind::Placeholder(_) => format!("${}", name),
+            _ => format!(":{}", name),
+        };
+
+        return Err(
+            Diagnostic::error(&format!("Missing value for parameter `{}`", literal))
+                .add_help("Bind a value to the parameter before running the query")
+                .with_location(location)
+                .as_boxed(),
+        );
+    };
+
+    let result_type = value.data_type();
+    Ok(Box::new(PlaceholderExpr { name, result_type }))
+}
+
 fn un_expected_statement_error(tokens: &[Token], position: &mut usize) -> Box<Diagnostic> {
     let token: &Token = &tokens[*position];
     let location = token.location;
@@ -4331,7 +5552,7 @@ fn un_expected_content_after_correct_statement(
 
 #[inline(always)]
 #[allow(clippy::borrowed_box)]
-fn expression_literal(expression: &Box<dyn Expr>) -> Option<String> {
+pub(crate) fn expression_literal(expression: &Box<dyn Expr>) -> Option<String> {
     if let Some(symbol) = expression.as_any().downcast_ref::<SymbolExpr>() {
         return Some(symbol.value.to_string());
     }
@@ -4545,8 +5766,10 @@ fn is_join_or_join_type_token(tokens: &[Token], position: &usize) -> bool {
         && matches!(
             tokens[*position].kind,
             TokenKind::Join
+                | TokenKind::Natural
                 | TokenKind::Left
                 | TokenKind::Right
+                | TokenKind::Full
                 | TokenKind::Cross
                 | TokenKind::Inner
         )
@@ -12,6 +12,7 @@ pub enum TokenKind {
     Group,
     Where,
     Having,
+    Qualify,
     Limit,
     Offset,
     Order,
@@ -20,9 +21,16 @@ pub enum TokenKind {
     Glob,
     Describe,
     Show,
+    Explain,
+    Analyze,
+    Create,
+    Function,
+    View,
+    Returns,
     RegExp,
     Array,
     Cast,
+    TryCast,
     Benchmark,
     Join,
     Left,
@@ -30,6 +38,8 @@ pub enum TokenKind {
     Cross,
     Inner,
     Outer,
+    Full,
+    Natural,
     Case,
     When,
     Then,
@@ -51,6 +61,11 @@ pub enum TokenKind {
     As,
     With,
     Rollup,
+    Unnest,
+    Ordinality,
+    Grouping,
+    Sets,
+    Cube,
     OrKeyword,
     AndKeyword,
     XorKeyword,
@@ -61,6 +76,15 @@ pub enum TokenKind {
     Window,
     Over,
     Partition,
+    Separator,
+    Filter,
+    Rows,
+    Range,
+    Unbounded,
+    Preceding,
+    Following,
+    Current,
+    Row,
     First,
     Last,
     Interval,
@@ -68,6 +92,7 @@ pub enum TokenKind {
     // Values
     Symbol(String),
     GlobalVariable(String),
+    Placeholder(String),
     String(String),
     Integer(i64),
     Float(f64),
@@ -92,6 +117,9 @@ pub enum TokenKind {
     NullSafeEqual,
     AtRightArrow,
     ArrowRightAt,
+    MinusBarMinus,
+    RightArrow,
+    LongRightArrow,
     LeftParen,
     RightParen,
     LeftBracket,
@@ -130,6 +158,7 @@ impl Display for TokenKind {
             TokenKind::Group => "GROUP",
             TokenKind::Where => "WHERE",
             TokenKind::Having => "HAVING",
+            TokenKind::Qualify => "QUALIFY",
             TokenKind::Limit => "LIMIT",
             TokenKind::Offset => "OFFSET",
             TokenKind::Order => "ORDER",
@@ -138,9 +167,16 @@ impl Display for TokenKind {
             TokenKind::Glob => "GLOB",
             TokenKind::Describe => "DESCRIBE",
             TokenKind::Show => "SHOW",
+            TokenKind::Explain => "EXPLAIN",
+            TokenKind::Analyze => "ANALYZE",
+            TokenKind::Create => "CREATE",
+            TokenKind::Function => "FUNCTION",
+            TokenKind::View => "VIEW",
+            TokenKind::Returns => "RETURNS",
             TokenKind::RegExp => "REGEXP",
             TokenKind::Array => "ARRAY",
             TokenKind::Cast => "CAST",
+            TokenKind::TryCast => "TRY_CAST",
             TokenKind::Benchmark => "BENCHMARK",
             TokenKind::Join => "JOIN",
             TokenKind::Left => "LEFT",
@@ -148,6 +184,8 @@ impl Display for TokenKind {
             TokenKind::Cross => "CROSS",
             TokenKind::Inner => "INNER",
             TokenKind::Outer => "OUTER",
+            TokenKind::Full => "FULL",
+            TokenKind::Natural => "NATURAL",
             TokenKind::Case => "CASE",
             TokenKind::When => "WHEN",
             TokenKind::Then => "THEN",
@@ -169,6 +207,11 @@ impl Display for TokenKind {
             TokenKind::As => "AS",
             TokenKind::With => "WITH",
             TokenKind::Rollup => "ROLLUP",
+            TokenKind::Unnest => "UNNEST",
+            TokenKind::Ordinality => "ORDINALITY",
+            TokenKind::Grouping => "GROUPING",
+            TokenKind::Sets => "SETS",
+            TokenKind::Cube => "CUBE",
             TokenKind::OrKeyword => "OR",
             TokenKind::AndKeyword => "AND",
             TokenKind::XorKeyword => "XOE",
@@ -179,6 +222,15 @@ impl Display for TokenKind {
             TokenKind::Window => "WINDOW",
             TokenKind::Over => "OVER",
             TokenKind::Partition => "PARTITION",
+            TokenKind::Separator => "SEPARATOR",
+            TokenKind::Filter => "FILTER",
+            TokenKind::Rows => "ROWS",
+            TokenKind::Range => "RANGE",
+            TokenKind::Unbounded => "UNBOUNDED",
+            TokenKind::Preceding => "PRECEDING",
+            TokenKind::Following => "FOLLOWING",
+            TokenKind::Current => "CURRENT",
+            TokenKind::Row => "ROW",
             TokenKind::Nulls => "NULLS",
             TokenKind::First => "FIRST",
             TokenKind::Last => "LAST",
@@ -187,6 +239,7 @@ impl Display for TokenKind {
             // Values
             TokenKind::Symbol(literal) => literal,
             TokenKind::GlobalVariable(literal) => literal,
+            TokenKind::Placeholder(name) => &format!("${}", name),
             TokenKind::String(string) => string,
             TokenKind::Integer(integer) => &integer.to_string(),
             TokenKind::Float(float) => &float.to_string(),
@@ -212,6 +265,9 @@ impl Display for TokenKind {
             TokenKind::NullSafeEqual => "<=>",
             TokenKind::AtRightArrow => "@>",
             TokenKind::ArrowRightAt => "<@",
+            TokenKind::MinusBarMinus => "-|-",
+            TokenKind::RightArrow => "->",
+            TokenKind::LongRightArrow => "->>",
             TokenKind::LeftParen => "(",
             TokenKind::RightParen => ")",
             TokenKind::LeftBracket => "[",
@@ -334,9 +390,18 @@ fn resolve_symbol_kind(symbol: String) -> TokenKind {
         "glob" => TokenKind::Glob,
         "describe" => TokenKind::Describe,
         "show" => TokenKind::Show,
+        "explain" => TokenKind::Explain,
+        "analyze" => TokenKind::Analyze,
         "regexp" => TokenKind::RegExp,
 
+        // User definitions
+        "create" => TokenKind::Create,
+        "function" => TokenKind::Function,
+        "view" => TokenKind::View,
+        "returns" => TokenKind::Returns,
+
         "cast" => TokenKind::Cast,
+        "try_cast" => TokenKind::TryCast,
         "benchmark" => TokenKind::Benchmark,
 
         "interval" => TokenKind::Interval,
@@ -357,13 +422,23 @@ fn resolve_symbol_kind(symbol: String) -> TokenKind {
         "cross" => TokenKind::Cross,
         "inner" => TokenKind::Inner,
         "outer" => TokenKind::Outer,
+        "full" => TokenKind::Full,
+        "natural" => TokenKind::Natural,
+
+        // Unnest
+        "unnest" => TokenKind::Unnest,
+        "ordinality" => TokenKind::Ordinality,
 
         // Grouping
         "group" => TokenKind::Group,
         "by" => TokenKind::By,
         "having" => TokenKind::Having,
+        "qualify" => TokenKind::Qualify,
         "with" => TokenKind::With,
         "rollup" => TokenKind::Rollup,
+        "grouping" => TokenKind::Grouping,
+        "sets" => TokenKind::Sets,
+        "cube" => TokenKind::Cube,
 
         // Between kind
         "symmetric" => TokenKind::Symmetric,
@@ -412,6 +487,19 @@ fn resolve_symbol_kind(symbol: String) -> TokenKind {
         "over" => TokenKind::Over,
         "partition" => TokenKind::Partition,
 
+        // Aggregation function modifiers
+        "separator" => TokenKind::Separator,
+        "filter" => TokenKind::Filter,
+
+        // Window frame
+        "rows" => TokenKind::Rows,
+        "range" => TokenKind::Range,
+        "unbounded" => TokenKind::Unbounded,
+        "preceding" => TokenKind::Preceding,
+        "following" => TokenKind::Following,
+        "current" => TokenKind::Current,
+        "row" => TokenKind::Row,
+
         // Identifier
         _ => TokenKind::Symbol(symbol),
     }
@@ -73,6 +73,12 @@ impl Tokenizer {
                 continue;
             }
 
+            // Positional Placeholder
+            if char == '$' {
+                tokens.push(self.consume_positional_placeholder()?);
+                continue;
+            }
+
             // Number
             if char.is_numeric() {
                 if char == '0' && self.index + 1 < len {
@@ -128,7 +134,7 @@ impl Tokenizer {
                 continue;
             }
 
-            // Minus
+            // Minus, Right Arrow, Long Right Arrow or Range Adjacent
             if char == '-' {
                 // Ignore single line comment which from -- until the end of the current line
                 if self.index + 1 < self.content_len && self.content[self.index + 1] == '-' {
@@ -137,6 +143,37 @@ impl Tokenizer {
                 }
 
                 let location = self.current_source_location();
+
+                // ->>
+                if self.index + 2 < self.content_len
+                    && self.content[self.index + 1] == '>'
+                    && self.content[self.index + 2] == '>'
+                {
+                    tokens.push(Token::new(TokenKind::LongRightArrow, location));
+                    // Advance `->>`
+                    self.advance_n(3);
+                    continue;
+                }
+
+                // -|-
+                if self.index + 2 < self.content_len
+                    && self.content[self.index + 1] == '|'
+                    && self.content[self.index + 2] == '-'
+                {
+                    tokens.push(Token::new(TokenKind::MinusBarMinus, location));
+                    // Advance `-|-`
+                    self.advance_n(3);
+                    continue;
+                }
+
+                // ->
+                if self.index + 1 < self.content_len && self.content[self.index + 1] == '>' {
+                    tokens.push(Token::new(TokenKind::RightArrow, location));
+                    // Advance `->`
+                    self.advance_n(2);
+                    continue;
+                }
+
                 tokens.push(Token::new(TokenKind::Minus, location));
                 self.advance();
                 continue;
@@ -411,6 +448,11 @@ impl Tokenizer {
         // Advance `@`
         self.advance();
 
+        // Advance the second `@` of system variable name like `@@time_zone`
+        if self.has_next() && self.content[self.index] == '@' {
+            self.advance();
+        }
+
         // Make sure first character is  alphabetic
         if self.has_next() && !self.content[self.index].is_alphabetic() {
             return Err(Diagnostic::error(
@@ -434,6 +476,28 @@ impl Tokenizer {
         Ok(Token::new(TokenKind::GlobalVariable(string), location))
     }
 
+    fn consume_positional_placeholder(&mut self) -> Result<Token, Box<Diagnostic>> {
+        // Advance `$`
+        self.advance();
+
+        let start_index = self.index;
+        while self.has_next() && self.is_current_char_func(|c| c.is_ascii_digit()) {
+            self.advance();
+        }
+
+        if start_index == self.index {
+            return Err(Diagnostic::error("Expect position number after `$`")
+                .add_help("Positional placeholder starts from `$1`")
+                .with_location(self.current_source_location())
+                .as_boxed());
+        }
+
+        let literal = &self.content[start_index..self.index];
+        let position: String = literal.iter().collect();
+        let location = self.current_source_location();
+        Ok(Token::new(TokenKind::Placeholder(position), location))
+    }
+
     fn consume_identifier(&mut self) -> Token {
         let start_index = self.index;
 
@@ -5,6 +5,9 @@ use gitql_ast::expression::Expr;
 use gitql_ast::statement::TableSelection;
 use gitql_ast::types::any::AnyType;
 use gitql_ast::types::dynamic::DynamicType;
+use gitql_ast::types::lambda::LambdaType;
+use gitql_ast::types::null::NullType;
+use gitql_ast::types::optional::OptionType;
 use gitql_ast::types::varargs::VarargsType;
 use gitql_ast::types::DataType;
 use gitql_core::environment::Environment;
@@ -12,17 +15,25 @@ use gitql_core::environment::Environment;
 use crate::diagnostic::Diagnostic;
 use crate::token::SourceLocation;
 
-/// Checks if all values has the same type
+/// Checks if all values has the same type, NULL values are accepted with any type
 /// If they have the same type, return it or return None
 pub fn check_all_values_are_same_type(arguments: &[Box<dyn Expr>]) -> Option<Box<dyn DataType>> {
-    let arguments_count = arguments.len();
-    if arguments_count == 0 {
+    if arguments.is_empty() {
         return Some(Box::new(AnyType));
     }
 
-    let data_type = arguments[0].expr_type();
-    for argument in arguments.iter().take(arguments_count).skip(1) {
+    let mut data_type: Box<dyn DataType> = Box::new(NullType);
+    for argument in arguments.iter() {
         let expr_type = argument.expr_type();
+        if expr_type.is_null() {
+            continue;
+        }
+
+        if data_type.is_null() {
+            data_type = expr_type;
+            continue;
+        }
+
         if !data_type.equals(&expr_type) {
             return None;
         }
@@ -98,8 +109,8 @@ pub fn check_function_call_arguments(
             .as_boxed());
         }
 
-        // Both types are equals
-        if parameter_type.equals(&argument_type) {
+        // Both types are equals, or the argument is NULL which is accepted with any type
+        if parameter_type.equals(&argument_type) || argument_type.is_null() {
             continue;
         }
 
@@ -148,8 +159,8 @@ pub fn check_function_call_arguments(
             .as_boxed());
         }
 
-        // Both types are equals
-        if parameter_type.equals(&argument_type) {
+        // Both types are equals, or the argument is NULL which is accepted with any type
+        if parameter_type.equals(&argument_type) || argument_type.is_null() {
             continue;
         }
 
@@ -194,8 +205,8 @@ pub fn check_function_call_arguments(
                 .as_boxed());
             }
 
-            // Both types are equals
-            if varargs_type.equals(&argument_type) {
+            // Both types are equals, or the argument is NULL which is accepted with any type
+            if varargs_type.equals(&argument_type) || argument_type.is_null() {
                 continue;
             }
 
@@ -208,21 +219,43 @@ pub fn check_function_call_arguments(
                 continue;
             }
 
-            return Err(Diagnostic::error(&format!(
+            let mut diagnostic = Diagnostic::error(&format!(
                 "Function `{}` argument number {} with type `{}` don't match expected type `{}`",
                 function_name,
                 index,
                 &argument_type.literal(),
                 &varargs_type.literal()
-            ))
-            .with_location(location)
-            .as_boxed());
+            ));
+
+            // Dynamic variable parameters are resolved from other arguments types
+            if is_dynamic_varargs(parameters.last().unwrap()) {
+                diagnostic = diagnostic.add_help(&format!(
+                    "Function `{}` arguments must have a common type",
+                    function_name
+                ));
+            }
+
+            return Err(diagnostic.with_location(location).as_boxed());
         }
     }
 
     Ok(())
 }
 
+#[allow(clippy::borrowed_box)]
+fn is_dynamic_varargs(parameter: &Box<dyn DataType>) -> bool {
+    parameter
+        .as_any()
+        .downcast_ref::<VarargsType>()
+        .is_some_and(|varargs| {
+            varargs
+                .base
+                .as_any()
+                .downcast_ref::<DynamicType>()
+                .is_some()
+        })
+}
+
 /// Check that all selected fields types are defined correctly in selected tables
 /// Return the columns classified for each table
 /// Return a Diagnostic Error if anything is wrong
@@ -348,6 +381,16 @@ pub fn resolve_dynamic_data_type(
         return resolved_data_type;
     }
 
+    // Resolve Dynamic? to <TYPE>? recursively
+    if let Some(optional) = data_type.as_any().downcast_ref::<OptionType>() {
+        if let Some(base) = &optional.base {
+            if base.as_any().downcast_ref::<DynamicType>().is_some() {
+                let base = resolve_dynamic_data_type(parameters, arguments, base);
+                return Box::new(OptionType { base: Some(base) });
+            }
+        }
+    }
+
     // Resolve ...Dynamic to ...<TYPE> recursively
     if let Some(varargs) = data_type.as_any().downcast_ref::<VarargsType>() {
         if varargs
@@ -363,3 +406,42 @@ pub fn resolve_dynamic_data_type(
 
     data_type.clone()
 }
+
+/// Resolve the function return type, Optional type `T?` is resolved to `T`
+/// because NULL is a valid value of any type
+#[allow(clippy::borrowed_box)]
+pub fn resolve_return_data_type(
+    parameters: &[Box<dyn DataType>],
+    arguments: &[Box<dyn Expr>],
+    return_type: &Box<dyn DataType>,
+) -> Box<dyn DataType> {
+    if let Some(optional_type) = return_type.as_any().downcast_ref::<OptionType>() {
+        if let Some(base) = &optional_type.base {
+            return resolve_dynamic_data_type(parameters, arguments, base);
+        }
+    }
+    resolve_dynamic_data_type(parameters, arguments, return_type)
+}
+
+/// Resolve the lambda type of the parameter after the already parsed arguments
+/// Return None if this parameter is not a lambda
+pub fn resolve_lambda_parameter_type(
+    parameters: &[Box<dyn DataType>],
+    arguments: &[Box<dyn Expr>],
+) -> Option<LambdaType> {
+    let parameter = parameters.get(arguments.len())?;
+    let parameter_type = match parameter.as_any().downcast_ref::<DynamicType>() {
+        Some(dynamic_type) => {
+            let mut arguments_types: Vec<Box<dyn DataType>> = Vec::with_capacity(arguments.len());
+            for argument in arguments {
+                arguments_types.push(argument.expr_type());
+            }
+            (dynamic_type.function)(&arguments_types)
+        }
+        None => parameter.clone(),
+    };
+    parameter_type
+        .as_any()
+        .downcast_ref::<LambdaType>()
+        .cloned()
+}
@@ -6,26 +6,42 @@ use gitql_ast::types::any::AnyType;
 use gitql_ast::types::boolean::BoolType;
 use gitql_ast::types::date::DateType;
 use gitql_ast::types::datetime::DateTimeType;
+use gitql_ast::types::datetimetz::DateTimeTzType;
+use gitql_ast::types::decimal::DecimalType;
 use gitql_ast::types::dynamic::DynamicType;
 use gitql_ast::types::float::FloatType;
 use gitql_ast::types::integer::IntType;
+use gitql_ast::types::json::JsonType;
 use gitql_ast::types::null::NullType;
 use gitql_ast::types::optional::OptionType;
+use gitql_ast::types::range::RangeType;
+use gitql_ast::types::semver::SemVerType;
 use gitql_ast::types::text::TextType;
 use gitql_ast::types::time::TimeType;
 use gitql_ast::types::varargs::VarargsType;
 use gitql_ast::types::variant::VariantType;
+use gitql_ast::types::DataType;
+use gitql_ast::Decimal;
 use gitql_core::signature::AggregationFunction;
+use gitql_core::signature::FallibleAggregationFunction;
 use gitql_core::signature::Signature;
 use gitql_core::values::array::ArrayValue;
 use gitql_core::values::boolean::BoolValue;
+use gitql_core::values::decimal::DecimalValue;
+use gitql_core::values::float::FloatValue;
 use gitql_core::values::integer::IntValue;
+use gitql_core::values::json::value_to_json;
+use gitql_core::values::json::JsonValue;
+use gitql_core::values::multirange::MultiRangeValue;
 use gitql_core::values::null::NullValue;
+use gitql_core::values::range::RangeValue;
 use gitql_core::values::text::TextValue;
 use gitql_core::values::Value;
 
 use crate::meta_types::array_of_type;
 use crate::meta_types::first_element_type;
+use crate::meta_types::multirange_of_type;
+use crate::meta_types::range_element_type;
 
 pub fn aggregation_functions() -> &'static HashMap<&'static str, AggregationFunction> {
     static HASHMAP: OnceLock<HashMap<&'static str, AggregationFunction>> = OnceLock::new();
@@ -43,6 +59,30 @@ pub fn aggregation_functions() -> &'static HashMap<&'static str, AggregationFunc
         map.insert("bit_or", aggregation_bit_or);
         map.insert("bit_xor", aggregation_bit_xor);
         map.insert("array_agg", aggregation_array_agg);
+        map.insert("json_agg", aggregation_json_agg);
+        map.insert("range_agg", aggregation_range_agg);
+        map.insert("median", aggregation_median);
+        map.insert("mode", aggregation_mode);
+        map.insert("stddev", aggregation_stddev_samp);
+        map.insert("stddev_samp", aggregation_stddev_samp);
+        map.insert("stddev_pop", aggregation_stddev_pop);
+        map.insert("variance", aggregation_var_samp);
+        map.insert("var_samp", aggregation_var_samp);
+        map.insert("var_pop", aggregation_var_pop);
+        map.insert("corr", aggregation_corr);
+        map.insert("covar_samp", aggregation_covar_samp);
+        map.insert("covar_pop", aggregation_covar_pop);
+        map
+    })
+}
+
+pub fn fallible_aggregation_functions(
+) -> &'static HashMap<&'static str, FallibleAggregationFunction> {
+    static HASHMAP: OnceLock<HashMap<&'static str, FallibleAggregationFunction>> = OnceLock::new();
+    HASHMAP.get_or_init(|| {
+        let mut map: HashMap<&'static str, FallibleAggregationFunction> = HashMap::new();
+        map.insert("percentile_cont", aggregation_percentile_cont);
+        map.insert("percentile_disc", aggregation_percentile_disc);
         map
     })
 }
@@ -60,6 +100,8 @@ pub fn aggregation_function_signatures() -> HashMap<&'static str, Signature> {
                     Box::new(DateType),
                     Box::new(TimeType),
                     Box::new(DateTimeType),
+                    Box::new(DateTimeTzType),
+                    Box::new(SemVerType),
                 ],
             })],
             return_type: Box::new(DynamicType {
@@ -78,6 +120,8 @@ pub fn aggregation_function_signatures() -> HashMap<&'static str, Signature> {
                     Box::new(DateType),
                     Box::new(TimeType),
                     Box::new(DateTimeType),
+                    Box::new(DateTimeTzType),
+                    Box::new(SemVerType),
                 ],
             })],
             return_type: Box::new(DynamicType {
@@ -88,15 +132,29 @@ pub fn aggregation_function_signatures() -> HashMap<&'static str, Signature> {
     map.insert(
         "sum",
         Signature {
-            parameters: vec![Box::new(IntType)],
-            return_type: Box::new(IntType),
+            parameters: vec![Box::new(VariantType {
+                variants: vec![Box::new(IntType), Box::new(DecimalType::default())],
+            })],
+            return_type: Box::new(DynamicType {
+                function: first_element_type,
+            }),
         },
     );
     map.insert(
         "avg",
         Signature {
-            parameters: vec![Box::new(IntType)],
-            return_type: Box::new(IntType),
+            parameters: vec![Box::new(VariantType {
+                variants: vec![Box::new(IntType), Box::new(DecimalType::default())],
+            })],
+            return_type: Box::new(DynamicType {
+                function: |elements| {
+                    if elements[0].is_decimal() {
+                        let count_type: Box<dyn DataType> = Box::new(IntType);
+                        return elements[0].div_op_result_type(&count_type);
+                    }
+                    Box::new(IntType)
+                },
+            }),
         },
     );
     map.insert(
@@ -161,50 +219,208 @@ pub fn aggregation_function_signatures() -> HashMap<&'static str, Signature> {
             }),
         },
     );
+    map.insert(
+        "range_agg",
+        Signature {
+            parameters: vec![Box::new(RangeType {
+                base: Box::new(AnyType),
+            })],
+            return_type: Box::new(DynamicType {
+                function: |elements| {
+                    multirange_of_type(range_element_type(first_element_type(elements)))
+                },
+            }),
+        },
+    );
+    map.insert(
+        "json_agg",
+        Signature {
+            parameters: vec![Box::new(AnyType)],
+            return_type: Box::new(JsonType),
+        },
+    );
+    map.insert(
+        "median",
+        Signature {
+            parameters: vec![number_type()],
+            return_type: Box::new(FloatType),
+        },
+    );
+    map.insert(
+        "percentile_cont",
+        Signature {
+            parameters: vec![number_type(), Box::new(FloatType)],
+            return_type: Box::new(FloatType),
+        },
+    );
+    map.insert(
+        "percentile_disc",
+        Signature {
+            parameters: vec![
+                Box::new(VariantType {
+                    variants: vec![
+                        Box::new(IntType),
+                        Box::new(FloatType),
+                        Box::new(TextType),
+                        Box::new(DateType),
+                        Box::new(TimeType),
+                        Box::new(DateTimeType),
+                    ],
+                }),
+                Box::new(FloatType),
+            ],
+            return_type: Box::new(DynamicType {
+                function: first_element_type,
+            }),
+        },
+    );
+    map.insert(
+        "mode",
+        Signature {
+            parameters: vec![Box::new(AnyType)],
+            return_type: Box::new(DynamicType {
+                function: first_element_type,
+            }),
+        },
+    );
+    for name in [
+        "stddev",
+        "stddev_samp",
+        "stddev_pop",
+        "variance",
+        "var_samp",
+        "var_pop",
+    ] {
+        map.insert(
+            name,
+            Signature {
+                parameters: vec![number_type()],
+                return_type: Box::new(FloatType),
+            },
+        );
+    }
+    for name in ["corr", "covar_samp", "covar_pop"] {
+        map.insert(
+            name,
+            Signature {
+                parameters: vec![number_type(), number_type()],
+                return_type: Box::new(FloatType),
+            },
+        );
+    }
     map
 }
 
+/// Integer or Float parameter type for the statistical aggregations
+fn number_type() -> Box<dyn DataType> {
+    Box::new(VariantType {
+        variants: vec![Box::new(IntType), Box::new(FloatType)],
+    })
+}
+
 pub fn aggregation_max(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
-    let mut max_value = &group_values[0][0];
+    let mut max_value: Option<&Box<dyn Value>> = None;
     for row_values in group_values {
         let single_value = &row_values[0];
-        if max_value.compare(single_value) == Some(Ordering::Less) {
-            max_value = single_value;
+        if single_value.is_null() {
+            continue;
+        }
+
+        match max_value {
+            Some(value) if value.compare(single_value) != Some(Ordering::Less) => {}
+            _ => max_value = Some(single_value),
         }
     }
-    max_value.clone()
+
+    match max_value {
+        Some(value) => value.clone(),
+        None => Box::new(NullValue),
+    }
 }
 
 pub fn aggregation_min(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
-    let mut min_value = &group_values[0][0];
+    let mut min_value: Option<&Box<dyn Value>> = None;
     for row_values in group_values {
         let single_value = &row_values[0];
-        if min_value.compare(single_value) == Some(Ordering::Greater) {
-            min_value = single_value;
+        if single_value.is_null() {
+            continue;
         }
+
+        match min_value {
+            Some(value) if value.compare(single_value) != Some(Ordering::Greater) => {}
+            _ => min_value = Some(single_value),
+        }
+    }
+
+    match min_value {
+        Some(value) => value.clone(),
+        None => Box::new(NullValue),
     }
-    min_value.clone()
 }
 
+/// Return the sum of the values, Null if the sum overflows the value type range
 pub fn aggregation_sum(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    if group_values
+        .iter()
+        .any(|row_values| row_values[0].is_decimal())
+    {
+        return match decimal_sum(group_values) {
+            Some((sum, _)) => Box::new(DecimalValue::new(sum)),
+            None => Box::new(NullValue),
+        };
+    }
+
+    match integer_sum(group_values) {
+        Some((sum, _)) => Box::new(IntValue { value: sum }),
+        None => Box::new(NullValue),
+    }
+}
+
+/// Return the average of the non null values, Null if there are no values or the sum overflows
+pub fn aggregation_average(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    if group_values
+        .iter()
+        .any(|row_values| row_values[0].is_decimal())
+    {
+        return match decimal_sum(group_values) {
+            Some((sum, count)) if count > 0 => match sum.div(&Decimal::from_int(count)) {
+                Ok(average) => Box::new(DecimalValue::new(average)),
+                Err(_) => Box::new(NullValue),
+            },
+            _ => Box::new(NullValue),
+        };
+    }
+
+    match integer_sum(group_values) {
+        Some((sum, count)) if count > 0 => Box::new(IntValue { value: sum / count }),
+        _ => Box::new(NullValue),
+    }
+}
+
+/// Sum and count of the non null integer values, or None if the sum overflows
+fn integer_sum(group_values: &[Vec<Box<dyn Value>>]) -> Option<(i64, i64)> {
     let mut sum: i64 = 0;
+    let mut count: i64 = 0;
     for row_values in group_values {
         if let Some(int_value) = row_values[0].as_any().downcast_ref::<IntValue>() {
-            sum += int_value.value;
+            sum = sum.checked_add(int_value.value)?;
+            count += 1;
         }
     }
-    Box::new(IntValue { value: sum })
+    Some((sum, count))
 }
 
-pub fn aggregation_average(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
-    let mut sum: i64 = 0;
+/// Sum and count of the non null decimal values, or None if the sum overflows
+fn decimal_sum(group_values: &[Vec<Box<dyn Value>>]) -> Option<(Decimal, i64)> {
+    let mut sum = Decimal::from_int(0);
+    let mut count: i64 = 0;
     for row_values in group_values {
-        if let Some(int_value) = row_values[0].as_any().downcast_ref::<IntValue>() {
-            sum += int_value.value;
+        if let Some(decimal) = row_values[0].as_decimal() {
+            sum = sum.add(&decimal).ok()?;
+            count += 1;
         }
     }
-    let count: i64 = group_values[0].len().try_into().unwrap();
-    Box::new(IntValue { value: sum / count })
+    Some((sum, count))
 }
 
 pub fn aggregation_count(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
@@ -327,3 +543,255 @@ pub fn aggregation_array_agg(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Va
         base_type: element_type,
     })
 }
+
+pub fn aggregation_range_agg(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    let mut ranges: Vec<RangeValue> = vec![];
+    for row_values in group_values {
+        if let Some(range) = row_values[0].as_any().downcast_ref::<RangeValue>() {
+            ranges.push(range.clone());
+        }
+    }
+
+    let base_type = if ranges.is_empty() {
+        Box::new(NullType)
+    } else {
+        ranges[0].base_type.clone()
+    };
+
+    Box::new(MultiRangeValue::new(ranges, base_type))
+}
+
+pub fn aggregation_json_agg(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    let mut array: Vec<serde_json::Value> = Vec::with_capacity(group_values.len());
+    for row_values in group_values {
+        array.push(value_to_json(&row_values[0]));
+    }
+    Box::new(JsonValue::new(serde_json::Value::Array(array)))
+}
+
+pub fn aggregation_median(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    let mut values = numeric_values(group_values, 0);
+    values.sort_by(f64::total_cmp);
+    match continuous_percentile(&values, 0.5) {
+        Some(median) => Box::new(FloatValue::new(median)),
+        None => Box::new(NullValue),
+    }
+}
+
+pub fn aggregation_percentile_cont(
+    group_values: &[Vec<Box<dyn Value>>],
+) -> Result<Box<dyn Value>, String> {
+    let Some(fraction) = percentile_fraction(group_values)? else {
+        return Ok(Box::new(NullValue));
+    };
+
+    let mut values = numeric_values(group_values, 0);
+    values.sort_by(f64::total_cmp);
+    match continuous_percentile(&values, fraction) {
+        Some(percentile) => Ok(Box::new(FloatValue::new(percentile))),
+        None => Ok(Box::new(NullValue)),
+    }
+}
+
+pub fn aggregation_percentile_disc(
+    group_values: &[Vec<Box<dyn Value>>],
+) -> Result<Box<dyn Value>, String> {
+    let Some(fraction) = percentile_fraction(group_values)? else {
+        return Ok(Box::new(NullValue));
+    };
+
+    let mut values: Vec<&Box<dyn Value>> = group_values
+        .iter()
+        .map(|row_values| &row_values[0])
+        .filter(|value| !value.is_null())
+        .collect();
+
+    if values.is_empty() {
+        return Ok(Box::new(NullValue));
+    }
+
+    values.sort_by(|a, b| a.compare(b).unwrap_or(Ordering::Equal));
+
+    // First value that its position in the sorted values is greater than or equal the fraction
+    let position = (fraction * values.len() as f64).ceil() as usize;
+    Ok(values[position.saturating_sub(1)].clone())
+}
+
+pub fn aggregation_mode(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    // Count the occurrences of each value literal and keep the first row of each value
+    let mut occurrences: HashMap<String, (usize, usize)> = HashMap::new();
+    for (index, row_values) in group_values.iter().enumerate() {
+        let value = &row_values[0];
+        if value.is_null() {
+            continue;
+        }
+
+        let occurrence = occurrences.entry(value.literal()).or_insert((0, index));
+        occurrence.0 += 1;
+    }
+
+    // Most frequent value, or the first one in case of equal frequencies
+    let most_frequent = occurrences
+        .values()
+        .max_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+    match most_frequent {
+        Some((_, index)) => group_values[*index][0].clone(),
+        None => Box::new(NullValue),
+    }
+}
+
+pub fn aggregation_stddev_samp(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    let values = numeric_values(group_values, 0);
+    match variance(&values, true) {
+        Some(variance) => Box::new(FloatValue::new(variance.sqrt())),
+        None => Box::new(NullValue),
+    }
+}
+
+pub fn aggregation_stddev_pop(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    let values = numeric_values(group_values, 0);
+    match variance(&values, false) {
+        Some(variance) => Box::new(FloatValue::new(variance.sqrt())),
+        None => Box::new(NullValue),
+    }
+}
+
+pub fn aggregation_var_samp(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    let values = numeric_values(group_values, 0);
+    match variance(&values, true) {
+        Some(variance) => Box::new(FloatValue::new(variance)),
+        None => Box::new(NullValue),
+    }
+}
+
+pub fn aggregation_var_pop(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    let values = numeric_values(group_values, 0);
+    match variance(&values, false) {
+        Some(variance) => Box::new(FloatValue::new(variance)),
+        None => Box::new(NullValue),
+    }
+}
+
+pub fn aggregation_corr(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    let pairs = numeric_pairs(group_values);
+    let ys: Vec<f64> = pairs.iter().map(|pair| pair.0).collect();
+    let xs: Vec<f64> = pairs.iter().map(|pair| pair.1).collect();
+    let (Some(covariance), Some(y_variance), Some(x_variance)) = (
+        covariance(&pairs, false),
+        variance(&ys, false),
+        variance(&xs, false),
+    ) else {
+        return Box::new(NullValue);
+    };
+
+    if y_variance == 0.0 || x_variance == 0.0 {
+        return Box::new(NullValue);
+    }
+
+    let correlation = covariance / (y_variance.sqrt() * x_variance.sqrt());
+    Box::new(FloatValue::new(correlation))
+}
+
+pub fn aggregation_covar_samp(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    let pairs = numeric_pairs(group_values);
+    match covariance(&pairs, true) {
+        Some(covariance) => Box::new(FloatValue::new(covariance)),
+        None => Box::new(NullValue),
+    }
+}
+
+pub fn aggregation_covar_pop(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    let pairs = numeric_pairs(group_values);
+    match covariance(&pairs, false) {
+        Some(covariance) => Box::new(FloatValue::new(covariance)),
+        None => Box::new(NullValue),
+    }
+}
+
+/// Collect the non null Integer and Float values of the argument at index as f64
+fn numeric_values(group_values: &[Vec<Box<dyn Value>>], index: usize) -> Vec<f64> {
+    group_values
+        .iter()
+        .filter_map(|row_values| numeric_value(&row_values[index]))
+        .collect()
+}
+
+/// Collect the rows that have non null values for both arguments as (y, x) pairs
+fn numeric_pairs(group_values: &[Vec<Box<dyn Value>>]) -> Vec<(f64, f64)> {
+    group_values
+        .iter()
+        .filter_map(|row_values| {
+            let y = numeric_value(&row_values[0])?;
+            let x = numeric_value(&row_values[1])?;
+            Some((y, x))
+        })
+        .collect()
+}
+
+#[allow(clippy::borrowed_box)]
+fn numeric_value(value: &Box<dyn Value>) -> Option<f64> {
+    if let Some(int_value) = value.as_int() {
+        return Some(int_value as f64);
+    }
+    value.as_float()
+}
+
+/// Percentile fraction is the second argument and must be between 0 and 1,
+/// None if there are no rows or the fraction is Null
+fn percentile_fraction(group_values: &[Vec<Box<dyn Value>>]) -> Result<Option<f64>, String> {
+    let Some(fraction) = group_values
+        .first()
+        .and_then(|row_values| numeric_value(&row_values[1]))
+    else {
+        return Ok(None);
+    };
+
+    if (0.0..=1.0).contains(&fraction) {
+        Ok(Some(fraction))
+    } else {
+        Err(format!(
+            "Percentile fraction `{}` is not between 0 and 1",
+            fraction
+        ))
+    }
+}
+
+/// Percentile of sorted values with linear interpolation between the nearest values
+fn continuous_percentile(sorted_values: &[f64], fraction: f64) -> Option<f64> {
+    if sorted_values.is_empty() {
+        return None;
+    }
+
+    let position = fraction * (sorted_values.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let weight = position - lower as f64;
+    Some(sorted_values[lower] + (sorted_values[upper] - sorted_values[lower]) * weight)
+}
+
+/// Sample variance requires at least two values, population variance requires at least one
+fn variance(values: &[f64], is_sample: bool) -> Option<f64> {
+    let count = values.len();
+    if count == 0 || (is_sample && count < 2) {
+        return None;
+    }
+
+    let mean = values.iter().sum::<f64>() / count as f64;
+    let squares_sum: f64 = values.iter().map(|value| (value - mean).powi(2)).sum();
+    let divisor = if is_sample { count - 1 } else { count };
+    Some(squares_sum / divisor as f64)
+}
+
+fn covariance(pairs: &[(f64, f64)], is_sample: bool) -> Option<f64> {
+    let count = pairs.len();
+    if count == 0 || (is_sample && count < 2) {
+        return None;
+    }
+
+    let y_mean = pairs.iter().map(|pair| pair.0).sum::<f64>() / count as f64;
+    let x_mean = pairs.iter().map(|pair| pair.1).sum::<f64>() / count as f64;
+    let products_sum: f64 = pairs.iter().map(|(y, x)| (y - y_mean) * (x - x_mean)).sum();
+    let divisor = if is_sample { count - 1 } else { count };
+    Some(products_sum / divisor as f64)
+}
@@ -2,12 +2,16 @@ use std::collections::HashMap;
 
 use gitql_ast::types::any::AnyType;
 use gitql_ast::types::array::ArrayType;
+use gitql_ast::types::boolean::BoolType;
 use gitql_ast::types::dynamic::DynamicType;
 use gitql_ast::types::integer::IntType;
 use gitql_ast::types::text::TextType;
+use gitql_core::signature::HigherOrderFunction;
+use gitql_core::signature::LambdaCall;
 use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
 use gitql_core::values::array::ArrayValue;
+use gitql_core::values::boolean::BoolValue;
 use gitql_core::values::integer::IntValue;
 use gitql_core::values::null::NullValue;
 use gitql_core::values::text::TextValue;
@@ -16,6 +20,8 @@ use gitql_core::values::Value;
 use crate::meta_types::array_element_type;
 use crate::meta_types::array_of_type;
 use crate::meta_types::first_element_type;
+use crate::meta_types::lambda_of_type;
+use crate::meta_types::lambda_return_type;
 use crate::meta_types::second_element_type;
 
 use rand::seq::SliceRandom;
@@ -35,6 +41,17 @@ pub fn register_std_array_functions(map: &mut HashMap<&'static str, StandardFunc
     map.insert("trim_array", array_trim);
 }
 
+#[inline(always)]
+pub fn register_std_array_higher_order_functions(
+    map: &mut HashMap<&'static str, HigherOrderFunction>,
+) {
+    map.insert("array_filter", array_filter);
+    map.insert("array_transform", array_transform);
+    map.insert("array_any", array_any);
+    map.insert("array_all", array_all);
+    map.insert("array_reduce", array_reduce);
+}
+
 #[inline(always)]
 pub fn register_std_array_function_signatures(map: &mut HashMap<&'static str, Signature>) {
     map.insert(
@@ -175,6 +192,108 @@ pub fn register_std_array_function_signatures(map: &mut HashMap<&'static str, Si
             }),
         },
     );
+    map.insert(
+        "array_filter",
+        Signature {
+            parameters: vec![
+                Box::new(ArrayType {
+                    base: Box::new(AnyType),
+                }),
+                Box::new(DynamicType {
+                    function: |elements| {
+                        let element_type = array_element_type(first_element_type(elements));
+                        lambda_of_type(vec![element_type], Box::new(BoolType))
+                    },
+                }),
+            ],
+            return_type: Box::new(DynamicType {
+                function: first_element_type,
+            }),
+        },
+    );
+    map.insert(
+        "array_transform",
+        Signature {
+            parameters: vec![
+                Box::new(ArrayType {
+                    base: Box::new(AnyType),
+                }),
+                Box::new(DynamicType {
+                    function: |elements| {
+                        let element_type = array_element_type(first_element_type(elements));
+                        lambda_of_type(vec![element_type], Box::new(AnyType))
+                    },
+                }),
+            ],
+            return_type: Box::new(DynamicType {
+                function: |elements| {
+                    let return_type = lambda_return_type(second_element_type(elements));
+                    if return_type.is_any() {
+                        return return_type;
+                    }
+                    array_of_type(return_type)
+                },
+            }),
+        },
+    );
+    map.insert(
+        "array_any",
+        Signature {
+            parameters: vec![
+                Box::new(ArrayType {
+                    base: Box::new(AnyType),
+                }),
+                Box::new(DynamicType {
+                    function: |elements| {
+                        let element_type = array_element_type(first_element_type(elements));
+                        lambda_of_type(vec![element_type], Box::new(BoolType))
+                    },
+                }),
+            ],
+            return_type: Box::new(BoolType),
+        },
+    );
+    map.insert(
+        "array_all",
+        Signature {
+            parameters: vec![
+                Box::new(ArrayType {
+                    base: Box::new(AnyType),
+                }),
+                Box::new(DynamicType {
+                    function: |elements| {
+                        let element_type = array_element_type(first_element_type(elements));
+                        lambda_of_type(vec![element_type], Box::new(BoolType))
+                    },
+                }),
+            ],
+            return_type: Box::new(BoolType),
+        },
+    );
+    map.insert(
+        "array_reduce",
+        Signature {
+            parameters: vec![
+                Box::new(ArrayType {
+                    base: Box::new(AnyType),
+                }),
+                Box::new(AnyType),
+                Box::new(DynamicType {
+                    function: |elements| {
+                        let accumulator_type = second_element_type(elements);
+                        let element_type = array_element_type(first_element_type(elements));
+                        lambda_of_type(
+                            vec![accumulator_type.clone(), element_type],
+                            accumulator_type,
+                        )
+                    },
+                }),
+            ],
+            return_type: Box::new(DynamicType {
+                function: second_element_type,
+            }),
+        },
+    );
 }
 
 pub fn array_append(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
@@ -308,3 +427,95 @@ pub fn array_trim(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
         base_type: array_type,
     })
 }
+
+pub fn array_filter(
+    inputs: &[Box<dyn Value>],
+    lambda: &mut LambdaCall,
+) -> Result<Box<dyn Value>, String> {
+    let Some(array) = inputs[0].as_any().downcast_ref::<ArrayValue>() else {
+        return Ok(Box::new(NullValue));
+    };
+
+    let mut values: Vec<Box<dyn Value>> = vec![];
+    for element in array.values.iter() {
+        let condition = lambda(std::slice::from_ref(element))?;
+        if condition.as_bool().unwrap_or(false) {
+            values.push(element.clone());
+        }
+    }
+
+    Ok(Box::new(ArrayValue {
+        values,
+        base_type: array.base_type.clone(),
+    }))
+}
+
+pub fn array_transform(
+    inputs: &[Box<dyn Value>],
+    lambda: &mut LambdaCall,
+) -> Result<Box<dyn Value>, String> {
+    let Some(array) = inputs[0].as_array() else {
+        return Ok(Box::new(NullValue));
+    };
+
+    let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(array.len());
+    for element in array {
+        values.push(lambda(&[element])?);
+    }
+
+    // Element type is the type of the first non null result, or Any for empty results
+    let base_type = values
+        .iter()
+        .map(|value| value.data_type())
+        .find(|data_type| !data_type.is_null())
+        .unwrap_or(Box::new(AnyType));
+
+    Ok(Box::new(ArrayValue { values, base_type }))
+}
+
+pub fn array_any(
+    inputs: &[Box<dyn Value>],
+    lambda: &mut LambdaCall,
+) -> Result<Box<dyn Value>, String> {
+    let Some(array) = inputs[0].as_array() else {
+        return Ok(Box::new(NullValue));
+    };
+
+    for element in array {
+        if lambda(&[element])?.as_bool().unwrap_or(false) {
+            return Ok(Box::new(BoolValue::new_true()));
+        }
+    }
+    Ok(Box::new(BoolValue::new_false()))
+}
+
+pub fn array_all(
+    inputs: &[Box<dyn Value>],
+    lambda: &mut LambdaCall,
+) -> Result<Box<dyn Value>, String> {
+    let Some(array) = inputs[0].as_array() else {
+        return Ok(Box::new(NullValue));
+    };
+
+    for element in array {
+        if !lambda(&[element])?.as_bool().unwrap_or(false) {
+            return Ok(Box::new(BoolValue::new_false()));
+        }
+    }
+    Ok(Box::new(BoolValue::new_true()))
+}
+
+pub fn array_reduce(
+    inputs: &[Box<dyn Value>],
+    lambda: &mut LambdaCall,
+) -> Result<Box<dyn Value>, String> {
+    let Some(array) = inputs[0].as_array() else {
+        return Ok(Box::new(NullValue));
+    };
+
+    let mut accumulator = inputs[1].clone();
+    for element in array {
+        accumulator = lambda(&[accumulator, element])?;
+    }
+    Ok(accumulator)
+}
@@ -0,0 +1,176 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use md5::Md5;
+use sha1::Digest;
+use sha1::Sha1;
+use sha2::Sha256;
+
+use gitql_ast::types::bytes::BytesType;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::text::TextType;
+use gitql_ast::types::variant::VariantType;
+use gitql_ast::types::DataType;
+use gitql_core::signature::FallibleFunction;
+use gitql_core::signature::Signature;
+use gitql_core::signature::StandardFunction;
+use gitql_core::values::bytes::BytesValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+use std::collections::HashMap;
+
+#[inline(always)]
+pub fn register_std_bytes_functions(map: &mut HashMap<&'static str, StandardFunction>) {
+    map.insert("length", bytes_length);
+    map.insert("md5", bytes_md5);
+    map.insert("sha1", bytes_sha1);
+    map.insert("sha256", bytes_sha256);
+}
+
+#[inline(always)]
+pub fn register_std_bytes_fallible_functions(map: &mut HashMap<&'static str, FallibleFunction>) {
+    map.insert("encode", bytes_encode);
+    map.insert("decode", bytes_decode);
+}
+
+#[inline(always)]
+pub fn register_std_bytes_function_signatures(map: &mut HashMap<&'static str, Signature>) {
+    map.insert(
+        "length",
+        Signature {
+            parameters: vec![bytes_or_text_type()],
+            return_type: Box::new(IntType),
+        },
+    );
+    map.insert(
+        "encode",
+        Signature {
+            parameters: vec![Box::new(BytesType), Box::new(TextType)],
+            return_type: Box::new(TextType),
+        },
+    );
+    map.insert(
+        "decode",
+        Signature {
+            parameters: vec![Box::new(TextType), Box::new(TextType)],
+            return_type: Box::new(BytesType),
+        },
+    );
+    map.insert(
+        "md5",
+        Signature {
+            parameters: vec![bytes_or_text_type()],
+            return_type: Box::new(TextType),
+        },
+    );
+    map.insert(
+        "sha1",
+        Signature {
+            parameters: vec![bytes_or_text_type()],
+            return_type: Box::new(TextType),
+        },
+    );
+    map.insert(
+        "sha256",
+        Signature {
+            parameters: vec![bytes_or_text_type()],
+            return_type: Box::new(TextType),
+        },
+    );
+}
+
+pub(crate) fn bytes_or_text_type() -> Box<dyn DataType> {
+    Box::new(VariantType {
+        variants: vec![Box::new(BytesType), Box::new(TextType)],
+    })
+}
+
+/// Return the bytes of Bytes value or the UTF-8 bytes of Text value
+#[allow(clippy::borrowed_box)]
+fn bytes_of(value: &Box<dyn Value>) -> Vec<u8> {
+    match value.as_bytes() {
+        Some(bytes) => bytes,
+        None => value.as_text().unwrap().into_bytes(),
+    }
+}
+
+/// Return the number of bytes of Bytes value or the number of characters of Text value
+pub fn bytes_length(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let length = match inputs[0].as_bytes() {
+        Some(bytes) => bytes.len(),
+        None => inputs[0].as_text().unwrap().chars().count(),
+    };
+    Box::new(IntValue::new(length as i64))
+}
+
+/// Return the bytes starting from one based position with the given length
+pub fn bytes_substring(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let bytes = inputs[0].as_bytes().unwrap();
+    let start = inputs[1].as_int().unwrap().max(1) as usize - 1;
+    let length = inputs[2].as_int().unwrap().max(0) as usize;
+    let start = start.min(bytes.len());
+    let end = start.saturating_add(length).min(bytes.len());
+    Box::new(BytesValue::new(bytes[start..end].to_vec()))
+}
+
+/// Encode bytes as `hex` or `base64` text
+pub fn bytes_encode(inputs: &[Box<dyn Value>]) -> Result<Box<dyn Value>, String> {
+    let bytes = BytesValue::new(inputs[0].as_bytes().unwrap());
+    let format = inputs[1].as_text().unwrap();
+    match format.to_lowercase().as_str() {
+        "hex" => Ok(Box::new(TextValue::new(bytes.to_hex()))),
+        "base64" => Ok(Box::new(TextValue::new(BASE64.encode(&bytes.value)))),
+        _ => Err(unknown_format_error(&format)),
+    }
+}
+
+/// Decode `hex` or `base64` text to bytes
+pub fn bytes_decode(inputs: &[Box<dyn Value>]) -> Result<Box<dyn Value>, String> {
+    let text = inputs[0].as_text().unwrap();
+    let format = inputs[1].as_text().unwrap();
+    let bytes = match format.to_lowercase().as_str() {
+        "hex" => decode_hex(text.trim()),
+        "base64" => BASE64.decode(text.trim()).ok(),
+        _ => return Err(unknown_format_error(&format)),
+    };
+
+    match bytes {
+        Some(bytes) => Ok(Box::new(BytesValue::new(bytes))),
+        None => Err(format!("Invalid `{}` encoded text `{}`", format, text)),
+    }
+}
+
+fn unknown_format_error(format: &str) -> String {
+    format!(
+        "Unknown encoding format `{}`, expected `hex` or `base64`",
+        format
+    )
+}
+
+pub fn bytes_md5(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let digest = Md5::digest(bytes_of(&inputs[0]));
+    Box::new(TextValue::new(BytesValue::new(digest.to_vec()).to_hex()))
+}
+
+pub fn bytes_sha1(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let digest = Sha1::digest(bytes_of(&inputs[0]));
+    Box::new(TextValue::new(BytesValue::new(digest.to_vec()).to_hex()))
+}
+
+pub fn bytes_sha256(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let digest = Sha256::digest(bytes_of(&inputs[0]));
+    Box::new(TextValue::new(BytesValue::new(digest.to_vec()).to_hex()))
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    let text = text.strip_prefix("\\x").unwrap_or(text);
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return None;
+    }
+
+    (0..text.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&text[index..index + 2], 16).ok())
+        .collect()
+}
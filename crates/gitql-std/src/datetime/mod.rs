@@ -1,34 +1,52 @@
 use std::collections::HashMap;
+use std::fmt::Write;
 
 extern crate chrono;
+use chrono::format::Item;
+use chrono::format::StrftimeItems;
 use chrono::DateTime;
 use chrono::Datelike;
+use chrono::Days;
+use chrono::Months;
 use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+use chrono::Offset;
 use chrono::TimeZone;
 use chrono::Timelike;
 use chrono::Utc;
 use chrono::Weekday;
+use chrono_tz::Tz;
 
 use gitql_ast::types::any::AnyType;
 use gitql_ast::types::boolean::BoolType;
 use gitql_ast::types::date::DateType;
 use gitql_ast::types::datetime::DateTimeType;
+use gitql_ast::types::datetimetz::DateTimeTzType;
+use gitql_ast::types::dynamic::DynamicType;
 use gitql_ast::types::integer::IntType;
 use gitql_ast::types::interval::IntervalType;
+use gitql_ast::types::optional::OptionType;
 use gitql_ast::types::text::TextType;
 use gitql_ast::types::time::TimeType;
 use gitql_ast::types::variant::VariantType;
+use gitql_ast::Interval;
+use gitql_core::signature::FallibleFunction;
 use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
 use gitql_core::values::boolean::BoolValue;
+use gitql_core::values::converters::string_literal_to_timestamp;
 use gitql_core::values::date::DateValue;
 use gitql_core::values::datetime::DateTimeValue;
+use gitql_core::values::datetimetz::DateTimeTzValue;
 use gitql_core::values::integer::IntValue;
 use gitql_core::values::interval::IntervalValue;
 use gitql_core::values::text::TextValue;
 use gitql_core::values::time::TimeValue;
 use gitql_core::values::Value;
 
+use crate::meta_types::second_element_type;
+
 #[inline(always)]
 pub fn register_std_datetime_functions(map: &mut HashMap<&'static str, StandardFunction>) {
     map.insert("date", date_extract_date);
@@ -55,19 +73,28 @@ pub fn register_std_datetime_functions(map: &mut HashMap<&'static str, StandardF
     map.insert("to_days", date_to_days);
     map.insert("last_day", date_last_day);
     map.insert("yearweek", date_year_and_week);
+    map.insert("to_char", date_to_char);
+    map.insert("age", date_age);
 
     map.insert("justify_days", interval_justify_days);
     map.insert("justify_hours", interval_justify_hours);
 }
 
+#[inline(always)]
+pub fn register_std_datetime_fallible_functions(map: &mut HashMap<&'static str, FallibleFunction>) {
+    map.insert("date_trunc", date_trunc);
+    map.insert("date_part", date_part);
+    map.insert("date_bin", date_bin);
+    map.insert("strftime", date_strftime);
+    map.insert("parse_datetime", date_parse_datetime);
+}
+
 #[inline(always)]
 pub fn register_std_datetime_function_signatures(map: &mut HashMap<&'static str, Signature>) {
     map.insert(
         "date",
         Signature {
-            parameters: vec![Box::new(VariantType {
-                variants: vec![Box::new(DateType), Box::new(DateTimeType)],
-            })],
+            parameters: vec![any_date_time_type()],
             return_type: Box::new(DateType),
         },
     );
@@ -116,7 +143,7 @@ pub fn register_std_datetime_function_signatures(map: &mut HashMap<&'static str,
     map.insert(
         "dayname",
         Signature {
-            parameters: vec![Box::new(DateType)],
+            parameters: vec![any_date_time_type()],
             return_type: Box::new(TextType),
         },
     );
@@ -137,14 +164,14 @@ pub fn register_std_datetime_function_signatures(map: &mut HashMap<&'static str,
     map.insert(
         "hour",
         Signature {
-            parameters: vec![Box::new(DateTimeType)],
+            parameters: vec![date_time_or_date_time_tz_type()],
             return_type: Box::new(IntType),
         },
     );
     map.insert(
         "minute",
         Signature {
-            parameters: vec![Box::new(DateTimeType)],
+            parameters: vec![date_time_or_date_time_tz_type()],
             return_type: Box::new(IntType),
         },
     );
@@ -179,7 +206,7 @@ pub fn register_std_datetime_function_signatures(map: &mut HashMap<&'static str,
     map.insert(
         "weekofyear",
         Signature {
-            parameters: vec![Box::new(DateType)],
+            parameters: vec![any_date_time_type()],
             return_type: Box::new(IntType),
         },
     );
@@ -233,6 +260,77 @@ pub fn register_std_datetime_function_signatures(map: &mut HashMap<&'static str,
         },
     );
 
+    map.insert(
+        "date_trunc",
+        Signature {
+            parameters: vec![Box::new(TextType), any_date_time_type()],
+            return_type: Box::new(DynamicType {
+                function: second_element_type,
+            }),
+        },
+    );
+    map.insert(
+        "date_part",
+        Signature {
+            parameters: vec![
+                Box::new(TextType),
+                Box::new(VariantType {
+                    variants: vec![
+                        Box::new(DateType),
+                        Box::new(DateTimeType),
+                        Box::new(DateTimeTzType),
+                        Box::new(IntervalType),
+                    ],
+                }),
+            ],
+            return_type: Box::new(IntType),
+        },
+    );
+    map.insert(
+        "date_bin",
+        Signature {
+            parameters: vec![
+                Box::new(IntervalType),
+                date_time_or_date_time_tz_type(),
+                date_time_or_date_time_tz_type(),
+            ],
+            return_type: Box::new(DynamicType {
+                function: second_element_type,
+            }),
+        },
+    );
+    map.insert(
+        "to_char",
+        Signature {
+            parameters: vec![any_date_time_type(), Box::new(TextType)],
+            return_type: Box::new(TextType),
+        },
+    );
+    map.insert(
+        "strftime",
+        Signature {
+            parameters: vec![Box::new(TextType), any_date_time_type()],
+            return_type: Box::new(TextType),
+        },
+    );
+    map.insert(
+        "age",
+        Signature {
+            parameters: vec![
+                any_date_time_type(),
+                Box::new(OptionType::new(Some(any_date_time_type()))),
+            ],
+            return_type: Box::new(IntervalType),
+        },
+    );
+    map.insert(
+        "parse_datetime",
+        Signature {
+            parameters: vec![Box::new(TextType)],
+            return_type: Box::new(DateTimeType),
+        },
+    );
+
     map.insert(
         "justify_days",
         Signature {
@@ -250,11 +348,72 @@ pub fn register_std_datetime_function_signatures(map: &mut HashMap<&'static str,
     );
 }
 
+fn date_time_or_date_time_tz_type() -> Box<VariantType> {
+    Box::new(VariantType {
+        variants: vec![Box::new(DateTimeType), Box::new(DateTimeTzType)],
+    })
+}
+
+fn any_date_time_type() -> Box<VariantType> {
+    Box::new(VariantType {
+        variants: vec![
+            Box::new(DateType),
+            Box::new(DateTimeType),
+            Box::new(DateTimeTzType),
+        ],
+    })
+}
+
+/// Return the date time of Date or DateTime in UTC and DateTimeTz in its time zone
+#[allow(clippy::borrowed_box)]
+fn zoned_date_time(value: &Box<dyn Value>) -> DateTime<Tz> {
+    let (timestamp, time_zone) = value.as_date_time_tz().unwrap_or_else(|| {
+        let timestamp = value.as_date_time().or_else(|| value.as_date());
+        (timestamp.unwrap_or_default(), Tz::UTC)
+    });
+    let date_time = DateTime::from_timestamp(timestamp, 0).unwrap();
+    date_time.with_timezone(&time_zone)
+}
+
+/// Return the local date time of Date or DateTime in UTC and DateTimeTz in its time zone
+#[allow(clippy::borrowed_box)]
+fn local_date_time(value: &Box<dyn Value>) -> NaiveDateTime {
+    zoned_date_time(value).naive_local()
+}
+
+/// Create value with the same type of the original value from local date time
+#[allow(clippy::borrowed_box)]
+fn date_time_value_like(original: &Box<dyn Value>, local: NaiveDateTime) -> Box<dyn Value> {
+    if let Some((_, time_zone)) = original.as_date_time_tz() {
+        let date_time = time_zone
+            .from_local_datetime(&local)
+            .earliest()
+            .unwrap_or_else(|| time_zone.from_utc_datetime(&local));
+        return Box::new(DateTimeTzValue::new(date_time.timestamp(), time_zone));
+    }
+
+    if original.is_date() {
+        let date = local.date().and_hms_opt(0, 0, 0).unwrap();
+        return Box::new(DateValue::new(date.and_utc().timestamp()));
+    }
+
+    Box::new(DateTimeValue::new(local.and_utc().timestamp()))
+}
+
 pub fn date_extract_date(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     let argument_type = inputs[0].data_type();
     if argument_type.is_date() {
         return inputs[0].clone();
     }
+
+    if argument_type.is_date_time_tz() {
+        let date = local_date_time(&inputs[0])
+            .date()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        return Box::new(DateValue::new(date.and_utc().timestamp()));
+    }
+
     let timestamp = inputs[0].as_date_time().unwrap();
     Box::new(DateValue::new(timestamp))
 }
@@ -300,8 +459,7 @@ pub fn date_day(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
 }
 
 pub fn date_dayname(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
-    let date = inputs[0].as_date().unwrap();
-    let parsed_date = DateTime::from_timestamp(date, 0).unwrap();
+    let parsed_date = local_date_time(&inputs[0]);
     let day_name = match parsed_date.weekday() {
         Weekday::Mon => "Monday",
         Weekday::Tue => "Tuesday",
@@ -339,16 +497,12 @@ pub fn date_monthname(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
 }
 
 pub fn date_hour(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
-    let date = inputs[0].as_date_time().unwrap();
-    let date_time = DateTime::from_timestamp(date, 0);
-    let dt = date_time.unwrap().time();
+    let dt = local_date_time(&inputs[0]).time();
     Box::new(IntValue::new(dt.hour() as i64))
 }
 
 pub fn date_minute(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
-    let date = inputs[0].as_date_time().unwrap();
-    let date_time = DateTime::from_timestamp(date, 0);
-    let dt = date_time.unwrap().time();
+    let dt = local_date_time(&inputs[0]).time();
     Box::new(IntValue::new(dt.minute() as i64))
 }
 
@@ -377,10 +531,7 @@ pub fn date_day_of_year(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
 }
 
 pub fn date_week_of_year(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
-    let date = inputs[0].as_date().unwrap();
-
-    let parsed_date = DateTime::from_timestamp(date, 0).unwrap();
-    let native_date = parsed_date.date_naive();
+    let native_date = local_date_time(&inputs[0]).date();
     let first_day_of_year = NaiveDate::from_ymd_opt(native_date.year(), 1, 1).unwrap();
     let days_diff = native_date
         .signed_duration_since(first_day_of_year)
@@ -493,3 +644,274 @@ pub fn interval_justify_hours(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     }
     Box::new(IntervalValue::new(input_interval))
 }
+
+/// Truncate the date time to the start of `second`, `minute`, `hour`, `day`, `week`, `month`,
+/// `quarter`, `year`, `decade`, `century` or `millennium`
+pub fn date_trunc(inputs: &[Box<dyn Value>]) -> Result<Box<dyn Value>, String> {
+    let field = inputs[0].as_text().unwrap().to_lowercase();
+    let local = local_date_time(&inputs[1]);
+    let date = local.date();
+
+    let truncated_date = match field.as_str() {
+        "second" => return Ok(date_time_value_like(&inputs[1], local)),
+        "minute" => {
+            let truncated = local.with_second(0).unwrap();
+            return Ok(date_time_value_like(&inputs[1], truncated));
+        }
+        "hour" => {
+            let time = NaiveTime::from_hms_opt(local.hour(), 0, 0).unwrap();
+            return Ok(date_time_value_like(&inputs[1], date.and_time(time)));
+        }
+        "day" => Some(date),
+        "week" => date.checked_sub_days(Days::new(date.weekday().num_days_from_monday() as u64)),
+        "month" => date.with_day(1),
+        "quarter" => NaiveDate::from_ymd_opt(date.year(), (date.month0() / 3) * 3 + 1, 1),
+        "year" => NaiveDate::from_ymd_opt(date.year(), 1, 1),
+        "decade" => NaiveDate::from_ymd_opt(date.year().div_euclid(10) * 10, 1, 1),
+        "century" => NaiveDate::from_ymd_opt((date.year() - 1).div_euclid(100) * 100 + 1, 1, 1),
+        "millennium" => {
+            NaiveDate::from_ymd_opt((date.year() - 1).div_euclid(1000) * 1000 + 1, 1, 1)
+        }
+        _ => return Err(unknown_field_error("date_trunc", &field)),
+    };
+
+    match truncated_date {
+        Some(date) => Ok(date_time_value_like(
+            &inputs[1],
+            date.and_hms_opt(0, 0, 0).unwrap(),
+        )),
+        None => Err("Function `date_trunc` result is out of range".to_string()),
+    }
+}
+
+/// Return the field of date time or interval as integer
+pub fn date_part(inputs: &[Box<dyn Value>]) -> Result<Box<dyn Value>, String> {
+    let field = inputs[0].as_text().unwrap().to_lowercase();
+    if let Some(interval) = inputs[1].as_interval() {
+        let value = match field.as_str() {
+            "year" => interval.years,
+            "month" => interval.months,
+            "day" => interval.days,
+            "hour" => interval.hours,
+            "minute" => interval.minutes,
+            "second" => interval.seconds as i64,
+            "epoch" => interval.to_seconds(),
+            _ => return Err(unknown_field_error("date_part", &field)),
+        };
+        return Ok(Box::new(IntValue::new(value)));
+    }
+
+    let date_time = zoned_date_time(&inputs[1]);
+    let year = date_time.year() as i64;
+    let value = match field.as_str() {
+        "millennium" => (year - 1).div_euclid(1000) + 1,
+        "century" => (year - 1).div_euclid(100) + 1,
+        "decade" => year.div_euclid(10),
+        "year" => year,
+        "isoyear" => date_time.iso_week().year() as i64,
+        "quarter" => (date_time.month0() / 3 + 1) as i64,
+        "month" => date_time.month() as i64,
+        "week" => date_time.iso_week().week() as i64,
+        "day" => date_time.day() as i64,
+        "dow" => date_time.weekday().num_days_from_sunday() as i64,
+        "isodow" => date_time.weekday().number_from_monday() as i64,
+        "doy" => date_time.ordinal() as i64,
+        "hour" => date_time.hour() as i64,
+        "minute" => date_time.minute() as i64,
+        "second" => date_time.second() as i64,
+        "epoch" => date_time.timestamp(),
+        "timezone" => date_time.offset().fix().local_minus_utc() as i64,
+        _ => return Err(unknown_field_error("date_part", &field)),
+    };
+    Ok(Box::new(IntValue::new(value)))
+}
+
+/// Bin the date time into the stride interval aligned with the origin,
+/// the stride must be positive and can't contain months or years
+pub fn date_bin(inputs: &[Box<dyn Value>]) -> Result<Box<dyn Value>, String> {
+    let stride = inputs[0].as_interval().unwrap();
+    if stride.years != 0 || stride.months != 0 {
+        return Err("Function `date_bin` stride can't contain months or years".to_string());
+    }
+
+    let stride_seconds = stride.to_seconds();
+    if stride_seconds <= 0 {
+        return Err("Function `date_bin` stride must be greater than zero".to_string());
+    }
+
+    let timestamp = zoned_date_time(&inputs[1]).timestamp();
+    let origin = zoned_date_time(&inputs[2]).timestamp();
+    let binned = origin + (timestamp - origin).div_euclid(stride_seconds) * stride_seconds;
+
+    if let Some((_, time_zone)) = inputs[1].as_date_time_tz() {
+        return Ok(Box::new(DateTimeTzValue::new(binned, time_zone)));
+    }
+    Ok(Box::new(DateTimeValue::new(binned)))
+}
+
+/// Format the date time using template patterns like `YYYY-MM-DD HH24:MI:SS`,
+/// text between double quotes and unknown characters are copied as it
+pub fn date_to_char(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let date_time = zoned_date_time(&inputs[0]);
+    let pattern = inputs[1].as_text().unwrap();
+
+    let mut formatted = String::new();
+    let mut rest = pattern.as_str();
+    'outer: while !rest.is_empty() {
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let end = quoted.find('"').unwrap_or(quoted.len());
+            formatted.push_str(&quoted[..end]);
+            rest = quoted.get(end + 1..).unwrap_or_default();
+            continue;
+        }
+
+        for (template, format) in TO_CHAR_TEMPLATE_PATTERNS {
+            if let Some(remaining) = rest.strip_prefix(template) {
+                formatted.push_str(&format_template_pattern(&date_time, format));
+                rest = remaining;
+                continue 'outer;
+            }
+        }
+
+        let mut chars = rest.chars();
+        formatted.push(chars.next().unwrap());
+        rest = chars.as_str();
+    }
+
+    Box::new(TextValue::new(formatted))
+}
+
+/// Template patterns of `to_char` mapped to `strftime` format,
+/// longer patterns must come before the patterns that are prefix of them
+const TO_CHAR_TEMPLATE_PATTERNS: [(&str, &str); 30] = [
+    ("HH24", "%H"),
+    ("HH12", "%I"),
+    ("HH", "%I"),
+    ("MI", "%M"),
+    ("SS", "%S"),
+    ("MS", "%3f"),
+    ("AM", "%p"),
+    ("PM", "%p"),
+    ("am", "%P"),
+    ("pm", "%P"),
+    ("YYYY", "%Y"),
+    ("YY", "%y"),
+    ("IYYY", "%G"),
+    ("IW", "%V"),
+    ("MONTH", "upper:%B"),
+    ("Month", "%B"),
+    ("month", "lower:%B"),
+    ("MON", "upper:%b"),
+    ("Mon", "%b"),
+    ("mon", "lower:%b"),
+    ("MM", "%m"),
+    ("DAY", "upper:%A"),
+    ("Day", "%A"),
+    ("day", "lower:%A"),
+    ("DY", "upper:%a"),
+    ("Dy", "%a"),
+    ("dy", "lower:%a"),
+    ("DDD", "%j"),
+    ("DD", "%d"),
+    ("TZ", "%Z"),
+];
+
+fn format_template_pattern(date_time: &DateTime<Tz>, format: &str) -> String {
+    if let Some(format) = format.strip_prefix("upper:") {
+        return date_time.format(format).to_string().to_uppercase();
+    }
+
+    if let Some(format) = format.strip_prefix("lower:") {
+        return date_time.format(format).to_string().to_lowercase();
+    }
+
+    date_time.format(format).to_string()
+}
+
+/// Format the date time using `strftime` specifiers like `%Y-%m-%d`
+pub fn date_strftime(inputs: &[Box<dyn Value>]) -> Result<Box<dyn Value>, String> {
+    let format = inputs[0].as_text().unwrap();
+    let items: Vec<Item> = StrftimeItems::new(&format).collect();
+    let invalid_format_error = || format!("Invalid `strftime` format `{}`", format);
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(invalid_format_error());
+    }
+
+    let date_time = zoned_date_time(&inputs[1]);
+    let mut formatted = String::new();
+    if write!(formatted, "{}", date_time.format_with_items(items.iter())).is_err() {
+        return Err(invalid_format_error());
+    }
+
+    Ok(Box::new(TextValue::new(formatted)))
+}
+
+/// Return the interval between two date times in years, months and days,
+/// or between the current date and the date time if only one is passed
+pub fn date_age(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let (end, start) = if inputs.len() == 1 {
+        let today = Utc::now().date_naive().and_hms_opt(0, 0, 0).unwrap();
+        (today, local_date_time(&inputs[0]))
+    } else {
+        (local_date_time(&inputs[0]), local_date_time(&inputs[1]))
+    };
+
+    let (later, earlier, sign) = if end >= start {
+        (end, start, 1)
+    } else {
+        (start, end, -1)
+    };
+
+    let mut years = (later.year() - earlier.year()) as i64;
+    let mut months = later.month() as i64 - earlier.month() as i64;
+    let mut days = later.day() as i64 - earlier.day() as i64;
+    let mut seconds =
+        later.num_seconds_from_midnight() as i64 - earlier.num_seconds_from_midnight() as i64;
+
+    if seconds < 0 {
+        seconds += 24 * 60 * 60;
+        days -= 1;
+    }
+
+    // Borrow the number of days of the earlier date month like PostgreSQL
+    if days < 0 {
+        days += days_in_month(earlier.year(), earlier.month());
+        months -= 1;
+    }
+
+    if months < 0 {
+        months += 12;
+        years -= 1;
+    }
+
+    Box::new(IntervalValue::new(Interval {
+        years: sign * years,
+        months: sign * months,
+        days: sign * days,
+        hours: sign * (seconds / 3600),
+        minutes: sign * (seconds % 3600 / 60),
+        seconds: (sign * (seconds % 60)) as f64,
+    }))
+}
+
+/// Parse date time in one of the lenient formats accepted by git like `2 weeks ago`,
+/// `yesterday`, `last monday` or `2024-03-01 12:00 +0200`
+pub fn date_parse_datetime(inputs: &[Box<dyn Value>]) -> Result<Box<dyn Value>, String> {
+    let text = inputs[0].as_text().unwrap();
+    match string_literal_to_timestamp(&text) {
+        Some(timestamp) => Ok(Box::new(DateTimeValue::new(timestamp))),
+        None => Err(format!("Invalid date time `{}`", text)),
+    }
+}
+
+fn unknown_field_error(function_name: &str, field: &str) -> String {
+    format!("Function `{}` unknown field `{}`", function_name, field)
+}
+
+fn days_in_month(year: i32, month: u32) -> i64 {
+    let first_day = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+    let next_month_first_day = first_day.checked_add_months(Months::new(1)).unwrap();
+    next_month_first_day
+        .signed_duration_since(first_day)
+        .num_days()
+}
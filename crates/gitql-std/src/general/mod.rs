@@ -4,15 +4,18 @@ use std::collections::HashMap;
 use gitql_ast::types::any::AnyType;
 use gitql_ast::types::boolean::BoolType;
 use gitql_ast::types::dynamic::DynamicType;
+use gitql_ast::types::optional::OptionType;
 use gitql_ast::types::text::TextType;
 use gitql_ast::types::varargs::VarargsType;
 use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
 use gitql_core::values::boolean::BoolValue;
+use gitql_core::values::null::NullValue;
 use gitql_core::values::text::TextValue;
 use gitql_core::values::Value;
 
 use crate::meta_types::first_element_type;
+use crate::meta_types::first_non_null_element_type;
 use crate::meta_types::second_element_type;
 
 use uuid::Uuid;
@@ -27,6 +30,8 @@ pub fn register_std_general_functions(map: &mut HashMap<&'static str, StandardFu
     map.insert("uuid", general_uuid);
     map.insert("if", general_if);
     map.insert("ifnull", general_ifnull);
+    map.insert("coalesce", general_coalesce);
+    map.insert("nullif", general_nullif);
 }
 
 #[inline(always)]
@@ -109,8 +114,38 @@ pub fn register_std_general_function_signatures(map: &mut HashMap<&'static str,
                     function: first_element_type,
                 }),
             ],
-            return_type: Box::new(DynamicType {
+            return_type: Box::new(OptionType::new(Some(Box::new(DynamicType {
                 function: first_element_type,
+            })))),
+        },
+    );
+    map.insert(
+        "coalesce",
+        Signature {
+            parameters: vec![
+                Box::new(AnyType),
+                Box::new(VarargsType {
+                    base: Box::new(DynamicType {
+                        function: first_non_null_element_type,
+                    }),
+                }),
+            ],
+            return_type: Box::new(DynamicType {
+                function: first_non_null_element_type,
+            }),
+        },
+    );
+    map.insert(
+        "nullif",
+        Signature {
+            parameters: vec![
+                Box::new(AnyType),
+                Box::new(DynamicType {
+                    function: first_non_null_element_type,
+                }),
+            ],
+            return_type: Box::new(DynamicType {
+                function: first_non_null_element_type,
             }),
         },
     );
@@ -181,3 +216,19 @@ pub fn general_ifnull(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     }
     inputs[0].clone()
 }
+
+pub fn general_coalesce(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    for input in inputs {
+        if !input.data_type().is_null() {
+            return input.clone();
+        }
+    }
+    Box::new(NullValue)
+}
+
+pub fn general_nullif(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    if inputs[0].equals(&inputs[1]) {
+        return Box::new(NullValue);
+    }
+    inputs[0].clone()
+}
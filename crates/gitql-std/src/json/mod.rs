@@ -0,0 +1,140 @@
+use gitql_ast::types::any::AnyType;
+use gitql_ast::types::array::ArrayType;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::json::JsonType;
+use gitql_ast::types::text::TextType;
+use gitql_ast::types::variant::VariantType;
+use gitql_core::signature::Signature;
+use gitql_core::signature::StandardFunction;
+use gitql_core::values::array::ArrayValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::json::value_to_json;
+use gitql_core::values::json::JsonValue;
+use gitql_core::values::null::NullValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+use std::collections::HashMap;
+
+#[inline(always)]
+pub fn register_std_json_functions(map: &mut HashMap<&'static str, StandardFunction>) {
+    map.insert("json_extract", json_extract);
+    map.insert("json_array_length", json_array_length);
+    map.insert("json_object_keys", json_object_keys);
+    map.insert("to_json", to_json);
+}
+
+#[inline(always)]
+pub fn register_std_json_function_signatures(map: &mut HashMap<&'static str, Signature>) {
+    map.insert(
+        "json_extract",
+        Signature {
+            parameters: vec![json_or_text_type(), Box::new(TextType)],
+            return_type: Box::new(JsonType),
+        },
+    );
+    map.insert(
+        "json_array_length",
+        Signature {
+            parameters: vec![json_or_text_type()],
+            return_type: Box::new(IntType),
+        },
+    );
+    map.insert(
+        "json_object_keys",
+        Signature {
+            parameters: vec![json_or_text_type()],
+            return_type: Box::new(ArrayType::new(Box::new(TextType))),
+        },
+    );
+    map.insert(
+        "to_json",
+        Signature {
+            parameters: vec![Box::new(AnyType)],
+            return_type: Box::new(JsonType),
+        },
+    );
+}
+
+fn json_or_text_type() -> Box<VariantType> {
+    Box::new(VariantType {
+        variants: vec![Box::new(JsonType), Box::new(TextType)],
+    })
+}
+
+/// Return the json document from json value or parse it from text, or None if the text is not valid json
+#[allow(clippy::borrowed_box)]
+fn input_as_json(input: &Box<dyn Value>) -> Option<serde_json::Value> {
+    if let Some(json) = input.as_json() {
+        return Some(json);
+    }
+
+    let text = input.as_text()?;
+    serde_json::from_str(&text).ok()
+}
+
+/// Return the member at path like `$.name`, `$.items[0].id`, or Null if the path not exists
+pub fn json_extract(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let Some(document) = input_as_json(&inputs[0]) else {
+        return Box::new(NullValue);
+    };
+
+    let path = inputs[1].as_text().unwrap();
+    let Some(mut path) = path.trim().strip_prefix('$') else {
+        return Box::new(NullValue);
+    };
+
+    let mut current = &document;
+    while !path.is_empty() {
+        let member = if let Some(rest) = path.strip_prefix('.') {
+            let end = rest.find(['.', '[']).unwrap_or(rest.len());
+            path = &rest[end..];
+            current.get(&rest[..end])
+        } else if let Some(rest) = path.strip_prefix('[') {
+            let Some(end) = rest.find(']') else {
+                return Box::new(NullValue);
+            };
+            path = &rest[end + 1..];
+            let key = rest[..end].trim();
+            match key.parse::<usize>() {
+                Ok(index) => current.get(index),
+                Err(_) => current.get(key.trim_matches(|c| c == '"' || c == '\'')),
+            }
+        } else {
+            None
+        };
+
+        match member {
+            Some(value) => current = value,
+            None => return Box::new(NullValue),
+        }
+    }
+
+    Box::new(JsonValue::new(current.clone()))
+}
+
+/// Return the number of elements in json array, or Null if it's not an array
+pub fn json_array_length(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    if let Some(serde_json::Value::Array(array)) = input_as_json(&inputs[0]) {
+        return Box::new(IntValue {
+            value: array.len() as i64,
+        });
+    }
+    Box::new(NullValue)
+}
+
+/// Return the keys of json object, or empty array if it's not an object
+pub fn json_object_keys(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let mut keys: Vec<Box<dyn Value>> = vec![];
+    if let Some(serde_json::Value::Object(object)) = input_as_json(&inputs[0]) {
+        for key in object.keys() {
+            keys.push(Box::new(TextValue::new(key.to_string())));
+        }
+    }
+    Box::new(ArrayValue::new(keys, Box::new(TextType)))
+}
+
+/// Convert any value to json, composite values are converted to objects and arrays to json arrays
+pub fn to_json(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    Box::new(JsonValue::new(value_to_json(&inputs[0])))
+}
@@ -1,11 +1,14 @@
 pub mod aggregation;
 pub mod array;
+pub mod bytes;
 pub mod datetime;
 pub mod general;
+pub mod json;
 pub mod meta_types;
 pub mod number;
 pub mod range;
 pub mod regex;
+pub mod semver;
 pub mod standard;
 pub mod text;
 pub mod window;
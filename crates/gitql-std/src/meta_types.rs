@@ -1,4 +1,9 @@
+use gitql_ast::types::any::AnyType;
 use gitql_ast::types::array::ArrayType;
+use gitql_ast::types::lambda::LambdaType;
+use gitql_ast::types::multirange::MultiRangeType;
+use gitql_ast::types::null::NullType;
+use gitql_ast::types::range::RangeType;
 use gitql_ast::types::DataType;
 
 /// Returns the type of of first element
@@ -8,11 +13,23 @@ pub fn first_element_type(elements: &[Box<dyn DataType>]) -> Box<dyn DataType> {
     elements[0].clone()
 }
 
+/// Returns the type of first element that is not Null, or Null if all of them are Null
+/// (Null, T2, ...) -> T2
+#[inline(always)]
+pub fn first_non_null_element_type(elements: &[Box<dyn DataType>]) -> Box<dyn DataType> {
+    for element in elements {
+        if !element.is_null() {
+            return element.clone();
+        }
+    }
+    Box::new(NullType)
+}
+
 /// Returns the type of second element
 /// (T1, T2, ...) -> T2
 #[inline(always)]
 pub fn second_element_type(elements: &[Box<dyn DataType>]) -> Box<dyn DataType> {
-    elements[0].clone()
+    elements[1].clone()
 }
 
 /// Returns Array type of the passed element type
@@ -33,3 +50,55 @@ pub fn array_element_type(array: Box<dyn DataType>) -> Box<dyn DataType> {
     }
     panic!("Expect Array type")
 }
+
+/// Returns Lambda type of the passed parameters and return types
+/// (T1, T2, ...), R -> Lambda((T1, T2, ...) -> R)
+#[inline(always)]
+pub fn lambda_of_type(
+    parameters: Vec<Box<dyn DataType>>,
+    return_type: Box<dyn DataType>,
+) -> Box<dyn DataType> {
+    Box::new(LambdaType {
+        parameters,
+        return_type,
+    })
+}
+
+/// Returns return type of passed Lambda type, or Any if it's not resolved yet
+/// Lambda((T1, T2, ...) -> R) -> R
+#[inline(always)]
+pub fn lambda_return_type(lambda: Box<dyn DataType>) -> Box<dyn DataType> {
+    if let Some(lambda) = lambda.as_any().downcast_ref::<LambdaType>() {
+        return lambda.return_type.clone();
+    }
+    Box::new(AnyType)
+}
+
+/// Returns Range type of the passed element type
+/// T -> Range<T>
+#[inline(always)]
+pub fn range_of_type(element_type: Box<dyn DataType>) -> Box<dyn DataType> {
+    Box::new(RangeType::new(element_type))
+}
+
+/// Returns MultiRange type of the passed element type
+/// T -> MultiRange<T>
+#[inline(always)]
+pub fn multirange_of_type(element_type: Box<dyn DataType>) -> Box<dyn DataType> {
+    Box::new(MultiRangeType::new(element_type))
+}
+
+/// Returns element type of passed Range or MultiRange type, or the type itself for other types
+/// Range<T> -> T, MultiRange<T> -> T, T -> T
+#[inline(always)]
+pub fn range_element_type(range: Box<dyn DataType>) -> Box<dyn DataType> {
+    if let Some(range_type) = range.as_any().downcast_ref::<RangeType>() {
+        return range_type.base.clone();
+    }
+
+    if let Some(multirange_type) = range.as_any().downcast_ref::<MultiRangeType>() {
+        return multirange_type.base.clone();
+    }
+
+    range
+}
@@ -6,6 +6,7 @@ use gitql_ast::types::float::FloatType;
 use gitql_ast::types::integer::IntType;
 use gitql_ast::types::optional::OptionType;
 use gitql_ast::types::variant::VariantType;
+use gitql_core::signature::FallibleFunction;
 use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
 use gitql_core::values::float::FloatValue;
@@ -22,7 +23,6 @@ use rand::SeedableRng;
 
 #[inline(always)]
 pub fn register_std_number_functions(map: &mut HashMap<&'static str, StandardFunction>) {
-    map.insert("abs", numeric_abs);
     map.insert("pi", numeric_pi);
     map.insert("floor", numeric_floor);
     map.insert("round", numeric_round);
@@ -39,6 +39,11 @@ pub fn register_std_number_functions(map: &mut HashMap<&'static str, StandardFun
     map.insert("rand", numeric_rand);
 }
 
+#[inline(always)]
+pub fn register_std_number_fallible_functions(map: &mut HashMap<&'static str, FallibleFunction>) {
+    map.insert("abs", numeric_abs);
+}
+
 #[inline(always)]
 pub fn register_std_number_function_signatures(map: &mut HashMap<&'static str, Signature>) {
     map.insert(
@@ -161,13 +166,17 @@ pub fn register_std_number_function_signatures(map: &mut HashMap<&'static str, S
     );
 }
 
-pub fn numeric_abs(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+pub fn numeric_abs(inputs: &[Box<dyn Value>]) -> Result<Box<dyn Value>, String> {
     let input_type = inputs[0].data_type();
     if input_type.is_float() {
         let value = inputs[0].as_float().unwrap().abs();
-        return Box::new(FloatValue::new(value));
+        return Ok(Box::new(FloatValue::new(value)));
+    }
+
+    match inputs[0].as_int().unwrap().checked_abs() {
+        Some(value) => Ok(Box::new(IntValue::new(value))),
+        None => Err("Integer overflow while performing `ABS` function".to_string()),
     }
-    Box::new(IntValue::new(inputs[0].as_int().unwrap().abs()))
 }
 
 pub fn numeric_pi(_inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
@@ -2,22 +2,47 @@ use gitql_ast::types::any::AnyType;
 use gitql_ast::types::boolean::BoolType;
 use gitql_ast::types::date::DateType;
 use gitql_ast::types::datetime::DateTimeType;
+use gitql_ast::types::dynamic::DynamicType;
+use gitql_ast::types::float::FloatType;
 use gitql_ast::types::integer::IntType;
+use gitql_ast::types::interval::IntervalType;
+use gitql_ast::types::multirange::MultiRangeType;
+use gitql_ast::types::optional::OptionType;
 use gitql_ast::types::range::RangeType;
+use gitql_ast::types::text::TextType;
+use gitql_ast::types::variant::VariantType;
+use gitql_ast::types::DataType;
+use gitql_ast::Interval;
 use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
 use gitql_core::values::boolean::BoolValue;
+use gitql_core::values::float::FloatValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::interval::IntervalValue;
+use gitql_core::values::multirange::MultiRangeValue;
+use gitql_core::values::null::NullValue;
 use gitql_core::values::range::RangeValue;
 use gitql_core::values::Value;
 
+use crate::meta_types::first_element_type;
+use crate::meta_types::multirange_of_type;
+use crate::meta_types::range_element_type;
+use crate::meta_types::range_of_type;
+
 use std::collections::HashMap;
 
 #[inline(always)]
 pub fn register_std_range_functions(map: &mut HashMap<&'static str, StandardFunction>) {
     map.insert("int4range", int4range);
+    map.insert("numrange", numrange);
+    map.insert("floatrange", numrange);
     map.insert("daterange", daterange);
     map.insert("tsrange", tsrange);
+    map.insert("multirange", multirange);
     map.insert("isempty", isempty);
+    map.insert("range_len", range_len);
+    map.insert("range_adjacent", range_adjacent);
+    map.insert("range_merge", range_merge);
 }
 
 #[inline(always)]
@@ -31,6 +56,24 @@ pub fn register_std_range_function_signatures(map: &mut HashMap<&'static str, Si
             }),
         },
     );
+    map.insert(
+        "numrange",
+        Signature {
+            parameters: vec![number_type(), number_type()],
+            return_type: Box::new(RangeType {
+                base: Box::new(FloatType),
+            }),
+        },
+    );
+    map.insert(
+        "floatrange",
+        Signature {
+            parameters: vec![number_type(), number_type()],
+            return_type: Box::new(RangeType {
+                base: Box::new(FloatType),
+            }),
+        },
+    );
     map.insert(
         "daterange",
         Signature {
@@ -50,14 +93,117 @@ pub fn register_std_range_function_signatures(map: &mut HashMap<&'static str, Si
         },
     );
     map.insert(
-        "isempty",
+        "multirange",
         Signature {
             parameters: vec![Box::new(RangeType {
                 base: Box::new(AnyType),
             })],
+            return_type: Box::new(DynamicType {
+                function: |elements| {
+                    multirange_of_type(range_element_type(first_element_type(elements)))
+                },
+            }),
+        },
+    );
+    map.insert(
+        "isempty",
+        Signature {
+            parameters: vec![range_or_multirange_type()],
+            return_type: Box::new(BoolType),
+        },
+    );
+    map.insert(
+        "range_len",
+        Signature {
+            parameters: vec![range_or_multirange_type()],
+            return_type: Box::new(DynamicType {
+                function: |elements| {
+                    range_length_type(range_element_type(first_element_type(elements)))
+                },
+            }),
+        },
+    );
+    map.insert(
+        "range_adjacent",
+        Signature {
+            parameters: vec![
+                Box::new(RangeType {
+                    base: Box::new(AnyType),
+                }),
+                Box::new(RangeType {
+                    base: Box::new(AnyType),
+                }),
+            ],
             return_type: Box::new(BoolType),
         },
     );
+    map.insert(
+        "range_merge",
+        Signature {
+            parameters: vec![
+                range_or_multirange_type(),
+                Box::new(OptionType {
+                    base: Some(Box::new(RangeType {
+                        base: Box::new(AnyType),
+                    })),
+                }),
+            ],
+            return_type: Box::new(DynamicType {
+                function: |elements| {
+                    range_of_type(range_element_type(first_element_type(elements)))
+                },
+            }),
+        },
+    );
+}
+
+/// Variant of Text, Range or MultiRange for functions like `lower` and `upper`
+/// that work on both texts and range bounds
+pub(crate) fn text_or_range_type() -> Box<dyn DataType> {
+    Box::new(VariantType {
+        variants: vec![
+            Box::new(TextType),
+            Box::new(RangeType {
+                base: Box::new(AnyType),
+            }),
+            Box::new(MultiRangeType {
+                base: Box::new(AnyType),
+            }),
+        ],
+    })
+}
+
+fn number_type() -> Box<dyn DataType> {
+    Box::new(VariantType {
+        variants: vec![Box::new(IntType), Box::new(FloatType)],
+    })
+}
+
+fn range_or_multirange_type() -> Box<dyn DataType> {
+    Box::new(VariantType {
+        variants: vec![
+            Box::new(RangeType {
+                base: Box::new(AnyType),
+            }),
+            Box::new(MultiRangeType {
+                base: Box::new(AnyType),
+            }),
+        ],
+    })
+}
+
+/// Returns the type of the range length depending on the range element type
+/// Date -> Int (days), DateTime -> Interval, T -> T
+fn range_length_type(element_type: Box<dyn DataType>) -> Box<dyn DataType> {
+    if element_type.is_date() {
+        return Box::new(IntType);
+    }
+
+    if element_type.is_date_time() {
+        return Box::new(IntervalType);
+    }
+
+    element_type
 }
 
 pub fn int4range(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
@@ -68,6 +214,14 @@ pub fn int4range(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     })
 }
 
+pub fn numrange(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    Box::new(RangeValue {
+        start: float_of(&inputs[0]),
+        end: float_of(&inputs[1]),
+        base_type: Box::new(FloatType),
+    })
+}
+
 pub fn daterange(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     Box::new(RangeValue {
         start: inputs[0].clone(),
@@ -84,9 +238,158 @@ pub fn tsrange(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     })
 }
 
+pub fn multirange(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let range = inputs[0].as_any().downcast_ref::<RangeValue>().unwrap();
+    Box::new(MultiRangeValue::new(
+        vec![range.clone()],
+        range.base_type.clone(),
+    ))
+}
+
 pub fn isempty(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
-    let range = inputs[0].as_range().unwrap();
+    let is_empty = match ranges_of(&inputs[0]) {
+        Some(ranges) => ranges.iter().all(|range| range.is_empty()),
+        None => true,
+    };
+    Box::new(BoolValue { value: is_empty })
+}
+
+/// Return the length of range, or the total length of the MultiRange ranges
+pub fn range_len(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let ranges = ranges_of(&inputs[0]).unwrap();
+    let mut total: Option<Box<dyn Value>> = None;
+    for range in ranges.iter().filter(|range| !range.is_empty()) {
+        let length = match range_length(range) {
+            Some(length) => length,
+            None => return Box::new(NullValue),
+        };
+
+        total = match total {
+            Some(total) => match total.add_op(&length) {
+                Ok(sum) => Some(sum),
+                Err(_) => return Box::new(NullValue),
+            },
+            None => Some(length),
+        };
+    }
+
+    match total {
+        Some(total) => total,
+        None => empty_range_length(ranges.first().map(|range| &range.base_type)),
+    }
+}
+
+pub fn range_adjacent(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let first = inputs[0].as_any().downcast_ref::<RangeValue>().unwrap();
+    let second = inputs[1].as_any().downcast_ref::<RangeValue>().unwrap();
     Box::new(BoolValue {
-        value: range.0.equals(&range.1),
+        value: first.is_adjacent(second),
     })
 }
+
+/// Return the smallest range that contains the two ranges or all the MultiRange ranges
+pub fn range_merge(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let mut ranges = ranges_of(&inputs[0]).unwrap();
+    if inputs.len() == 2 {
+        ranges.extend(ranges_of(&inputs[1]).unwrap());
+    }
+
+    let mut merged = match ranges.first() {
+        Some(range) => range.clone(),
+        None => return Box::new(NullValue),
+    };
+
+    for range in ranges.iter().skip(1) {
+        merged = merged.merge(range);
+    }
+
+    Box::new(merged)
+}
+
+/// Return the lower bound of Range or MultiRange, or Null if it's empty
+pub fn range_lower(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let ranges = ranges_of(&inputs[0]).unwrap();
+    match ranges.iter().find(|range| !range.is_empty()) {
+        Some(range) => range.start.clone(),
+        None => Box::new(NullValue),
+    }
+}
+
+/// Return the upper bound of Range or MultiRange, or Null if it's empty
+pub fn range_upper(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let ranges = ranges_of(&inputs[0]).unwrap();
+    match ranges.iter().rev().find(|range| !range.is_empty()) {
+        Some(range) => range.end.clone(),
+        None => Box::new(NullValue),
+    }
+}
+
+/// Return the number as Float value, so ranges of Int and Float bounds are comparable
+#[allow(clippy::borrowed_box)]
+fn float_of(value: &Box<dyn Value>) -> Box<dyn Value> {
+    match value.as_int() {
+        Some(int) => Box::new(FloatValue::new(int as f64)),
+        None => value.clone(),
+    }
+}
+
+/// Return the ranges of Range or MultiRange value
+#[allow(clippy::borrowed_box)]
+fn ranges_of(value: &Box<dyn Value>) -> Option<Vec<RangeValue>> {
+    if let Some(range) = value.as_any().downcast_ref::<RangeValue>() {
+        return Some(vec![range.clone()]);
+    }
+
+    if let Some(multirange) = value.as_any().downcast_ref::<MultiRangeValue>() {
+        return Some(multirange.ranges.clone());
+    }
+
+    None
+}
+
+fn range_length(range: &RangeValue) -> Option<Box<dyn Value>> {
+    if let (Some(start), Some(end)) = (range.start.as_date(), range.end.as_date()) {
+        return Some(Box::new(IntValue::new((end - start) / SECONDS_PER_DAY)));
+    }
+
+    if let (Some(start), Some(end)) = (range.start.as_date_time(), range.end.as_date_time()) {
+        return Some(Box::new(IntervalValue::new(interval_of_seconds(
+            end - start,
+        ))));
+    }
+
+    range.end.sub_op(&range.start).ok()
+}
+
+#[allow(clippy::borrowed_box)]
+fn empty_range_length(base_type: Option<&Box<dyn DataType>>) -> Box<dyn Value> {
+    let Some(base_type) = base_type else {
+        return Box::new(NullValue);
+    };
+
+    if base_type.is_int() || base_type.is_date() {
+        return Box::new(IntValue::new(0));
+    }
+
+    if base_type.is_float() {
+        return Box::new(FloatValue::new(0.0));
+    }
+
+    if base_type.is_date_time() {
+        return Box::new(IntervalValue::new(Interval::default()));
+    }
+
+    Box::new(NullValue)
+}
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+fn interval_of_seconds(seconds: i64) -> Interval {
+    Interval {
+        days: seconds / SECONDS_PER_DAY,
+        hours: (seconds % SECONDS_PER_DAY) / 3600,
+        minutes: (seconds % 3600) / 60,
+        seconds: (seconds % 60) as f64,
+        ..Interval::default()
+    }
+}
@@ -0,0 +1,117 @@
+use gitql_ast::types::boolean::BoolType;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::semver::SemVerType;
+use gitql_ast::types::text::TextType;
+use gitql_ast::types::variant::VariantType;
+use gitql_ast::SemVer;
+use gitql_core::signature::Signature;
+use gitql_core::signature::StandardFunction;
+use gitql_core::values::boolean::BoolValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::null::NullValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
+
+use std::collections::HashMap;
+
+#[inline(always)]
+pub fn register_std_semver_functions(map: &mut HashMap<&'static str, StandardFunction>) {
+    map.insert("semver_major", semver_major);
+    map.insert("semver_minor", semver_minor);
+    map.insert("semver_patch", semver_patch);
+    map.insert("semver_bump_kind", semver_bump_kind);
+    map.insert("is_prerelease", semver_is_prerelease);
+}
+
+#[inline(always)]
+pub fn register_std_semver_function_signatures(map: &mut HashMap<&'static str, Signature>) {
+    map.insert(
+        "semver_major",
+        Signature {
+            parameters: vec![semver_or_text_type()],
+            return_type: Box::new(IntType),
+        },
+    );
+    map.insert(
+        "semver_minor",
+        Signature {
+            parameters: vec![semver_or_text_type()],
+            return_type: Box::new(IntType),
+        },
+    );
+    map.insert(
+        "semver_patch",
+        Signature {
+            parameters: vec![semver_or_text_type()],
+            return_type: Box::new(IntType),
+        },
+    );
+    map.insert(
+        "semver_bump_kind",
+        Signature {
+            parameters: vec![semver_or_text_type(), semver_or_text_type()],
+            return_type: Box::new(TextType),
+        },
+    );
+    map.insert(
+        "is_prerelease",
+        Signature {
+            parameters: vec![semver_or_text_type()],
+            return_type: Box::new(BoolType),
+        },
+    );
+}
+
+fn semver_or_text_type() -> Box<VariantType> {
+    Box::new(VariantType {
+        variants: vec![Box::new(SemVerType), Box::new(TextType)],
+    })
+}
+
+/// Return the version from semver value or parse it from text, or None if the text is not valid version
+#[allow(clippy::borrowed_box)]
+fn input_as_semver(input: &Box<dyn Value>) -> Option<SemVer> {
+    if let Some(version) = input.as_semver() {
+        return Some(version);
+    }
+
+    let text = input.as_text()?;
+    SemVer::parse(&text)
+}
+
+pub fn semver_major(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    match input_as_semver(&inputs[0]) {
+        Some(version) => Box::new(IntValue::new(version.major as i64)),
+        None => Box::new(NullValue),
+    }
+}
+
+pub fn semver_minor(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    match input_as_semver(&inputs[0]) {
+        Some(version) => Box::new(IntValue::new(version.minor as i64)),
+        None => Box::new(NullValue),
+    }
+}
+
+pub fn semver_patch(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    match input_as_semver(&inputs[0]) {
+        Some(version) => Box::new(IntValue::new(version.patch as i64)),
+        None => Box::new(NullValue),
+    }
+}
+
+/// Return the kind of change between two versions `major`, `minor`, `patch`, `prerelease` or `none`
+/// If any of them is not a valid version, it returns Null
+pub fn semver_bump_kind(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    match (input_as_semver(&inputs[0]), input_as_semver(&inputs[1])) {
+        (Some(from), Some(to)) => Box::new(TextValue::new(from.bump_kind(&to).to_string())),
+        _ => Box::new(NullValue),
+    }
+}
+
+pub fn semver_is_prerelease(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    match input_as_semver(&inputs[0]) {
+        Some(version) => Box::new(BoolValue::new(version.is_prerelease())),
+        None => Box::new(NullValue),
+    }
+}
@@ -1,11 +1,16 @@
 use crate::array::*;
+use crate::bytes::*;
 use crate::datetime::*;
 use crate::general::*;
+use crate::json::*;
 use crate::number::*;
 use crate::range::*;
 use crate::regex::*;
+use crate::semver::*;
 use crate::text::*;
 
+use gitql_core::signature::FallibleFunction;
+use gitql_core::signature::HigherOrderFunction;
 use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
 use std::collections::HashMap;
@@ -22,6 +27,29 @@ pub fn standard_functions() -> &'static HashMap<&'static str, StandardFunction>
         register_std_regex_functions(&mut map);
         register_std_array_functions(&mut map);
         register_std_range_functions(&mut map);
+        register_std_json_functions(&mut map);
+        register_std_semver_functions(&mut map);
+        register_std_bytes_functions(&mut map);
+        map
+    })
+}
+
+pub fn fallible_functions() -> &'static HashMap<&'static str, FallibleFunction> {
+    static HASHMAP: OnceLock<HashMap<&'static str, FallibleFunction>> = OnceLock::new();
+    HASHMAP.get_or_init(|| {
+        let mut map: HashMap<&'static str, FallibleFunction> = HashMap::new();
+        register_std_number_fallible_functions(&mut map);
+        register_std_datetime_fallible_functions(&mut map);
+        register_std_bytes_fallible_functions(&mut map);
+        map
+    })
+}
+
+pub fn higher_order_functions() -> &'static HashMap<&'static str, HigherOrderFunction> {
+    static HASHMAP: OnceLock<HashMap<&'static str, HigherOrderFunction>> = OnceLock::new();
+    HASHMAP.get_or_init(|| {
+        let mut map: HashMap<&'static str, HigherOrderFunction> = HashMap::new();
+        register_std_array_higher_order_functions(&mut map);
         map
     })
 }
@@ -35,5 +63,8 @@ pub fn standard_function_signatures() -> HashMap<&'static str, Signature> {
     register_std_regex_function_signatures(&mut map);
     register_std_array_function_signatures(&mut map);
     register_std_range_function_signatures(&mut map);
+    register_std_json_function_signatures(&mut map);
+    register_std_semver_function_signatures(&mut map);
+    register_std_bytes_function_signatures(&mut map);
     map
 }
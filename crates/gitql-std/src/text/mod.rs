@@ -1,4 +1,5 @@
 use gitql_ast::types::any::AnyType;
+use gitql_ast::types::dynamic::DynamicType;
 use gitql_ast::types::float::FloatType;
 use gitql_ast::types::integer::IntType;
 use gitql_ast::types::optional::OptionType;
@@ -13,6 +14,14 @@ use gitql_core::values::Value;
 
 use std::collections::HashMap;
 
+use crate::bytes::bytes_or_text_type;
+use crate::bytes::bytes_substring;
+use crate::meta_types::first_element_type;
+use crate::meta_types::range_element_type;
+use crate::range::range_lower;
+use crate::range::range_upper;
+use crate::range::text_or_range_type;
+
 #[inline(always)]
 pub fn register_std_text_functions(map: &mut HashMap<&'static str, StandardFunction>) {
     map.insert("bin", text_bin);
@@ -58,15 +67,19 @@ pub fn register_std_text_function_signatures(map: &mut HashMap<&'static str, Sig
     map.insert(
         "lower",
         Signature {
-            parameters: vec![Box::new(TextType)],
-            return_type: Box::new(TextType),
+            parameters: vec![text_or_range_type()],
+            return_type: Box::new(DynamicType {
+                function: |elements| range_element_type(first_element_type(elements)),
+            }),
         },
     );
     map.insert(
         "upper",
         Signature {
-            parameters: vec![Box::new(TextType)],
-            return_type: Box::new(TextType),
+            parameters: vec![text_or_range_type()],
+            return_type: Box::new(DynamicType {
+                function: |elements| range_element_type(first_element_type(elements)),
+            }),
         },
     );
     map.insert(
@@ -170,8 +183,10 @@ pub fn register_std_text_function_signatures(map: &mut HashMap<&'static str, Sig
     map.insert(
         "substring",
         Signature {
-            parameters: vec![Box::new(TextType), Box::new(IntType), Box::new(IntType)],
-            return_type: Box::new(TextType),
+            parameters: vec![bytes_or_text_type(), Box::new(IntType), Box::new(IntType)],
+            return_type: Box::new(DynamicType {
+                function: first_element_type,
+            }),
         },
     );
     map.insert(
@@ -294,12 +309,20 @@ pub fn text_bin(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
 }
 
 pub fn text_lowercase(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    if inputs[0].is_range() || inputs[0].is_multirange() {
+        return range_lower(inputs);
+    }
+
     Box::new(TextValue {
         value: inputs[0].as_text().unwrap().to_lowercase(),
     })
 }
 
 pub fn text_uppercase(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    if inputs[0].is_range() || inputs[0].is_multirange() {
+        return range_upper(inputs);
+    }
+
     Box::new(TextValue {
         value: inputs[0].as_text().unwrap().to_uppercase(),
     })
@@ -435,6 +458,10 @@ pub fn text_replace(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
 }
 
 pub fn text_substring(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    if inputs[0].is_bytes() {
+        return bytes_substring(inputs);
+    }
+
     let text = inputs[0].as_text().unwrap();
     // According to the specs, a string starts at position 1.
     // but in Rust, the index of a string starts from 0
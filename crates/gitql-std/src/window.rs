@@ -3,9 +3,13 @@ use std::sync::OnceLock;
 
 use gitql_ast::types::any::AnyType;
 use gitql_ast::types::dynamic::DynamicType;
+use gitql_ast::types::float::FloatType;
 use gitql_ast::types::integer::IntType;
+use gitql_ast::types::optional::OptionType;
+use gitql_core::signature::FallibleWindowFunction;
 use gitql_core::signature::Signature;
 use gitql_core::signature::WindowFunction;
+use gitql_core::values::float::FloatValue;
 use gitql_core::values::integer::IntValue;
 use gitql_core::values::null::NullValue;
 use gitql_core::values::Value;
@@ -20,6 +24,21 @@ pub fn window_functions() -> &'static HashMap<&'static str, WindowFunction> {
         map.insert("nth_value", window_nth_value);
         map.insert("last_value", window_last_value);
         map.insert("row_number", window_row_number);
+        map.insert("rank", window_rank);
+        map.insert("dense_rank", window_dense_rank);
+        map.insert("percent_rank", window_percent_rank);
+        map.insert("cume_dist", window_cume_dist);
+        map.insert("lag", window_lag);
+        map.insert("lead", window_lead);
+        map
+    })
+}
+
+pub fn fallible_window_functions() -> &'static HashMap<&'static str, FallibleWindowFunction> {
+    static HASHMAP: OnceLock<HashMap<&'static str, FallibleWindowFunction>> = OnceLock::new();
+    HASHMAP.get_or_init(|| {
+        let mut map: HashMap<&'static str, FallibleWindowFunction> = HashMap::new();
+        map.insert("ntile", window_ntile);
         map
     })
 }
@@ -63,6 +82,86 @@ pub fn window_function_signatures() -> HashMap<&'static str, Signature> {
             return_type: Box::new(IntType),
         },
     );
+
+    map.insert(
+        "rank",
+        Signature {
+            parameters: vec![],
+            return_type: Box::new(IntType),
+        },
+    );
+
+    map.insert(
+        "dense_rank",
+        Signature {
+            parameters: vec![],
+            return_type: Box::new(IntType),
+        },
+    );
+
+    map.insert(
+        "percent_rank",
+        Signature {
+            parameters: vec![],
+            return_type: Box::new(FloatType),
+        },
+    );
+
+    map.insert(
+        "cume_dist",
+        Signature {
+            parameters: vec![],
+            return_type: Box::new(FloatType),
+        },
+    );
+
+    map.insert(
+        "ntile",
+        Signature {
+            parameters: vec![Box::new(IntType)],
+            return_type: Box::new(IntType),
+        },
+    );
+
+    map.insert(
+        "lag",
+        Signature {
+            parameters: vec![
+                Box::new(AnyType),
+                Box::new(OptionType {
+                    base: Some(Box::new(IntType)),
+                }),
+                Box::new(OptionType {
+                    base: Some(Box::new(DynamicType {
+                        function: first_element_type,
+                    })),
+                }),
+            ],
+            return_type: Box::new(DynamicType {
+                function: first_element_type,
+            }),
+        },
+    );
+
+    map.insert(
+        "lead",
+        Signature {
+            parameters: vec![
+                Box::new(AnyType),
+                Box::new(OptionType {
+                    base: Some(Box::new(IntType)),
+                }),
+                Box::new(OptionType {
+                    base: Some(Box::new(DynamicType {
+                        function: first_element_type,
+                    })),
+                }),
+            ],
+            return_type: Box::new(DynamicType {
+                function: first_element_type,
+            }),
+        },
+    );
     map
 }
 
@@ -111,3 +210,148 @@ pub fn window_row_number(frame: &[Vec<Box<dyn Value>>]) -> Vec<Box<dyn Value>> {
     }
     values
 }
+
+pub fn window_rank(frame: &[Vec<Box<dyn Value>>]) -> Vec<Box<dyn Value>> {
+    let frame_len = frame.len();
+    let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(frame_len);
+    let mut rank = 1;
+    for i in 0..frame_len {
+        if i > 0 && !is_peer_rows(&frame[i - 1], &frame[i]) {
+            rank = i as i64 + 1;
+        }
+        values.push(Box::new(IntValue { value: rank }));
+    }
+    values
+}
+
+pub fn window_dense_rank(frame: &[Vec<Box<dyn Value>>]) -> Vec<Box<dyn Value>> {
+    let frame_len = frame.len();
+    let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(frame_len);
+    let mut rank = 1;
+    for i in 0..frame_len {
+        if i > 0 && !is_peer_rows(&frame[i - 1], &frame[i]) {
+            rank += 1;
+        }
+        values.push(Box::new(IntValue { value: rank }));
+    }
+    values
+}
+
+pub fn window_percent_rank(frame: &[Vec<Box<dyn Value>>]) -> Vec<Box<dyn Value>> {
+    let frame_len = frame.len();
+    let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(frame_len);
+    let mut rank = 1;
+    for i in 0..frame_len {
+        if i > 0 && !is_peer_rows(&frame[i - 1], &frame[i]) {
+            rank = i + 1;
+        }
+
+        let percent = if frame_len > 1 {
+            (rank - 1) as f64 / (frame_len - 1) as f64
+        } else {
+            0.0
+        };
+        values.push(Box::new(FloatValue::new(percent)));
+    }
+    values
+}
+
+pub fn window_cume_dist(frame: &[Vec<Box<dyn Value>>]) -> Vec<Box<dyn Value>> {
+    let frame_len = frame.len();
+    let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(frame_len);
+    let mut peers_start = 0;
+    while peers_start < frame_len {
+        // Number of rows preceding or peer with the current row
+        let mut peers_end = peers_start + 1;
+        while peers_end < frame_len && is_peer_rows(&frame[peers_start], &frame[peers_end]) {
+            peers_end += 1;
+        }
+
+        let distribution = peers_end as f64 / frame_len as f64;
+        for _ in peers_start..peers_end {
+            values.push(Box::new(FloatValue::new(distribution)));
+        }
+        peers_start = peers_end;
+    }
+    values
+}
+
+/// Divide the rows into buckets, Null buckets count returns Null for every row
+pub fn window_ntile(frame: &[Vec<Box<dyn Value>>]) -> Result<Vec<Box<dyn Value>>, String> {
+    let frame_len = frame.len();
+    let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(frame_len);
+    let Some(buckets_count) = frame[0][0].as_int() else {
+        for _ in 0..frame_len {
+            values.push(Box::new(NullValue));
+        }
+        return Ok(values);
+    };
+
+    if buckets_count <= 0 {
+        return Err(format!(
+            "Argument of `ntile` must be greater than zero but got `{}`",
+            buckets_count
+        ));
+    }
+
+    // The first (frame_len % buckets) buckets have one extra row
+    let buckets_count = buckets_count as usize;
+    let bucket_size = frame_len / buckets_count;
+    let larger_buckets_count = frame_len % buckets_count;
+    let larger_buckets_rows = larger_buckets_count * (bucket_size + 1);
+    for i in 0..frame_len {
+        let bucket = if i < larger_buckets_rows {
+            i / (bucket_size + 1)
+        } else {
+            larger_buckets_count + (i - larger_buckets_rows) / bucket_size
+        };
+        values.push(Box::new(IntValue {
+            value: bucket as i64 + 1,
+        }));
+    }
+    Ok(values)
+}
+
+pub fn window_lag(frame: &[Vec<Box<dyn Value>>]) -> Vec<Box<dyn Value>> {
+    window_offset_value(frame, false)
+}
+
+pub fn window_lead(frame: &[Vec<Box<dyn Value>>]) -> Vec<Box<dyn Value>> {
+    window_offset_value(frame, true)
+}
+
+/// Return the value of the row that is `offset` rows before or after the current row,
+/// or the default value if there is no such row in the frame
+fn window_offset_value(frame: &[Vec<Box<dyn Value>>], is_forward: bool) -> Vec<Box<dyn Value>> {
+    let frame_len = frame.len();
+    let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(frame_len);
+    for (i, row) in frame.iter().enumerate() {
+        let offset = if row[1].is_null() {
+            1
+        } else {
+            row[1].as_int().unwrap()
+        };
+
+        let target = if is_forward {
+            i as i64 + offset
+        } else {
+            i as i64 - offset
+        };
+
+        if target >= 0 && (target as usize) < frame_len {
+            values.push(frame[target as usize][0].clone());
+        } else {
+            values.push(row[2].clone());
+        }
+    }
+    values
+}
+
+/// Check if two rows are peers, which means they have equal values of `ORDER BY` arguments,
+/// without ordering all rows in the frame are peers
+fn is_peer_rows(first: &[Box<dyn Value>], second: &[Box<dyn Value>]) -> bool {
+    first
+        .iter()
+        .zip(second.iter())
+        .all(|(first, second)| first.equals(second))
+}
@@ -3,12 +3,14 @@ use std::collections::HashSet;
 
 use gitql_ast::types::array::ArrayType;
 use gitql_ast::types::boolean::BoolType;
+use gitql_ast::types::bytes::BytesType;
 use gitql_ast::types::integer::IntType;
 use gitql_ast::types::text::TextType;
 use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
 use gitql_core::values::array::ArrayValue;
 use gitql_core::values::boolean::BoolValue;
+use gitql_core::values::bytes::BytesValue;
 use gitql_core::values::integer::IntValue;
 use gitql_core::values::text::TextValue;
 use gitql_core::values::Value;
@@ -20,6 +22,7 @@ use crate::gitql::values::diff_changes::DiffChangesValue;
 #[inline(always)]
 pub(crate) fn register_diffs_functions(map: &mut HashMap<&'static str, StandardFunction>) {
     map.insert("diff_content", diff_changes_full_content);
+    map.insert("diff_content_bytes", diff_changes_full_content_bytes);
     map.insert("diff_added_content", diff_changes_added_content);
     map.insert("diff_deleted_content", diff_changes_deleted_content);
     map.insert("diff_modified_content", diff_changes_modified_content);
@@ -51,6 +54,11 @@ pub(crate) fn register_diffs_function_signatures(map: &mut HashMap<&'static str,
         Signature::with_return(Box::new(TextType)).add_parameter(Box::new(DiffChangesType)),
     );
 
+    map.insert(
+        "diff_content_bytes",
+        Signature::with_return(Box::new(BytesType)).add_parameter(Box::new(DiffChangesType)),
+    );
+
     map.insert(
         "diff_added_content",
         Signature::with_return(Box::new(TextType)).add_parameter(Box::new(DiffChangesType)),
@@ -111,6 +119,7 @@ pub(crate) fn register_diffs_function_signatures(map: &mut HashMap<&'static str,
     );
 }
 
+/// Return the content of all changes as text, the invalid UTF-8 sequences are replaced with `�`
 fn diff_changes_full_content(values: &[Box<dyn Value>]) -> Box<dyn Value> {
     if let Some(changes) = values[0].as_any().downcast_ref::<DiffChangesValue>() {
         let mut content = String::new();
@@ -123,6 +132,19 @@ fn diff_changes_full_content(values: &[Box<dyn Value>]) -> Box<dyn Value> {
     Box::new(TextValue::empty())
 }
 
+/// Return the raw content of all changes, unlike `diff_content` the invalid UTF-8 sequences are kept
+fn diff_changes_full_content_bytes(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    if let Some(changes) = values[0].as_any().downcast_ref::<DiffChangesValue>() {
+        let mut content: Vec<u8> = vec![];
+        for change in changes.changes.iter() {
+            content.extend_from_slice(&change.content);
+        }
+
+        return Box::new(BytesValue::new(content));
+    }
+    Box::new(BytesValue::new(vec![]))
+}
+
 fn diff_changes_added_content(values: &[Box<dyn Value>]) -> Box<dyn Value> {
     if let Some(changes) = values[0].as_any().downcast_ref::<DiffChangesValue>() {
         let mut content = String::new();
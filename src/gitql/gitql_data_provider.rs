@@ -1,13 +1,16 @@
 use std::convert::Infallible;
 
+use gitql_ast::SemVer;
 use gitql_core::object::Row;
 use gitql_core::values::boolean::BoolValue;
 use gitql_core::values::datetime::DateTimeValue;
 use gitql_core::values::integer::IntValue;
 use gitql_core::values::null::NullValue;
+use gitql_core::values::semver::SemVerValue;
 use gitql_core::values::text::TextValue;
 use gitql_core::values::Value;
 use gitql_engine::data_provider::DataProvider;
+use gitql_engine::data_provider::RowsBatchIterator;
 
 use gix::diff::blob::pipeline::Mode;
 use gix::refs::Category;
@@ -17,12 +20,20 @@ use super::values::diff_changes::DiffChangesValue;
 
 pub struct GitQLDataProvider {
     repos: Vec<gix::Repository>,
+    jobs: usize,
 }
 
 impl GitQLDataProvider {
     #[must_use]
     pub fn new(repos: Vec<gix::Repository>) -> Self {
-        Self { repos }
+        Self { repos, jobs: 1 }
+    }
+
+    /// Set the maximum number of threads used to scan the repositories and compute the diffs
+    #[must_use]
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.jobs = jobs.max(1);
+        self
     }
 }
 
@@ -30,29 +41,343 @@ impl DataProvider for GitQLDataProvider {
     fn provide(&self, table: &str, selected_columns: &[String]) -> Result<Vec<Row>, String> {
         let mut rows: Vec<Row> = vec![];
 
-        for repository in &self.repos {
-            let mut repo_rows =
-                select_gql_objects(repository, table.to_string(), selected_columns)?;
-            rows.append(&mut repo_rows);
+        for batch in self.provide_batches(table, selected_columns)? {
+            rows.append(&mut batch?);
         }
 
         Ok(rows)
     }
+
+    fn provide_batches<'a>(
+        &'a self,
+        table: &str,
+        selected_columns: &[String],
+    ) -> Result<RowsBatchIterator<'a>, String> {
+        let jobs = self.jobs;
+        let table = table.to_string();
+        let selected_columns = selected_columns.to_vec();
+
+        // Walk the commits of all repositories in parallel, rows still provided in repositories order
+        if matches!(table.as_str(), "commits" | "diffs" | "diffs_changes") {
+            let batches = walk_repositories_commits(&self.repos, jobs)
+                .into_iter()
+                .flat_map(move |walk_result| -> RowsBatchIterator<'a> {
+                    match walk_result {
+                        Ok((repo, commits)) => Box::new(CommitsRowsBatches::new(
+                            repo,
+                            commits,
+                            table.to_string(),
+                            selected_columns.to_vec(),
+                            jobs,
+                        )),
+                        Err(error) => Box::new(std::iter::once(Err(error))),
+                    }
+                });
+            return Ok(Box::new(batches));
+        }
+
+        let batches = self.repos.iter().flat_map(move |repository| {
+            select_gql_objects_batches(
+                repository,
+                table.to_string(),
+                selected_columns.to_vec(),
+                jobs,
+            )
+        });
+        Ok(Box::new(batches))
+    }
+}
+
+/// Number of commits to select their rows in each batch per job
+const COMMITS_BATCH_SIZE: usize = 64;
+
+/// The information needed to select the rows of one commit
+struct CommitInfo {
+    id: gix::ObjectId,
+    parent_id: Option<gix::ObjectId>,
+    commit_time: Option<i64>,
+}
+
+/// The changes between a commit and its first parent
+#[derive(Default)]
+struct CommitChanges {
+    files_changed: usize,
+    changes: Vec<DiffChange>,
+}
+
+type RepositoryCommits = (gix::Repository, Vec<CommitInfo>);
+
+/// Collect the commits of each repository, up to `jobs` repositories are walked at the same time
+fn walk_repositories_commits(
+    repos: &[gix::Repository],
+    jobs: usize,
+) -> Vec<Result<RepositoryCommits, String>> {
+    let repos: Vec<gix::Repository> = repos
+        .iter()
+        .map(|repo| {
+            let mut repo = repo.clone();
+            repo.object_cache_size_if_unset(4 * 1024 * 1024);
+            repo
+        })
+        .collect();
+
+    if jobs == 1 || repos.len() == 1 {
+        return repos.into_iter().map(walk_repository_commits).collect();
+    }
+
+    let mut results = Vec::with_capacity(repos.len());
+    let mut repos = repos.into_iter().peekable();
+    while repos.peek().is_some() {
+        let chunk: Vec<gix::Repository> = repos.by_ref().take(jobs).collect();
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = chunk
+                .into_iter()
+                .map(|repo| scope.spawn(move || walk_repository_commits(repo)))
+                .collect();
+
+            for handle in handles {
+                results.push(handle.join().unwrap());
+            }
+        });
+    }
+    results
+}
+
+fn walk_repository_commits(repo: gix::Repository) -> Result<RepositoryCommits, String> {
+    let head_id = repo.head_id();
+    if let Err(error) = head_id {
+        return Err(error.to_string());
+    }
+
+    // Only the commits ids are collected, the rows are selected later on demand
+    let walker = head_id
+        .unwrap()
+        .ancestors()
+        .all()
+        .map_err(|error| error.to_string())?;
+
+    let mut commits: Vec<CommitInfo> = vec![];
+    for commit_info in walker {
+        let commit_info = commit_info.map_err(|error| error.to_string())?;
+        commits.push(CommitInfo {
+            id: commit_info.id,
+            parent_id: commit_info.parent_ids().next().map(|id| id.detach()),
+            commit_time: commit_info.commit_time,
+        });
+    }
+
+    Ok((repo, commits))
+}
+
+/// Compute the changes of commits, each worker own a repository handle and diff caches
+/// so they can be used from different threads
+struct DiffWorker {
+    repo: gix::Repository,
+    rewrite_cache: gix::diff::blob::Platform,
+    diff_cache: gix::diff::blob::Platform,
+}
+
+impl DiffWorker {
+    fn new(repo: &gix::Repository) -> Self {
+        let rewrite_cache = repo
+            .diff_resource_cache(Mode::ToGit, Default::default())
+            .unwrap();
+
+        let diff_cache = rewrite_cache.clone();
+
+        DiffWorker {
+            repo: repo.clone(),
+            rewrite_cache,
+            diff_cache,
+        }
+    }
+
+    fn commit_changes(&mut self, commit_info: &CommitInfo, with_content: bool) -> CommitChanges {
+        let mut commit_changes = CommitChanges::default();
+
+        let repo = &self.repo;
+        if let Some(parent) = commit_info
+            .parent_id
+            .map(|id| repo.find_object(id).unwrap().into_commit().tree().unwrap())
+        {
+            let commit = repo.find_object(commit_info.id).unwrap().into_commit();
+            let current = commit.tree().unwrap();
+            self.rewrite_cache.clear_resource_cache_keep_allocation();
+            self.diff_cache.clear_resource_cache_keep_allocation();
+
+            let diff_cache = &mut self.diff_cache;
+            if let Ok(mut changes) = current.changes() {
+                let _ = changes.for_each_to_obtain_tree_with_cache(
+                    &parent,
+                    &mut self.rewrite_cache,
+                    |change| {
+                        commit_changes.files_changed +=
+                            usize::from(change.entry_mode().is_no_tree());
+                        let diff_change = if with_content {
+                            DiffChange::new_with_content(&change, diff_cache, repo)
+                        } else {
+                            DiffChange::new_without_content(&change, diff_cache)
+                        };
+                        commit_changes.changes.push(diff_change);
+                        Ok::<_, Infallible>(Default::default())
+                    },
+                );
+            }
+        }
+
+        commit_changes
+    }
+}
+
+/// Compute the changes of the commits using the workers in parallel, keeping the commits order
+fn compute_commits_changes(
+    workers: &mut [DiffWorker],
+    commits: &[CommitInfo],
+    with_content: bool,
+) -> Vec<CommitChanges> {
+    if workers.len() == 1 || commits.len() <= COMMITS_BATCH_SIZE {
+        let worker = &mut workers[0];
+        return commits
+            .iter()
+            .map(|commit_info| worker.commit_changes(commit_info, with_content))
+            .collect();
+    }
+
+    let chunk_size = commits.len().div_ceil(workers.len());
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = commits
+            .chunks(chunk_size)
+            .zip(workers.iter_mut())
+            .map(|(chunk, worker)| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|commit_info| worker.commit_changes(commit_info, with_content))
+                        .collect::<Vec<CommitChanges>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
+
+/// Select the rows of tables that have one or more rows per commit, a batch of commits at a time
+struct CommitsRowsBatches {
+    repo: gix::Repository,
+    table: String,
+    selected_columns: Vec<String>,
+    commits: std::vec::IntoIter<CommitInfo>,
+    should_calculate_diffs: bool,
+    workers: Vec<DiffWorker>,
 }
 
-fn select_gql_objects(
+impl CommitsRowsBatches {
+    fn new(
+        repo: gix::Repository,
+        commits: Vec<CommitInfo>,
+        table: String,
+        selected_columns: Vec<String>,
+        jobs: usize,
+    ) -> Self {
+        // Calculate the diff between two commits take time, and should calculated once per commit
+        let should_calculate_diffs = match table.as_str() {
+            "diffs" => selected_columns.iter().any(|col| {
+                col == "insertions"
+                    || col == "removals"
+                    || col == "files_changed"
+                    || col == "diff_changes"
+            }),
+            "diffs_changes" => true,
+            _ => false,
+        };
+
+        let workers_count = if should_calculate_diffs { jobs } else { 0 };
+        let workers = (0..workers_count).map(|_| DiffWorker::new(&repo)).collect();
+
+        CommitsRowsBatches {
+            repo,
+            table,
+            selected_columns,
+            commits: commits.into_iter(),
+            should_calculate_diffs,
+            workers,
+        }
+    }
+}
+
+impl Iterator for CommitsRowsBatches {
+    type Item = Result<Vec<Row>, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch_size = COMMITS_BATCH_SIZE * self.workers.len().max(1);
+        let commits: Vec<CommitInfo> = self.commits.by_ref().take(batch_size).collect();
+        if commits.is_empty() {
+            return None;
+        }
+
+        let mut commits_changes = if self.should_calculate_diffs {
+            let with_content = self.table == "diffs";
+            compute_commits_changes(&mut self.workers, &commits, with_content).into_iter()
+        } else {
+            vec![].into_iter()
+        };
+
+        let mut rows: Vec<Row> = Vec::with_capacity(commits.len());
+        for commit_info in commits.iter() {
+            let commit_changes = commits_changes.next().unwrap_or_default();
+            match self.table.as_str() {
+                "commits" => {
+                    rows.push(select_commit_row(
+                        &self.repo,
+                        commit_info,
+                        &self.selected_columns,
+                    ));
+                }
+                "diffs" => {
+                    rows.push(select_diff_row(
+                        &self.repo,
+                        commit_info,
+                        commit_changes,
+                        &self.selected_columns,
+                    ));
+                }
+                _ => {
+                    select_diff_changes_rows(
+                        &self.repo,
+                        commit_info,
+                        commit_changes,
+                        &self.selected_columns,
+                        &mut rows,
+                    );
+                }
+            }
+        }
+
+        Some(Ok(rows))
+    }
+}
+
+fn select_gql_objects_batches(
     repo: &gix::Repository,
     table: String,
-    selected_columns: &[String],
-) -> Result<Vec<Row>, String> {
+    selected_columns: Vec<String>,
+    jobs: usize,
+) -> RowsBatchIterator<'_> {
     match table.as_str() {
-        "refs" => select_references(repo, selected_columns),
-        "commits" => select_commits(repo, selected_columns),
-        "branches" => select_branches(repo, selected_columns),
-        "diffs" => select_diffs(repo, selected_columns),
-        "diffs_changes" => select_diffs_changes(repo, selected_columns),
-        "tags" => select_tags(repo, selected_columns),
-        _ => Ok(vec![Row { values: vec![] }]),
+        "refs" => Box::new(std::iter::once_with(move || {
+            select_references(repo, &selected_columns)
+        })),
+        "branches" => Box::new(std::iter::once_with(move || {
+            select_branches(repo, &selected_columns, jobs)
+        })),
+        "tags" => Box::new(std::iter::once_with(move || {
+            select_tags(repo, &selected_columns)
+        })),
+        _ => Box::new(std::iter::once(Ok(vec![Row { values: vec![] }]))),
     }
 }
 
@@ -109,94 +434,85 @@ fn select_references(
     Ok(rows)
 }
 
-fn select_commits(repo: &gix::Repository, selected_columns: &[String]) -> Result<Vec<Row>, String> {
-    let head_id = repo.head_id();
-    if let Err(error) = head_id {
-        return Err(error.to_string());
-    }
-
+fn select_commit_row(
+    repo: &gix::Repository,
+    commit_info: &CommitInfo,
+    selected_columns: &[String],
+) -> Row {
     let repo_path = repo.path().to_str().unwrap();
-    let walker = head_id.unwrap().ancestors().all().unwrap();
-    let mut rows: Vec<Row> = vec![];
-
-    for commit_info in walker {
-        let commit_info = commit_info.unwrap();
-        let commit = repo.find_object(commit_info.id).unwrap().into_commit();
-        let commit = commit.decode().unwrap();
-
-        let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(selected_columns.len());
-        for column_name in selected_columns {
-            if column_name == "commit_id" {
-                values.push(Box::new(TextValue::new(commit_info.id.to_string())));
-                continue;
-            }
-
-            if column_name == "author_name" {
-                let author_name = commit.author().name.to_string();
-                values.push(Box::new(TextValue::new(author_name)));
-                continue;
-            }
+    let commit = repo.find_object(commit_info.id).unwrap().into_commit();
+    let commit = commit.decode().unwrap();
+
+    let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(selected_columns.len());
+    for column_name in selected_columns {
+        if column_name == "commit_id" {
+            values.push(Box::new(TextValue::new(commit_info.id.to_string())));
+            continue;
+        }
 
-            if column_name == "author_email" {
-                let author_email = commit.author().email.to_string();
-                values.push(Box::new(TextValue::new(author_email)));
-                continue;
-            }
+        if column_name == "author_name" {
+            let author_name = commit.author().name.to_string();
+            values.push(Box::new(TextValue::new(author_name)));
+            continue;
+        }
 
-            if column_name == "committer_name" {
-                let committer_name = commit.committer().name.to_string();
-                values.push(Box::new(TextValue::new(committer_name)));
-                continue;
-            }
+        if column_name == "author_email" {
+            let author_email = commit.author().email.to_string();
+            values.push(Box::new(TextValue::new(author_email)));
+            continue;
+        }
 
-            if column_name == "committer_email" {
-                let committer_email = commit.committer().email.to_string();
-                values.push(Box::new(TextValue::new(committer_email)));
-                continue;
-            }
+        if column_name == "committer_name" {
+            let committer_name = commit.committer().name.to_string();
+            values.push(Box::new(TextValue::new(committer_name)));
+            continue;
+        }
 
-            if column_name == "title" {
-                let title = commit.message().summary().to_string();
-                values.push(Box::new(TextValue::new(title)));
-                continue;
-            }
+        if column_name == "committer_email" {
+            let committer_email = commit.committer().email.to_string();
+            values.push(Box::new(TextValue::new(committer_email)));
+            continue;
+        }
 
-            if column_name == "message" {
-                values.push(Box::new(TextValue::new(commit.message.to_string())));
-                continue;
-            }
+        if column_name == "title" {
+            let title = commit.message().summary().to_string();
+            values.push(Box::new(TextValue::new(title)));
+            continue;
+        }
 
-            if column_name == "datetime" {
-                let time_stamp = commit_info
-                    .commit_time
-                    .unwrap_or_else(|| commit.time().seconds);
-                values.push(Box::new(DateTimeValue::new(time_stamp)));
-                continue;
-            }
+        if column_name == "message" {
+            values.push(Box::new(TextValue::new(commit.message.to_string())));
+            continue;
+        }
 
-            if column_name == "parents_count" {
-                values.push(Box::new(IntValue::new(commit.parents.len() as i64)));
-                continue;
-            }
+        if column_name == "datetime" {
+            let time_stamp = commit_info
+                .commit_time
+                .unwrap_or_else(|| commit.time().seconds);
+            values.push(Box::new(DateTimeValue::new(time_stamp)));
+            continue;
+        }
 
-            if column_name == "repo" {
-                values.push(Box::new(TextValue::new(repo_path.to_string())));
-                continue;
-            }
+        if column_name == "parents_count" {
+            values.push(Box::new(IntValue::new(commit.parents.len() as i64)));
+            continue;
+        }
 
-            values.push(Box::new(NullValue));
+        if column_name == "repo" {
+            values.push(Box::new(TextValue::new(repo_path.to_string())));
+            continue;
         }
 
-        let row = Row { values };
-        rows.push(row);
+        values.push(Box::new(NullValue));
     }
 
-    Ok(rows)
+    Row { values }
 }
 
 fn select_branches(
     repo: &gix::Repository,
     selected_columns: &[String],
+    jobs: usize,
 ) -> Result<Vec<Row>, String> {
     let mut rows: Vec<Row> = vec![];
 
@@ -216,7 +532,23 @@ fn select_branches(
     }
 
     let head_ref = head_ref_option.unwrap();
-    for mut branch in local_and_remote_branches.flatten() {
+    let branches: Vec<gix::Reference> = local_and_remote_branches.flatten().collect();
+
+    // Counting the commits walks the history of each branch, so it's computed in parallel
+    let commits_counts = if selected_columns
+        .iter()
+        .any(|column| column == "commit_count")
+    {
+        let branches_ids: Vec<Option<gix::ObjectId>> = branches
+            .iter()
+            .map(|branch| branch.try_id().map(|id| id.detach()))
+            .collect();
+        count_branches_commits(repo, &branches_ids, jobs)
+    } else {
+        vec![]
+    };
+
+    for (branch_index, mut branch) in branches.into_iter().enumerate() {
         let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(selected_columns.len());
 
         for column_name in selected_columns {
@@ -227,13 +559,8 @@ fn select_branches(
             }
 
             if column_name == "commit_count" {
-                if let Some(id) = branch.try_id() {
-                    if let Ok(walker) = id.ancestors().all() {
-                        values.push(Box::new(IntValue::new(walker.count() as i64)));
-                        continue;
-                    }
-                }
-                values.push(Box::new(IntValue::new_zero()));
+                let commits_count = commits_counts[branch_index];
+                values.push(Box::new(IntValue::new(commits_count)));
                 continue;
             }
 
@@ -284,109 +611,169 @@ fn select_branches(
     Ok(rows)
 }
 
-fn select_diffs(repo: &gix::Repository, selected_columns: &[String]) -> Result<Vec<Row>, String> {
-    let repo = {
-        let mut repo = repo.clone();
-        repo.object_cache_size_if_unset(4 * 1024 * 1024);
-        repo
+/// Count the commits reachable from each branch, up to `jobs` branches are walked at the same time
+fn count_branches_commits(
+    repo: &gix::Repository,
+    branches_ids: &[Option<gix::ObjectId>],
+    jobs: usize,
+) -> Vec<i64> {
+    let count_commits = |repo: &gix::Repository, id: &Option<gix::ObjectId>| -> i64 {
+        id.and_then(|id| repo.rev_walk(Some(id)).all().ok())
+            .map_or(0, |walker| walker.count() as i64)
     };
 
-    let mut rewrite_cache = repo
-        .diff_resource_cache(Mode::ToGit, Default::default())
-        .unwrap();
-
-    let mut diff_cache = rewrite_cache.clone();
+    if jobs == 1 || branches_ids.len() <= 1 {
+        return branches_ids
+            .iter()
+            .map(|id| count_commits(repo, id))
+            .collect();
+    }
 
-    let should_calculate_diffs = selected_columns.iter().any(|col| {
-        col == "insertions" || col == "removals" || col == "files_changed" || col == "diff_changes"
-    });
+    let chunk_size = branches_ids.len().div_ceil(jobs);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = branches_ids
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let repo = repo.clone();
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|id| count_commits(&repo, id))
+                        .collect::<Vec<i64>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
 
+fn select_diff_row(
+    repo: &gix::Repository,
+    commit_info: &CommitInfo,
+    commit_changes: CommitChanges,
+    selected_columns: &[String],
+) -> Row {
     let repo_path = repo.path().to_str().unwrap();
-    let walker = repo.head_id().unwrap().ancestors().all().unwrap();
-    let commits_info = walker.filter_map(Result::ok);
+    let commit = repo.find_object(commit_info.id).unwrap().into_commit();
+    let commit_ref = commit.decode().unwrap();
+    let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(selected_columns.len());
+
+    let files_changed = commit_changes.files_changed;
+    let diff_changes = commit_changes.changes;
+    let (mut insertions, mut removals) = (0, 0);
+    for diff_change in diff_changes.iter() {
+        insertions += diff_change.insertions;
+        removals += diff_change.removals;
+    }
 
-    let mut rows: Vec<Row> = vec![];
+    for column_name in selected_columns {
+        if column_name == "commit_id" {
+            values.push(Box::new(TextValue::new(commit_info.id.to_string())));
+            continue;
+        }
 
-    for commit_info in commits_info.into_iter() {
-        let commit = commit_info.id().object().unwrap().into_commit();
-        let commit_ref = commit.decode().unwrap();
-        let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(selected_columns.len());
+        if column_name == "author_name" {
+            let author_name = commit_ref.author().name.to_string();
+            values.push(Box::new(TextValue::new(author_name)));
+            continue;
+        }
 
-        // Calculate the diff between two commits take time, and  should calculated once per commit
-        let (mut insertions, mut removals, mut files_changed) = (0, 0, 0);
-        let mut diff_changes: Vec<DiffChange> = vec![];
-
-        if should_calculate_diffs {
-            if let Some(parent) = commit_info
-                .parent_ids()
-                .next()
-                .map(|id| id.object().unwrap().into_commit().tree().unwrap())
-            {
-                let current = commit.tree().unwrap();
-                rewrite_cache.clear_resource_cache_keep_allocation();
-                diff_cache.clear_resource_cache_keep_allocation();
-
-                if let Ok(mut changes) = current.changes() {
-                    let _ = changes.for_each_to_obtain_tree_with_cache(
-                        &parent,
-                        &mut rewrite_cache,
-                        |change| {
-                            files_changed += usize::from(change.entry_mode().is_no_tree());
-                            let diff_change =
-                                DiffChange::new_with_content(&change, &mut diff_cache, &repo);
-                            insertions += diff_change.insertions;
-                            removals += diff_change.removals;
-                            diff_changes.push(diff_change);
-                            Ok::<_, Infallible>(Default::default())
-                        },
-                    );
-                }
-            }
+        if column_name == "author_email" {
+            let author_email = commit_ref.author().email.to_string();
+            values.push(Box::new(TextValue::new(author_email)));
+            continue;
         }
 
+        if column_name == "datetime" {
+            let time_stamp = commit_info
+                .commit_time
+                .unwrap_or_else(|| commit_ref.time().seconds);
+            values.push(Box::new(DateTimeValue::new(time_stamp)));
+            continue;
+        }
+
+        if column_name == "insertions" {
+            values.push(Box::new(IntValue::new(insertions as i64)));
+            continue;
+        }
+
+        if column_name == "removals" {
+            values.push(Box::new(IntValue::new(removals as i64)));
+            continue;
+        }
+
+        if column_name == "files_changed" {
+            values.push(Box::new(IntValue::new(files_changed as i64)));
+            continue;
+        }
+
+        if column_name == "diff_changes" {
+            values.push(Box::new(DiffChangesValue::new(diff_changes.clone())));
+            continue;
+        }
+
+        if column_name == "repo" {
+            values.push(Box::new(TextValue::new(repo_path.to_string())));
+            continue;
+        }
+
+        values.push(Box::new(NullValue));
+    }
+
+    Row { values }
+}
+
+fn select_diff_changes_rows(
+    repo: &gix::Repository,
+    commit_info: &CommitInfo,
+    commit_changes: CommitChanges,
+    selected_columns: &[String],
+    rows: &mut Vec<Row>,
+) {
+    let repo_path = repo.path().to_str().unwrap();
+    let selected_columns_len = selected_columns.len();
+    let commit = repo.find_object(commit_info.id).unwrap().into_commit();
+    let commit_ref = commit.decode().unwrap();
+
+    for diff_change in commit_changes.changes {
+        let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(selected_columns_len);
         for column_name in selected_columns {
             if column_name == "commit_id" {
                 values.push(Box::new(TextValue::new(commit_info.id.to_string())));
                 continue;
             }
 
-            if column_name == "author_name" {
-                let author_name = commit_ref.author().name.to_string();
-                values.push(Box::new(TextValue::new(author_name)));
-                continue;
-            }
-
-            if column_name == "author_email" {
-                let author_email = commit_ref.author().email.to_string();
-                values.push(Box::new(TextValue::new(author_email)));
-                continue;
-            }
-
-            if column_name == "datetime" {
-                let time_stamp = commit_info
-                    .commit_time
-                    .unwrap_or_else(|| commit_ref.time().seconds);
-                values.push(Box::new(DateTimeValue::new(time_stamp)));
+            if column_name == "insertions" {
+                values.push(Box::new(IntValue::new(diff_change.insertions as i64)));
                 continue;
             }
 
-            if column_name == "insertions" {
-                values.push(Box::new(IntValue::new(insertions as i64)));
+            if column_name == "removals" {
+                values.push(Box::new(IntValue::new(diff_change.removals as i64)));
                 continue;
             }
 
-            if column_name == "removals" {
-                values.push(Box::new(IntValue::new(removals as i64)));
+            if column_name == "mode" {
+                let mode = diff_change.kind.mode().to_string();
+                values.push(Box::new(TextValue::new(mode)));
                 continue;
             }
 
-            if column_name == "files_changed" {
-                values.push(Box::new(IntValue::new(files_changed as i64)));
+            if column_name == "path" {
+                let path = diff_change.location.to_string();
+                values.push(Box::new(TextValue::new(path)));
                 continue;
             }
 
-            if column_name == "diff_changes" {
-                values.push(Box::new(DiffChangesValue::new(diff_changes.to_owned())));
+            if column_name == "datetime" {
+                let time_stamp = commit_info
+                    .commit_time
+                    .unwrap_or_else(|| commit_ref.time().seconds);
+                values.push(Box::new(DateTimeValue::new(time_stamp)));
                 continue;
             }
 
@@ -401,109 +788,6 @@ fn select_diffs(repo: &gix::Repository, selected_columns: &[String]) -> Result<V
         let row = Row { values };
         rows.push(row);
     }
-
-    Ok(rows)
-}
-
-fn select_diffs_changes(
-    repo: &gix::Repository,
-    selected_columns: &[String],
-) -> Result<Vec<Row>, String> {
-    let repo = {
-        let mut repo = repo.clone();
-        repo.object_cache_size_if_unset(4 * 1024 * 1024);
-        repo
-    };
-
-    let mut rewrite_cache = repo
-        .diff_resource_cache(Mode::ToGit, Default::default())
-        .unwrap();
-
-    let mut diff_cache = rewrite_cache.clone();
-
-    let repo_path = repo.path().to_str().unwrap();
-    let walker = repo.head_id().unwrap().ancestors().all().unwrap();
-    let commits_info = walker.filter_map(Result::ok);
-
-    let mut rows: Vec<Row> = vec![];
-    let selected_columns_len = selected_columns.len();
-    for commit_info in commits_info.into_iter() {
-        let commit = commit_info.id().object().unwrap().into_commit();
-        let commit_ref = commit.decode().unwrap();
-
-        if let Some(parent) = commit_info
-            .parent_ids()
-            .next()
-            .map(|id| id.object().unwrap().into_commit().tree().unwrap())
-        {
-            let current = commit.tree().unwrap();
-            rewrite_cache.clear_resource_cache_keep_allocation();
-            diff_cache.clear_resource_cache_keep_allocation();
-
-            if let Ok(mut changes) = current.changes() {
-                let _ = changes.for_each_to_obtain_tree_with_cache(
-                    &parent,
-                    &mut rewrite_cache,
-                    |change| {
-                        let diff_change = DiffChange::new_without_content(&change, &mut diff_cache);
-
-                        let mut values: Vec<Box<dyn Value>> =
-                            Vec::with_capacity(selected_columns_len);
-                        for column_name in selected_columns {
-                            if column_name == "commit_id" {
-                                values.push(Box::new(TextValue::new(commit_info.id.to_string())));
-                                continue;
-                            }
-
-                            if column_name == "insertions" {
-                                values.push(Box::new(IntValue::new(diff_change.insertions as i64)));
-                                continue;
-                            }
-
-                            if column_name == "removals" {
-                                values.push(Box::new(IntValue::new(diff_change.removals as i64)));
-                                continue;
-                            }
-
-                            if column_name == "mode" {
-                                let mode = diff_change.kind.mode().to_string();
-                                values.push(Box::new(TextValue::new(mode)));
-                                continue;
-                            }
-
-                            if column_name == "path" {
-                                let path = diff_change.location.to_string();
-                                values.push(Box::new(TextValue::new(path)));
-                                continue;
-                            }
-
-                            if column_name == "datetime" {
-                                let time_stamp = commit_info
-                                    .commit_time
-                                    .unwrap_or_else(|| commit_ref.time().seconds);
-                                values.push(Box::new(DateTimeValue::new(time_stamp)));
-                                continue;
-                            }
-
-                            if column_name == "repo" {
-                                values.push(Box::new(TextValue::new(repo_path.to_string())));
-                                continue;
-                            }
-
-                            values.push(Box::new(NullValue));
-                        }
-
-                        let row = Row { values };
-                        rows.push(row);
-
-                        Ok::<_, Infallible>(Default::default())
-                    },
-                );
-            }
-        }
-    }
-
-    Ok(rows)
 }
 
 fn select_tags(repo: &gix::Repository, selected_columns: &[String]) -> Result<Vec<Row>, String> {
@@ -513,14 +797,22 @@ fn select_tags(repo: &gix::Repository, selected_columns: &[String]) -> Result<Ve
     let mut rows: Vec<Row> = vec![];
     for tag_ref in tag_names.flatten() {
         let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(selected_columns.len());
+        let tag_name = tag_ref
+            .name()
+            .category_and_short_name()
+            .map_or_else(String::default, |(_, short_name)| short_name.to_string());
 
         for column_name in selected_columns {
             if column_name == "name" {
-                let tag_name = tag_ref
-                    .name()
-                    .category_and_short_name()
-                    .map_or_else(String::default, |(_, short_name)| short_name.to_string());
-                values.push(Box::new(TextValue::new(tag_name)));
+                values.push(Box::new(TextValue::new(tag_name.to_string())));
+                continue;
+            }
+
+            if column_name == "version" {
+                match SemVer::parse(&tag_name) {
+                    Some(version) => values.push(Box::new(SemVerValue::new(version))),
+                    None => values.push(Box::new(NullValue)),
+                }
                 continue;
             }
 
@@ -538,3 +830,82 @@ fn select_tags(repo: &gix::Repository, selected_columns: &[String]) -> Result<Ve
 
     Ok(rows)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create a repository with a linear history of commits with the given messages and return the commits ids
+    fn create_repository(
+        name: &str,
+        messages: &[&str],
+    ) -> (std::path::PathBuf, Vec<gix::ObjectId>) {
+        let path = std::env::temp_dir().join(format!("gitql-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        let repo = gix::init(&path).unwrap();
+
+        let signature = gix::actor::Signature {
+            name: "GitQL".into(),
+            email: "gitql@example.com".into(),
+            time: gix::date::Time::new(1700000000, 0),
+        };
+
+        let tree = repo.empty_tree().id;
+        let mut ids: Vec<gix::ObjectId> = vec![];
+        for message in messages {
+            let commit = gix::objs::Commit {
+                tree,
+                parents: ids.last().copied().into_iter().collect(),
+                author: signature.clone(),
+                committer: signature.clone(),
+                encoding: None,
+                message: (*message).into(),
+                extra_headers: vec![],
+            };
+            ids.push(repo.write_object(&commit).unwrap().detach());
+        }
+
+        let head = format!("{}\n", ids.last().unwrap());
+        std::fs::write(path.join(".git").join("HEAD"), head).unwrap();
+        (path, ids)
+    }
+
+    fn select_titles(path: &std::path::Path, jobs: usize) -> Result<Vec<String>, String> {
+        let repo = gix::open(path).unwrap();
+        let provider = GitQLDataProvider::new(vec![repo]).with_jobs(jobs);
+        let rows = provider.provide("commits", &["title".to_string()])?;
+        Ok(rows.iter().map(|row| row.values[0].literal()).collect())
+    }
+
+    #[test]
+    fn test_commits_rows_from_repository_history() {
+        let (path, _) = create_repository("history", &["first", "second", "third"]);
+        for jobs in [1, 4] {
+            let titles = select_titles(&path, jobs);
+            assert_eq!(
+                titles,
+                Ok(vec!["third".into(), "second".into(), "first".into()])
+            );
+        }
+        std::fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn test_commits_walk_error_is_reported() {
+        let (path, ids) = create_repository("missing-parent", &["first", "second", "third"]);
+
+        // Remove the middle commit so the history walk fails instead of returning partial rows
+        let hex = ids[1].to_string();
+        let object_path = path
+            .join(".git")
+            .join("objects")
+            .join(&hex[..2])
+            .join(&hex[2..]);
+        std::fs::remove_file(object_path).unwrap();
+
+        for jobs in [1, 4] {
+            assert!(select_titles(&path, jobs).is_err());
+        }
+        std::fs::remove_dir_all(path).unwrap();
+    }
+}
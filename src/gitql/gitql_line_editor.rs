@@ -12,7 +12,7 @@ use lineeditor::Span;
 use lineeditor::StringPrompt;
 use lineeditor::Suggestion;
 
-const GITQL_RESERVED_KEYWORDS: [&str; 57] = [
+const GITQL_RESERVED_KEYWORDS: [&str; 68] = [
     "do",
     "set",
     "select",
@@ -37,6 +37,12 @@ const GITQL_RESERVED_KEYWORDS: [&str; 57] = [
     "glob",
     "describe",
     "show",
+    "explain",
+    "analyze",
+    "create",
+    "function",
+    "view",
+    "returns",
     "regexp",
     "into",
     "outfile",
@@ -51,9 +57,14 @@ const GITQL_RESERVED_KEYWORDS: [&str; 57] = [
     "cross",
     "inner",
     "outer",
+    "full",
+    "natural",
+    "unnest",
+    "ordinality",
     "group",
     "by",
     "having",
+    "qualify",
     "with",
     "rollup",
     "div",
@@ -4,6 +4,7 @@ use std::sync::OnceLock;
 use gitql_ast::types::boolean::BoolType;
 use gitql_ast::types::datetime::DateTimeType;
 use gitql_ast::types::integer::IntType;
+use gitql_ast::types::semver::SemVerType;
 use gitql_ast::types::text::TextType;
 use gitql_ast::types::DataType;
 
@@ -34,6 +35,7 @@ pub fn tables_fields_types() -> HashMap<&'static str, Box<dyn DataType>> {
     map.insert("path", Box::new(TextType));
     map.insert("mode", Box::new(TextType));
     map.insert("repo", Box::new(TextType));
+    map.insert("version", Box::new(SemVerType));
     map
 }
 
@@ -93,7 +95,7 @@ pub fn tables_fields_names() -> &'static HashMap<&'static str, Vec<&'static str>
                 "repo",
             ],
         );
-        map.insert("tags", vec!["name", "repo"]);
+        map.insert("tags", vec!["name", "version", "repo"]);
         map
     })
 }
@@ -4,6 +4,10 @@ use gitql_schema::tables_fields_names;
 use gitql_schema::tables_fields_types;
 use gitql_std::aggregation::aggregation_function_signatures;
 use gitql_std::aggregation::aggregation_functions;
+use gitql_std::aggregation::fallible_aggregation_functions;
+use gitql_std::standard::fallible_functions;
+use gitql_std::standard::higher_order_functions;
+use gitql_std::window::fallible_window_functions;
 use gitql_std::window::window_function_signatures;
 use gitql_std::window::window_functions;
 
@@ -31,8 +35,12 @@ pub(crate) fn create_gitql_environment() -> Environment {
 
     let mut env = Environment::new(schema);
     env.with_standard_functions(&std_signatures, std_functions);
+    env.with_fallible_functions(fallible_functions());
+    env.with_higher_order_functions(higher_order_functions());
     env.with_aggregation_functions(&aggregation_signatures, aggregation_functions);
+    env.with_fallible_aggregation_functions(fallible_aggregation_functions());
     env.with_window_functions(&window_signatures, window_function);
+    env.with_fallible_window_functions(fallible_window_functions());
     env
 }
 
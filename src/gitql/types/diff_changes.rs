@@ -1,5 +1,8 @@
 use std::any::Any;
 
+use gitql_ast::types::composite::CompositeType;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::text::TextType;
 use gitql_ast::types::DataType;
 
 #[derive(Clone)]
@@ -21,4 +24,26 @@ impl DataType for DiffChangesType {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn can_perform_unnest_op(&self) -> bool {
+        true
+    }
+
+    fn unnest_op_result_type(&self) -> Box<dyn DataType> {
+        Box::new(DiffChangeType::composite())
+    }
+}
+
+/// Composite type of each change expanded from [`DiffChangesType`] using `UNNEST`,
+/// with the same members as the `diffs_changes` table columns
+pub struct DiffChangeType;
+
+impl DiffChangeType {
+    pub fn composite() -> CompositeType {
+        CompositeType::empty("DiffChange".to_string())
+            .add_member("path".to_string(), Box::new(TextType))
+            .add_member("mode".to_string(), Box::new(TextType))
+            .add_member("insertions".to_string(), Box::new(IntType))
+            .add_member("removals".to_string(), Box::new(IntType))
+    }
 }
@@ -2,6 +2,9 @@ use std::any::Any;
 use std::cmp::Ordering;
 
 use gitql_ast::types::DataType;
+use gitql_core::values::composite::CompositeValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::text::TextValue;
 use gitql_core::values::Value;
 use gix::diff::blob::Platform;
 use gix::object::tree::diff::Change;
@@ -150,4 +153,21 @@ impl Value for DiffChangesValue {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn unnest_op(&self) -> Result<Vec<Box<dyn Value>>, String> {
+        let mut changes: Vec<Box<dyn Value>> = Vec::with_capacity(self.changes.len());
+        for change in self.changes.iter() {
+            let path = TextValue::new(change.location.to_string());
+            let mode = TextValue::new(change.kind.mode().to_string());
+            let insertions = IntValue::new(change.insertions as i64);
+            let removals = IntValue::new(change.removals as i64);
+            let composite = CompositeValue::empty("DiffChange".to_string())
+                .add_member("path".to_string(), Box::new(path))
+                .add_member("mode".to_string(), Box::new(mode))
+                .add_member("insertions".to_string(), Box::new(insertions))
+                .add_member("removals".to_string(), Box::new(removals));
+            changes.push(Box::new(composite));
+        }
+        Ok(changes)
+    }
 }
@@ -9,6 +9,7 @@ use gitql_cli::arguments;
 use gitql_cli::arguments::Arguments;
 use gitql_cli::arguments::Command;
 use gitql_cli::arguments::OutputFormat;
+use gitql_cli::arguments::Parameter;
 use gitql_cli::diagnostic_reporter;
 use gitql_cli::diagnostic_reporter::DiagnosticReporter;
 use gitql_cli::printer::base::OutputPrinter;
@@ -16,9 +17,16 @@ use gitql_cli::printer::csv_printer::CSVPrinter;
 use gitql_cli::printer::json_printer::JSONPrinter;
 use gitql_cli::printer::table_printer::TablePrinter;
 use gitql_core::environment::Environment;
+use gitql_core::values::boolean::BoolValue;
+use gitql_core::values::float::FloatValue;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::text::TextValue;
+use gitql_core::values::Value;
 use gitql_engine::data_provider::DataProvider;
 use gitql_engine::engine;
 use gitql_engine::engine::EvaluationResult::SelectedGroups;
+use gitql_engine::engine::StreamEvaluationResult::Evaluated;
+use gitql_engine::engine::StreamEvaluationResult::SelectedRowsStream;
 use gitql_parser::diagnostic::Diagnostic;
 use gitql_parser::parser;
 use gitql_parser::tokenizer::Tokenizer;
@@ -52,6 +60,12 @@ fn main() {
 
             let repos = git_repos_result.ok().unwrap();
             let mut env = create_gitql_environment();
+            if let Err(error) = bind_parameters(&arguments, &mut env) {
+                reporter.report_diagnostic("", Diagnostic::error(&error));
+                return;
+            }
+
+            execute_init_script(&arguments, &repos, &mut env, &mut reporter);
             let query =
                 fs::read_to_string(script_file).expect("Should have been able to read the file");
             execute_gitql_query(query, &arguments, &repos, &mut env, &mut reporter);
@@ -69,6 +83,12 @@ fn main() {
 
             let repos = git_repos_result.ok().unwrap();
             let mut env = create_gitql_environment();
+            if let Err(error) = bind_parameters(&arguments, &mut env) {
+                reporter.report_diagnostic(&query, Diagnostic::error(&error));
+                return;
+            }
+
+            execute_init_script(&arguments, &repos, &mut env, &mut reporter);
 
             execute_gitql_query(query, &arguments, &repos, &mut env, &mut reporter);
         }
@@ -98,6 +118,13 @@ fn launch_gitql_repl(arguments: &Arguments) {
 
     let git_repositories = git_repos_result.ok().unwrap();
     let mut global_env = create_gitql_environment();
+    if let Err(error) = bind_parameters(arguments, &mut global_env) {
+        reporter.report_diagnostic("", Diagnostic::error(&error));
+        return;
+    }
+
+    execute_init_script(arguments, &git_repositories, &mut global_env, &mut reporter);
+    global_env.clear_session();
 
     // Launch the right line editor if the flag is enabled
     // Later this line editor will be the default editor
@@ -173,6 +200,76 @@ fn launch_gitql_repl(arguments: &Arguments) {
     }
 }
 
+/// Bind the parameters values passed from the arguments, if the type is not set it's inferred from the literal
+fn bind_parameters(arguments: &Arguments, env: &mut Environment) -> Result<(), String> {
+    for parameter in arguments.parameters.iter() {
+        let literal = &parameter.value;
+        let value: Box<dyn Value> = match parameter.type_name.as_deref() {
+            Some("int") => match literal.parse::<i64>() {
+                Ok(integer) => Box::new(IntValue::new(integer)),
+                Err(_) => return Err(invalid_parameter_value(parameter)),
+            },
+            Some("float") => match literal.parse::<f64>() {
+                Ok(float) => Box::new(FloatValue::new(float)),
+                Err(_) => return Err(invalid_parameter_value(parameter)),
+            },
+            Some("bool") => match literal.to_lowercase().parse::<bool>() {
+                Ok(boolean) => Box::new(BoolValue::new(boolean)),
+                Err(_) => return Err(invalid_parameter_value(parameter)),
+            },
+            Some(_) => Box::new(TextValue::new(literal.to_string())),
+            None => infer_parameter_value(literal),
+        };
+
+        env.bind_parameter(parameter.name.to_string(), value);
+    }
+    Ok(())
+}
+
+fn infer_parameter_value(literal: &str) -> Box<dyn Value> {
+    if let Ok(integer) = literal.parse::<i64>() {
+        Box::new(IntValue::new(integer))
+    } else if let Ok(float) = literal.parse::<f64>() {
+        Box::new(FloatValue::new(float))
+    } else if literal.eq_ignore_ascii_case("true") || literal.eq_ignore_ascii_case("false") {
+        Box::new(BoolValue::new(literal.eq_ignore_ascii_case("true")))
+    } else {
+        Box::new(TextValue::new(literal.to_string()))
+    }
+}
+
+fn invalid_parameter_value(parameter: &Parameter) -> String {
+    format!(
+        "Parameter `{}` value `{}` is not a valid `{}`",
+        parameter.name,
+        parameter.value,
+        parameter.type_name.as_deref().unwrap_or_default()
+    )
+}
+
+/// Execute the init script if passed to register the definitions before the session
+fn execute_init_script(
+    arguments: &Arguments,
+    repos: &[gix::Repository],
+    env: &mut Environment,
+    reporter: &mut DiagnosticReporter,
+) {
+    let Some(init_script) = &arguments.init_script else {
+        return;
+    };
+
+    match fs::read_to_string(init_script) {
+        Ok(script) => execute_gitql_query(script, arguments, repos, env, reporter),
+        Err(error) => reporter.report_diagnostic(
+            "",
+            Diagnostic::error(&format!(
+                "Can't read init script `{}`: {}",
+                init_script, error
+            )),
+        ),
+    }
+}
+
 fn execute_gitql_query(
     query: String,
     arguments: &Arguments,
@@ -203,35 +300,62 @@ fn execute_gitql_query(
     let query_node = parser_result.ok().unwrap();
     let front_duration = front_start.elapsed();
 
-    let engine_start = std::time::Instant::now();
-    let provider: Box<dyn DataProvider> = Box::new(GitQLDataProvider::new(repos.to_vec()));
-    let evaluation_result = engine::evaluate(env, &provider, query_node);
-    let engine_duration = engine_start.elapsed();
-
-    // Report Runtime exceptions if they exists
-    if evaluation_result.is_err() {
-        let exception = Diagnostic::exception(&evaluation_result.err().unwrap());
-        reporter.report_diagnostic("", exception);
-        return;
-    }
-
-    let printer: Box<dyn OutputPrinter> = match arguments.output_format {
+    let provider: Box<dyn DataProvider> =
+        Box::new(GitQLDataProvider::new(repos.to_vec()).with_jobs(arguments.jobs));
+    let mut printer: Box<dyn OutputPrinter> = match arguments.output_format {
         OutputFormat::Render => {
             Box::new(TablePrinter::new(arguments.pagination, arguments.page_size))
         }
-        OutputFormat::JSON => Box::new(JSONPrinter {}),
-        OutputFormat::CSV => Box::new(CSVPrinter {}),
+        OutputFormat::JSON => Box::<JSONPrinter>::default(),
+        OutputFormat::CSV => Box::<CSVPrinter>::default(),
     };
 
-    // Render the result only if they are selected groups not any other statement
-    let evaluations_results = evaluation_result.ok().unwrap();
-    for evaluation_result in evaluations_results {
+    for query in query_node {
+        let engine_start = std::time::Instant::now();
+        let evaluation_result = engine::evaluate_as_stream(env, &provider, query);
+        let mut engine_duration = engine_start.elapsed();
+
+        // Report Runtime exceptions if they exists
+        if evaluation_result.is_err() {
+            let exception = Diagnostic::exception(&evaluation_result.err().unwrap());
+            reporter.report_diagnostic("", exception);
+            return;
+        }
+
+        // Render the result only if they are selected rows not any other statement
         let mut rows_count = 0;
-        if let SelectedGroups(mut groups) = evaluation_result {
-            if !groups.is_empty() {
-                rows_count += groups.groups[0].len();
-                printer.print(&mut groups);
+        match evaluation_result.ok().unwrap() {
+            SelectedRowsStream(mut stream) => {
+                printer.start(&stream.titles);
+                loop {
+                    // Rows are loaded and executed only when the next batch is requested
+                    let batch_start = std::time::Instant::now();
+                    let batch_result = stream.next();
+                    engine_duration += batch_start.elapsed();
+
+                    match batch_result {
+                        Some(Ok(rows)) => {
+                            rows_count += rows.len();
+                            printer.print_rows(&rows);
+                        }
+                        Some(Err(error)) => {
+                            printer.finish();
+                            let exception = Diagnostic::exception(&error);
+                            reporter.report_diagnostic("", exception);
+                            return;
+                        }
+                        None => break,
+                    }
+                }
+                printer.finish();
+            }
+            Evaluated(SelectedGroups(mut groups)) => {
+                if !groups.is_empty() {
+                    rows_count += groups.groups[0].len();
+                    printer.print(&mut groups);
+                }
             }
+            Evaluated(_) => {}
         }
 
         if arguments.analysis {